@@ -0,0 +1,269 @@
+//! Automatic selection of the encoding of a block.
+//!
+//! Blocks compressed with [compress_auto] are prefixed with a one byte header recording the
+//! encoding and bit length that were used, so they can be decompressed with [decompress_auto]
+//! without the caller having to track either.
+//!
+//! The header holds `encoding * 65 + compressed_bit_length`, where the encoding is `0` for
+//! plain, `1` for Delta and `2` for Delta-1 encoding, which leaves room for every bit length
+//! from `0` up to and including `64`.
+use crate::{CompressibleArray, CompressionDetails, X128};
+
+/// The number of bytes of the header written before the compressed block.
+pub const AUTO_HEADER_LEN: usize = 1;
+
+/// The number of header values each encoding spans, one for every bit length from `0` to `64`.
+const ENCODING_STRIDE: u8 = 65;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The encodings [compress_auto] selects between.
+enum Encoding {
+    Plain = 0,
+    Delta = 1,
+    Delta1 = 2,
+}
+
+impl Encoding {
+    const ALL: [Self; 3] = [Self::Plain, Self::Delta, Self::Delta1];
+
+    fn header(self, compressed_bit_length: u8) -> u8 {
+        self as u8 * ENCODING_STRIDE + compressed_bit_length
+    }
+
+    fn from_header(header: u8) -> Option<(Self, u8)> {
+        let encoding = Self::ALL.get((header / ENCODING_STRIDE) as usize)?;
+        Some((*encoding, header % ENCODING_STRIDE))
+    }
+}
+
+/// Apply bitpacking compression to the provided input with whichever of the plain, Delta and
+/// Delta-1 encodings produces the smallest output, writing a one byte header describing the
+/// selected encoding followed by the compressed block to `out`.
+///
+/// `initial_value` is only used by the Delta encodings. Unsigned inputs which are not sorted
+/// wrap around when Delta encoded and so fall back to the plain encoding, ties are broken in
+/// favour of the plain encoding followed by Delta encoding.
+///
+/// `out` must hold at least [AUTO_HEADER_LEN] + `A::MAX_OUTPUT_SIZE` bytes.
+///
+/// The returned [CompressionDetails::bytes_written] includes the header and
+/// [CompressionDetails::last_value] is always set so it can be used as the `initial_value`
+/// of the next block.
+pub fn compress_auto<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &A,
+    out: &mut [u8],
+) -> CompressionDetails
+where
+    A: CompressibleArray,
+{
+    assert!(n <= X128, "provided n is is greater than 128");
+    assert!(
+        out.len() >= AUTO_HEADER_LEN + A::MAX_OUTPUT_SIZE,
+        "output buffer is too small to hold the header and compressed block",
+    );
+
+    let bit_lengths = A::encoding_bit_lengths(initial_value, n, input);
+    let encoding = Encoding::ALL
+        .into_iter()
+        .min_by_key(|encoding| bit_lengths[*encoding as usize])
+        .unwrap();
+
+    let (header, body) = out.split_at_mut(AUTO_HEADER_LEN);
+    let body = A::compressed_buffer_mut(body);
+    let details = match encoding {
+        Encoding::Plain => CompressionDetails {
+            last_value: A::last_value(initial_value, n, input),
+            ..A::compress(n, input, body)
+        },
+        Encoding::Delta => A::compress_delta_ref(initial_value, n, input, body),
+        Encoding::Delta1 => A::compress_delta1_ref(initial_value, n, input, body),
+    };
+    header[0] = encoding.header(details.compressed_bit_length);
+
+    CompressionDetails {
+        bytes_written: AUTO_HEADER_LEN + details.bytes_written,
+        ..details
+    }
+}
+
+/// Decompress a block written by [compress_auto], reversing whichever encoding the header
+/// records and writing the recovered values to `out`.
+///
+/// - `initial_value` must be the value provided when compressing the block.
+/// - `n` should be the number of elements that the compressed buffer holds.
+///
+/// The input does not need to be padded, it only needs to hold the header and the
+/// compressed block as reported by [CompressionDetails::bytes_written].
+///
+/// Returns the number of bytes read from the input, including the header.
+pub fn decompress_auto<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    let (header, body) = input
+        .split_first()
+        .expect("input buffer is too small to hold the header");
+    let (encoding, compressed_bit_length) =
+        Encoding::from_header(*header).expect("invalid auto encoding header");
+
+    let bytes_read = match encoding {
+        Encoding::Plain => crate::decompress_unpadded(n, compressed_bit_length, body, out),
+        Encoding::Delta => {
+            crate::decompress_delta_unpadded(initial_value, n, compressed_bit_length, body, out)
+        },
+        Encoding::Delta1 => {
+            crate::decompress_delta1_unpadded(initial_value, n, compressed_bit_length, body, out)
+        },
+    };
+    AUTO_HEADER_LEN + bytes_read
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    const MAX_OUTPUT_LEN: usize = AUTO_HEADER_LEN + X128 * size_of::<u64>();
+
+    fn assert_roundtrip<A, T>(
+        initial_value: A::InitialValue,
+        n: usize,
+        input: &A,
+        mut decompressed: A,
+    ) -> Encoding
+    where
+        A: CompressibleArray + AsRef<[T]>,
+        A::InitialValue: Copy,
+        T: PartialEq + core::fmt::Debug,
+    {
+        let mut compressed = [0; MAX_OUTPUT_LEN];
+        let details = compress_auto(initial_value, n, input, &mut compressed);
+        assert_eq!(details.last_value, A::last_value(initial_value, n, input));
+
+        let read = decompress_auto(
+            initial_value,
+            n,
+            &compressed[..details.bytes_written],
+            &mut decompressed,
+        );
+        assert_eq!(read, details.bytes_written);
+        assert_eq!(decompressed.as_ref()[..n], input.as_ref()[..n]);
+
+        let (encoding, nbits) = Encoding::from_header(compressed[0]).unwrap();
+        assert_eq!(nbits, details.compressed_bit_length);
+        encoding
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(7)]
+    #[case::partial_right(100)]
+    #[case::full(X128)]
+    fn test_sample_data_roundtrip(#[case] n: usize) {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        let mut initial_value = 0;
+        for sample in sample_data.iter() {
+            let details = compress_auto(initial_value, n, sample, &mut compressed);
+            let mut delta_compressed = [0; X128_MAX_OUTPUT_LEN];
+            let delta = crate::compress_delta_ref(initial_value, n, sample, &mut delta_compressed);
+            assert!(details.compressed_bit_length <= delta.compressed_bit_length);
+            assert!(details.bytes_written <= AUTO_HEADER_LEN + delta.bytes_written);
+
+            let read = decompress_auto(
+                initial_value,
+                n,
+                &compressed[..details.bytes_written],
+                &mut decompressed,
+            );
+            assert_eq!(read, details.bytes_written);
+            assert_eq!(decompressed[..n], sample[..n]);
+
+            initial_value = details.last_value as u32;
+        }
+    }
+
+    #[test]
+    fn test_selects_smallest_encoding() {
+        let sorted: [u32; X128] = core::array::from_fn(|i| 1_000_000 + i as u32 * 3);
+        let encoding = assert_roundtrip(999_999, X128, &sorted, [0; X128]);
+        assert_eq!(encoding, Encoding::Delta);
+
+        let monotonic: [u32; X128] = core::array::from_fn(|i| 1_000_000 + i as u32);
+        let encoding = assert_roundtrip(999_999, X128, &monotonic, [0; X128]);
+        assert_eq!(encoding, Encoding::Delta1);
+
+        let mut unsorted = sorted;
+        unsorted.swap(10, 11);
+        let encoding = assert_roundtrip(999_999, X128, &unsorted, [0; X128]);
+        assert_eq!(encoding, Encoding::Plain);
+
+        let small: [u32; X128] = core::array::from_fn(|i| (i % 2) as u32);
+        let encoding = assert_roundtrip(0, X128, &small, [0; X128]);
+        assert_eq!(encoding, Encoding::Plain);
+    }
+
+    #[test]
+    fn test_all_element_types() {
+        let values: [u8; X128] = core::array::from_fn(|i| i as u8 + 1);
+        assert_eq!(
+            assert_roundtrip(0, X128, &values, [0; X128]),
+            Encoding::Delta1
+        );
+        let values: [u16; X128] = core::array::from_fn(|i| i as u16 * 100);
+        assert_eq!(assert_roundtrip(0, 65, &values, [0; X128]), Encoding::Delta);
+        let values: [i16; X128] = core::array::from_fn(|i| 50 - i as i16);
+        assert_eq!(
+            assert_roundtrip(51, X128, &values, [0; X128]),
+            Encoding::Delta
+        );
+        let values: [i32; X128] = core::array::from_fn(|i| i as i32 * -7);
+        assert_eq!(
+            assert_roundtrip(0, 100, &values, [0; X128]),
+            Encoding::Delta
+        );
+        let values: [u64; X128] = core::array::from_fn(|i| u64::MAX - i as u64);
+        assert_eq!(
+            assert_roundtrip(0, X128, &values, [0; X128]),
+            Encoding::Plain
+        );
+    }
+
+    #[test]
+    fn test_full_width_header() {
+        let values = [u64::MAX; X128];
+        let mut compressed = [0; MAX_OUTPUT_LEN];
+        let details = compress_auto(0, X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 64);
+        assert_eq!(compressed[0], 64);
+
+        let values = [u64::MAX; X128];
+        let details = compress_auto(u64::MAX - 1, X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 1);
+        assert_eq!(compressed[0], Encoding::Delta.header(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid auto encoding header")]
+    fn test_invalid_header() {
+        let mut out = [0u32; X128];
+        decompress_auto(0, X128, &[3 * ENCODING_STRIDE], &mut out);
+    }
+
+    #[test]
+    #[should_panic(expected = "output buffer is too small")]
+    fn test_output_too_small() {
+        let mut compressed = [0; X128 * size_of::<u32>()];
+        compress_auto(0, X128, &[0u32; X128], &mut compressed);
+    }
+}
@@ -0,0 +1,476 @@
+use crate::{Backend, CompressibleArray, CompressionDetails, Error};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// A handle to the compression routines pinned to a specific [Backend].
+///
+/// The free functions and [CompressibleArray] methods always use the best backend the
+/// runtime CPU supports, a `Codec` allows selecting the backend explicitly, e.g. to compare
+/// backends or to avoid AVX512 on hosts where the frequency drop outweighs the speedup.
+///
+/// ```
+/// use upack::{Backend, Codec, X128};
+///
+/// let codec = Codec::with_backend(Backend::Scalar).unwrap();
+///
+/// let values = [3u32; X128];
+/// let mut compressed = [0; upack::uint32::X128_MAX_OUTPUT_LEN];
+/// let details = codec.compress(X128, &values, &mut compressed);
+///
+/// let mut decompressed = [0; X128];
+/// codec.decompress(X128, details.compressed_bit_length, &compressed, &mut decompressed);
+/// assert_eq!(decompressed, values);
+/// ```
+pub struct Codec {
+    backend: Backend,
+}
+
+impl Default for Codec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Codec {
+    #[inline]
+    /// Create a new codec using the best backend the runtime CPU supports.
+    pub fn new() -> Self {
+        Self {
+            backend: Backend::detect(),
+        }
+    }
+
+    /// Create a new codec using the provided backend.
+    ///
+    /// Returns [Error::UnsupportedBackend] if the backend is not compiled in or
+    /// the runtime CPU does not support it.
+    pub fn with_backend(backend: Backend) -> Result<Self, Error> {
+        if !backend.is_supported() {
+            return Err(Error::UnsupportedBackend { backend });
+        }
+        Ok(Self { backend })
+    }
+
+    #[inline]
+    /// Returns the backend used by the codec.
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Apply bitpacking compression to the provided input.
+    ///
+    /// See [crate::compress] for more details.
+    pub fn compress<A>(
+        &self,
+        n: usize,
+        input: &A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_with(*self, n, input, out)
+    }
+
+    /// Apply bitpacking compression to the provided input after first
+    /// applying Delta encoding to the array.
+    ///
+    /// See [crate::compress_delta] for more details.
+    pub fn compress_delta<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        input: &mut A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_delta_with(*self, initial_value, n, input, out)
+    }
+
+    /// Apply bitpacking compression to the provided input after first
+    /// applying Delta-1 encoding to the array.
+    ///
+    /// See [crate::compress_delta1] for more details.
+    pub fn compress_delta1<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        input: &mut A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_delta1_with(*self, initial_value, n, input, out)
+    }
+
+    /// Apply bitpacking compression to the provided input after applying Delta encoding,
+    /// without modifying the input.
+    ///
+    /// See [crate::compress_delta_ref] for more details.
+    pub fn compress_delta_ref<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        input: &A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_delta_ref_with(*self, initial_value, n, input, out)
+    }
+
+    /// Apply bitpacking compression to the provided input after applying Delta-1 encoding,
+    /// without modifying the input.
+    ///
+    /// See [crate::compress_delta1_ref] for more details.
+    pub fn compress_delta1_ref<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        input: &A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_delta1_ref_with(*self, initial_value, n, input, out)
+    }
+
+    /// Apply bitpacking compression to the provided input after first subtracting
+    /// the smallest of the selected values from every value.
+    ///
+    /// See [crate::compress_for] for more details.
+    pub fn compress_for<A>(
+        &self,
+        n: usize,
+        input: &A,
+        out: &mut A::CompressedBuffer,
+    ) -> CompressionDetails
+    where
+        A: CompressibleArray,
+    {
+        A::compress_for_with(*self, n, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, writing the decompressed
+    /// values to `out`.
+    ///
+    /// See [crate::decompress] for more details.
+    pub fn decompress<A>(
+        &self,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        A::decompress_with(*self, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, reverse the Delta encoding
+    /// and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_delta] for more details.
+    pub fn decompress_delta<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        A::decompress_delta_with(*self, initial_value, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, reverse the Delta-1 encoding
+    /// and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_delta1] for more details.
+    pub fn decompress_delta1<A>(
+        &self,
+        initial_value: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        A::decompress_delta1_with(*self, initial_value, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, add back the frame of
+    /// reference and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_for] for more details.
+    pub fn decompress_for<A>(
+        &self,
+        reference: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        A::decompress_for_with(*self, reference, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, add `base` to each value
+    /// and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_with_offset] for more details.
+    pub fn decompress_with_offset<A>(
+        &self,
+        base: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        A::decompress_with_offset_with(*self, base, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, reverse the Delta encoding,
+    /// add `base` to each value and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_delta_with_offset] for more details.
+    pub fn decompress_delta_with_offset<A>(
+        &self,
+        initial_value: A::InitialValue,
+        base: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        let initial_value = A::wrapping_offset(initial_value, base);
+        A::decompress_delta_with(*self, initial_value, n, compressed_bit_length, input, out)
+    }
+
+    /// Decompress the input block containing the packed values, reverse the Delta-1 encoding,
+    /// add `base` to each value and then writing the decompressed values to `out`.
+    ///
+    /// See [crate::decompress_delta1_with_offset] for more details.
+    pub fn decompress_delta1_with_offset<A>(
+        &self,
+        initial_value: A::InitialValue,
+        base: A::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut A,
+    ) -> usize
+    where
+        A: CompressibleArray,
+    {
+        let initial_value = A::wrapping_offset(initial_value, base);
+        A::decompress_delta1_with(*self, initial_value, n, compressed_bit_length, input, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X128;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    const ALL_BACKENDS: [Backend; 6] = [
+        Backend::Scalar,
+        Backend::Sse41,
+        Backend::Avx2,
+        Backend::Avx512,
+        Backend::Neon,
+        Backend::WasmSimd128,
+    ];
+
+    #[test]
+    fn test_backends_are_interchangeable() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+        let scalar = Codec::with_backend(Backend::Scalar).unwrap();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for backend in ALL_BACKENDS.into_iter().filter(|b| b.is_supported()) {
+            let codec = Codec::with_backend(backend).unwrap();
+            assert_eq!(codec.backend(), backend);
+
+            for select_n in [1, 7, 64, 65, 100, X128] {
+                for sample in sample_data.iter() {
+                    let details =
+                        codec.compress_delta(0, select_n, &mut { *sample }, &mut compressed);
+
+                    for decoder in [codec, scalar] {
+                        decompressed.fill(0);
+                        let read = decoder.decompress_delta(
+                            0,
+                            select_n,
+                            details.compressed_bit_length,
+                            &compressed,
+                            &mut decompressed,
+                        );
+                        assert_eq!(read, details.bytes_written);
+                        assert_eq!(
+                            decompressed[..select_n],
+                            sample[..select_n],
+                            "backend:{backend:?} decoder:{:?}",
+                            decoder.backend(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decompress_with_offset_all_backends() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+        let base = 1 << 30;
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for backend in ALL_BACKENDS.into_iter().filter(|b| b.is_supported()) {
+            let codec = Codec::with_backend(backend).unwrap();
+
+            for select_n in [1, 37, 64, 100, X128] {
+                for sample in sample_data.iter() {
+                    let expected = sample.map(|v| v.wrapping_add(base));
+
+                    let details = codec.compress(select_n, sample, &mut compressed);
+                    codec.decompress_with_offset(
+                        base,
+                        select_n,
+                        details.compressed_bit_length,
+                        &compressed,
+                        &mut decompressed,
+                    );
+                    assert_eq!(decompressed[..select_n], expected[..select_n]);
+
+                    let details =
+                        codec.compress_delta(0, select_n, &mut { *sample }, &mut compressed);
+                    codec.decompress_delta_with_offset(
+                        0,
+                        base,
+                        select_n,
+                        details.compressed_bit_length,
+                        &compressed,
+                        &mut decompressed,
+                    );
+                    assert_eq!(
+                        decompressed[..select_n],
+                        expected[..select_n],
+                        "backend:{backend:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_delta_ref_chains_blocks() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for backend in ALL_BACKENDS.into_iter().filter(|b| b.is_supported()) {
+            let codec = Codec::with_backend(backend).unwrap();
+
+            for select_n in [0, 1, 7, 64, 65, 100, X128] {
+                let mut initial_value = 0;
+                for sample in sample_data.iter() {
+                    let block = *sample;
+                    let details =
+                        codec.compress_delta_ref(initial_value, select_n, &block, &mut compressed);
+                    assert_eq!(&block, sample, "input should not be modified");
+
+                    let expected = codec.compress_delta(
+                        initial_value,
+                        select_n,
+                        &mut { block },
+                        &mut [0; X128_MAX_OUTPUT_LEN],
+                    );
+                    assert_eq!(
+                        details.compressed_bit_length,
+                        expected.compressed_bit_length
+                    );
+                    assert_eq!(details.bytes_written, expected.bytes_written);
+
+                    let last_value = select_n.checked_sub(1).map_or(initial_value, |i| block[i]);
+                    assert_eq!(details.last_value, last_value as u64, "backend:{backend:?}");
+
+                    codec.decompress_delta(
+                        initial_value,
+                        select_n,
+                        details.compressed_bit_length,
+                        &compressed,
+                        &mut decompressed,
+                    );
+                    assert_eq!(decompressed[..select_n], block[..select_n]);
+
+                    initial_value = details.last_value as u32;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_delta1_ref_signed_last_value() {
+        let block: [i32; X128] = core::array::from_fn(|i| i as i32 - 100);
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for backend in ALL_BACKENDS.into_iter().filter(|b| b.is_supported()) {
+            let codec = Codec::with_backend(backend).unwrap();
+
+            let details = codec.compress_delta1_ref(-101, 50, &block, &mut compressed);
+            assert_eq!(details.last_value as i32, -51, "backend:{backend:?}");
+            assert_eq!(
+                details.last_value as i64, -51,
+                "value should be sign extended"
+            );
+
+            codec.decompress_delta1(
+                -101,
+                50,
+                details.compressed_bit_length,
+                &compressed,
+                &mut decompressed,
+            );
+            assert_eq!(decompressed[..50], block[..50]);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_backend() {
+        for backend in ALL_BACKENDS {
+            match Codec::with_backend(backend) {
+                Ok(codec) => assert!(backend.is_supported() && codec.backend() == backend),
+                Err(err) => {
+                    assert!(!backend.is_supported());
+                    assert_eq!(err, Error::UnsupportedBackend { backend });
+                },
+            }
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        assert!(Codec::with_backend(Backend::Neon).is_err());
+        #[cfg(target_arch = "aarch64")]
+        assert!(Codec::with_backend(Backend::Avx2).is_err());
+    }
+}
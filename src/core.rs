@@ -1,18 +1,50 @@
+use crate::{Codec, padded_len};
+
 #[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
 /// Information about the compressed block.
+///
+/// This is only constructed by the compression routines, new fields may be added
+/// without a breaking release.
 pub struct CompressionDetails {
     /// The bit length of the compressed values.
     pub compressed_bit_length: u8,
     /// The number of bytes written to the `output`.
     pub bytes_written: usize,
+    /// The frame of reference subtracted from each value before packing.
+    ///
+    /// This is only set by [CompressibleArray::compress_for] and is `0` otherwise.
+    /// The value is widened to a `u64`, casting it back to the element type with `as`
+    /// recovers the original reference, including for signed integers.
+    pub reference: u64,
+    /// The last of the selected values before encoding, to be used as the `initial_value`
+    /// of the next block.
+    ///
+    /// This is only set by the Delta and Delta-1 routines and is `0` for every other
+    /// encoding. When `n` is `0` no values are selected and it is the provided
+    /// `initial_value` instead. The value is widened to a `u64` in the same way as
+    /// [CompressionDetails::reference].
+    pub last_value: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The number of bytes a block compresses to with each of the encodings, as returned by
+/// the `encoding_costs` estimators.
+pub struct EncodingCosts {
+    /// The bytes written by [CompressibleArray::compress].
+    pub plain: usize,
+    /// The bytes written by [CompressibleArray::compress_delta].
+    pub delta: usize,
+    /// The bytes written by [CompressibleArray::compress_delta1].
+    pub delta1: usize,
 }
 
 /// An array of values that can be compressed.
 pub trait CompressibleArray {
     /// The output array to have the compressed output written to.
     type CompressedBuffer;
-    /// The type of the initial value.
-    type InitialValue;
+    /// The type of the initial value or frame of reference.
+    type InitialValue: Copy;
 
     /// The maximum number of bytes that can be written to the output
     const MAX_OUTPUT_SIZE: usize;
@@ -21,30 +53,180 @@ pub trait CompressibleArray {
     ///
     /// `n` should be the number of elements to select from the input
     /// to compress.
-    fn compress(n: usize, input: &Self, output: &mut Self::CompressedBuffer) -> CompressionDetails;
+    fn compress(n: usize, input: &Self, output: &mut Self::CompressedBuffer) -> CompressionDetails {
+        Self::compress_with(Codec::new(), n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails;
 
     /// Compress the input after applying standard Delta encoding
     /// and write the compressed data to output.
     ///
     /// `n` should be the number of elements to select from the input
     /// to compress.
+    ///
+    /// The input is left holding the Delta encoded values of the whole block, use
+    /// [CompressibleArray::compress_delta_ref] to leave the input untouched.
     fn compress_delta(
         initial_value: Self::InitialValue,
         n: usize,
         input: &mut Self,
         output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        Self::compress_delta_with(Codec::new(), initial_value, n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_delta_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &mut Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        let details = Self::compress_delta_ref_with(codec, initial_value, n, input, output);
+        Self::encode_delta_in_place(initial_value, input, false);
+        details
+    }
+
+    /// Compress the input after applying standard Delta encoding
+    /// and write the compressed data to output, leaving the input untouched.
+    ///
+    /// `n` should be the number of elements to select from the input
+    /// to compress.
+    ///
+    /// The last selected value is reported by [CompressionDetails::last_value]
+    /// and can be passed as the `initial_value` of the next block.
+    fn compress_delta_ref(
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        Self::compress_delta_ref_with(Codec::new(), initial_value, n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_delta_ref_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
     ) -> CompressionDetails;
 
+    #[doc(hidden)]
+    /// Compress the input like [CompressibleArray::compress_delta_ref] unless one of the first
+    /// `n` values is smaller than the value before it, in which case its position is
+    /// returned and nothing is written, the first value is compared against `initial_value`.
+    ///
+    /// Signed arrays ZigZag encode the differences so accept unsorted values.
+    fn compress_delta_checked_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize>;
+
     /// Compress the input after applying standard Delta-1 encoding
     /// and write the compressed data to output.
     ///
     /// `n` should be the number of elements to select from the input
     /// to compress.
+    ///
+    /// The input is left holding the Delta-1 encoded values of the whole block, use
+    /// [CompressibleArray::compress_delta1_ref] to leave the input untouched.
     fn compress_delta1(
         initial_value: Self::InitialValue,
         n: usize,
         input: &mut Self,
         output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        Self::compress_delta1_with(Codec::new(), initial_value, n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_delta1_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &mut Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        let details = Self::compress_delta1_ref_with(codec, initial_value, n, input, output);
+        Self::encode_delta_in_place(initial_value, input, true);
+        details
+    }
+
+    /// Compress the input after applying standard Delta-1 encoding
+    /// and write the compressed data to output, leaving the input untouched.
+    ///
+    /// `n` should be the number of elements to select from the input
+    /// to compress.
+    ///
+    /// The last selected value is reported by [CompressionDetails::last_value]
+    /// and can be passed as the `initial_value` of the next block.
+    fn compress_delta1_ref(
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        Self::compress_delta1_ref_with(Codec::new(), initial_value, n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_delta1_ref_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails;
+
+    #[doc(hidden)]
+    /// Compress the input like [CompressibleArray::compress_delta1_ref] unless one of the first
+    /// `n` values is not greater than the value before it, in which case its position is
+    /// returned and nothing is written, the first value is compared against `initial_value`.
+    ///
+    /// Signed arrays ZigZag encode the differences so accept unsorted values.
+    fn compress_delta1_checked_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize>;
+
+    /// Compress the input after subtracting the smallest of the selected values, the
+    /// frame of reference, from every value and write the compressed data to output.
+    ///
+    /// `n` should be the number of elements to select from the input
+    /// to compress.
+    ///
+    /// The reference is reported by [CompressionDetails::reference] and must be provided
+    /// when decompressing the block.
+    fn compress_for(
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        Self::compress_for_with(Codec::new(), n, input, output)
+    }
+
+    #[doc(hidden)]
+    fn compress_for_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
     ) -> CompressionDetails;
 
     /// Decompress the input and write the recovered values to the output.
@@ -54,7 +236,35 @@ pub trait CompressibleArray {
     ///   as reported by the [CompressionDetails] after compressing the block.
     ///
     /// Returns the number of bytes read from the input.
-    fn decompress(n: usize, compressed_bit_length: u8, input: &[u8], output: &mut Self) -> usize;
+    fn decompress(n: usize, compressed_bit_length: u8, input: &[u8], output: &mut Self) -> usize {
+        Self::decompress_with(Codec::new(), n, compressed_bit_length, input, output)
+    }
+
+    #[doc(hidden)]
+    fn decompress_with(
+        codec: Codec,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            input.len() >= padded_len(compressed_bit_length),
+            "input buffer is too small/incorrectly padded to safely decompress",
+        );
+        Self::decompress_unpadded_with(codec, n, compressed_bit_length, input, output)
+    }
+
+    #[doc(hidden)]
+    /// Decompress a block from an input which only needs to hold the compressed size of the
+    /// `n` values rather than the padded length of the block.
+    fn decompress_unpadded_with(
+        codec: Codec,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize;
 
     /// Decompress the input and write the recovered values, reverse the Delta encoding
     /// and write the output.
@@ -70,6 +280,50 @@ pub trait CompressibleArray {
         compressed_bit_length: u8,
         input: &[u8],
         output: &mut Self,
+    ) -> usize {
+        Self::decompress_delta_with(
+            Codec::new(),
+            initial_value,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    fn decompress_delta_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            input.len() >= padded_len(compressed_bit_length),
+            "input buffer is too small/incorrectly padded to safely decompress",
+        );
+        Self::decompress_delta_unpadded_with(
+            codec,
+            initial_value,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    /// Decompress a block from an input which only needs to hold the compressed size of the
+    /// `n` values rather than the padded length of the block.
+    fn decompress_delta_unpadded_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
     ) -> usize;
 
     /// Decompress the input and write the recovered values, reverse the Delta-1 encoding
@@ -86,5 +340,204 @@ pub trait CompressibleArray {
         compressed_bit_length: u8,
         input: &[u8],
         output: &mut Self,
+    ) -> usize {
+        Self::decompress_delta1_with(
+            Codec::new(),
+            initial_value,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    fn decompress_delta1_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            input.len() >= padded_len(compressed_bit_length),
+            "input buffer is too small/incorrectly padded to safely decompress",
+        );
+        Self::decompress_delta1_unpadded_with(
+            codec,
+            initial_value,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    /// Decompress a block from an input which only needs to hold the compressed size of the
+    /// `n` values rather than the padded length of the block.
+    fn decompress_delta1_unpadded_with(
+        codec: Codec,
+        initial_value: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize;
+
+    /// Decompress the input and write the recovered values to the output after adding
+    /// back the frame of reference.
+    ///
+    /// - `reference` should be the frame of reference as reported by the [CompressionDetails]
+    ///   after compressing the block.
+    /// - `n` should be the number of elements that the compressed buffer holds.
+    /// - `compressed_bit_length` should be the bit length of the compressed block values
+    ///   as reported by the [CompressionDetails] after compressing the block.
+    ///
+    /// Returns the number of bytes read from the input.
+    fn decompress_for(
+        reference: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_for_with(
+            Codec::new(),
+            reference,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    fn decompress_for_with(
+        codec: Codec,
+        reference: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize;
+
+    /// Decompress the input and write the recovered values to the output after adding
+    /// `base` to each of them, wrapping on overflow.
+    ///
+    /// This is used to remap the values of a block, e.g. document ids when merging segments,
+    /// without a second pass over the output.
+    ///
+    /// Unsigned arrays reuse the frame of reference kernels, which already add a constant to
+    /// each value while it is still in registers, signed arrays add `base` right after
+    /// reversing the ZigZag encoding.
+    ///
+    /// - `base` is the offset to add to each value.
+    /// - `n` should be the number of elements that the compressed buffer holds.
+    /// - `compressed_bit_length` should be the bit length of the compressed block values
+    ///   as reported by the [CompressionDetails] after compressing the block.
+    ///
+    /// Returns the number of bytes read from the input.
+    fn decompress_with_offset(
+        base: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_with_offset_with(
+            Codec::new(),
+            base,
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    fn decompress_with_offset_with(
+        codec: Codec,
+        base: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
     ) -> usize;
+
+    /// Decompress the input and write the recovered values, reverse the Delta encoding
+    /// and add `base` to each value, wrapping on overflow.
+    ///
+    /// The prefix sum carries the offset from the initial value to every element, so this
+    /// costs the same as [CompressibleArray::decompress_delta].
+    ///
+    /// Returns the number of bytes read from the input.
+    fn decompress_delta_with_offset(
+        initial_value: Self::InitialValue,
+        base: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_delta_with(
+            Codec::new(),
+            Self::wrapping_offset(initial_value, base),
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    /// Decompress the input and write the recovered values, reverse the Delta-1 encoding
+    /// and add `base` to each value, wrapping on overflow.
+    ///
+    /// The prefix sum carries the offset from the initial value to every element, so this
+    /// costs the same as [CompressibleArray::decompress_delta1].
+    ///
+    /// Returns the number of bytes read from the input.
+    fn decompress_delta1_with_offset(
+        initial_value: Self::InitialValue,
+        base: Self::InitialValue,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_delta1_with(
+            Codec::new(),
+            Self::wrapping_offset(initial_value, base),
+            n,
+            compressed_bit_length,
+            input,
+            output,
+        )
+    }
+
+    #[doc(hidden)]
+    /// Replaces each value of the block with its difference from the value before it, less
+    /// one when `delta1` is set, wrapping on overflow, the first value is compared against
+    /// `initial_value`.
+    fn encode_delta_in_place(initial_value: Self::InitialValue, input: &mut Self, delta1: bool);
+
+    #[doc(hidden)]
+    /// Returns `value + base`, wrapping on overflow.
+    fn wrapping_offset(value: Self::InitialValue, base: Self::InitialValue) -> Self::InitialValue;
+
+    #[doc(hidden)]
+    /// Returns the bit lengths of the first `n` values once compressed with the plain,
+    /// Delta and Delta-1 encodings, in that order.
+    fn encoding_bit_lengths(initial_value: Self::InitialValue, n: usize, input: &Self) -> [u8; 3];
+
+    #[doc(hidden)]
+    /// Returns the last of the first `n` values, or `initial_value` if `n` is `0`, widened
+    /// as described by [CompressionDetails::last_value].
+    fn last_value(initial_value: Self::InitialValue, n: usize, input: &Self) -> u64;
+
+    #[doc(hidden)]
+    /// Returns the first [CompressibleArray::MAX_OUTPUT_SIZE] bytes of `output` as
+    /// a [CompressibleArray::CompressedBuffer].
+    fn compressed_buffer_mut(output: &mut [u8]) -> &mut Self::CompressedBuffer;
 }
@@ -0,0 +1,229 @@
+//! Selection of the backend used by the compression routines.
+//!
+//! The best backend the runtime CPU supports is resolved once and cached, rather than
+//! probing the CPU features on every call. When the crate is compiled with the target
+//! features of the best available backend enabled, e.g. `-C target-feature=+avx512f,+avx512bw`,
+//! detection is skipped entirely and the backend is selected at compile time.
+//!
+//! Runtime detection requires the `std` feature, without it the backend is selected purely
+//! from the target features enabled at compile time.
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+/// The implementation backing the compression routines.
+///
+/// Every variant exists on all targets, [Backend::is_supported] reports whether the backend
+/// is compiled in and can run on the current CPU.
+pub enum Backend {
+    /// The portable scalar implementation, supported everywhere.
+    Scalar = 1,
+    /// The AVX2 implementation, requires the `avx2` feature and an `x86_64` CPU supporting `avx2`.
+    Avx2 = 2,
+    /// The AVX512 implementation, requires the `avx512` feature and an `x86_64` CPU supporting
+    /// `avx512f` and `avx512bw`.
+    Avx512 = 3,
+    /// The NEON implementation, requires the `neon` feature and an `aarch64` CPU.
+    Neon = 4,
+    /// The SSE4.1 implementation, requires the `sse41` feature and an `x86_64` CPU supporting
+    /// `sse4.1`.
+    Sse41 = 5,
+    /// The WebAssembly SIMD implementation, requires the `simd128` feature and a `wasm32`
+    /// target compiled with the `simd128` target feature enabled.
+    WasmSimd128 = 6,
+}
+
+impl Backend {
+    /// Returns the best backend the current CPU supports.
+    ///
+    /// This is the backend selected by the compression routines unless a [crate::Codec]
+    /// is created with a specific backend.
+    pub fn detect() -> Self {
+        backend()
+    }
+
+    /// Returns `true` if the backend is compiled in and the current CPU can run it.
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => crate::uint32::avx512::can_use(),
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => crate::uint32::avx2::can_use(),
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => crate::uint32::sse41::can_use(),
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => crate::uint32::neon::can_use(),
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => crate::uint32::wasm_simd128::can_use(),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+
+const UNRESOLVED: u8 = 0;
+static SELECTED_BACKEND: AtomicU8 = AtomicU8::new(UNRESOLVED);
+
+/// The backend selected at compile time, if the enabled target features make
+/// runtime detection unnecessary.
+///
+/// The branches are ordered from the widest backend down, so the first backend with
+/// both its crate feature and target features enabled is picked.
+const STATIC_BACKEND: Option<Backend> = if cfg!(all(
+    target_arch = "x86_64",
+    feature = "avx512",
+    target_feature = "avx512f",
+    target_feature = "avx512bw",
+)) {
+    Some(Backend::Avx512)
+} else if cfg!(all(
+    target_arch = "x86_64",
+    feature = "avx2",
+    target_feature = "avx2",
+)) {
+    Some(Backend::Avx2)
+} else if cfg!(all(
+    target_arch = "x86_64",
+    feature = "sse41",
+    target_feature = "sse4.1",
+)) {
+    Some(Backend::Sse41)
+} else if cfg!(all(
+    target_arch = "aarch64",
+    feature = "neon",
+    target_feature = "neon"
+)) {
+    Some(Backend::Neon)
+} else if cfg!(all(
+    target_arch = "wasm32",
+    feature = "simd128",
+    target_feature = "simd128"
+)) {
+    Some(Backend::WasmSimd128)
+} else if cfg!(not(any(
+    all(
+        target_arch = "x86_64",
+        any(feature = "avx2", feature = "avx512", feature = "sse41")
+    ),
+    all(target_arch = "aarch64", feature = "neon"),
+    all(target_arch = "wasm32", feature = "simd128"),
+))) {
+    Some(Backend::Scalar)
+} else {
+    None
+};
+
+#[inline]
+/// Returns the backend used by the compression routines.
+fn backend() -> Backend {
+    if let Some(backend) = STATIC_BACKEND {
+        return backend;
+    }
+
+    // Without `std` the backends can only be detected from the target features enabled at
+    // compile time, so the detection is constant and needs no caching.
+    if cfg!(not(feature = "std")) {
+        return detect_backend();
+    }
+
+    match SELECTED_BACKEND.load(Ordering::Relaxed) {
+        UNRESOLVED => resolve_backend(),
+        1 => Backend::Scalar,
+        2 => Backend::Avx2,
+        3 => Backend::Avx512,
+        4 => Backend::Neon,
+        5 => Backend::Sse41,
+        _ => Backend::WasmSimd128,
+    }
+}
+
+#[cold]
+fn resolve_backend() -> Backend {
+    let backend = detect_backend();
+    SELECTED_BACKEND.store(backend as u8, Ordering::Relaxed);
+    backend
+}
+
+/// Detect the best backend the runtime CPU supports.
+fn detect_backend() -> Backend {
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    if crate::uint32::avx512::can_use() {
+        return Backend::Avx512;
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+    if crate::uint32::avx2::can_use() {
+        return Backend::Avx2;
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+    if crate::uint32::sse41::can_use() {
+        return Backend::Sse41;
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    if crate::uint32::neon::can_use() {
+        return Backend::Neon;
+    }
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        feature = "simd128"
+    ))]
+    if crate::uint32::wasm_simd128::can_use() {
+        return Backend::WasmSimd128;
+    }
+
+    Backend::Scalar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_matches_detection() {
+        let expected = STATIC_BACKEND.unwrap_or_else(detect_backend);
+        assert_eq!(backend(), expected);
+        // The second call is served from the cache or the compile time selection.
+        assert_eq!(backend(), expected);
+        assert!(backend().is_supported());
+    }
+
+    #[test]
+    #[cfg(all(
+        target_arch = "x86_64",
+        feature = "avx2",
+        target_feature = "avx2",
+        not(all(
+            feature = "avx512",
+            target_feature = "avx512f",
+            target_feature = "avx512bw"
+        ))
+    ))]
+    fn test_avx2_is_selected_at_compile_time() {
+        assert_eq!(STATIC_BACKEND, Some(Backend::Avx2));
+    }
+
+    #[test]
+    #[cfg(all(
+        target_arch = "x86_64",
+        feature = "avx512",
+        target_feature = "avx512f",
+        target_feature = "avx512bw"
+    ))]
+    fn test_avx512_is_selected_at_compile_time() {
+        assert_eq!(STATIC_BACKEND, Some(Backend::Avx512));
+    }
+
+    #[test]
+    fn test_scalar_is_always_supported() {
+        assert!(Backend::Scalar.is_supported());
+    }
+}
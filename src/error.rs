@@ -0,0 +1,76 @@
+use core::fmt;
+
+use crate::Backend;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An error returned by the fallible compression and decompression routines.
+pub enum Error {
+    /// The compressed bit length is larger than the bit width of the element type.
+    InvalidBitLength {
+        /// The bit length that was provided.
+        bit_length: u8,
+        /// The largest bit length supported by the element type.
+        max_bit_length: u8,
+    },
+    /// The input buffer is too small/incorrectly padded to safely decompress.
+    InputTooShort {
+        /// The number of bytes the input must hold.
+        required: usize,
+        /// The number of bytes the input holds.
+        actual: usize,
+    },
+    /// The number of elements is greater than the [crate::X128] elements a block can hold.
+    TooManyElements {
+        /// The number of elements that was provided.
+        n: usize,
+    },
+    /// The value at `index` is smaller than the value before it, or not greater than it for
+    /// Delta-1 encoding, so the input cannot be Delta encoded.
+    Unsorted {
+        /// The position of the first value which is out of order.
+        index: usize,
+    },
+    /// The requested backend is not compiled in or not supported by the current CPU.
+    UnsupportedBackend {
+        /// The backend that was requested.
+        backend: Backend,
+    },
+    /// The length header of a compressed stream is truncated, or holds more values than the
+    /// stream can.
+    CorruptStreamHeader,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidBitLength {
+                bit_length,
+                max_bit_length,
+            } => write!(
+                f,
+                "compressed bit length {bit_length} is greater than the maximum of {max_bit_length}"
+            ),
+            Error::InputTooShort { required, actual } => write!(
+                f,
+                "input buffer is too small to safely decompress, {required} bytes are required \
+                 but only {actual} were provided"
+            ),
+            Error::TooManyElements { n } => {
+                write!(f, "provided n ({n}) is greater than 128")
+            },
+            Error::Unsorted { index } => {
+                write!(
+                    f,
+                    "the input is not sorted, the value at index {index} is out of order"
+                )
+            },
+            Error::UnsupportedBackend { backend } => {
+                write!(f, "the {backend:?} backend is not supported on this CPU")
+            },
+            Error::CorruptStreamHeader => write!(f, "stream header length is corrupt"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -1,9 +1,28 @@
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod auto;
+mod codec;
 mod core;
+mod dispatch;
+mod error;
+#[cfg(feature = "alloc")]
+pub mod stream;
 pub mod uint16;
 pub mod uint32;
+pub mod uint64;
+pub mod uint8;
 mod util;
 
-pub use self::core::{CompressibleArray, CompressionDetails};
+pub use self::auto::{AUTO_HEADER_LEN, compress_auto, decompress_auto};
+pub use self::codec::Codec;
+pub use self::core::{CompressibleArray, CompressionDetails, EncodingCosts};
+pub use self::dispatch::Backend;
+pub use self::error::Error;
 
 /// 128 elements
 pub const X128: usize = 128;
@@ -20,7 +39,11 @@ where
 /// Apply bitpacking compression to the provided input after first
 /// applying Delta encoding to the array.
 ///
-/// This requires that the input values are sorted from smallest to largest.
+/// For unsigned arrays this requires that the input values are sorted from smallest
+/// to largest, signed arrays ZigZag encode the differences so may be unsorted.
+///
+/// The input is left holding the Delta encoded values of the whole block, use
+/// [compress_delta_ref] to leave the input untouched.
 pub fn compress_delta<A>(
     initial_value: A::InitialValue,
     n: usize,
@@ -38,6 +61,9 @@ where
 ///
 /// This requires that the input values are monotonic, meaning there must be _at least_
 /// a gap of `1` between each succeeding value.
+///
+/// The input is left holding the Delta-1 encoded values of the whole block, use
+/// [compress_delta1_ref] to leave the input untouched.
 pub fn compress_delta1<A>(
     initial_value: A::InitialValue,
     n: usize,
@@ -50,6 +76,55 @@ where
     A::compress_delta1(initial_value, n, input, out)
 }
 
+/// Apply bitpacking compression to the provided input after applying Delta encoding,
+/// without modifying the input.
+///
+/// This has the same requirements as [compress_delta], the last selected value is
+/// reported by [CompressionDetails::last_value] so it can be used as the
+/// `initial_value` of the next block.
+pub fn compress_delta_ref<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &A,
+    out: &mut A::CompressedBuffer,
+) -> CompressionDetails
+where
+    A: CompressibleArray,
+{
+    A::compress_delta_ref(initial_value, n, input, out)
+}
+
+/// Apply bitpacking compression to the provided input after applying Delta-1 encoding,
+/// without modifying the input.
+///
+/// This has the same requirements as [compress_delta1], the last selected value is
+/// reported by [CompressionDetails::last_value] so it can be used as the
+/// `initial_value` of the next block.
+pub fn compress_delta1_ref<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &A,
+    out: &mut A::CompressedBuffer,
+) -> CompressionDetails
+where
+    A: CompressibleArray,
+{
+    A::compress_delta1_ref(initial_value, n, input, out)
+}
+
+/// Apply bitpacking compression to the provided input after first subtracting
+/// the smallest of the selected values from every value.
+///
+/// Unlike [compress_delta] the input does not need to be sorted, blocks where all values
+/// are clustered around a large common base compress to the bit length of their range.
+/// The subtracted reference value is returned in [CompressionDetails::reference].
+pub fn compress_for<A>(n: usize, input: &A, out: &mut A::CompressedBuffer) -> CompressionDetails
+where
+    A: CompressibleArray,
+{
+    A::compress_for(n, input, out)
+}
+
 /// Decompress the input block containing the packed values, writing the decompressed
 /// values to `out`.
 ///
@@ -107,6 +182,357 @@ where
     A::decompress_delta1(initial_value, n, compressed_bit_length, input, out)
 }
 
+/// Decompress the input block containing the packed values, add back the frame of reference
+/// and then writing the decompressed values to `out`.
+///
+/// This requires that the values contained were originally compressed with [compress_for].
+///
+/// - `reference` should be the frame of reference as reported by the [CompressionDetails]
+///   after compressing the block.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [CompressionDetails] after compressing the block.
+pub fn decompress_for<A>(
+    reference: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_for(reference, n, compressed_bit_length, input, out)
+}
+
+/// Decompress the input block containing the packed values, add `base` to each value and then
+/// writing the decompressed values to `out`.
+///
+/// This requires that the values contained were originally compressed with [compress], the
+/// offset is added while the values are still in registers, wrapping on overflow.
+///
+/// - `base` is the offset to add to each value, e.g. the document id base of a segment.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [CompressionDetails] after compressing the block.
+pub fn decompress_with_offset<A>(
+    base: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_with_offset(base, n, compressed_bit_length, input, out)
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta encoding, add
+/// `base` to each value and then writing the decompressed values to `out`.
+///
+/// This requires that the values contained were originally compressed with [compress_delta].
+///
+/// - `base` is the offset to add to each value, e.g. the document id base of a segment.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [CompressionDetails] after compressing the block.
+pub fn decompress_delta_with_offset<A>(
+    initial_value: A::InitialValue,
+    base: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_delta_with_offset(initial_value, base, n, compressed_bit_length, input, out)
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta-1 encoding, add
+/// `base` to each value and then writing the decompressed values to `out`.
+///
+/// This requires that the values contained were originally compressed with [compress_delta1].
+///
+/// - `base` is the offset to add to each value, e.g. the document id base of a segment.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [CompressionDetails] after compressing the block.
+pub fn decompress_delta1_with_offset<A>(
+    initial_value: A::InitialValue,
+    base: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_delta1_with_offset(initial_value, base, n, compressed_bit_length, input, out)
+}
+
+/// Decompress the input block containing the packed values, writing the decompressed
+/// values to `out`.
+///
+/// Unlike [decompress] the input does not need to be padded, it only needs to hold the
+/// `compressed_size(compressed_bit_length, n)` bytes of the block. This allows blocks to be
+/// decompressed directly from tightly packed storage, e.g. a memory mapped file.
+///
+/// The kernels bound their loads from the final partial half of the block to the compressed
+/// size of its values, so the block is always decompressed in place.
+///
+/// See [decompress] for more details.
+pub fn decompress_unpadded<A>(
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_unpadded_with(Codec::new(), n, compressed_bit_length, input, out)
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta encoding and then
+/// writing the decompressed values to `out`.
+///
+/// Unlike [decompress_delta] the input does not need to be padded, it only needs to hold the
+/// `compressed_size(compressed_bit_length, n)` bytes of the block.
+///
+/// See [decompress_delta] and [decompress_unpadded] for more details.
+pub fn decompress_delta_unpadded<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_delta_unpadded_with(
+        Codec::new(),
+        initial_value,
+        n,
+        compressed_bit_length,
+        input,
+        out,
+    )
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta-1 encoding and then
+/// writing the decompressed values to `out`.
+///
+/// Unlike [decompress_delta1] the input does not need to be padded, it only needs to hold the
+/// `compressed_size(compressed_bit_length, n)` bytes of the block.
+///
+/// See [decompress_delta1] and [decompress_unpadded] for more details.
+pub fn decompress_delta1_unpadded<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> usize
+where
+    A: CompressibleArray,
+{
+    A::decompress_delta1_unpadded_with(
+        Codec::new(),
+        initial_value,
+        n,
+        compressed_bit_length,
+        input,
+        out,
+    )
+}
+
+/// Returns the bit width of the element type of the array.
+///
+/// Every block is [X128] elements wide, so the bit width follows from the worst case
+/// output size.
+const fn max_bit_length<A: CompressibleArray>() -> u8 {
+    (A::MAX_OUTPUT_SIZE * 8 / X128) as u8
+}
+
+/// Returns the padded length of the input the decompression routines require.
+const fn padded_len(compressed_bit_length: u8) -> usize {
+    compressed_bit_length as usize * X128 / 8
+}
+
+/// Apply bitpacking compression to the provided input, returning an [Error] rather
+/// than panicking if `n` is greater than [X128].
+///
+/// See [compress] for more details.
+pub fn try_compress<A>(
+    n: usize,
+    input: &A,
+    out: &mut A::CompressedBuffer,
+) -> Result<CompressionDetails, Error>
+where
+    A: CompressibleArray,
+{
+    check_n(n)?;
+    Ok(A::compress(n, input, out))
+}
+
+/// Apply bitpacking compression to the provided input after applying Delta encoding,
+/// returning an [Error] rather than producing an oversized block if the input is not sorted.
+///
+/// The order of the selected values is verified alongside the bit length of the block,
+/// [Error::Unsorted] reports the first value which is smaller than the value before it, where
+/// the first value is compared against `initial_value`, and nothing is written to `out`.
+/// Signed arrays ZigZag encode the differences so are never rejected.
+///
+/// Like [compress_delta_ref] the input is not modified, see [compress_delta] for more details.
+pub fn compress_delta_checked<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &A,
+    out: &mut A::CompressedBuffer,
+) -> Result<CompressionDetails, Error>
+where
+    A: CompressibleArray,
+{
+    check_n(n)?;
+    A::compress_delta_checked_with(Codec::new(), initial_value, n, input, out)
+        .map_err(|index| Error::Unsorted { index })
+}
+
+/// Apply bitpacking compression to the provided input after applying Delta-1 encoding,
+/// returning an [Error] rather than producing an oversized block if the input is not
+/// strictly increasing.
+///
+/// The order of the selected values is verified alongside the bit length of the block,
+/// [Error::Unsorted] reports the first value which is not greater than the value before it, where
+/// the first value is compared against `initial_value`, and nothing is written to `out`.
+/// Signed arrays ZigZag encode the differences so are never rejected.
+///
+/// Like [compress_delta1_ref] the input is not modified, see [compress_delta1] for more
+/// details.
+pub fn compress_delta1_checked<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    input: &A,
+    out: &mut A::CompressedBuffer,
+) -> Result<CompressionDetails, Error>
+where
+    A: CompressibleArray,
+{
+    check_n(n)?;
+    A::compress_delta1_checked_with(Codec::new(), initial_value, n, input, out)
+        .map_err(|index| Error::Unsorted { index })
+}
+
+/// Decompress the input block containing the packed values, writing the decompressed
+/// values to `out`.
+///
+/// Unlike [decompress] this never panics, the arguments are validated before
+/// decompressing so corrupt or untrusted blocks return an [Error] instead.
+///
+/// See [decompress] for more details.
+pub fn try_decompress<A>(
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> Result<usize, Error>
+where
+    A: CompressibleArray,
+{
+    check_decompress_args::<A>(n, compressed_bit_length, input)?;
+    Ok(A::decompress(n, compressed_bit_length, input, out))
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta encoding and then
+/// writing the decompressed values to `out`.
+///
+/// Unlike [decompress_delta] this never panics, the arguments are validated before
+/// decompressing so corrupt or untrusted blocks return an [Error] instead.
+///
+/// See [decompress_delta] for more details.
+pub fn try_decompress_delta<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> Result<usize, Error>
+where
+    A: CompressibleArray,
+{
+    check_decompress_args::<A>(n, compressed_bit_length, input)?;
+    Ok(A::decompress_delta(
+        initial_value,
+        n,
+        compressed_bit_length,
+        input,
+        out,
+    ))
+}
+
+/// Decompress the input block containing the packed values, reverse the Delta-1 encoding and then
+/// writing the decompressed values to `out`.
+///
+/// Unlike [decompress_delta1] this never panics, the arguments are validated before
+/// decompressing so corrupt or untrusted blocks return an [Error] instead.
+///
+/// See [decompress_delta1] for more details.
+pub fn try_decompress_delta1<A>(
+    initial_value: A::InitialValue,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut A,
+) -> Result<usize, Error>
+where
+    A: CompressibleArray,
+{
+    check_decompress_args::<A>(n, compressed_bit_length, input)?;
+    Ok(A::decompress_delta1(
+        initial_value,
+        n,
+        compressed_bit_length,
+        input,
+        out,
+    ))
+}
+
+fn check_n(n: usize) -> Result<(), Error> {
+    if n > X128 {
+        return Err(Error::TooManyElements { n });
+    }
+    Ok(())
+}
+
+/// Validate the arguments the decompression routines otherwise assert on.
+fn check_decompress_args<A>(n: usize, compressed_bit_length: u8, input: &[u8]) -> Result<(), Error>
+where
+    A: CompressibleArray,
+{
+    let max_bit_length = max_bit_length::<A>();
+    if compressed_bit_length > max_bit_length {
+        return Err(Error::InvalidBitLength {
+            bit_length: compressed_bit_length,
+            max_bit_length,
+        });
+    }
+
+    let required = padded_len(compressed_bit_length);
+    if input.len() < required {
+        return Err(Error::InputTooShort {
+            required,
+            actual: input.len(),
+        });
+    }
+
+    check_n(n)
+}
+
 #[cfg(test)]
 mod test_utils {
     use crate::X128;
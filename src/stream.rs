@@ -0,0 +1,526 @@
+//! Encoding and decoding of arbitrary length sequences as a stream of compressed blocks.
+//!
+//! The layout of a stream is:
+//!
+//! - The total number of values in the stream as an unsigned LEB128 varint, at most
+//!   [MAX_LENGTH_HEADER_LEN] bytes long.
+//! - Each block as a single byte header holding the compressed bit length of the block,
+//!   followed by the compressed values of the block.
+//!
+//! Every block holds [X128] values except for the final block, which holds the remaining
+//! values of the sequence and is only as large as the values it holds.
+use alloc::vec;
+use alloc::vec::Vec;
+
+use self::private::Encoding;
+use crate::{CompressibleArray, CompressionDetails, Error, X128};
+
+/// The largest length header, a `u64` encoded as a LEB128 varint.
+pub const MAX_LENGTH_HEADER_LEN: usize = u64::BITS.div_ceil(7) as usize;
+/// The largest compressed block of any [StreamElement].
+const MAX_BLOCK_OUTPUT_LEN: usize = X128 * size_of::<u64>();
+
+/// An integer type which can be encoded as a stream of compressed blocks.
+///
+/// This trait is sealed and implemented for every integer type supported by the crate.
+pub trait StreamElement: Copy + Default + private::Sealed {
+    #[doc(hidden)]
+    fn compress_block(
+        encoding: Encoding<Self>,
+        n: usize,
+        block: &[Self; X128],
+        out: &mut [u8],
+    ) -> CompressionDetails;
+
+    #[doc(hidden)]
+    fn decompress_block(
+        encoding: Encoding<Self>,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        out: &mut [Self; X128],
+    ) -> usize;
+
+    #[doc(hidden)]
+    fn compressed_size(bit_length: usize, n: usize) -> usize;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    #[derive(Debug, Copy, Clone)]
+    pub enum Encoding<T> {
+        Plain,
+        Delta(T),
+        Delta1(T),
+    }
+}
+
+macro_rules! impl_stream_element {
+    ($element:ty, $module:ident) => {
+        impl private::Sealed for $element {}
+
+        impl StreamElement for $element {
+            fn compress_block(
+                encoding: Encoding<Self>,
+                n: usize,
+                block: &[Self; X128],
+                out: &mut [u8],
+            ) -> CompressionDetails {
+                const MAX_OUTPUT_SIZE: usize = <[$element; X128]>::MAX_OUTPUT_SIZE;
+                let out: &mut [u8; MAX_OUTPUT_SIZE] =
+                    (&mut out[..MAX_OUTPUT_SIZE]).try_into().unwrap();
+                match encoding {
+                    Encoding::Plain => crate::compress(n, block, out),
+                    Encoding::Delta(last_value) => {
+                        crate::compress_delta_ref(last_value, n, block, out)
+                    },
+                    Encoding::Delta1(last_value) => {
+                        crate::compress_delta1_ref(last_value, n, block, out)
+                    },
+                }
+            }
+
+            fn decompress_block(
+                encoding: Encoding<Self>,
+                n: usize,
+                compressed_bit_length: u8,
+                input: &[u8],
+                out: &mut [Self; X128],
+            ) -> usize {
+                match encoding {
+                    Encoding::Plain => {
+                        crate::decompress_unpadded(n, compressed_bit_length, input, out)
+                    },
+                    Encoding::Delta(last_value) => crate::decompress_delta_unpadded(
+                        last_value,
+                        n,
+                        compressed_bit_length,
+                        input,
+                        out,
+                    ),
+                    Encoding::Delta1(last_value) => crate::decompress_delta1_unpadded(
+                        last_value,
+                        n,
+                        compressed_bit_length,
+                        input,
+                        out,
+                    ),
+                }
+            }
+
+            fn compressed_size(bit_length: usize, n: usize) -> usize {
+                crate::$module::compressed_size(bit_length, n)
+            }
+        }
+    };
+}
+
+impl_stream_element!(u8, uint8);
+impl_stream_element!(u16, uint16);
+impl_stream_element!(i16, uint16);
+impl_stream_element!(u32, uint32);
+impl_stream_element!(i32, uint32);
+impl_stream_element!(u64, uint64);
+
+/// Encodes a sequence of any length as a stream of compressed blocks.
+///
+/// Values are buffered until a full block is available, the final partial block is
+/// written by [Encoder::finish].
+///
+/// ```
+/// use upack::stream::{Decoder, Encoder};
+///
+/// let values: Vec<u32> = (0..1000).map(|i| i * 3).collect();
+///
+/// let mut encoder = Encoder::delta(0);
+/// encoder.push(&values);
+/// let encoded = encoder.finish();
+///
+/// let decoded = Decoder::delta(&encoded, 0).unwrap().decode_to_vec();
+/// assert_eq!(decoded, values);
+/// ```
+pub struct Encoder<T: StreamElement> {
+    encoding: Encoding<T>,
+    output: Vec<u8>,
+    block: [T; X128],
+    block_len: usize,
+    num_values: u64,
+}
+
+impl<T: StreamElement> Default for Encoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: StreamElement> Encoder<T> {
+    /// Create a new encoder which packs the values as is.
+    pub fn new() -> Self {
+        Self::with_encoding(Encoding::Plain)
+    }
+
+    /// Create a new encoder which applies Delta encoding to the values, starting
+    /// from `initial_value`.
+    ///
+    /// See [crate::compress_delta] for the requirements of the values.
+    pub fn delta(initial_value: T) -> Self {
+        Self::with_encoding(Encoding::Delta(initial_value))
+    }
+
+    /// Create a new encoder which applies Delta-1 encoding to the values, starting
+    /// from `initial_value`.
+    ///
+    /// See [crate::compress_delta1] for the requirements of the values.
+    pub fn delta1(initial_value: T) -> Self {
+        Self::with_encoding(Encoding::Delta1(initial_value))
+    }
+
+    fn with_encoding(encoding: Encoding<T>) -> Self {
+        Self {
+            encoding,
+            output: Vec::new(),
+            block: [T::default(); X128],
+            block_len: 0,
+            num_values: 0,
+        }
+    }
+
+    /// Append the provided values to the stream.
+    pub fn push(&mut self, mut values: &[T]) {
+        while !values.is_empty() {
+            let take = values.len().min(X128 - self.block_len);
+            self.block[self.block_len..][..take].copy_from_slice(&values[..take]);
+            self.block_len += take;
+            values = &values[take..];
+
+            if self.block_len == X128 {
+                self.write_block();
+            }
+        }
+    }
+
+    /// Write the final block and return the encoded stream.
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.block_len > 0 {
+            self.write_block();
+        }
+        let mut header = Vec::with_capacity(MAX_LENGTH_HEADER_LEN);
+        write_varint(self.num_values, &mut header);
+        self.output.splice(0..0, header);
+        self.output
+    }
+
+    fn write_block(&mut self) {
+        let n = self.block_len;
+        let last_value = self.block[n - 1];
+
+        let start = self.output.len();
+        self.output.resize(start + 1 + MAX_BLOCK_OUTPUT_LEN, 0);
+        let out = &mut self.output[start + 1..];
+        let details = T::compress_block(self.encoding, n, &self.block, out);
+        self.output[start] = details.compressed_bit_length;
+        self.output.truncate(start + 1 + details.bytes_written);
+
+        self.encoding = match self.encoding {
+            Encoding::Plain => Encoding::Plain,
+            Encoding::Delta(_) => Encoding::Delta(last_value),
+            Encoding::Delta1(_) => Encoding::Delta1(last_value),
+        };
+        self.num_values += n as u64;
+        self.block_len = 0;
+    }
+}
+
+/// Writes `value` as an unsigned LEB128 varint.
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads an unsigned LEB128 varint from the start of `input`, returning the value and the
+/// rest of the input.
+///
+/// Returns `None` if the varint is truncated or does not fit in a `u64`.
+fn read_varint(input: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, &byte) in input.iter().take(MAX_LENGTH_HEADER_LEN).enumerate() {
+        let bits = u64::from(byte & 0x7F);
+        let shift = i as u32 * 7;
+        if bits.checked_shl(shift)? >> shift != bits {
+            return None;
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Some((value, &input[i + 1..]));
+        }
+    }
+    None
+}
+
+/// Decodes a stream of compressed blocks written by an [Encoder].
+///
+/// The decoder must be created with the same encoding and initial value as the
+/// [Encoder] which wrote the stream.
+pub struct Decoder<'a, T: StreamElement> {
+    encoding: Encoding<T>,
+    input: &'a [u8],
+    remaining: usize,
+}
+
+impl<'a, T: StreamElement> Decoder<'a, T> {
+    /// Create a new decoder for a stream written by [Encoder::new].
+    ///
+    /// Returns [Error::CorruptStreamHeader] if the length header is truncated or holds
+    /// more values than the input can.
+    pub fn new(input: &'a [u8]) -> Result<Self, Error> {
+        Self::with_encoding(Encoding::Plain, input)
+    }
+
+    /// Create a new decoder for a stream written by [Encoder::delta].
+    ///
+    /// Returns an error in the same cases as [Decoder::new].
+    pub fn delta(input: &'a [u8], initial_value: T) -> Result<Self, Error> {
+        Self::with_encoding(Encoding::Delta(initial_value), input)
+    }
+
+    /// Create a new decoder for a stream written by [Encoder::delta1].
+    ///
+    /// Returns an error in the same cases as [Decoder::new].
+    pub fn delta1(input: &'a [u8], initial_value: T) -> Result<Self, Error> {
+        Self::with_encoding(Encoding::Delta1(initial_value), input)
+    }
+
+    fn with_encoding(encoding: Encoding<T>, input: &'a [u8]) -> Result<Self, Error> {
+        let (num_values, input) = read_varint(input).ok_or(Error::CorruptStreamHeader)?;
+
+        // The length sizes the output of `decode_to_vec` before any block is validated, so a
+        // corrupt header is rejected here. Every block holds at least its 1 byte header.
+        let num_values = usize::try_from(num_values)
+            .ok()
+            .filter(|num_values| num_values.div_ceil(X128) <= input.len())
+            .ok_or(Error::CorruptStreamHeader)?;
+
+        Ok(Self {
+            encoding,
+            input,
+            remaining: num_values,
+        })
+    }
+
+    /// Returns the number of values remaining in the stream.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Decode the next block of the stream into `out`.
+    ///
+    /// Returns the number of values written to `out`, or `None` once every block
+    /// of the stream has been decoded.
+    pub fn next_block(&mut self, out: &mut [T; X128]) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let n = self.remaining.min(X128);
+        let (&compressed_bit_length, input) = self
+            .input
+            .split_first()
+            .expect("input buffer is too small to hold the block header");
+        let block_len = T::compressed_size(compressed_bit_length as usize, n);
+        assert!(
+            input.len() >= block_len,
+            "input buffer is too small to hold the compressed block",
+        );
+
+        T::decompress_block(self.encoding, n, compressed_bit_length, input, out);
+
+        self.encoding = match self.encoding {
+            Encoding::Plain => Encoding::Plain,
+            Encoding::Delta(_) => Encoding::Delta(out[n - 1]),
+            Encoding::Delta1(_) => Encoding::Delta1(out[n - 1]),
+        };
+        self.input = &input[block_len..];
+        self.remaining -= n;
+
+        Some(n)
+    }
+
+    /// Decode the remaining values of the stream into `out`.
+    ///
+    /// Returns the number of values written, `out` must be able to hold
+    /// [Decoder::remaining] values.
+    pub fn decode_into(mut self, out: &mut [T]) -> usize {
+        assert!(
+            out.len() >= self.remaining,
+            "output buffer is too small to hold the decoded values",
+        );
+
+        let mut written = 0;
+        let mut block = [T::default(); X128];
+        while let Some(n) = self.next_block(&mut block) {
+            out[written..][..n].copy_from_slice(&block[..n]);
+            written += n;
+        }
+        written
+    }
+
+    /// Decode the remaining values of the stream into a new [Vec].
+    pub fn decode_to_vec(self) -> Vec<T> {
+        let mut values = vec![T::default(); self.remaining];
+        self.decode_into(&mut values);
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::tail_only(7)]
+    #[case::one_block(X128)]
+    #[case::blocks_and_tail(1000)]
+    fn test_encode_decode_plain(#[case] len: usize) {
+        let values: Vec<u32> = (0..len as u32)
+            .map(|i| i.wrapping_mul(2654435761) >> 7)
+            .collect();
+
+        let mut encoder = Encoder::new();
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        let decoded = Decoder::<u32>::new(&encoded).unwrap().decode_to_vec();
+        assert_eq!(decoded, values);
+    }
+
+    #[rstest::rstest]
+    #[case::tail_only(7)]
+    #[case::blocks_and_tail(1000)]
+    fn test_encode_decode_delta(#[case] len: usize) {
+        let values: Vec<u32> = (0..len as u32).map(|i| 100 + i * 3).collect();
+
+        let mut encoder = Encoder::delta(100);
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        let decoded = Decoder::delta(&encoded, 100).unwrap().decode_to_vec();
+        assert_eq!(decoded, values);
+
+        let mut encoder = Encoder::delta1(99);
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        let mut decoded = vec![0; len];
+        let written = Decoder::delta1(&encoded, 99)
+            .unwrap()
+            .decode_into(&mut decoded);
+        assert_eq!(written, len);
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_push_in_chunks_matches_single_push() {
+        let values: Vec<i32> = (0..777).map(|i| (i % 13) - 6).collect();
+
+        let mut encoder = Encoder::new();
+        encoder.push(&values);
+        let expected = encoder.finish();
+
+        let mut encoder = Encoder::new();
+        for chunk in values.chunks(50) {
+            encoder.push(chunk);
+        }
+        let encoded = encoder.finish();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn test_tail_block_is_variable_size() {
+        let values = [1u64; 130];
+
+        let mut encoder = Encoder::new();
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        let block_len = crate::uint64::compressed_size(1, X128);
+        let tail_len = crate::uint64::compressed_size(1, 2);
+        // 130 values take a 2 byte varint.
+        assert_eq!(encoded.len(), 2 + 1 + block_len + 1 + tail_len);
+    }
+
+    #[test]
+    fn test_block_iteration() {
+        let values: Vec<u16> = (0..300).collect();
+
+        let mut encoder = Encoder::delta(0);
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        let mut decoder = Decoder::delta(&encoded, 0).unwrap();
+        assert_eq!(decoder.remaining(), 300);
+
+        let mut block = [0; X128];
+        let mut lengths = Vec::new();
+        while let Some(n) = decoder.next_block(&mut block) {
+            let offset = lengths.iter().sum::<usize>();
+            assert_eq!(block[..n], values[offset..][..n]);
+            lengths.push(n);
+        }
+        assert_eq!(lengths, [X128, X128, 44]);
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "input buffer is too small to hold the compressed block")]
+    fn test_truncated_stream() {
+        let values = [u32::MAX; 200];
+
+        let mut encoder = Encoder::new();
+        encoder.push(&values);
+        let encoded = encoder.finish();
+
+        Decoder::<u32>::new(&encoded[..encoded.len() - 1])
+            .unwrap()
+            .decode_to_vec();
+    }
+
+    #[rstest::rstest]
+    #[case::zero(0, 1)]
+    #[case::one_byte(0x7F, 1)]
+    #[case::two_bytes(0x80, 2)]
+    #[case::max(u64::MAX, MAX_LENGTH_HEADER_LEN)]
+    fn test_varint_round_trip(#[case] value: u64, #[case] expected_len: usize) {
+        let mut encoded = Vec::new();
+        write_varint(value, &mut encoded);
+        assert_eq!(encoded.len(), expected_len);
+
+        encoded.push(0xAB);
+        assert_eq!(read_varint(&encoded), Some((value, &[0xAB][..])));
+    }
+
+    #[rstest::rstest]
+    #[case::huge(u64::MAX)]
+    #[case::more_blocks_than_bytes(X128 as u64 * 4 + 1)]
+    fn test_corrupt_length_header(#[case] num_values: u64) {
+        let mut encoded = Vec::new();
+        write_varint(num_values, &mut encoded);
+        encoded.extend_from_slice(&[0; 4]);
+        let result = Decoder::<u32>::new(&encoded);
+        assert_eq!(result.err(), Some(Error::CorruptStreamHeader));
+    }
+
+    #[rstest::rstest]
+    #[case::empty(&[])]
+    #[case::truncated(&[0x80, 0x80])]
+    #[case::overlong(&[0xFF; MAX_LENGTH_HEADER_LEN + 1])]
+    #[case::overflow(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02])]
+    fn test_malformed_length_header(#[case] encoded: &[u8]) {
+        let result = Decoder::<u32>::new(encoded);
+        assert_eq!(result.err(), Some(Error::CorruptStreamHeader));
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use crate::X64;
 
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -30,7 +30,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [__m256i; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -82,7 +82,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [__m256i; 2]) {
     let hi_1bitmask2 = _mm256_movemask_epi8(hi_1bit2) as u32;
 
     let hi_merged_mask = ((hi_1bitmask2 as u64) << 32) | hi_1bitmask1 as u64;
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -26,7 +26,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [__m256i; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.cast(), merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -56,8 +56,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: [__m256i; 2], pack_n: usize) {
     let hi_merged_mask = ((hi_mask2 as u64) << 32) | hi_mask1 as u64;
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -92,9 +92,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [__m256i; 2], pack_n: usize) {
     let b2_merged_mask = ((b2_mask2 as u64) << 32) | b2_mask1 as u64;
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -112,6 +112,12 @@ pub(super) unsafe fn pack_u4_registers(out: *mut u8, data: [__m256i; 2]) {
 
     let madd_multiplier = _mm256_set1_epi16(0x1001);
 
+    // Elements beyond `pack_n` may hold values larger than 4 bits, these would
+    // otherwise saturate when packing and clobber the valid neighbouring nibble.
+    let mask = _mm256_set1_epi8(0x0F);
+    let d1 = _mm256_and_si256(d1, mask);
+    let d2 = _mm256_and_si256(d2, mask);
+
     let nibbles1 = _mm256_maddubs_epi16(d1, madd_multiplier);
     let nibbles2 = _mm256_maddubs_epi16(d2, madd_multiplier);
     let interleaved = _mm256_packus_epi16(nibbles1, nibbles2);
@@ -408,6 +414,21 @@ mod tests {
         assert_eq!(out[7], 0b0000_1111); // [15, 0]
     }
 
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_to_u4_ignores_elements_beyond_pack_n() {
+        let mut data = [0xFF; X64];
+        data[0] = 1;
+        data[1] = 15;
+        data[2] = 2;
+        let data = unsafe { load_u16x64(&data) };
+
+        let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
+        unsafe { to_u4(out.as_mut_ptr(), data, 3) };
+        assert_eq!(out[0], 0b1111_0001); // [1, 15]
+        assert_eq!(out[1] & 0x0F, 0b0010); // [2, ..]
+    }
+
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_to_u5() {
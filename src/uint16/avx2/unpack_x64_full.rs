@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -16,7 +16,7 @@ pub unsafe fn from_u1(input: *const u8) -> [__m256i; 4] {
 
 #[target_feature(enable = "avx2")]
 unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
 
     let ones = _mm256_set1_epi8(0b1);
     let mut packed1 = expand_mask_epi8(mask as __mmask32);
@@ -60,7 +60,7 @@ unsafe fn unpack_u3_registers(input: *const u8) -> [__m256i; 2] {
     let packed_2bit = unsafe { _mm_loadu_si128(input.add(0).cast()) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let bit = _mm256_set1_epi8(0b100);
     let mut hi_bits_packed1 = expand_mask_epi8(hi_bitmask as __mmask32);
@@ -320,7 +320,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -363,7 +363,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -403,7 +403,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,24 +1,25 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
+use crate::util::read_partial;
 
 #[target_feature(enable = "avx2")]
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[target_feature(enable = "avx2")]
-unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.cast()) };
+unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
 
     let ones = _mm256_set1_epi8(0b1);
     let mut packed1 = expand_mask_epi8(mask as __mmask32);
@@ -33,7 +34,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m256i; 4] {
@@ -44,9 +45,9 @@ pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m256i; 4] {
 
 #[target_feature(enable = "avx2")]
 unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
-    let hi_offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(hi_offset).cast()) };
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _mm256_set1_epi8(0b01);
     let mut lo_bits_packed1 = expand_mask_epi8(mask1 as __mmask32);
@@ -70,7 +71,7 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m256i; 4] {
@@ -82,9 +83,9 @@ pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m256i; 4] {
 unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _mm256_set1_epi8(0b001);
     let mut b0_bits_packed1 = expand_mask_epi8(mask1 as __mmask32);
@@ -116,23 +117,24 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[target_feature(enable = "avx2")]
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
-    let ordered = unsafe { _mm256_loadu_si256(input.cast()) };
+pub(super) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+    let ordered = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let ordered = unsafe { _mm256_loadu_si256(ordered.as_ptr().cast()) };
     let interleaved = _mm256_permute4x64_epi64::<0xD8>(ordered);
 
     let low_mask = _mm256_set1_epi8(0x0F);
     let low_nibbles = _mm256_and_si256(interleaved, low_mask);
-    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16(interleaved, 4), low_mask);
+    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16::<4>(interleaved), low_mask);
 
     let d1 = _mm256_unpacklo_epi8(low_nibbles, high_nibbles);
     let d2 = _mm256_unpackhi_epi8(low_nibbles, high_nibbles);
@@ -144,7 +146,7 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m256i; 4] {
@@ -156,8 +158,8 @@ pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m256i; 4] {
 #[target_feature(enable = "avx2")]
 unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
 }
@@ -166,7 +168,7 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m256i; 4] {
@@ -178,7 +180,7 @@ pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m256i; 4] {
 #[target_feature(enable = "avx2")]
 unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
@@ -188,7 +190,7 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m256i; 4] {
@@ -200,7 +202,7 @@ pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m256i; 4] {
 #[target_feature(enable = "avx2")]
 unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
@@ -210,12 +212,12 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_si256x2(input) };
+    let packed = unsafe { load_si256x2(read_partial::<64>(input, read_n).as_ptr()) };
     unpack_u8_to_u16_ordered(packed)
 }
 
@@ -223,16 +225,16 @@ pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 4>(hi_bits);
 
@@ -243,12 +245,12 @@ pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -263,12 +265,12 @@ pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -283,16 +285,16 @@ pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 4>(hi_bits);
 
@@ -303,12 +305,12 @@ pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -323,12 +325,12 @@ pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -343,12 +345,12 @@ pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -363,12 +365,12 @@ pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m256i; 4] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m256i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_si256x4(input.add(0)) }
+    unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -403,7 +405,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -446,7 +448,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -489,7 +491,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
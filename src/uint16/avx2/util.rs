@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline]
 #[allow(non_snake_case)]
@@ -204,11 +204,115 @@ pub(super) fn slli_epi16<const IMM8: i32, const N: usize>(mut data: [__m256i; N]
     data
 }
 
+#[target_feature(enable = "avx2")]
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [__m256i; 4]) -> [__m256i; 4] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        let v = data[i];
+        data[i] = _mm256_xor_si256(_mm256_slli_epi16::<1>(v), _mm256_srai_epi16::<15>(v));
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [__m256i; 4]) -> [__m256i; 4] {
+    let zero = _mm256_setzero_si256();
+    let one = _mm256_set1_epi16(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        let v = data[i];
+        data[i] = _mm256_xor_si256(
+            _mm256_srli_epi16::<1>(v),
+            _mm256_sub_epi16(zero, _mm256_and_si256(v, one)),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m256i; 4], reference: u16) -> [__m256i; 4] {
+    let reference = _mm256_set1_epi16(reference as i16);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = _mm256_sub_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m256i; 4], reference: u16) -> [__m256i; 4] {
+    let reference = _mm256_set1_epi16(reference as i16);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = _mm256_add_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the number of bits required to represent the largest of the first `n` 16-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m256i; 4], n: usize) -> u32 {
+    let limit = _mm256_set1_epi16(n as i16 - 1);
+    let step = _mm256_set1_epi16(16);
+    let mut positions = _mm256_setr_epi16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+    let mut acc = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi16(positions, limit);
+        acc = _mm256_or_si256(acc, _mm256_andnot_si256(excluded, *register));
+        positions = _mm256_add_epi16(positions, step);
+    }
+
+    acc = _mm256_or_si256(acc, _mm256_permute2x128_si256::<0x01>(acc, acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0x4E>(acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0xB1>(acc));
+
+    let lanes = _mm256_cvtsi256_si32(acc) as u32;
+    16 - ((lanes | (lanes >> 16)) as u16).leading_zeros()
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the position of the first 16-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[__m256i; 4],
+    deltas: &[__m256i; 4],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let max = _mm256_max_epu16(*value, *delta);
+        // Each 16-bit lane sets two bits of the byte mask.
+        let mask = if strict {
+            _mm256_movemask_epi8(_mm256_cmpeq_epi16(max, *delta)) as u32
+        } else {
+            !_mm256_movemask_epi8(_mm256_cmpeq_epi16(max, *value)) as u32
+        };
+        if mask != 0 {
+            return Some(i * 16 + mask.trailing_zeros() as usize / 2);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::X64;
-    use crate::uint16::avx2::data::{load_si256x2, load_si256x4, load_u16x64};
+    use crate::uint16::avx2::data::{load_si256x2, load_si256x4};
     use crate::uint16::test_util::*;
 
     #[test]
@@ -224,45 +328,98 @@ mod tests {
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_si256x2(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_si256x2(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_si256x2(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [__m256i; 4]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m256i; 4]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(16, 16)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 16)]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u16; X64] = core::array::from_fn(|i| 1 << (i % 16));
+        input[X64 - 1] = u16::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m256i; 4]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
 }
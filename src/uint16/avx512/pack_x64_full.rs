@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -22,7 +22,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [__m512i; 2]) {
 unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
     let bits = _mm512_slli_epi16::<7>(data);
     let mask = _mm512_movepi8_mask(bits);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -72,7 +72,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: __m512i) {
     let hi_1bit = _mm512_slli_epi16::<5>(data);
     let hi_1bitmask = _mm512_movepi8_mask(hi_1bit);
 
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_1bitmask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_1bitmask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
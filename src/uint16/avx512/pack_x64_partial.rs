@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -22,7 +22,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [__m512i; 2], _pack_n: usize) {
 unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
     let bits = _mm512_slli_epi16::<7>(data);
     let mask = _mm512_movepi8_mask(bits);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -49,8 +49,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let hi_mask = _mm512_movepi8_mask(hi_bits);
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -79,9 +79,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let b2_mask = _mm512_movepi8_mask(b2_bits);
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -1,24 +1,25 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
+use crate::util::read_partial;
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
-unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> __m512i {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input.add(0), read_n.div_ceil(8)) });
     let ones = _mm512_set1_epi8(1);
     _mm512_maskz_mov_epi8(mask, ones)
 }
@@ -27,10 +28,10 @@ unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u2_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
@@ -38,9 +39,9 @@ pub(super) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 2] {
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> __m512i {
-    let offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(offset).cast()) };
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _mm512_set1_epi8(0b01);
     let lo_bits_packed = _mm512_maskz_mov_epi8(mask1, bit);
@@ -55,10 +56,10 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u3_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
@@ -68,9 +69,9 @@ pub(super) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 2] {
 unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> __m512i {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _mm512_set1_epi8(0b001);
     let b0_bits_packed = _mm512_maskz_mov_epi8(mask1, bit);
@@ -89,18 +90,19 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
-    let packed = unsafe { _mm256_loadu_si256(input.cast()) };
+pub(super) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> __m512i {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed = unsafe { _mm256_loadu_si256(packed.as_ptr().cast()) };
     let wide = _mm512_cvtepu8_epi16(packed);
     let lo = _mm512_and_si512(wide, _mm512_set1_epi16(0x000F));
     let hi = _mm512_slli_epi16(_mm512_srli_epi16(wide, 4), 8);
@@ -111,10 +113,10 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u5_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
@@ -123,8 +125,8 @@ pub(super) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 2] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
 }
@@ -133,10 +135,10 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u6_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
@@ -145,7 +147,7 @@ pub(super) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 2] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
@@ -155,10 +157,10 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u7_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
@@ -167,7 +169,7 @@ pub(super) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 2] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
@@ -177,12 +179,12 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { _mm512_loadu_epi8(input.cast()) };
+    let packed = unsafe { _mm512_loadu_epi8(read_partial::<64>(input, read_n).as_ptr().cast()) };
     unpack_u8_to_u16_ordered(packed)
 }
 
@@ -190,16 +192,17 @@ pub(super) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 2>(hi_bits);
 
@@ -210,12 +213,13 @@ pub(super) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -230,12 +234,13 @@ pub(super) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -250,16 +255,17 @@ pub(super) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 2>(hi_bits);
 
@@ -270,12 +276,13 @@ pub(super) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -290,12 +297,13 @@ pub(super) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -310,12 +318,13 @@ pub(super) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -330,12 +339,12 @@ pub(super) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 2] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m512i; 2] {
+pub(crate) unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m512i; 2] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_si512x2(input) }
+    unsafe { load_si512x2(read_partial::<128>(input, read_n * 2).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -375,7 +384,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -421,7 +430,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -467,7 +476,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -515,7 +524,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 pub const _MM_TERNLOG_A: i32 = 0xF0; // 11110000
 pub const _MM_TERNLOG_B: i32 = 0xCC; // 11001100
@@ -162,13 +162,105 @@ pub(super) fn slli_epi16<const IMM8: u32, const N: usize>(mut data: [__m512i; N]
     data
 }
 
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [__m512i; 2]) -> [__m512i; 2] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        let v = data[i];
+        data[i] = _mm512_xor_si512(_mm512_slli_epi16::<1>(v), _mm512_srai_epi16::<15>(v));
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [__m512i; 2]) -> [__m512i; 2] {
+    let zero = _mm512_setzero_si512();
+    let one = _mm512_set1_epi16(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        let v = data[i];
+        data[i] = _mm512_xor_si512(
+            _mm512_srli_epi16::<1>(v),
+            _mm512_sub_epi16(zero, _mm512_and_si512(v, one)),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m512i; 2], reference: u16) -> [__m512i; 2] {
+    let reference = _mm512_set1_epi16(reference as i16);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        data[i] = _mm512_sub_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m512i; 2], reference: u16) -> [__m512i; 2] {
+    let reference = _mm512_set1_epi16(reference as i16);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        data[i] = _mm512_add_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the number of bits required to represent the largest of the first `n` 16-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m512i; 2], n: usize) -> u32 {
+    let mut acc = _mm512_setzero_si512();
+    for (i, register) in data.iter().enumerate() {
+        let selected = n.saturating_sub(i * 32).min(32);
+        let mask = ((1u64 << selected) - 1) as __mmask32;
+        acc = _mm512_or_si512(acc, _mm512_maskz_mov_epi16(mask, *register));
+    }
+
+    let lanes = _mm512_reduce_or_epi32(acc) as u32;
+    16 - ((lanes | (lanes >> 16)) as u16).leading_zeros()
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the position of the first 16-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[__m512i; 2],
+    deltas: &[__m512i; 2],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let mask = if strict {
+            _mm512_cmple_epu16_mask(*value, *delta)
+        } else {
+            _mm512_cmplt_epu16_mask(*value, *delta)
+        };
+        if mask != 0 {
+            return Some(i * 32 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use std::cmp;
-
     use super::*;
     use crate::X64;
-    use crate::uint16::avx512::data::{load_si512x2, load_u16x64};
+    use crate::uint16::avx512::data::load_si512x2;
     use crate::uint16::test_util::*;
 
     #[test]
@@ -187,7 +279,7 @@ mod tests {
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -197,13 +289,13 @@ mod tests {
         ignore
     )]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data =
             unsafe { _mm512_loadu_epi8(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr().cast()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
@@ -213,13 +305,13 @@ mod tests {
         ignore
     )]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { _mm512_loadu_epi8(expected.as_ptr().cast()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
@@ -229,13 +321,75 @@ mod tests {
         ignore
     )]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { _mm512_loadu_epi8(expected.as_ptr().cast()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [__m512i; 2]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m512i; 2]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(32, 16)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 16)]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u16; X64] = core::array::from_fn(|i| 1 << (i % 16));
+        input[X64 - 1] = u16::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m512i; 2]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::arch::aarch64::*;
-use std::mem::MaybeUninit;
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
 
 use super::polyfill::*;
 use crate::X64;
@@ -14,7 +14,7 @@ pub(super) fn load_u16x64(block: &[u16; X64]) -> [uint16x8_t; 8] {
     for i in 0..8 {
         data[i].write(unsafe { _neon_load_u16(ptr.add(i * 8)) });
     }
-    unsafe { std::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
+    unsafe { core::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
 }
 
 #[target_feature(enable = "neon")]
@@ -62,5 +62,5 @@ pub(super) unsafe fn load_u16x8x8(ptr: *const u8) -> [uint16x8_t; 8] {
     for i in 0..8 {
         data[i].write(unsafe { _neon_load_u16(ptr.add(i * 8)) });
     }
-    unsafe { std::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
+    unsafe { core::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
 }
@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -31,7 +31,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
 
     let mask = _neon_nonzero_mask_u8([cmp1, cmp2, cmp3, cmp4]);
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -80,7 +80,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let hi_1bit4 = _neon_srli_u8::<2>(data[3]);
 
     let mask = _neon_nonzero_mask_u8([hi_1bit1, hi_1bit2, hi_1bit3, hi_1bit4]);
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), mask) };
 }
 
 #[target_feature(enable = "neon")]
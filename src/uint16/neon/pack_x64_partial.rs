@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -20,7 +20,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [uint16x8_t; 8], _pack_n: usize)
 unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let select_mask = _neon_set1_u8(0b1);
     let mask = test_nonzero_mask(data, select_mask);
-    unsafe { std::ptr::write_unaligned(out.cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.cast(), mask) };
 }
 
 #[inline]
@@ -55,8 +55,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize)
     let hi_mask = test_nonzero_mask(data, hi_select_mask);
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -80,9 +80,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize)
     let b2_mask = test_nonzero_mask(data, b2_select_mask);
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -246,7 +246,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u1() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 2) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 2) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -272,7 +272,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u2() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 3) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 3) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -308,7 +308,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u3() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 4) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 4) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -347,7 +347,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u4() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 16) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 16) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -380,7 +380,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u5() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 32) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 32) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -416,7 +416,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u6() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 64) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 64) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -455,7 +455,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u7() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 64) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 64) as u16);
         let data = unsafe { load_u16x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline]
 #[target_feature(enable = "neon")]
@@ -153,7 +153,7 @@ pub(super) fn _neon_slli_u8<const IMM8: i32>(a: uint8x16_t) -> uint8x16_t {
 #[target_feature(enable = "neon")]
 /// Return a bitmask with a set bit indicating the element at the same index is non-zero.
 pub fn _neon_nonzero_mask_u8(regs: [uint8x16_t; 4]) -> u64 {
-    let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(regs) };
+    let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(regs) };
     let interleaved = unsafe { vld4q_u8(view.as_ptr()) };
 
     let zeroes = vdupq_n_u8(0);
@@ -175,7 +175,7 @@ fn vmovmaskq_u8(chunks: [uint8x16_t; 4]) -> u64 {
     let t2 = vsriq_n_u8::<2>(t1, t0);
     let t3 = vsriq_n_u8::<4>(t2, t2);
     let t4 = vshrn_n_u16::<4>(vreinterpretq_u16_u8(t3));
-    unsafe { std::mem::transmute::<uint8x8_t, u64>(t4) }
+    unsafe { core::mem::transmute::<uint8x8_t, u64>(t4) }
 }
 
 #[inline]
@@ -271,7 +271,7 @@ mod tests {
             let a = _neon_set1_u16(4);
             let b = _neon_set1_u16(2);
             let result = _neon_pack_u16x16(a, b);
-            let view = std::mem::transmute::<uint8x16_t, [u8; 16]>(result);
+            let view = core::mem::transmute::<uint8x16_t, [u8; 16]>(result);
             assert_eq!(
                 view,
                 [
@@ -289,7 +289,7 @@ mod tests {
             let a = _neon_set1_u16(4);
             let b = _neon_set1_u16(4);
             let result = _neon_cvteu16_u8(a, b);
-            let view = std::mem::transmute::<uint8x16_t, [u8; 16]>(result);
+            let view = core::mem::transmute::<uint8x16_t, [u8; 16]>(result);
             assert_eq!(view, [4; 16]);
         }
     }
@@ -300,7 +300,7 @@ mod tests {
         unsafe {
             let a = _neon_set1_u8(4);
             let result = _neon_cvteu8_u16(a);
-            let view = std::mem::transmute::<[uint16x8_t; 2], [u16; 16]>(result);
+            let view = core::mem::transmute::<[uint16x8_t; 2], [u16; 16]>(result);
             assert_eq!(view, [4; 16]);
         }
     }
@@ -360,11 +360,11 @@ mod tests {
     fn test_mov_maskz_u8() {
         unsafe {
             let result = _neon_mov_maskz_u8(u64::MAX);
-            let view = std::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(result);
+            let view = core::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(result);
             assert_eq!(view, [1; 64]);
 
             let result = _neon_mov_maskz_u8(0);
-            let view = std::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(result);
+            let view = core::mem::transmute::<[uint8x16_t; 4], [u8; 64]>(result);
             assert_eq!(view, [0; 64]);
         }
     }
@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -20,7 +20,7 @@ pub unsafe fn from_u1(input: *const u8) -> [uint16x8_t; 8] {
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
 unsafe fn unpack_u1_registers(input: *const u8) -> [uint8x16_t; 4] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
     _neon_mov_maskz_u8(mask)
 }
 
@@ -63,7 +63,7 @@ unsafe fn unpack_u3_registers(input: *const u8) -> [uint8x16_t; 4] {
     let packed_2bit = unsafe { _neon_load_u8(input.add(0)) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let mut hi_1bits = _neon_mov_maskz_u8(hi_bitmask);
     hi_1bits = slli_u8::<2, 4>(hi_1bits);
@@ -329,7 +329,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -372,7 +372,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::polyfill::*;
 
@@ -253,6 +253,109 @@ pub(super) fn slli_u16<const IMM8: i32, const N: usize>(
     data
 }
 
+#[target_feature(enable = "neon")]
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [uint16x8_t; 8]) -> [uint16x8_t; 8] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = veorq_u16(
+            vshlq_n_u16::<1>(v),
+            vreinterpretq_u16_s16(vshrq_n_s16::<15>(vreinterpretq_s16_u16(v))),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [uint16x8_t; 8]) -> [uint16x8_t; 8] {
+    let one = vdupq_n_u16(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = veorq_u16(
+            vshrq_n_u16::<1>(v),
+            vreinterpretq_u16_s16(vnegq_s16(vreinterpretq_s16_u16(vandq_u16(v, one)))),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [uint16x8_t; 8], reference: u16) -> [uint16x8_t; 8] {
+    let reference = vdupq_n_u16(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = vsubq_u16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [uint16x8_t; 8], reference: u16) -> [uint16x8_t; 8] {
+    let reference = vdupq_n_u16(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = vaddq_u16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the number of bits required to represent the largest of the first `n` 16-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[uint16x8_t; 8], n: usize) -> u32 {
+    const POSITIONS: [u16; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+
+    let limit = vdupq_n_u16(n as u16);
+    let step = vdupq_n_u16(8);
+    let mut positions = unsafe { vld1q_u16(POSITIONS.as_ptr()) };
+
+    let mut acc = vdupq_n_u16(0);
+    for register in data {
+        let selected = vcltq_u16(positions, limit);
+        acc = vmaxq_u16(acc, vandq_u16(selected, *register));
+        positions = vaddq_u16(positions, step);
+    }
+
+    16 - vmaxvq_u16(acc).leading_zeros()
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the position of the first 16-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[uint16x8_t; 8],
+    deltas: &[uint16x8_t; 8],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let descent = if strict {
+            vcleq_u16(*value, *delta)
+        } else {
+            vcltq_u16(*value, *delta)
+        };
+        // Narrow each 16-bit lane of the mask to 8 bits so it fits within a single u64.
+        let mask = vget_lane_u64::<0>(vreinterpret_u64_u8(vmovn_u16(descent)));
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,64 +366,117 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u16_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u8_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_u8x16x4(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_u8x16x4(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_u8x16x4(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [uint16x8_t; 8]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(encoded) };
         assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(8, 8)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 16)]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u16; X64] = core::array::from_fn(|i| 1 << (i % 16));
+        input[X64 - 1] = u16::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
     }
 }
@@ -0,0 +1,124 @@
+//! Random access to single values of a compressed block.
+//!
+//! Each [X64] half of a block is packed either in full, where the kernels are free to
+//! shuffle the elements across lanes, or partially with the element order maintained.
+use crate::uint16::{compressed_size, max_compressed_size};
+use crate::util::{HalfLayout, unpack_lane};
+use crate::{X64, X128};
+
+/// Get the value at `index` of a compressed block without decompressing the rest of
+/// the block.
+///
+/// Only the bytes holding the bits of the selected value are read from the input, which
+/// is considerably cheaper than [crate::decompress] when only a few values of the block
+/// are needed, e.g. when probing skip lists.
+///
+/// - `nbits` should be the bit length of the compressed block as reported by the
+///   [crate::CompressionDetails] after compressing the block.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `index` is the position of the element to read and must be less than `n`.
+///
+/// Unlike [crate::decompress], the input does not need to be padded, it only needs to hold
+/// the `compressed_size(nbits, n)` bytes of the block.
+///
+/// The returned value is the value as it was packed, blocks compressed with one of the
+/// delta modes will return the delta rather than the original value.
+pub fn get(nbits: u8, n: usize, input: &[u8], index: usize) -> u16 {
+    assert!(nbits <= 16, "compressed bitlength must be no more than 16");
+    assert!(n <= X128, "provided n is is greater than 128");
+    assert!(
+        index < n,
+        "provided index is out of bounds of the compressed block"
+    );
+    let nbits = nbits as usize;
+    assert!(
+        input.len() >= compressed_size(nbits, n),
+        "input buffer is too small to hold the compressed block",
+    );
+
+    let (half, position, pack_n) = if index < X64 {
+        (input, index, n.min(X64))
+    } else {
+        (
+            &input[max_compressed_size::<X64>(nbits)..],
+            index - X64,
+            n - X64,
+        )
+    };
+
+    let is_full = (index < X64 && n > X64) || n == X128;
+    let (layout, u8_lane) = if is_full {
+        (HalfLayout::Unordered, unordered_u8_lane(position))
+    } else {
+        (HalfLayout::Ordered { pack_n }, position)
+    };
+
+    match nbits {
+        0 => 0,
+        1..=8 => unpack_lane(half, nbits, u8_lane, layout) as u16,
+        9..=15 => {
+            let stride = layout.lane_stride();
+            let hi = unpack_lane(&half[stride..], nbits - 8, u8_lane, layout) as u16;
+            half[u8_lane] as u16 | (hi << 8)
+        },
+        _ => u16::from_le_bytes([half[position * 2], half[position * 2 + 1]]),
+    }
+}
+
+/// Returns the lane of the element at `position` after packing the half to 8-bit
+/// elements, the inverse of `PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT`.
+const fn unordered_u8_lane(position: usize) -> usize {
+    let within_half = position % 32;
+    (within_half / 16) * 32 + (within_half % 16) * 2 + position / 32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint16::X128_MAX_OUTPUT_LEN;
+    use crate::uint16::test_util::{
+        PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT,
+        load_uint16_regression_layout,
+    };
+
+    #[test]
+    fn test_unordered_lanes_match_layout() {
+        for position in 0..X64 {
+            let lane = unordered_u8_lane(position);
+            assert_eq!(
+                PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT[lane] as usize,
+                position
+            );
+        }
+    }
+
+    #[test]
+    fn test_v1_layout_regression() {
+        let tester = load_uint16_regression_layout();
+
+        let mut padded = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for (len, bit_len, _, input) in tester.iter_tests() {
+            padded[..input.len()].copy_from_slice(input);
+            crate::decompress(len, bit_len, &padded, &mut decompressed);
+
+            for (index, expected) in decompressed[..len].iter().enumerate() {
+                assert_eq!(
+                    get(bit_len, len, input, index),
+                    *expected,
+                    "value does not match decompress, length:{len} bit_len:{bit_len} index:{index}",
+                );
+            }
+        }
+    }
+
+    #[rstest::rstest]
+    #[should_panic(expected = "provided index is out of bounds of the compressed block")]
+    #[case(64, 64)]
+    #[should_panic(expected = "input buffer is too small to hold the compressed block")]
+    #[case(128, 0)]
+    fn test_get_invalid_args(#[case] n: usize, #[case] index: usize) {
+        let input = [0; 64 * 2];
+        get(16, n, &input, index);
+    }
+}
@@ -1,14 +1,16 @@
 use crate::uint16::{X128_MAX_OUTPUT_LEN, compressed_size};
-use crate::{CompressionDetails, X128};
+use crate::util::{as_unsigned_i16, as_unsigned_i16_mut, zigzag_encode_i16};
+use crate::{CompressionDetails, X64, X128};
 
 pub(super) mod data;
 mod pack_x128;
 pub(super) mod pack_x64_full;
 pub(super) mod pack_x64_partial;
-pub(super) mod polyfill;
+pub(crate) mod polyfill;
+mod unpack_widen_x128;
 mod unpack_x128;
-pub(super) mod unpack_x64_full;
-pub(super) mod unpack_x64_partial;
+pub(crate) mod unpack_x64_full;
+pub(crate) mod unpack_x64_partial;
 mod util;
 
 /// Pack a block of 128 16-bit integers and write the compressed block to `out`.
@@ -21,13 +23,42 @@ pub unsafe fn pack_x128(
     pack_n: usize,
 ) -> CompressionDetails {
     let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
-    let nbits = 32 - max.leading_zeros();
+    let nbits = 16 - max.leading_zeros();
 
     unsafe { pack_x128::to_nbits(nbits as usize, out.as_mut_ptr(), block, pack_n) };
 
     CompressionDetails {
         compressed_bit_length: nbits as u8,
         bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+/// Pack the two x64 halves of a block of 128 16-bit integers and write the compressed
+/// block to `out`, the right half is only read when `pack_n` is greater than 64.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_halves_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    [left, right]: [&[u16; X64]; 2],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = left
+        .iter()
+        .chain(right)
+        .take(pack_n)
+        .fold(0, |a, b| a.max(*b));
+    let nbits = 16 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits_halves(nbits as usize, out.as_mut_ptr(), [left, right], pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
     }
 }
 
@@ -39,16 +70,16 @@ pub unsafe fn pack_x128(
 pub unsafe fn pack_delta_x128(
     mut last_value: u16,
     out: &mut [u8; X128_MAX_OUTPUT_LEN],
-    block: &mut [u16; X128],
+    block: &[u16; X128],
     pack_n: usize,
 ) -> CompressionDetails {
-    for v in block.iter_mut() {
-        let value = *v;
-        *v = value.wrapping_sub(last_value);
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value);
         last_value = value;
     }
 
-    unsafe { pack_x128(out, block, pack_n) }
+    unsafe { pack_x128(out, &deltas, pack_n) }
 }
 
 /// Pack a block of 128 16-bit integers and write the compressed block to `out` after
@@ -59,24 +90,117 @@ pub unsafe fn pack_delta_x128(
 pub unsafe fn pack_delta1_x128(
     mut last_value: u16,
     out: &mut [u8; X128_MAX_OUTPUT_LEN],
-    block: &mut [u16; X128],
+    block: &[u16; X128],
     pack_n: usize,
 ) -> CompressionDetails {
-    for v in block.iter_mut() {
-        let value = *v;
-        *v = value.wrapping_sub(last_value).wrapping_sub(1);
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value).wrapping_sub(1);
         last_value = value;
     }
 
-    unsafe { pack_x128(out, block, pack_n) }
+    unsafe { pack_x128(out, &deltas, pack_n) }
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_x128(block: &[u16; X128], pack_n: usize) -> u8 {
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    (16 - max.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta_x128(mut last_value: u16, block: &[u16; X128], pack_n: usize) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= value.wrapping_sub(last_value);
+        last_value = value;
+    }
+    (16 - acc.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta1_x128(
+    mut last_value: u16,
+    block: &[u16; X128],
+    pack_n: usize,
+) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= value.wrapping_sub(last_value).wrapping_sub(1);
+        last_value = value;
+    }
+    (16 - acc.leading_zeros()) as u8
+}
+
+/// Pack a block of 128 16-bit integers and write the compressed block to `out` after
+/// applying Delta encoding, unless one of the integers is smaller than the integer before it,
+/// in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta_x128(
+    mut last_value: u16,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u16; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let mut deltas = [0; X128];
+    for (i, (delta, &value)) in deltas.iter_mut().zip(block).take(pack_n).enumerate() {
+        if value < last_value {
+            return Err(i);
+        }
+        *delta = value - last_value;
+        last_value = value;
+    }
+
+    Ok(unsafe { pack_x128(out, &deltas, pack_n) })
+}
+
+/// Pack a block of 128 16-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding, unless one of the integers is not greater than the integer
+/// before it, in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta1_x128(
+    mut last_value: u16,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u16; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let mut deltas = [0; X128];
+    for (i, (delta, &value)) in deltas.iter_mut().zip(block).take(pack_n).enumerate() {
+        if value <= last_value {
+            return Err(i);
+        }
+        *delta = value - last_value - 1;
+        last_value = value;
+    }
+
+    Ok(unsafe { pack_x128(out, &deltas, pack_n) })
 }
 
 /// Pack a block of 128 16-bit integers and write the compressed block to `out`.
 ///
 /// # Safety
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `16`.
 pub unsafe fn unpack_x128(
     nbits: u8,
@@ -88,13 +212,49 @@ pub unsafe fn unpack_x128(
     compressed_size(nbits as usize, read_n)
 }
 
+/// Unpack a compressed block of 128 16-bit integers into the two x64 halves of a block,
+/// the right half is only written when `read_n` is greater than 64 or `nbits` is `0`.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_halves_x128(
+    nbits: u8,
+    input: &[u8],
+    halves: [&mut [u16; X64]; 2],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_halves(nbits as usize, input.as_ptr(), halves, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 integers which were packed as 8-bit integers, widening them to
+/// 16-bit integers as they are written to `block`.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_u8_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [u16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_widen_x128::from_nbits_u8(nbits as usize, input.as_ptr(), block, read_n) };
+    crate::uint8::compressed_size(nbits as usize, read_n)
+}
+
 /// Pack a block of 128 16-bit integers and write the compressed block to `out` after
 /// applying Delta encoding.
 ///
 /// # Safety
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `16`.
 pub unsafe fn unpack_delta_x128(
     nbits: u8,
@@ -114,8 +274,8 @@ pub unsafe fn unpack_delta_x128(
 ///
 /// # Safety
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `16`.
 pub unsafe fn unpack_delta1_x128(
     nbits: u8,
@@ -129,3 +289,311 @@ pub unsafe fn unpack_delta1_x128(
     };
     compressed_size(nbits as usize, read_n)
 }
+
+/// Pack a block of 128 signed 16-bit integers and write the compressed block to `out`
+/// after applying ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i16; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = block
+        .iter()
+        .take(pack_n)
+        .fold(0, |a, b| a.max(zigzag_encode_i16(*b)));
+    let nbits = 16 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits_zigzag(nbits as usize, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+/// Pack a block of 128 signed 16-bit integers and write the compressed block to `out`
+/// after applying Delta encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_delta_x128(
+    mut last_value: i16,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i16; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value);
+        last_value = value;
+    }
+
+    unsafe { pack_zigzag_x128(out, &deltas, pack_n) }
+}
+
+/// Pack a block of 128 signed 16-bit integers and write the compressed block to `out`
+/// after applying Delta-1 encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_delta1_x128(
+    mut last_value: i16,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i16; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value).wrapping_sub(1);
+        last_value = value;
+    }
+
+    unsafe { pack_zigzag_x128(out, &deltas, pack_n) }
+}
+
+/// Returns the bit length required to pack the first `pack_n` signed integers of the block
+/// after applying ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_zigzag_x128(block: &[i16; X128], pack_n: usize) -> u8 {
+    let max = block
+        .iter()
+        .take(pack_n)
+        .fold(0, |a, b| a.max(zigzag_encode_i16(*b)));
+    (16 - max.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` signed integers of the block
+/// after applying Delta encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_zigzag_delta_x128(
+    mut last_value: i16,
+    block: &[i16; X128],
+    pack_n: usize,
+) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= zigzag_encode_i16(value.wrapping_sub(last_value));
+        last_value = value;
+    }
+    (16 - acc.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` signed integers of the block
+/// after applying Delta-1 encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_zigzag_delta1_x128(
+    mut last_value: i16,
+    block: &[i16; X128],
+    pack_n: usize,
+) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= zigzag_encode_i16(value.wrapping_sub(last_value).wrapping_sub(1));
+        last_value = value;
+    }
+    (16 - acc.leading_zeros()) as u8
+}
+
+/// Unpack a block of 128 signed 16-bit integers and reverse the ZigZag encoding.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_zigzag_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [i16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_zigzag(nbits as usize, 0, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 signed 16-bit integers, reverse the ZigZag encoding and add
+/// `base` to each element, wrapping on overflow.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_zigzag_offset_x128(
+    nbits: u8,
+    base: i16,
+    input: &[u8],
+    block: &mut [i16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_zigzag(nbits as usize, base, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 signed 16-bit integers, reverse the ZigZag encoding
+/// and then the Delta encoding.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_zigzag_delta_x128(
+    nbits: u8,
+    last_value: i16,
+    input: &[u8],
+    block: &mut [i16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_zigzag_delta(
+            nbits as usize,
+            last_value,
+            input.as_ptr(),
+            block,
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 signed 16-bit integers, reverse the ZigZag encoding
+/// and then the Delta-1 encoding.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_zigzag_delta1_x128(
+    nbits: u8,
+    last_value: i16,
+    input: &[u8],
+    block: &mut [i16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_zigzag_delta1(
+            nbits as usize,
+            last_value,
+            input.as_ptr(),
+            block,
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Pack a block of 128 16-bit integers and write the compressed block to `out` after
+/// subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u16; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 16 - (max - reference).leading_zeros();
+
+    unsafe { pack_x128::to_nbits_for(nbits as usize, reference, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: reference as u64,
+        last_value: 0,
+    }
+}
+
+/// Pack a block of 128 signed 16-bit integers and write the compressed block to `out`
+/// after subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_signed_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i16; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).copied().max().unwrap_or(0);
+    let nbits = 16 - (max.wrapping_sub(reference) as u16).leading_zeros();
+
+    unsafe {
+        pack_x128::to_nbits_for(
+            nbits as usize,
+            reference as u16,
+            out.as_mut_ptr(),
+            as_unsigned_i16(block),
+            pack_n,
+        )
+    };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: reference as i64 as u64,
+        last_value: 0,
+    }
+}
+
+/// Unpack a block of 128 16-bit integers and add the `reference` value back to each element.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_for_x128(
+    nbits: u8,
+    reference: u16,
+    input: &[u8],
+    block: &mut [u16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(nbits as usize, reference, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 signed 16-bit integers and add the `reference` value back to
+/// each element.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_signed_for_x128(
+    nbits: u8,
+    reference: i16,
+    input: &[u8],
+    block: &mut [i16; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(
+            nbits as usize,
+            reference as u16,
+            input.as_ptr(),
+            as_unsigned_i16_mut(block),
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
@@ -27,7 +27,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 2-bit elements.
@@ -73,7 +73,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2]) {
     let hi_1bitmask2 = _scalar_mask_u8x32(hi_1bit2);
 
     let hi_merged_mask = ((hi_1bitmask2 as u64) << 32) | hi_1bitmask1 as u64;
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 4-bit elements.
@@ -24,7 +24,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.cast(), merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 2-bit elements.
@@ -53,8 +53,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let hi_merged_mask = ((hi_mask2 as u64) << 32) | hi_mask1 as u64;
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 3-bit elements.
@@ -88,9 +88,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let b2_merged_mask = ((b2_mask2 as u64) << 32) | b2_mask1 as u64;
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 4-bit elements.
@@ -245,7 +245,7 @@ mod tests {
 
     #[test]
     fn test_to_u1() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 2) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 2) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -270,7 +270,7 @@ mod tests {
 
     #[test]
     fn test_to_u2() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 3) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 3) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -305,7 +305,7 @@ mod tests {
 
     #[test]
     fn test_to_u3() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 4) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 4) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -343,7 +343,7 @@ mod tests {
 
     #[test]
     fn test_to_u4() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 16) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 16) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -375,7 +375,7 @@ mod tests {
 
     #[test]
     fn test_to_u5() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 32) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 32) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -410,7 +410,7 @@ mod tests {
 
     #[test]
     fn test_to_u6() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 64) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 64) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -448,7 +448,7 @@ mod tests {
 
     #[test]
     fn test_to_u7() {
-        let data: [u16; X64] = std::array::from_fn(|i| (i % 64) as u16);
+        let data: [u16; X64] = core::array::from_fn(|i| (i % 64) as u16);
         let data = load_u16x64(&data);
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
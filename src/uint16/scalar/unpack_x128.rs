@@ -1,6 +1,8 @@
 use super::data::*;
+use super::util::{add_reference, zigzag_decode};
 use super::{unpack_x64_full, unpack_x64_partial};
 use crate::uint16::{max_compressed_size, split_block_mut};
+use crate::util::as_unsigned_i16_mut;
 use crate::{X64, X128};
 
 #[inline]
@@ -11,15 +13,33 @@ use crate::{X64, X128};
 /// - `nbits` must be between 0 and 16.
 /// - `read_n` must be no greater than 128.
 pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u16; X128], read_n: usize) {
+    unsafe { from_nbits_halves(nbits, input, split_block_mut(out), read_n) };
+}
+
+#[inline]
+/// Unpack the `nbits` bit length elements of a block into its two x64 halves.
+///
+/// The right half is only written when `read_n` is greater than 64 or `nbits` is `0`.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_halves(
+    nbits: usize,
+    input: *const u8,
+    halves: [&mut [u16; X64]; 2],
+    read_n: usize,
+) {
     debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
     debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
     #[allow(clippy::type_complexity)]
-    const LUT: [unsafe fn(out: *const u8, &mut [u16; X128], usize); 17] = [
+    const LUT: [unsafe fn(out: *const u8, [&mut [u16; X64]; 2], usize); 17] = [
         from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8, from_u9,
         from_u10, from_u11, from_u12, from_u13, from_u14, from_u15, from_u16,
     ];
     let func = unsafe { LUT.get_unchecked(nbits) };
-    unsafe { func(input, out, read_n) };
+    unsafe { func(input, halves, read_n) };
 }
 
 #[inline]
@@ -60,15 +80,14 @@ pub unsafe fn from_nbits_delta1(
     decode_delta1(last_value, out);
 }
 
-unsafe fn from_u0(_input: *const u8, out: &mut [u16; X128], _read_n: usize) {
-    out.fill(0);
+unsafe fn from_u0(_input: *const u8, [left, right]: [&mut [u16; X64]; 2], _read_n: usize) {
+    left.fill(0);
+    right.fill(0);
 }
 
 macro_rules! define_x128_unpacker {
     ($func_name:ident, $bit_length:expr) => {
-        unsafe fn $func_name(input: *const u8, out: &mut [u16; X128], read_n: usize) {
-            let [left, right] = split_block_mut(out);
-
+        unsafe fn $func_name(input: *const u8, [left, right]: [&mut [u16; X64]; 2], read_n: usize) {
             if read_n <= 64 {
                 let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
                 store_u16x64(left, unpacked);
@@ -129,13 +148,249 @@ fn decode_delta1(mut last_value: u16, block: &mut [u16; X128]) -> u16 {
     last_value
 }
 
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and add `base` to each element, wrapping on overflow.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag(
+    nbits: usize,
+    base: i16,
+    input: *const u8,
+    out: &mut [i16; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, input: *const u8, &mut [i16; X128], usize); 17] = [
+        from_u0_zigzag,
+        from_u1_zigzag,
+        from_u2_zigzag,
+        from_u3_zigzag,
+        from_u4_zigzag,
+        from_u5_zigzag,
+        from_u6_zigzag,
+        from_u7_zigzag,
+        from_u8_zigzag,
+        from_u9_zigzag,
+        from_u10_zigzag,
+        from_u11_zigzag,
+        from_u12_zigzag,
+        from_u13_zigzag,
+        from_u14_zigzag,
+        from_u15_zigzag,
+        from_u16_zigzag,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(base as u16, input, out, read_n) };
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and then the Delta encoding.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag_delta(
+    nbits: usize,
+    last_value: i16,
+    input: *const u8,
+    out: &mut [i16; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits_zigzag(nbits, 0, input, out, read_n) };
+    decode_delta(last_value as u16, as_unsigned_i16_mut(out));
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and then the Delta-1 encoding.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag_delta1(
+    nbits: usize,
+    last_value: i16,
+    input: *const u8,
+    out: &mut [i16; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits_zigzag(nbits, 0, input, out, read_n) };
+    decode_delta1(last_value as u16, as_unsigned_i16_mut(out));
+}
+
+unsafe fn from_u0_zigzag(base: u16, _input: *const u8, out: &mut [i16; X128], _read_n: usize) {
+    out.fill(base as i16);
+}
+
+macro_rules! define_x128_unpacker_zigzag {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(base: u16, input: *const u8, out: &mut [i16; X128], read_n: usize) {
+            let [left, right] = split_block_mut(as_unsigned_i16_mut(out));
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u16x64(left, add_reference(zigzag_decode(unpacked), base));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u16x64(left, add_reference(zigzag_decode(unpacked), base));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u16x64(right, add_reference(zigzag_decode(unpacked), base));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u16x64(left, add_reference(zigzag_decode(unpacked), base));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u16x64(right, add_reference(zigzag_decode(unpacked), base));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_zigzag!(from_u1_zigzag, from_u1, 1);
+define_x128_unpacker_zigzag!(from_u2_zigzag, from_u2, 2);
+define_x128_unpacker_zigzag!(from_u3_zigzag, from_u3, 3);
+define_x128_unpacker_zigzag!(from_u4_zigzag, from_u4, 4);
+define_x128_unpacker_zigzag!(from_u5_zigzag, from_u5, 5);
+define_x128_unpacker_zigzag!(from_u6_zigzag, from_u6, 6);
+define_x128_unpacker_zigzag!(from_u7_zigzag, from_u7, 7);
+define_x128_unpacker_zigzag!(from_u8_zigzag, from_u8, 8);
+define_x128_unpacker_zigzag!(from_u9_zigzag, from_u9, 9);
+define_x128_unpacker_zigzag!(from_u10_zigzag, from_u10, 10);
+define_x128_unpacker_zigzag!(from_u11_zigzag, from_u11, 11);
+define_x128_unpacker_zigzag!(from_u12_zigzag, from_u12, 12);
+define_x128_unpacker_zigzag!(from_u13_zigzag, from_u13, 13);
+define_x128_unpacker_zigzag!(from_u14_zigzag, from_u14, 14);
+define_x128_unpacker_zigzag!(from_u15_zigzag, from_u15, 15);
+define_x128_unpacker_zigzag!(from_u16_zigzag, from_u16, 16);
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u16,
+    input: *const u8,
+    out: &mut [u16; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, input: *const u8, &mut [u16; X128], usize); 17] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+        from_u9_for,
+        from_u10_for,
+        from_u11_for,
+        from_u12_for,
+        from_u13_for,
+        from_u14_for,
+        from_u15_for,
+        from_u16_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+unsafe fn from_u0_for(reference: u16, _input: *const u8, out: &mut [u16; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(
+            reference: u16,
+            input: *const u8,
+            out: &mut [u16; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u16x64(left, add_reference(unpacked, reference));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u16x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u16x64(right, add_reference(unpacked, reference));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u16x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u16x64(right, add_reference(unpacked, reference));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+define_x128_unpacker_for!(from_u9_for, from_u9, 9);
+define_x128_unpacker_for!(from_u10_for, from_u10, 10);
+define_x128_unpacker_for!(from_u11_for, from_u11, 11);
+define_x128_unpacker_for!(from_u12_for, from_u12, 12);
+define_x128_unpacker_for!(from_u13_for, from_u13, 13);
+define_x128_unpacker_for!(from_u14_for, from_u14, 14);
+define_x128_unpacker_for!(from_u15_for, from_u15, 15);
+define_x128_unpacker_for!(from_u16_for, from_u16, 16);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::uint16::X128_MAX_OUTPUT_LEN;
+    use crate::uint16::scalar::pack_x128::{to_nbits_for, to_nbits_zigzag};
+    use crate::uint16::scalar::{pack_zigzag_delta_x128, pack_zigzag_delta1_x128};
 
     #[test]
     fn test_decode_delta_zero_starting_value() {
-        let expected_values: [u16; X128] = std::array::from_fn(|i| i as u16);
+        let expected_values: [u16; X128] = core::array::from_fn(|i| i as u16);
         let mut block = [1; X128];
         block[0] = 0;
         decode_delta(0, &mut block);
@@ -144,7 +399,7 @@ mod tests {
 
     #[test]
     fn test_decode_delta_starting_value() {
-        let expected_values: [u16; X128] = std::array::from_fn(|i| 4 + i as u16);
+        let expected_values: [u16; X128] = core::array::from_fn(|i| 4 + i as u16);
         let mut block = [1; X128];
         block[0] = 0;
         decode_delta(4, &mut block);
@@ -153,7 +408,7 @@ mod tests {
 
     #[test]
     fn test_decode_delta1_zero_starting_value() {
-        let expected_values: [u16; X128] = std::array::from_fn(|i| i as u16 + 1);
+        let expected_values: [u16; X128] = core::array::from_fn(|i| i as u16 + 1);
         let mut block = [0; X128];
         decode_delta1(0, &mut block);
         assert_eq!(block, expected_values);
@@ -161,7 +416,7 @@ mod tests {
 
     #[test]
     fn test_decode_delta1_starting_value() {
-        let expected_values: [u16; X128] = std::array::from_fn(|i| i as u16 + 5);
+        let expected_values: [u16; X128] = core::array::from_fn(|i| i as u16 + 5);
         let mut block = [0; X128];
         decode_delta1(4, &mut block);
         assert_eq!(block, expected_values);
@@ -183,4 +438,96 @@ mod tests {
             )
         }
     }
+
+    #[rstest::rstest]
+    fn test_zigzag_roundtrip(
+        #[values(0, 1, 2, 5, 7, 8, 9, 15, 16)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, -1200, i16::MAX)] base: i16,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u16::MAX >> (16 - nbits)
+        };
+        let expected: [i16; X128] = core::array::from_fn(|i| {
+            let encoded = (i as u32).wrapping_mul(40503) as u16 & mask;
+            crate::util::zigzag_decode_i16(encoded)
+        });
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_zigzag(nbits, packed.as_mut_ptr(), &expected, n);
+            from_nbits_zigzag(nbits, base, packed.as_ptr(), &mut unpacked, n);
+        }
+        let expected = expected.map(|v| v.wrapping_add(base));
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    fn test_zigzag_delta_roundtrip(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, -9, 311)] last_value: i16,
+    ) {
+        let expected: [i16; X128] = core::array::from_fn(|i| {
+            let step = (i as i16 % 5) * 7;
+            if i % 2 == 0 {
+                last_value - step
+            } else {
+                last_value + step
+            }
+        });
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+
+        let details = unsafe { pack_zigzag_delta_x128(last_value, &mut packed, &expected, n) };
+        unsafe {
+            from_nbits_zigzag_delta(
+                details.compressed_bit_length as usize,
+                last_value,
+                packed.as_ptr(),
+                &mut unpacked,
+                n,
+            )
+        };
+        assert_eq!(unpacked[..n], expected[..n]);
+
+        let expected: [i16; X128] = core::array::from_fn(|i| last_value - 3 * (i as i16 + 1));
+        let details = unsafe { pack_zigzag_delta1_x128(last_value, &mut packed, &expected, n) };
+        unsafe {
+            from_nbits_zigzag_delta1(
+                details.compressed_bit_length as usize,
+                last_value,
+                packed.as_ptr(),
+                &mut unpacked,
+                n,
+            )
+        };
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    fn test_for_roundtrip(
+        #[values(0, 1, 7, 9, 16)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u16::MAX >> (16 - nbits)
+        };
+        let reference = u16::MAX - mask;
+        let expected: [u16; X128] =
+            core::array::from_fn(|i| reference + ((i as u16).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
 }
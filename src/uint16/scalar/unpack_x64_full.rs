@@ -13,7 +13,7 @@ pub unsafe fn from_u1(input: *const u8) -> [u16x16; 4] {
 
 #[inline]
 unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
 
     let ones = _scalar_set1_u8(0b1);
     let packed1 = _scalar_mov_maskz_u8x32(mask as u32, ones);
@@ -50,7 +50,7 @@ unsafe fn unpack_u3_registers(input: *const u8) -> [u8x32; 2] {
     let packed_2bit = unsafe { _scalar_load_u8x16(input.add(0)) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let ones = _scalar_set1_u8(0b100);
     let hi_1bits1 = _scalar_mov_maskz_u8x32(hi_bitmask as u32, ones);
@@ -279,7 +279,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -321,7 +321,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,21 +1,22 @@
 use super::data::*;
 use super::polyfill::*;
 use super::util::*;
+use crate::util::read_partial;
 
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[inline]
-unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.cast()) };
+unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
 
     let ones = _scalar_set1_u8(1);
     let packed1 = _scalar_mov_maskz_u8x32(mask as u32, ones);
@@ -27,7 +28,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -37,9 +38,9 @@ pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [u16x16; 4] {
 
 #[inline]
 unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
-    let hi_offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(hi_offset).cast()) };
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _scalar_set1_u8(0b01);
     let lo_bits_packed1 = _scalar_mov_maskz_u8x32(mask1 as u32, bit);
@@ -57,7 +58,7 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [u16x16; 4] {
     let packed = unsafe { unpack_u3_registers(input, read_n) };
@@ -68,9 +69,9 @@ pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [u16x16; 4] {
 unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _scalar_set1_u8(0b001);
     let b0_bits_packed1 = _scalar_mov_maskz_u8x32(mask1 as u32, bit);
@@ -95,17 +96,18 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 #[inline]
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
-    let packed = unsafe { _scalar_load_u8x32(input.cast()) };
+pub(super) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed = unsafe { _scalar_load_u8x32(packed.as_ptr().cast()) };
 
     let mut d1 = u8x32::ZERO;
     let mut d2 = u8x32::ZERO;
@@ -126,7 +128,7 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -137,8 +139,8 @@ pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [u16x16; 4] {
 #[inline]
 unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
 }
@@ -146,7 +148,7 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -157,7 +159,7 @@ pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [u16x16; 4] {
 #[inline]
 unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
@@ -166,7 +168,7 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -177,7 +179,7 @@ pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [u16x16; 4] {
 #[inline]
 unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
@@ -186,26 +188,26 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_u8x32x2(input) };
+    let packed = unsafe { load_u8x32x2(read_partial::<64>(input, read_n).as_ptr()) };
     unpack_u8_to_u16_ordered(packed)
 }
 
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16x16::<8, 4>(hi_bits);
 
@@ -215,11 +217,11 @@ pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -233,11 +235,11 @@ pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -251,15 +253,15 @@ pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16x16::<8, 4>(hi_bits);
 
@@ -269,11 +271,11 @@ pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -287,11 +289,11 @@ pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -305,11 +307,11 @@ pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -323,11 +325,11 @@ pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [u16x16; 4] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [u16x16; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_u16x16x4(input.add(0)) }
+    unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -361,7 +363,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -403,7 +405,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -445,7 +447,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -207,6 +207,48 @@ pub(super) fn slli_u16x16<const IMM8: u32, const N: usize>(mut data: [u16x16; N]
     data
 }
 
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [u16x16; 4]) -> [u16x16; 4] {
+    for i in 0..4 {
+        for j in 0..16 {
+            data[i][j] = crate::util::zigzag_encode_i16(data[i][j] as i16);
+        }
+    }
+    data
+}
+
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [u16x16; 4]) -> [u16x16; 4] {
+    for i in 0..4 {
+        for j in 0..16 {
+            data[i][j] = crate::util::zigzag_decode_i16(data[i][j]) as u16;
+        }
+    }
+    data
+}
+
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [u16x16; 4], reference: u16) -> [u16x16; 4] {
+    for i in 0..4 {
+        for j in 0..16 {
+            data[i][j] = data[i][j].wrapping_sub(reference);
+        }
+    }
+    data
+}
+
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [u16x16; 4], reference: u16) -> [u16x16; 4] {
+    for i in 0..4 {
+        for j in 0..16 {
+            data[i][j] = data[i][j].wrapping_add(reference);
+        }
+    }
+    data
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,60 +258,96 @@ mod tests {
 
     #[test]
     fn test_pack_u16_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
         let packed = pack_u16_to_u8_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u8_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
         let unpacked = unpack_u8_to_u16_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_u8x32x2(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unpack_u8_to_u16_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_u8x32x2(expected.as_ptr()) };
         let packed = pack_u8_to_u4_unordered(data);
         let unpacked = unpack_u4_to_u8_unordered(packed);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_u8x32x2(expected.as_ptr()) };
         let packed = pack_u8_to_u2_unordered(data);
         let unpacked = unpack_u2_to_u8_unordered(packed);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [u16x16; 4]>(input) };
+        let encoded = zigzag_encode(data);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(encoded) };
         assert_eq!(view, expected);
+
+        let decoded = zigzag_decode(encoded);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
+        let subtracted = sub_reference(data, reference);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = add_reference(subtracted, reference);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(added) };
+        assert_eq!(view, input);
     }
 }
@@ -0,0 +1,207 @@
+//! Compressing slices of up to 128 values without first copying them into a block.
+//!
+//! The packing kernels process the two x64 halves of a block independently, so the full
+//! halves of a slice are passed to them in place and only the final partial half is staged
+//! in a buffer on the stack.
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint16::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint16::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint16::neon;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+use crate::uint16::sse41;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+use crate::uint16::wasm_simd128;
+use crate::uint16::{X128_MAX_OUTPUT_LEN, max_compressed_size, scalar};
+use crate::util::{stage_halves, stage_halves_mut};
+use crate::{Backend, CompressionDetails, X64, X128};
+
+/// Apply bitpacking compression to the values of the provided slice.
+///
+/// The block is laid out as if compressed by [crate::compress] with `n` set to the length of
+/// the slice, so it can be decompressed with either [decompress_into_slice] or
+/// [crate::decompress].
+///
+/// The slice must hold no more than 128 values.
+pub fn compress_slice(input: &[u16], out: &mut [u8; X128_MAX_OUTPUT_LEN]) -> CompressionDetails {
+    assert!(input.len() <= X128, "input slice is longer than 128");
+    let n = input.len();
+
+    let mut staged = [0; X64];
+    let halves = stage_halves(input, &mut staged);
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::pack_halves_x128(out, halves, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::pack_halves_x128(out, halves, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::pack_halves_x128(out, halves, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::pack_halves_x128(out, halves, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe { wasm_simd128::pack_halves_x128(out, halves, n) },
+        _ => unsafe { scalar::pack_halves_x128(out, halves, n) },
+    }
+}
+
+/// Decompress the input block containing the packed values, writing one decompressed value
+/// to each element of `out`.
+///
+/// This requires that the values contained were originally compressed with [compress_slice]
+/// or [crate::compress], with `n` set to the length of `out`.
+///
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [CompressionDetails] after compressing the block.
+///
+/// Like [crate::decompress] the input must be padded to the maximum compressed size of a
+/// block with the given bit length, and `out` must hold no more than 128 values.
+pub fn decompress_into_slice(compressed_bit_length: u8, input: &[u8], out: &mut [u16]) -> usize {
+    assert!(
+        compressed_bit_length <= 16,
+        "compressed bitlength must be no more than 16"
+    );
+    assert!(
+        input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(out.len() <= X128, "output slice is longer than 128");
+    let n = out.len();
+
+    let (full, tail) = out.as_chunks_mut::<X64>();
+    let mut staged = [0; X64];
+    let mut spare = [0; X64];
+    let halves = stage_halves_mut(full, &mut staged, &mut spare);
+
+    let bytes_read = match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe {
+            avx512::unpack_halves_x128(compressed_bit_length, input, halves, n)
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe {
+            avx2::unpack_halves_x128(compressed_bit_length, input, halves, n)
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe {
+            sse41::unpack_halves_x128(compressed_bit_length, input, halves, n)
+        },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe {
+            neon::unpack_halves_x128(compressed_bit_length, input, halves, n)
+        },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::unpack_halves_x128(compressed_bit_length, input, halves, n)
+        },
+        _ => unsafe { scalar::unpack_halves_x128(compressed_bit_length, input, halves, n) },
+    };
+
+    tail.copy_from_slice(&staged[..tail.len()]);
+    bytes_read
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(37)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_slice_matches_block(#[case] n: usize) {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128()
+            .into_iter()
+            .map(|block| {
+                let base = block[0];
+                block.map(|value| (value - base) as u16)
+            })
+            .collect::<Vec<_>>();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut expected = [0; X128_MAX_OUTPUT_LEN];
+        for sample in sample_data.iter() {
+            let values = sample.to_vec();
+
+            let details = compress_slice(&values[..n], &mut compressed);
+            let block_details = crate::compress(n, sample, &mut expected);
+            assert_eq!(
+                details.compressed_bit_length,
+                block_details.compressed_bit_length
+            );
+            assert_eq!(details.bytes_written, block_details.bytes_written);
+
+            let mut block = [0; X128];
+            crate::decompress(n, details.compressed_bit_length, &compressed, &mut block);
+            assert_eq!(block[..n], sample[..n]);
+
+            let mut decompressed = vec![0; n];
+            let read = decompress_into_slice(
+                details.compressed_bit_length,
+                &compressed,
+                &mut decompressed,
+            );
+            assert_eq!(read, details.bytes_written);
+            assert_eq!(decompressed, sample[..n]);
+        }
+    }
+
+    #[test]
+    fn test_decompress_into_slice_leaves_surrounding_values() {
+        let values: Vec<u16> = (0..37).map(|i| i * 3).collect();
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = compress_slice(&values, &mut compressed);
+
+        let mut decompressed = [u16::MAX; 40];
+        decompress_into_slice(
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed[..37],
+        );
+        assert_eq!(decompressed[..37], values);
+        assert_eq!(decompressed[37..], [u16::MAX; 3]);
+    }
+
+    #[test]
+    fn test_zero_bit_length() {
+        let values = [0u16; 70];
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = compress_slice(&values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 0);
+
+        let mut decompressed = [1u16; 70];
+        decompress_into_slice(0, &compressed, &mut decompressed);
+        assert_eq!(decompressed, values);
+    }
+
+    #[test]
+    #[should_panic(expected = "input slice is longer than 128")]
+    fn test_compress_slice_too_long() {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        compress_slice(&[0; X128 + 1], &mut compressed);
+    }
+
+    #[test]
+    #[should_panic(expected = "output slice is longer than 128")]
+    fn test_decompress_into_slice_too_long() {
+        let compressed = [0; X128_MAX_OUTPUT_LEN];
+        decompress_into_slice(0, &compressed, &mut [0; X128 + 1]);
+    }
+}
@@ -0,0 +1,805 @@
+use core::arch::x86_64::*;
+
+use super::data::load_u16x64;
+use super::polyfill::_sse_set1_u16;
+use super::util::{bit_length, find_first_descent, sub_reference, zigzag_encode};
+use super::{pack_x64_full, pack_x64_partial};
+use crate::uint16::{max_compressed_size, split_block};
+use crate::util::as_unsigned_i16;
+use crate::{X64, X128};
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length  elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits(nbits: usize, out: *mut u8, block: &[u16; X128], pack_n: usize) {
+    unsafe { to_nbits_halves(nbits, out, split_block(block), pack_n) };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the two x64 halves of a block of integers to `nbits` bit length elements.
+///
+/// The right half is only read when `pack_n` is greater than 64.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_halves(nbits: usize, out: *mut u8, halves: [&[u16; X64]; 2], pack_n: usize) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *mut u8, [&[u16; X64]; 2], usize); 17] = [
+        to_u0, to_u1, to_u2, to_u3, to_u4, to_u5, to_u6, to_u7, to_u8, to_u9, to_u10, to_u11,
+        to_u12, to_u13, to_u14, to_u15, to_u16,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(out, halves, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0(_out: *mut u8, _halves: [&[u16; X64]; 2], _pack_n: usize) {}
+
+macro_rules! define_x128_packer {
+    ($func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn $func_name(out: *mut u8, [left, right]: [&[u16; X64]; 2], pack_n: usize) {
+            let left = load_u16x64(left);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$func_name(out.add(0), left) };
+                let right = load_u16x64(right);
+                unsafe {
+                    pack_x64_partial::$func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$func_name(out.add(0), left) };
+                let right = load_u16x64(right);
+                unsafe {
+                    pack_x64_full::$func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer!(to_u1, 1);
+define_x128_packer!(to_u2, 2);
+define_x128_packer!(to_u3, 3);
+define_x128_packer!(to_u4, 4);
+define_x128_packer!(to_u5, 5);
+define_x128_packer!(to_u6, 6);
+define_x128_packer!(to_u7, 7);
+define_x128_packer!(to_u8, 8);
+define_x128_packer!(to_u9, 9);
+define_x128_packer!(to_u10, 10);
+define_x128_packer!(to_u11, 11);
+define_x128_packer!(to_u12, 12);
+define_x128_packer!(to_u13, 13);
+define_x128_packer!(to_u14, 14);
+define_x128_packer!(to_u15, 15);
+define_x128_packer!(to_u16, 16);
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of signed integers to `nbits` bit length elements after
+/// applying ZigZag encoding.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag(nbits: usize, out: *mut u8, block: &[i16; X128], pack_n: usize) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *mut u8, &[i16; X128], usize); 17] = [
+        to_u0_zigzag,
+        to_u1_zigzag,
+        to_u2_zigzag,
+        to_u3_zigzag,
+        to_u4_zigzag,
+        to_u5_zigzag,
+        to_u6_zigzag,
+        to_u7_zigzag,
+        to_u8_zigzag,
+        to_u9_zigzag,
+        to_u10_zigzag,
+        to_u11_zigzag,
+        to_u12_zigzag,
+        to_u13_zigzag,
+        to_u14_zigzag,
+        to_u15_zigzag,
+        to_u16_zigzag,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_zigzag(_out: *mut u8, _block: &[i16; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_zigzag {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn $func_name(out: *mut u8, block: &[i16; X128], pack_n: usize) {
+            let [left, right] = split_block(as_unsigned_i16(block));
+
+            let left = zigzag_encode(load_u16x64(left));
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u16x64(right));
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u16x64(right));
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_zigzag!(to_u1_zigzag, to_u1, 1);
+define_x128_packer_zigzag!(to_u2_zigzag, to_u2, 2);
+define_x128_packer_zigzag!(to_u3_zigzag, to_u3, 3);
+define_x128_packer_zigzag!(to_u4_zigzag, to_u4, 4);
+define_x128_packer_zigzag!(to_u5_zigzag, to_u5, 5);
+define_x128_packer_zigzag!(to_u6_zigzag, to_u6, 6);
+define_x128_packer_zigzag!(to_u7_zigzag, to_u7, 7);
+define_x128_packer_zigzag!(to_u8_zigzag, to_u8, 8);
+define_x128_packer_zigzag!(to_u9_zigzag, to_u9, 9);
+define_x128_packer_zigzag!(to_u10_zigzag, to_u10, 10);
+define_x128_packer_zigzag!(to_u11_zigzag, to_u11, 11);
+define_x128_packer_zigzag!(to_u12_zigzag, to_u12, 12);
+define_x128_packer_zigzag!(to_u13_zigzag, to_u13, 13);
+define_x128_packer_zigzag!(to_u14_zigzag, to_u14, 14);
+define_x128_packer_zigzag!(to_u15_zigzag, to_u15, 15);
+define_x128_packer_zigzag!(to_u16_zigzag, to_u16, 16);
+
+macro_rules! define_x128_packer_delta {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr, $encode_func_name:ident) => {
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn $func_name(last_value: u16, out: *mut u8, block: &[u16; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let mut left = load_u16x64(left);
+            let previous = $encode_func_name(_sse_set1_u16(last_value), &mut left);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let mut right = load_u16x64(right);
+                $encode_func_name(previous, &mut right);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let mut right = load_u16x64(right);
+                $encode_func_name(previous, &mut right);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Returns the bit length required to pack the first `pack_n` elements of the block.
+pub fn plain_bit_length(block: &[u16; X128], pack_n: usize) -> usize {
+    let [left, right] = split_block(block);
+
+    let mut nbits = bit_length(&load_u16x64(left), pack_n.min(X64));
+    if pack_n > X64 {
+        nbits = nbits.max(bit_length(&load_u16x64(right), pack_n - X64));
+    }
+    nbits as usize
+}
+
+macro_rules! define_x128_bit_length {
+    ($func_name:ident, $encode_func_name:ident) => {
+        #[inline]
+        #[target_feature(enable = "sse4.1")]
+        /// Returns the bit length required to pack the first `pack_n` elements of the block
+        /// once encoded.
+        pub fn $func_name(last_value: u16, block: &[u16; X128], pack_n: usize) -> usize {
+            let [left, right] = split_block(block);
+
+            let mut left = load_u16x64(left);
+            let previous = $encode_func_name(_sse_set1_u16(last_value), &mut left);
+            let mut nbits = bit_length(&left, pack_n.min(X64));
+            if pack_n > X64 {
+                let mut right = load_u16x64(right);
+                $encode_func_name(previous, &mut right);
+                nbits = nbits.max(bit_length(&right, pack_n - X64));
+            }
+            nbits as usize
+        }
+    };
+}
+
+macro_rules! define_x128_checked_bit_length {
+    ($func_name:ident, $encode_func_name:ident, $strict:literal) => {
+        #[inline]
+        #[target_feature(enable = "sse4.1")]
+        /// Returns the bit length required to pack the first `pack_n` elements of the block
+        /// once encoded, or the position of the first of those elements which is out of order
+        /// for the encoding, the first element is compared against `last_value`.
+        pub fn $func_name(
+            last_value: u16,
+            block: &[u16; X128],
+            pack_n: usize,
+        ) -> Result<usize, usize> {
+            let [left, right] = split_block(block);
+
+            let values = load_u16x64(left);
+            let mut deltas = values;
+            let previous = $encode_func_name(_sse_set1_u16(last_value), &mut deltas);
+            if let Some(position) =
+                find_first_descent(&values, &deltas, $strict).filter(|&position| position < pack_n)
+            {
+                return Err(position);
+            }
+            let mut nbits = bit_length(&deltas, pack_n.min(X64));
+            if pack_n > X64 {
+                let values = load_u16x64(right);
+                let mut deltas = values;
+                $encode_func_name(previous, &mut deltas);
+                if let Some(position) = find_first_descent(&values, &deltas, $strict)
+                    .map(|position| X64 + position)
+                    .filter(|&position| position < pack_n)
+                {
+                    return Err(position);
+                }
+                nbits = nbits.max(bit_length(&deltas, pack_n - X64));
+            }
+            Ok(nbits as usize)
+        }
+    };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_delta(
+    nbits: usize,
+    last_value: u16,
+    out: *mut u8,
+    block: &[u16; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, out: *mut u8, &[u16; X128], usize); 17] = [
+        to_u0_delta,
+        to_u1_delta,
+        to_u2_delta,
+        to_u3_delta,
+        to_u4_delta,
+        to_u5_delta,
+        to_u6_delta,
+        to_u7_delta,
+        to_u8_delta,
+        to_u9_delta,
+        to_u10_delta,
+        to_u11_delta,
+        to_u12_delta,
+        to_u13_delta,
+        to_u14_delta,
+        to_u15_delta,
+        to_u16_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_delta(_last_value: u16, _out: *mut u8, _block: &[u16; X128], _pack_n: usize) {}
+
+define_x128_packer_delta!(to_u1_delta, to_u1, 1, encode_delta);
+define_x128_packer_delta!(to_u2_delta, to_u2, 2, encode_delta);
+define_x128_packer_delta!(to_u3_delta, to_u3, 3, encode_delta);
+define_x128_packer_delta!(to_u4_delta, to_u4, 4, encode_delta);
+define_x128_packer_delta!(to_u5_delta, to_u5, 5, encode_delta);
+define_x128_packer_delta!(to_u6_delta, to_u6, 6, encode_delta);
+define_x128_packer_delta!(to_u7_delta, to_u7, 7, encode_delta);
+define_x128_packer_delta!(to_u8_delta, to_u8, 8, encode_delta);
+define_x128_packer_delta!(to_u9_delta, to_u9, 9, encode_delta);
+define_x128_packer_delta!(to_u10_delta, to_u10, 10, encode_delta);
+define_x128_packer_delta!(to_u11_delta, to_u11, 11, encode_delta);
+define_x128_packer_delta!(to_u12_delta, to_u12, 12, encode_delta);
+define_x128_packer_delta!(to_u13_delta, to_u13, 13, encode_delta);
+define_x128_packer_delta!(to_u14_delta, to_u14, 14, encode_delta);
+define_x128_packer_delta!(to_u15_delta, to_u15, 15, encode_delta);
+define_x128_packer_delta!(to_u16_delta, to_u16, 16, encode_delta);
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta-1 encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_delta1(
+    nbits: usize,
+    last_value: u16,
+    out: *mut u8,
+    block: &[u16; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, out: *mut u8, &[u16; X128], usize); 17] = [
+        to_u0_delta1,
+        to_u1_delta1,
+        to_u2_delta1,
+        to_u3_delta1,
+        to_u4_delta1,
+        to_u5_delta1,
+        to_u6_delta1,
+        to_u7_delta1,
+        to_u8_delta1,
+        to_u9_delta1,
+        to_u10_delta1,
+        to_u11_delta1,
+        to_u12_delta1,
+        to_u13_delta1,
+        to_u14_delta1,
+        to_u15_delta1,
+        to_u16_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_delta1(_last_value: u16, _out: *mut u8, _block: &[u16; X128], _pack_n: usize) {}
+
+define_x128_packer_delta!(to_u1_delta1, to_u1, 1, encode_delta1);
+define_x128_packer_delta!(to_u2_delta1, to_u2, 2, encode_delta1);
+define_x128_packer_delta!(to_u3_delta1, to_u3, 3, encode_delta1);
+define_x128_packer_delta!(to_u4_delta1, to_u4, 4, encode_delta1);
+define_x128_packer_delta!(to_u5_delta1, to_u5, 5, encode_delta1);
+define_x128_packer_delta!(to_u6_delta1, to_u6, 6, encode_delta1);
+define_x128_packer_delta!(to_u7_delta1, to_u7, 7, encode_delta1);
+define_x128_packer_delta!(to_u8_delta1, to_u8, 8, encode_delta1);
+define_x128_packer_delta!(to_u9_delta1, to_u9, 9, encode_delta1);
+define_x128_packer_delta!(to_u10_delta1, to_u10, 10, encode_delta1);
+define_x128_packer_delta!(to_u11_delta1, to_u11, 11, encode_delta1);
+define_x128_packer_delta!(to_u12_delta1, to_u12, 12, encode_delta1);
+define_x128_packer_delta!(to_u13_delta1, to_u13, 13, encode_delta1);
+define_x128_packer_delta!(to_u14_delta1, to_u14, 14, encode_delta1);
+define_x128_packer_delta!(to_u15_delta1, to_u15, 15, encode_delta1);
+define_x128_packer_delta!(to_u16_delta1, to_u16, 16, encode_delta1);
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta encoding followed by ZigZag encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// The signed elements are provided as their unsigned bit pattern, see
+/// [as_unsigned_i16](crate::util::as_unsigned_i16).
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag_delta(
+    nbits: usize,
+    last_value: u16,
+    out: *mut u8,
+    block: &[u16; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, out: *mut u8, &[u16; X128], usize); 17] = [
+        to_u0_zigzag_delta,
+        to_u1_zigzag_delta,
+        to_u2_zigzag_delta,
+        to_u3_zigzag_delta,
+        to_u4_zigzag_delta,
+        to_u5_zigzag_delta,
+        to_u6_zigzag_delta,
+        to_u7_zigzag_delta,
+        to_u8_zigzag_delta,
+        to_u9_zigzag_delta,
+        to_u10_zigzag_delta,
+        to_u11_zigzag_delta,
+        to_u12_zigzag_delta,
+        to_u13_zigzag_delta,
+        to_u14_zigzag_delta,
+        to_u15_zigzag_delta,
+        to_u16_zigzag_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_zigzag_delta(
+    _last_value: u16,
+    _out: *mut u8,
+    _block: &[u16; X128],
+    _pack_n: usize,
+) {
+}
+
+define_x128_packer_delta!(to_u1_zigzag_delta, to_u1, 1, encode_zigzag_delta);
+define_x128_packer_delta!(to_u2_zigzag_delta, to_u2, 2, encode_zigzag_delta);
+define_x128_packer_delta!(to_u3_zigzag_delta, to_u3, 3, encode_zigzag_delta);
+define_x128_packer_delta!(to_u4_zigzag_delta, to_u4, 4, encode_zigzag_delta);
+define_x128_packer_delta!(to_u5_zigzag_delta, to_u5, 5, encode_zigzag_delta);
+define_x128_packer_delta!(to_u6_zigzag_delta, to_u6, 6, encode_zigzag_delta);
+define_x128_packer_delta!(to_u7_zigzag_delta, to_u7, 7, encode_zigzag_delta);
+define_x128_packer_delta!(to_u8_zigzag_delta, to_u8, 8, encode_zigzag_delta);
+define_x128_packer_delta!(to_u9_zigzag_delta, to_u9, 9, encode_zigzag_delta);
+define_x128_packer_delta!(to_u10_zigzag_delta, to_u10, 10, encode_zigzag_delta);
+define_x128_packer_delta!(to_u11_zigzag_delta, to_u11, 11, encode_zigzag_delta);
+define_x128_packer_delta!(to_u12_zigzag_delta, to_u12, 12, encode_zigzag_delta);
+define_x128_packer_delta!(to_u13_zigzag_delta, to_u13, 13, encode_zigzag_delta);
+define_x128_packer_delta!(to_u14_zigzag_delta, to_u14, 14, encode_zigzag_delta);
+define_x128_packer_delta!(to_u15_zigzag_delta, to_u15, 15, encode_zigzag_delta);
+define_x128_packer_delta!(to_u16_zigzag_delta, to_u16, 16, encode_zigzag_delta);
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta-1 encoding followed by ZigZag encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// The signed elements are provided as their unsigned bit pattern, see
+/// [as_unsigned_i16](crate::util::as_unsigned_i16).
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag_delta1(
+    nbits: usize,
+    last_value: u16,
+    out: *mut u8,
+    block: &[u16; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, out: *mut u8, &[u16; X128], usize); 17] = [
+        to_u0_zigzag_delta1,
+        to_u1_zigzag_delta1,
+        to_u2_zigzag_delta1,
+        to_u3_zigzag_delta1,
+        to_u4_zigzag_delta1,
+        to_u5_zigzag_delta1,
+        to_u6_zigzag_delta1,
+        to_u7_zigzag_delta1,
+        to_u8_zigzag_delta1,
+        to_u9_zigzag_delta1,
+        to_u10_zigzag_delta1,
+        to_u11_zigzag_delta1,
+        to_u12_zigzag_delta1,
+        to_u13_zigzag_delta1,
+        to_u14_zigzag_delta1,
+        to_u15_zigzag_delta1,
+        to_u16_zigzag_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_zigzag_delta1(
+    _last_value: u16,
+    _out: *mut u8,
+    _block: &[u16; X128],
+    _pack_n: usize,
+) {
+}
+
+define_x128_packer_delta!(to_u1_zigzag_delta1, to_u1, 1, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u2_zigzag_delta1, to_u2, 2, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u3_zigzag_delta1, to_u3, 3, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u4_zigzag_delta1, to_u4, 4, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u5_zigzag_delta1, to_u5, 5, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u6_zigzag_delta1, to_u6, 6, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u7_zigzag_delta1, to_u7, 7, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u8_zigzag_delta1, to_u8, 8, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u9_zigzag_delta1, to_u9, 9, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u10_zigzag_delta1, to_u10, 10, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u11_zigzag_delta1, to_u11, 11, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u12_zigzag_delta1, to_u12, 12, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u13_zigzag_delta1, to_u13, 13, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u14_zigzag_delta1, to_u14, 14, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u15_zigzag_delta1, to_u15, 15, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u16_zigzag_delta1, to_u16, 16, encode_zigzag_delta1);
+
+define_x128_bit_length!(delta_bit_length, encode_delta);
+define_x128_bit_length!(delta1_bit_length, encode_delta1);
+define_x128_bit_length!(zigzag_delta_bit_length, encode_zigzag_delta);
+define_x128_bit_length!(zigzag_delta1_bit_length, encode_zigzag_delta1);
+
+define_x128_checked_bit_length!(checked_delta_bit_length, encode_delta, false);
+define_x128_checked_bit_length!(checked_delta1_bit_length, encode_delta1, true);
+
+#[target_feature(enable = "sse4.1")]
+/// Delta encode the 16-bit elements held in the provided registers, returning the last
+/// register before encoding to carry into the next set of registers.
+///
+/// The last element of `previous` is subtracted from the first element of the block.
+fn encode_delta(mut previous: __m128i, block: &mut [__m128i; 8]) -> __m128i {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let values = block[i];
+
+        // [previous[7], values[0], ..., values[6]]
+        let shifted = _mm_alignr_epi8::<14>(values, previous);
+
+        block[i] = _mm_sub_epi16(values, shifted);
+        previous = values;
+    }
+
+    previous
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Delta-1 encode the 16-bit elements held in the provided registers, returning the last
+/// register before encoding to carry into the next set of registers.
+fn encode_delta1(previous: __m128i, block: &mut [__m128i; 8]) -> __m128i {
+    let ones = _sse_set1_u16(1);
+
+    let previous = encode_delta(previous, block);
+    for register in block.iter_mut() {
+        *register = _mm_sub_epi16(*register, ones);
+    }
+
+    previous
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Delta encode followed by ZigZag encode the signed 16-bit elements held in the provided
+/// registers, returning the last register before encoding.
+fn encode_zigzag_delta(previous: __m128i, block: &mut [__m128i; 8]) -> __m128i {
+    let previous = encode_delta(previous, block);
+    *block = zigzag_encode(*block);
+    previous
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Delta-1 encode followed by ZigZag encode the signed 16-bit elements held in the provided
+/// registers, returning the last register before encoding.
+fn encode_zigzag_delta1(previous: __m128i, block: &mut [__m128i; 8]) -> __m128i {
+    let previous = encode_delta1(previous, block);
+    *block = zigzag_encode(*block);
+    previous
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after
+/// subtracting the `reference` value from each element.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `sse4.1` instructions.
+/// - `nbits` must be between 0 and 16.
+/// - `pack_n` must be no greater than 128.
+/// - `reference` must be no greater than any of the first `pack_n` elements.
+pub unsafe fn to_nbits_for(
+    nbits: usize,
+    reference: u16,
+    out: *mut u8,
+    block: &[u16; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u16, out: *mut u8, &[u16; X128], usize); 17] = [
+        to_u0_for, to_u1_for, to_u2_for, to_u3_for, to_u4_for, to_u5_for, to_u6_for, to_u7_for,
+        to_u8_for, to_u9_for, to_u10_for, to_u11_for, to_u12_for, to_u13_for, to_u14_for,
+        to_u15_for, to_u16_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, out, block, pack_n) };
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn to_u0_for(_reference: u16, _out: *mut u8, _block: &[u16; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_for {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "sse4.1")]
+        unsafe fn $func_name(reference: u16, out: *mut u8, block: &[u16; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let left = sub_reference(load_u16x64(left), reference);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u16x64(right), reference);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u16x64(right), reference);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_for!(to_u1_for, to_u1, 1);
+define_x128_packer_for!(to_u2_for, to_u2, 2);
+define_x128_packer_for!(to_u3_for, to_u3, 3);
+define_x128_packer_for!(to_u4_for, to_u4, 4);
+define_x128_packer_for!(to_u5_for, to_u5, 5);
+define_x128_packer_for!(to_u6_for, to_u6, 6);
+define_x128_packer_for!(to_u7_for, to_u7, 7);
+define_x128_packer_for!(to_u8_for, to_u8, 8);
+define_x128_packer_for!(to_u9_for, to_u9, 9);
+define_x128_packer_for!(to_u10_for, to_u10, 10);
+define_x128_packer_for!(to_u11_for, to_u11, 11);
+define_x128_packer_for!(to_u12_for, to_u12, 12);
+define_x128_packer_for!(to_u13_for, to_u13, 13);
+define_x128_packer_for!(to_u14_for, to_u14, 14);
+define_x128_packer_for!(to_u15_for, to_u15, 15);
+define_x128_packer_for!(to_u16_for, to_u16, 16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint16::{X128_MAX_OUTPUT_LEN, scalar};
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint16::test_util::load_uint16_regression_layout();
+
+        let mut output_buffer = [0; X128_MAX_OUTPUT_LEN];
+        for (len, bit_len, input, expected_output) in tester.iter_tests() {
+            unsafe { to_nbits(bit_len as usize, output_buffer.as_mut_ptr(), input, len) };
+
+            let produced_buffer = &output_buffer[..expected_output.len()];
+            assert_eq!(
+                produced_buffer, expected_output,
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_bit_length_matches_scalar() {
+        let tester = crate::uint16::test_util::load_uint16_regression_layout();
+
+        for (len, _, input, _) in tester.iter_tests() {
+            let mut sorted = *input;
+            sorted.sort_unstable();
+
+            for block in [input, &sorted] {
+                let expected = unsafe { scalar::bit_length_x128(block, len) };
+                assert_eq!(unsafe { plain_bit_length(block, len) }, expected as usize);
+
+                for last_value in [0, block[0], block[0].wrapping_add(1), u16::MAX] {
+                    let expected = unsafe { scalar::bit_length_delta_x128(last_value, block, len) };
+                    let nbits = unsafe { delta_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected as usize, "delta len:{len}");
+
+                    let expected =
+                        unsafe { scalar::bit_length_delta1_x128(last_value, block, len) };
+                    let nbits = unsafe { delta1_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected as usize, "delta1 len:{len}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_checked_delta_bit_length_matches_scalar() {
+        let tester = crate::uint16::test_util::load_uint16_regression_layout();
+
+        for (len, _, input, _) in tester.iter_tests() {
+            let mut sorted = *input;
+            sorted.sort_unstable();
+            let mut strictly_sorted = sorted;
+            for i in 1..X128 {
+                strictly_sorted[i] =
+                    strictly_sorted[i].max(strictly_sorted[i - 1].saturating_add(1));
+            }
+
+            let mut descent = strictly_sorted;
+
+            descent[(len + 40) % X128] = 0;
+
+            for block in [input, &sorted, &strictly_sorted, &descent] {
+                for last_value in [0, block[0], u16::MAX] {
+                    let mut out = [0; X128_MAX_OUTPUT_LEN];
+                    let expected =
+                        unsafe { scalar::try_pack_delta_x128(last_value, &mut out, block, len) }
+                            .map(|details| details.compressed_bit_length as usize);
+                    let nbits = unsafe { checked_delta_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected, "delta len:{len}");
+
+                    let expected =
+                        unsafe { scalar::try_pack_delta1_x128(last_value, &mut out, block, len) }
+                            .map(|details| details.compressed_bit_length as usize);
+                    let nbits = unsafe { checked_delta1_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected, "delta1 len:{len}");
+                }
+            }
+        }
+    }
+}
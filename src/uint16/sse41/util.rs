@@ -0,0 +1,446 @@
+#![allow(clippy::needless_range_loop)]
+
+use core::arch::x86_64::*;
+
+use super::polyfill::*;
+
+#[target_feature(enable = "sse4.1")]
+/// Pack 4 sets of registers containing 16-bit elements and produce 4 registers holding
+/// 8-bit elements.
+///
+/// The order of elements is **not** maintained.
+pub(super) fn pack_u16_to_u8_unordered(data: [__m128i; 8]) -> [__m128i; 4] {
+    let mask = _sse_set1_u16(0x00FF);
+    let lo = [
+        _sse_and_u16(data[0], mask),
+        _sse_and_u16(data[1], mask),
+        _sse_and_u16(data[2], mask),
+        _sse_and_u16(data[3], mask),
+    ];
+    let hi = [
+        _sse_slli_u16::<8>(data[4]),
+        _sse_slli_u16::<8>(data[5]),
+        _sse_slli_u16::<8>(data[6]),
+        _sse_slli_u16::<8>(data[7]),
+    ];
+
+    [
+        _sse_or_u16(hi[0], lo[0]),
+        _sse_or_u16(hi[1], lo[1]),
+        _sse_or_u16(hi[2], lo[2]),
+        _sse_or_u16(hi[3], lo[3]),
+    ]
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Split the 16-bit values in the provided registers producing two 8-bit
+/// halves, packing the results while **not** maintaining the order.
+pub(super) fn split_u16_unordered(data: [__m128i; 8]) -> ([__m128i; 4], [__m128i; 4]) {
+    let mask = _sse_set1_u16(0x00FF);
+
+    let lo_bits = and_u16(data, mask);
+    let hi_bits = srli_u16::<8, 8>(data);
+
+    let lo_packed = pack_u16_to_u8_unordered(lo_bits);
+    let hi_packed = pack_u16_to_u8_unordered(hi_bits);
+
+    (hi_packed, lo_packed)
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Split the 16-bit values in the provided registers producing two 8-bit
+/// halves, packing the results while maintaining the order.
+pub(super) fn split_u16_ordered(data: [__m128i; 8]) -> ([__m128i; 4], [__m128i; 4]) {
+    let mask = _sse_set1_u16(0x00FF);
+
+    let lo_bits = and_u16(data, mask);
+    let hi_bits = srli_u16::<8, 8>(data);
+
+    let lo_packed = pack_u16_to_u8_ordered(lo_bits);
+    let hi_packed = pack_u16_to_u8_ordered(hi_bits);
+
+    (hi_packed, lo_packed)
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Pack 4 sets of registers containing 16-bit elements and produce 4 registers holding
+/// 8-bit elements.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u16_to_u8_ordered(data: [__m128i; 8]) -> [__m128i; 4] {
+    [
+        _sse_cvteu16_u8(data[0], data[1]),
+        _sse_cvteu16_u8(data[2], data[3]),
+        _sse_cvteu16_u8(data[4], data[5]),
+        _sse_cvteu16_u8(data[6], data[7]),
+    ]
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_ordered(data: [__m128i; 4]) -> [__m128i; 8] {
+    let [d1, d2] = _sse_cvteu8_u16(data[0]);
+    let [d3, d4] = _sse_cvteu8_u16(data[1]);
+    let [d5, d6] = _sse_cvteu8_u16(data[2]);
+    let [d7, d8] = _sse_cvteu8_u16(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_unordered(data: [__m128i; 4]) -> [__m128i; 8] {
+    let zeroes = _sse_set1_u8(0);
+    let d1 = _sse_blend_every_other_u8(data[0], zeroes);
+    let d2 = _sse_blend_every_other_u8(data[1], zeroes);
+    let d3 = _sse_blend_every_other_u8(data[2], zeroes);
+    let d4 = _sse_blend_every_other_u8(data[3], zeroes);
+
+    let d5 = _sse_srli_u16::<8>(data[0]);
+    let d6 = _sse_srli_u16::<8>(data[1]);
+    let d7 = _sse_srli_u16::<8>(data[2]);
+    let d8 = _sse_srli_u16::<8>(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn pack_u8_to_u4_unordered(data: [__m128i; 4]) -> [__m128i; 2] {
+    let shifted_1 = _sse_slli_u8::<4>(data[2]);
+    let shifted_2 = _sse_slli_u8::<4>(data[3]);
+    let d1 = _sse_or_u8(data[0], shifted_1);
+    let d2 = _sse_or_u8(data[1], shifted_2);
+    [d1, d2]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn unpack_u4_to_u8_unordered(data: [__m128i; 2]) -> [__m128i; 4] {
+    let mask = _sse_set1_u8(0x0F);
+    let lo_4bits_1 = _sse_and_u8(data[0], mask);
+    let lo_4bits_2 = _sse_and_u8(data[1], mask);
+    let hi_4bits_1 = _sse_srli_u8::<4>(data[0]);
+    let hi_4bits_2 = _sse_srli_u8::<4>(data[1]);
+    [lo_4bits_1, lo_4bits_2, hi_4bits_1, hi_4bits_2]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn pack_u8_to_u2_unordered(data: [__m128i; 4]) -> __m128i {
+    let [lo, hi] = pack_u8_to_u4_unordered(data);
+    _mm_or_si128(lo, _sse_slli_u8::<2>(hi))
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn unpack_u2_to_u8_unordered(data: __m128i) -> [__m128i; 4] {
+    let mask = _sse_set1_u8(0b0011_0011);
+    let lo = _mm_and_si128(data, mask);
+    let hi = _mm_and_si128(_sse_srli_u8::<2>(data), mask);
+    unpack_u4_to_u8_unordered([lo, hi])
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u16<const N: usize>(mut data: [__m128i; N], mask: __m128i) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_and_u16(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u8<const N: usize>(mut data: [__m128i; N], mask: __m128i) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_and_u8(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u16_all<const N: usize>(mut a: [__m128i; N], b: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        a[i] = _sse_or_u16(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u8_all<const N: usize>(mut a: [__m128i; N], b: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        a[i] = _sse_or_u8(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Shift all registers right by [IMM8] in 8-bit lanes.
+pub(super) fn srli_u8<const IMM8: i32, const N: usize>(mut data: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_srli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Shift all registers right by [IMM8] in 16-bit lanes.
+pub(super) fn srli_u16<const IMM8: i32, const N: usize>(mut data: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_srli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Shift all registers left by [IMM8] in 8-bit lanes.
+pub(super) fn slli_u8<const IMM8: i32, const N: usize>(mut data: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_slli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Shift all registers left by [IMM8] in 16-bit lanes.
+pub(super) fn slli_u16<const IMM8: i32, const N: usize>(mut data: [__m128i; N]) -> [__m128i; N] {
+    for i in 0..N {
+        data[i] = _sse_slli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[target_feature(enable = "sse4.1")]
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [__m128i; 8]) -> [__m128i; 8] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = _mm_xor_si128(_mm_slli_epi16::<1>(v), _mm_srai_epi16::<15>(v));
+    }
+    data
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [__m128i; 8]) -> [__m128i; 8] {
+    let one = _sse_set1_u16(1);
+    let zero = _mm_setzero_si128();
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        let sign = _mm_sub_epi16(zero, _mm_and_si128(v, one));
+        data[i] = _mm_xor_si128(_mm_srli_epi16::<1>(v), sign);
+    }
+    data
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m128i; 8], reference: u16) -> [__m128i; 8] {
+    let reference = _sse_set1_u16(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = _mm_sub_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m128i; 8], reference: u16) -> [__m128i; 8] {
+    let reference = _sse_set1_u16(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = _mm_add_epi16(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Returns the number of bits required to represent the largest of the first `n` 16-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m128i; 8], n: usize) -> u32 {
+    let limit = _sse_set1_u16(n as u16);
+    let step = _sse_set1_u16(8);
+    let mut positions = _mm_setr_epi16(0, 1, 2, 3, 4, 5, 6, 7);
+
+    let mut acc = _sse_set1_u16(0);
+    for register in data {
+        let selected = _mm_cmplt_epi16(positions, limit);
+        acc = _mm_max_epu16(acc, _mm_and_si128(selected, *register));
+        positions = _mm_add_epi16(positions, step);
+    }
+
+    16 - _sse_reduce_max_u16(acc).leading_zeros()
+}
+
+#[target_feature(enable = "sse4.1")]
+/// Returns the position of the first 16-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[__m128i; 8],
+    deltas: &[__m128i; 8],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let descent = if strict {
+            _sse_cmple_u16(*value, *delta)
+        } else {
+            _sse_cmplt_u16(*value, *delta)
+        };
+        let mask = _sse_movemask_u16(descent);
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint16::sse41::data::load_u8x16x4;
+    use crate::uint16::test_util::PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_pack_u16_to_u8_ordered() {
+        let input = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m128i; 8]>(input) };
+        let packed = unsafe { pack_u16_to_u8_ordered(data) };
+
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; X64]>(packed) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_unpack_u8_to_u16_ordered() {
+        let input = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [__m128i; 4]>(input) };
+        let unpacked = unsafe { unpack_u8_to_u16_ordered(data) };
+
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_unpack_u8_to_u16_unordered_layout() {
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { load_u8x16x4(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
+        let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
+
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_unpack_u4_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = unsafe { pack_u8_to_u4_unordered(data) };
+        let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
+
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_unpack_u2_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = unsafe { pack_u8_to_u2_unordered(data) };
+        let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
+
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [__m128i; 8]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [u16; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m128i; 8]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 8], [u16; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(8, 8)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 16)]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u16; X64] = core::array::from_fn(|i| 1 << (i % 16));
+        input[X64 - 1] = u16::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [__m128i; 8]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
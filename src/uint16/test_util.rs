@@ -48,7 +48,7 @@ impl RegressionLayout {
                 let key = format!("len:{len},bit:{bit_len}");
                 let blocks = self.metadata.offsets.get(&key).expect("unknown key");
 
-                blocks.iter().cloned().map(move |meta| {
+                blocks.iter().map(move |meta| {
                     let data = self.inputs_data.get(&meta.seed).expect("unknown seed");
                     let input_data: &[u16] = bytemuck::cast_slice(data);
 
@@ -0,0 +1,484 @@
+use core::arch::wasm32::*;
+
+use super::data::*;
+use super::polyfill::*;
+use super::util::*;
+use crate::util::read_partial;
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 1-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 1-bit bitmap provided
+/// by `input`.
+unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
+    _wasm_mov_maskz_u8(mask)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 2-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u2_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 2-bit bitmap provided
+/// by `input`.
+unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+
+    let lo_bits_packed = _wasm_mov_maskz_u8(mask1);
+    let mut hi_bits_packed = _wasm_mov_maskz_u8(mask2);
+    hi_bits_packed = slli_u8::<1, 4>(hi_bits_packed);
+
+    or_u8_all(hi_bits_packed, lo_bits_packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 3-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [v128; 8] {
+    let packed = unsafe { unpack_u3_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 3-bit bitmap provided
+/// by `input`.
+unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let step = read_n.div_ceil(8);
+
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
+
+    let b0_bits_packed = _wasm_mov_maskz_u8(mask1);
+
+    let mut b1_bits_packed = _wasm_mov_maskz_u8(mask2);
+    b1_bits_packed = slli_u8::<1, 4>(b1_bits_packed);
+
+    let mut b2_bits_packed = _wasm_mov_maskz_u8(mask3);
+    b2_bits_packed = slli_u8::<2, 4>(b2_bits_packed);
+
+    let mut three_bits = or_u8_all(b0_bits_packed, b1_bits_packed);
+    three_bits = or_u8_all(three_bits, b2_bits_packed);
+
+    three_bits
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 4-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
+/// by `input`.
+pub(super) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed1 = unsafe { _wasm_load_u8(packed.as_ptr().add(0)) };
+    let packed2 = unsafe { _wasm_load_u8(packed.as_ptr().add(16)) };
+    let [d1, d2] = _wasm_unpack_nibbles(packed1);
+    let [d3, d4] = _wasm_unpack_nibbles(packed2);
+    [d1, d2, d3, d4]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 5-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u5_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
+/// by `input`.
+unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let offset = read_n.div_ceil(2);
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
+    hi_bits = slli_u8::<4, 4>(hi_bits);
+    or_u8_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 6-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u6_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
+/// by `input`.
+unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let offset = read_n.div_ceil(2);
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
+    hi_bits = slli_u8::<4, 4>(hi_bits);
+    or_u8_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 7-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { unpack_u7_registers(input, read_n) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
+/// by `input`.
+unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [v128; 4] {
+    let offset = read_n.div_ceil(2);
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
+    hi_bits = slli_u8::<4, 4>(hi_bits);
+    or_u8_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 8-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let packed = unsafe { load_u8x16x4(read_partial::<64>(input, read_n).as_ptr()) };
+    unpack_u8_to_u16_ordered(packed)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 9-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 10-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 11-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 12-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 13-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u5_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 14-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u6_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 15-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
+    let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
+
+    let offset = read_n;
+    let hi_bits = unsafe { unpack_u7_registers(input.add(offset), read_n) };
+    let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
+    hi_bits = slli_u16::<8, 8>(hi_bits);
+
+    or_u16_all(hi_bits, lo_bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack the 16-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
+/// - `read_n` must be between no larger than `64`.
+pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [v128; 8] {
+    debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
+    unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint16::wasm_simd128::pack_x64_partial::*;
+    use crate::uint16::{X128_MAX_OUTPUT_LEN, max_compressed_size};
+
+    #[rstest::rstest]
+    #[case(1, from_u1)]
+    #[case(2, from_u2)]
+    #[case(3, from_u3)]
+    #[case(4, from_u4)]
+    #[case(5, from_u5)]
+    #[case(6, from_u6)]
+    #[case(7, from_u7)]
+    #[case(8, from_u8)]
+    #[case(9, from_u9)]
+    #[case(10, from_u10)]
+    #[case(11, from_u11)]
+    #[case(12, from_u12)]
+    #[case(13, from_u13)]
+    #[case(14, from_u14)]
+    #[case(15, from_u15)]
+    #[case(16, from_u16)]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_saturated_unpack(
+        #[case] bit_len: u8,
+        #[case] unpacker: unsafe fn(*const u8, usize) -> [v128; 8],
+    ) {
+        let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
+
+        let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
+        let unpacked = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+
+        let expected_value = (2u64.pow(bit_len as u32) - 1) as u16;
+        assert_eq!(unpacked, [expected_value; X64]);
+    }
+
+    #[rstest::rstest]
+    #[case(1, to_u1, from_u1)]
+    #[case(2, to_u2, from_u2)]
+    #[case(3, to_u3, from_u3)]
+    #[case(4, to_u4, from_u4)]
+    #[case(5, to_u5, from_u5)]
+    #[case(6, to_u6, from_u6)]
+    #[case(7, to_u7, from_u7)]
+    #[case(8, to_u8, from_u8)]
+    #[case(9, to_u9, from_u9)]
+    #[case(10, to_u10, from_u10)]
+    #[case(11, to_u11, from_u11)]
+    #[case(12, to_u12, from_u12)]
+    #[case(13, to_u13, from_u13)]
+    #[case(14, to_u14, from_u14)]
+    #[case(15, to_u15, from_u15)]
+    #[case(16, to_u16, from_u16)]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_unpack(
+        #[case] bit_len: u8,
+        #[case] packer: unsafe fn(*mut u8, [v128; 8], usize),
+        #[case] unpacker: unsafe fn(*const u8, usize) -> [v128; 8],
+    ) {
+        fastrand::seed(5876358762523525);
+
+        let max_value = (2u64.pow(bit_len as u32) - 1) as u16;
+
+        let mut values = [0; X64];
+        for value in values.iter_mut() {
+            *value = fastrand::u16(0..max_value);
+        }
+        let data = load_u16x64(&values);
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN / 2];
+        unsafe { packer(packed.as_mut_ptr(), data, X64) };
+
+        let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
+        let unpacked = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+        assert_eq!(unpacked, values);
+    }
+
+    #[rstest::rstest]
+    #[case(1, to_u1, from_u1)]
+    #[case(2, to_u2, from_u2)]
+    #[case(3, to_u3, from_u3)]
+    #[case(4, to_u4, from_u4)]
+    #[case(5, to_u5, from_u5)]
+    #[case(6, to_u6, from_u6)]
+    #[case(7, to_u7, from_u7)]
+    #[case(8, to_u8, from_u8)]
+    #[case(9, to_u9, from_u9)]
+    #[case(10, to_u10, from_u10)]
+    #[case(11, to_u11, from_u11)]
+    #[case(12, to_u12, from_u12)]
+    #[case(13, to_u13, from_u13)]
+    #[case(14, to_u14, from_u14)]
+    #[case(15, to_u15, from_u15)]
+    #[case(16, to_u16, from_u16)]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_unpack_length_permutations(
+        #[case] bit_len: u8,
+        #[case] packer: unsafe fn(*mut u8, [v128; 8], usize),
+        #[case] unpacker: unsafe fn(*const u8, usize) -> [v128; 8],
+    ) {
+        fastrand::seed(5876358762523525);
+
+        let max_value = (2u64.pow(bit_len as u32) - 1) as u16;
+
+        for length in 0..X64 {
+            let mut values = [0; X64];
+            for value in values.iter_mut() {
+                *value = fastrand::u16(0..max_value);
+            }
+            let data = load_u16x64(&values);
+
+            let mut packed = [0; X128_MAX_OUTPUT_LEN / 2];
+            unsafe { packer(packed.as_mut_ptr(), data, length) };
+            packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
+
+            let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
+            let unpacked = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+            assert_eq!(unpacked[..length], values[..length], "length:{length}");
+        }
+    }
+}
@@ -0,0 +1,447 @@
+#![allow(clippy::needless_range_loop)]
+
+use core::arch::wasm32::*;
+
+use super::polyfill::*;
+
+#[target_feature(enable = "simd128")]
+/// Pack 4 sets of registers containing 16-bit elements and produce 4 registers holding
+/// 8-bit elements.
+///
+/// The order of elements is **not** maintained.
+pub(super) fn pack_u16_to_u8_unordered(data: [v128; 8]) -> [v128; 4] {
+    let mask = _wasm_set1_u16(0x00FF);
+    let lo = [
+        _wasm_and_u16(data[0], mask),
+        _wasm_and_u16(data[1], mask),
+        _wasm_and_u16(data[2], mask),
+        _wasm_and_u16(data[3], mask),
+    ];
+    let hi = [
+        _wasm_slli_u16::<8>(data[4]),
+        _wasm_slli_u16::<8>(data[5]),
+        _wasm_slli_u16::<8>(data[6]),
+        _wasm_slli_u16::<8>(data[7]),
+    ];
+
+    [
+        _wasm_or_u16(hi[0], lo[0]),
+        _wasm_or_u16(hi[1], lo[1]),
+        _wasm_or_u16(hi[2], lo[2]),
+        _wasm_or_u16(hi[3], lo[3]),
+    ]
+}
+
+#[target_feature(enable = "simd128")]
+/// Split the 16-bit values in the provided registers producing two 8-bit
+/// halves, packing the results while **not** maintaining the order.
+pub(super) fn split_u16_unordered(data: [v128; 8]) -> ([v128; 4], [v128; 4]) {
+    let mask = _wasm_set1_u16(0x00FF);
+
+    let lo_bits = and_u16(data, mask);
+    let hi_bits = srli_u16::<8, 8>(data);
+
+    let lo_packed = pack_u16_to_u8_unordered(lo_bits);
+    let hi_packed = pack_u16_to_u8_unordered(hi_bits);
+
+    (hi_packed, lo_packed)
+}
+
+#[target_feature(enable = "simd128")]
+/// Split the 16-bit values in the provided registers producing two 8-bit
+/// halves, packing the results while maintaining the order.
+pub(super) fn split_u16_ordered(data: [v128; 8]) -> ([v128; 4], [v128; 4]) {
+    let mask = _wasm_set1_u16(0x00FF);
+
+    let lo_bits = and_u16(data, mask);
+    let hi_bits = srli_u16::<8, 8>(data);
+
+    let lo_packed = pack_u16_to_u8_ordered(lo_bits);
+    let hi_packed = pack_u16_to_u8_ordered(hi_bits);
+
+    (hi_packed, lo_packed)
+}
+
+#[target_feature(enable = "simd128")]
+/// Pack 4 sets of registers containing 16-bit elements and produce 4 registers holding
+/// 8-bit elements.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u16_to_u8_ordered(data: [v128; 8]) -> [v128; 4] {
+    [
+        _wasm_cvteu16_u8(data[0], data[1]),
+        _wasm_cvteu16_u8(data[2], data[3]),
+        _wasm_cvteu16_u8(data[4], data[5]),
+        _wasm_cvteu16_u8(data[6], data[7]),
+    ]
+}
+
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_ordered(data: [v128; 4]) -> [v128; 8] {
+    let [d1, d2] = _wasm_cvteu8_u16(data[0]);
+    let [d3, d4] = _wasm_cvteu8_u16(data[1]);
+    let [d5, d6] = _wasm_cvteu8_u16(data[2]);
+    let [d7, d8] = _wasm_cvteu8_u16(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_unordered(data: [v128; 4]) -> [v128; 8] {
+    let zeroes = _wasm_set1_u8(0);
+    let d1 = _wasm_blend_every_other_u8(data[0], zeroes);
+    let d2 = _wasm_blend_every_other_u8(data[1], zeroes);
+    let d3 = _wasm_blend_every_other_u8(data[2], zeroes);
+    let d4 = _wasm_blend_every_other_u8(data[3], zeroes);
+
+    let d5 = _wasm_srli_u16::<8>(data[0]);
+    let d6 = _wasm_srli_u16::<8>(data[1]);
+    let d7 = _wasm_srli_u16::<8>(data[2]);
+    let d8 = _wasm_srli_u16::<8>(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn pack_u8_to_u4_unordered(data: [v128; 4]) -> [v128; 2] {
+    let shifted_1 = _wasm_slli_u8::<4>(data[2]);
+    let shifted_2 = _wasm_slli_u8::<4>(data[3]);
+    let d1 = _wasm_or_u8(data[0], shifted_1);
+    let d2 = _wasm_or_u8(data[1], shifted_2);
+    [d1, d2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn unpack_u4_to_u8_unordered(data: [v128; 2]) -> [v128; 4] {
+    let mask = _wasm_set1_u8(0x0F);
+    let lo_4bits_1 = _wasm_and_u8(data[0], mask);
+    let lo_4bits_2 = _wasm_and_u8(data[1], mask);
+    let hi_4bits_1 = _wasm_srli_u8::<4>(data[0]);
+    let hi_4bits_2 = _wasm_srli_u8::<4>(data[1]);
+    [lo_4bits_1, lo_4bits_2, hi_4bits_1, hi_4bits_2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn pack_u8_to_u2_unordered(data: [v128; 4]) -> v128 {
+    let [lo, hi] = pack_u8_to_u4_unordered(data);
+    v128_or(lo, u8x16_shl(hi, 2))
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn unpack_u2_to_u8_unordered(data: v128) -> [v128; 4] {
+    let mask = u8x16_splat(0b0011_0011);
+    let lo = v128_and(data, mask);
+    let hi = v128_and(u8x16_shr(data, 2), mask);
+    unpack_u4_to_u8_unordered([lo, hi])
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u16<const N: usize>(mut data: [v128; N], mask: v128) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_and_u16(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u8<const N: usize>(mut data: [v128; N], mask: v128) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_and_u8(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u16_all<const N: usize>(mut a: [v128; N], b: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        a[i] = _wasm_or_u16(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u8_all<const N: usize>(mut a: [v128; N], b: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        a[i] = _wasm_or_u8(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers right by [IMM8] in 8-bit lanes.
+pub(super) fn srli_u8<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_srli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers right by [IMM8] in 16-bit lanes.
+pub(super) fn srli_u16<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_srli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers left by [IMM8] in 8-bit lanes.
+pub(super) fn slli_u8<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_slli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers left by [IMM8] in 16-bit lanes.
+pub(super) fn slli_u16<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_slli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// ZigZag encode the signed 16-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [v128; 8]) -> [v128; 8] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = v128_xor(u16x8_shl(v, 1), i16x8_shr(v, 15));
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Reverse the ZigZag encoding of the 16-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [v128; 8]) -> [v128; 8] {
+    let one = u16x8_splat(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = v128_xor(u16x8_shr(v, 1), i16x8_neg(v128_and(v, one)));
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Subtract the frame of reference from each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [v128; 8], reference: u16) -> [v128; 8] {
+    let reference = u16x8_splat(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = u16x8_sub(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Add the frame of reference back to each of the 16-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [v128; 8], reference: u16) -> [v128; 8] {
+    let reference = u16x8_splat(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = u16x8_add(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the number of bits required to represent the largest of the first `n` 16-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[v128; 8], n: usize) -> u32 {
+    let limit = u16x8_splat(n as u16);
+    let step = u16x8_splat(8);
+    let mut positions = u16x8(0, 1, 2, 3, 4, 5, 6, 7);
+
+    let mut acc = u16x8_splat(0);
+    for register in data {
+        let selected = u16x8_lt(positions, limit);
+        acc = u16x8_max(acc, v128_and(selected, *register));
+        positions = u16x8_add(positions, step);
+    }
+
+    acc = u16x8_max(acc, i16x8_shuffle::<4, 5, 6, 7, 0, 1, 2, 3>(acc, acc));
+    acc = u16x8_max(acc, i16x8_shuffle::<2, 3, 0, 1, 2, 3, 0, 1>(acc, acc));
+    acc = u16x8_max(acc, i16x8_shuffle::<1, 0, 1, 0, 1, 0, 1, 0>(acc, acc));
+    16 - u16x8_extract_lane::<0>(acc).leading_zeros()
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the position of the first 16-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[v128; 8],
+    deltas: &[v128; 8],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let descent = if strict {
+            u16x8_le(*value, *delta)
+        } else {
+            u16x8_lt(*value, *delta)
+        };
+        let mask = u16x8_bitmask(descent);
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint16::test_util::PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT;
+    use crate::uint16::wasm_simd128::data::load_u8x16x4;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u16_to_u8_ordered() {
+        let input = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [v128; 8]>(input) };
+        let packed = pack_u16_to_u8_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(packed) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u8_to_u16_ordered() {
+        let input = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [v128; 4]>(input) };
+        let unpacked = unpack_u8_to_u16_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u8_to_u16_unordered_layout() {
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { load_u8x16x4(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
+        let unpacked = unpack_u8_to_u16_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u4_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = pack_u8_to_u4_unordered(data);
+        let unpacked = unpack_u4_to_u8_unordered(packed);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u2_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = pack_u8_to_u2_unordered(data);
+        let unpacked = unpack_u2_to_u8_unordered(packed);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i16; X64] = core::array::from_fn(|i| match i {
+            0 => i16::MIN,
+            1 => i16::MAX,
+            2 => -1,
+            _ => (i as i16 - 32) * 101,
+        });
+        let expected: [u16; X64] = input.map(crate::util::zigzag_encode_i16);
+
+        let data = unsafe { core::mem::transmute::<[i16; X64], [v128; 8]>(input) };
+        let encoded = zigzag_encode(data);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = zigzag_decode(encoded);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [i16; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u16::MAX - 80;
+        let input: [u16; X64] = core::array::from_fn(|i| reference + i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [v128; 8]>(input) };
+        let subtracted = sub_reference(data, reference);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = add_reference(subtracted, reference);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(8, 8)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 16)]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u16; X64] = core::array::from_fn(|i| 1 << (i % 16));
+        input[X64 - 1] = u16::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [v128; 8]>(input) };
+        assert_eq!(bit_length(&data, n), expected);
+    }
+}
@@ -0,0 +1,85 @@
+//! Decompressing blocks of narrower integers straight into 16-bit output.
+//!
+//! The byte layout of a packed block depends on the width of the integers it was packed from,
+//! so a block compressed from a narrower array can not be decompressed as a `[u16; X128]` block
+//! with [crate::decompress]. These routines decode the layout of the narrower integers and widen
+//! the values in registers before they are written to the output.
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint16::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint16::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint16::neon;
+use crate::uint16::scalar;
+use crate::{Backend, X128, uint8};
+
+/// Decompress a block which was compressed from a `[u8; X128]` array, writing the decompressed
+/// values to `out` widened to 16-bit integers.
+///
+/// This requires that the values contained were originally compressed with [crate::compress]
+/// from an array of `u8` values.
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// Returns the number of bytes read from the input.
+pub fn decompress_from_u8(
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut [u16; X128],
+) -> usize {
+    assert!(
+        compressed_bit_length <= 8,
+        "compressed bitlength must be no more than 8"
+    );
+    assert!(
+        input.len() >= uint8::max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::unpack_u8_x128(compressed_bit_length, input, out, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::unpack_u8_x128(compressed_bit_length, input, out, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::unpack_u8_x128(compressed_bit_length, input, out, n) },
+        _ => unsafe { scalar::unpack_u8_x128(compressed_bit_length, input, out, n) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::partial_left(37)]
+    #[case::partial_right(100)]
+    #[case::full(X128)]
+    fn test_decompress_from_u8(#[case] n: usize) {
+        let values: [u8; X128] = std::array::from_fn(|i| (i as u8).wrapping_mul(37));
+        let mut compressed = [0; uint8::X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let read = decompress_from_u8(
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(read, details.bytes_written);
+        assert_eq!(decompressed[..n], values.map(u16::from)[..n]);
+    }
+
+    #[test]
+    #[should_panic(expected = "compressed bitlength must be no more than 8")]
+    fn test_decompress_from_u8_invalid_bit_length() {
+        let compressed = [0; crate::uint16::X128_MAX_OUTPUT_LEN];
+        decompress_from_u8(X128, 9, &compressed, &mut [0; X128]);
+    }
+}
@@ -0,0 +1,235 @@
+//! Aggregating the values of a compressed block without decompressing it.
+//!
+//! Faceting and block-max scoring only need a summary of a block, these routines reduce the
+//! values while still held in the registers of the unpacking kernels rather than writing out
+//! a `[u32; X128]` block and scanning it in a separate pass.
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint32::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint32::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint32::neon;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+use crate::uint32::sse41;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+use crate::uint32::wasm_simd128;
+use crate::uint32::{max_compressed_size, scalar};
+use crate::{Backend, X128};
+
+/// Returns the sum of the values of a compressed block.
+///
+/// This requires that the values contained were originally compressed with [crate::compress].
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// The sum is accumulated in 64 bits so it can not overflow.
+pub fn sum(n: usize, compressed_bit_length: u8, input: &[u8]) -> u64 {
+    validate(n, compressed_bit_length, input);
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::sum_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::sum_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::sum_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::sum_x128(compressed_bit_length, input, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe { wasm_simd128::sum_x128(compressed_bit_length, input, n) },
+        _ => unsafe { scalar::sum_x128(compressed_bit_length, input, n) },
+    }
+}
+
+/// Returns the smallest and largest of the values of a compressed block.
+///
+/// This requires that the values contained were originally compressed with [crate::compress].
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// Returns `None` if `n` is `0`.
+pub fn min_max(n: usize, compressed_bit_length: u8, input: &[u8]) -> Option<(u32, u32)> {
+    validate(n, compressed_bit_length, input);
+    if n == 0 {
+        return None;
+    }
+
+    let min_max = match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::min_max_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::min_max_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::min_max_x128(compressed_bit_length, input, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::min_max_x128(compressed_bit_length, input, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::min_max_x128(compressed_bit_length, input, n)
+        },
+        _ => unsafe { scalar::min_max_x128(compressed_bit_length, input, n) },
+    };
+    Some(min_max)
+}
+
+/// Returns the last value of a compressed block without reversing the Delta encoding of
+/// the rest of the block.
+///
+/// This requires that the values contained were originally compressed with
+/// [crate::compress_delta], the last value is the `initial_value` plus the sum of the deltas.
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// Returns `initial_value` if `n` is `0`, matching [crate::CompressionDetails::last_value].
+pub fn last_value_delta(
+    initial_value: u32,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+) -> u32 {
+    let deltas = sum(n, compressed_bit_length, input);
+    initial_value.wrapping_add(deltas as u32)
+}
+
+/// Returns the last value of a compressed block without reversing the Delta-1 encoding of
+/// the rest of the block.
+///
+/// This requires that the values contained were originally compressed with
+/// [crate::compress_delta1], the last value is the `initial_value` plus the sum of the
+/// deltas and `n`.
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// Returns `initial_value` if `n` is `0`, matching [crate::CompressionDetails::last_value].
+pub fn last_value_delta1(
+    initial_value: u32,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+) -> u32 {
+    let deltas = sum(n, compressed_bit_length, input) + n as u64;
+    initial_value.wrapping_add(deltas as u32)
+}
+
+fn validate(n: usize, compressed_bit_length: u8, input: &[u8]) {
+    assert!(
+        compressed_bit_length <= 32,
+        "compressed bitlength must be no more than 32"
+    );
+    assert!(
+        input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    #[rstest::rstest]
+    fn test_sum_and_min_max_all_bit_lengths(
+        #[values(1, 7, 37, 64, 65, 100, X128)] n: usize,
+        #[values(0, 1, 5, 8, 13, 16, 23, 31, 32)] nbits: u32,
+    ) {
+        let mask = (1u64 << nbits).wrapping_sub(1) as u32;
+        let values: [u32; X128] = core::array::from_fn(|i| {
+            if i == 3 {
+                mask
+            } else {
+                (i as u32).wrapping_mul(2654435761) & mask
+            }
+        });
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let expected_sum = values[..n].iter().map(|&v| v as u64).sum::<u64>();
+        let expected_min = *values[..n].iter().min().unwrap();
+        let expected_max = *values[..n].iter().max().unwrap();
+
+        assert_eq!(
+            sum(n, details.compressed_bit_length, &compressed),
+            expected_sum
+        );
+        assert_eq!(
+            min_max(n, details.compressed_bit_length, &compressed),
+            Some((expected_min, expected_max)),
+        );
+    }
+
+    #[test]
+    fn test_real_data_sample() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        for select_n in [1, 7, 64, 65, 100, X128] {
+            for sample in sample_data.iter() {
+                let values = &sample[..select_n];
+                let details = crate::compress(select_n, sample, &mut compressed);
+                assert_eq!(
+                    sum(select_n, details.compressed_bit_length, &compressed),
+                    values.iter().map(|&v| v as u64).sum::<u64>(),
+                );
+                assert_eq!(
+                    min_max(select_n, details.compressed_bit_length, &compressed),
+                    Some((*values.iter().min().unwrap(), *values.iter().max().unwrap())),
+                );
+
+                let details = crate::compress_delta(7, select_n, &mut { *sample }, &mut compressed);
+                assert_eq!(
+                    last_value_delta(7, select_n, details.compressed_bit_length, &compressed),
+                    details.last_value as u32,
+                );
+
+                let details =
+                    crate::compress_delta1(7, select_n, &mut { *sample }, &mut compressed);
+                assert_eq!(
+                    last_value_delta1(7, select_n, details.compressed_bit_length, &compressed),
+                    details.last_value as u32,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_does_not_overflow() {
+        let values = [u32::MAX; X128];
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(X128, &values, &mut compressed);
+        assert_eq!(
+            sum(X128, details.compressed_bit_length, &compressed),
+            u32::MAX as u64 * X128 as u64,
+        );
+    }
+
+    #[test]
+    fn test_empty_block() {
+        let compressed = [0; X128_MAX_OUTPUT_LEN];
+        assert_eq!(sum(0, 7, &compressed), 0);
+        assert_eq!(min_max(0, 7, &compressed), None);
+        assert_eq!(last_value_delta(42, 0, 7, &compressed), 42);
+        assert_eq!(last_value_delta1(42, 0, 7, &compressed), 42);
+    }
+}
@@ -0,0 +1,154 @@
+//! Evaluating range predicates against the values of a packed block while they are held
+//! in registers.
+//!
+//! The halves of the block are unpacked with the regular x64 kernels and compared straight
+//! away, only the bitmask of the selected positions leaves the registers.
+use super::util::select_range;
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint32::max_compressed_size;
+use crate::{X64, X128};
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Returns a bitmask of the first `read_n` elements of a block of `nbits` bit length elements
+/// which are within `lo..=lo + span`, the bit at position `i` being set when the `i`th
+/// element is selected.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn filter_nbits(
+    nbits: usize,
+    input: *const u8,
+    read_n: usize,
+    lo: u32,
+    span: u32,
+) -> u128 {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(*const u8, usize, u32, u32) -> u128; 33] = [
+        filter_u0, filter_u1, filter_u2, filter_u3, filter_u4, filter_u5, filter_u6, filter_u7,
+        filter_u8, filter_u9, filter_u10, filter_u11, filter_u12, filter_u13, filter_u14,
+        filter_u15, filter_u16, filter_u17, filter_u18, filter_u19, filter_u20, filter_u21,
+        filter_u22, filter_u23, filter_u24, filter_u25, filter_u26, filter_u27, filter_u28,
+        filter_u29, filter_u30, filter_u31, filter_u32,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, read_n, lo, span) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn filter_u0(_input: *const u8, read_n: usize, lo: u32, span: u32) -> u128 {
+    if 0u32.wrapping_sub(lo) <= span {
+        u128::MAX.checked_shr((X128 - read_n) as u32).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+macro_rules! define_x128_filter {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(input: *const u8, read_n: usize, lo: u32, span: u32) -> u128 {
+            let mut mask = 0;
+            for start in (0..read_n).step_by(X64) {
+                let n = (read_n - start).min(X64);
+                let input =
+                    unsafe { input.add(start / X64 * max_compressed_size::<X64>($bit_length)) };
+
+                let is_full = read_n > start + X64 || read_n == X128;
+                let unpacked = if is_full {
+                    unsafe { unpack_x64_full::$unpack_func_name(input) }
+                } else {
+                    unsafe { unpack_x64_partial::$unpack_func_name(input, n) }
+                };
+                let selected = select_range(&unpacked, lo, span) & (u64::MAX >> (X64 - n));
+                mask |= (selected as u128) << start;
+            }
+            mask
+        }
+    };
+}
+
+define_x128_filter!(filter_u1, from_u1, 1);
+define_x128_filter!(filter_u2, from_u2, 2);
+define_x128_filter!(filter_u3, from_u3, 3);
+define_x128_filter!(filter_u4, from_u4, 4);
+define_x128_filter!(filter_u5, from_u5, 5);
+define_x128_filter!(filter_u6, from_u6, 6);
+define_x128_filter!(filter_u7, from_u7, 7);
+define_x128_filter!(filter_u8, from_u8, 8);
+define_x128_filter!(filter_u9, from_u9, 9);
+define_x128_filter!(filter_u10, from_u10, 10);
+define_x128_filter!(filter_u11, from_u11, 11);
+define_x128_filter!(filter_u12, from_u12, 12);
+define_x128_filter!(filter_u13, from_u13, 13);
+define_x128_filter!(filter_u14, from_u14, 14);
+define_x128_filter!(filter_u15, from_u15, 15);
+define_x128_filter!(filter_u16, from_u16, 16);
+define_x128_filter!(filter_u17, from_u17, 17);
+define_x128_filter!(filter_u18, from_u18, 18);
+define_x128_filter!(filter_u19, from_u19, 19);
+define_x128_filter!(filter_u20, from_u20, 20);
+define_x128_filter!(filter_u21, from_u21, 21);
+define_x128_filter!(filter_u22, from_u22, 22);
+define_x128_filter!(filter_u23, from_u23, 23);
+define_x128_filter!(filter_u24, from_u24, 24);
+define_x128_filter!(filter_u25, from_u25, 25);
+define_x128_filter!(filter_u26, from_u26, 26);
+define_x128_filter!(filter_u27, from_u27, 27);
+define_x128_filter!(filter_u28, from_u28, 28);
+define_x128_filter!(filter_u29, from_u29, 29);
+define_x128_filter!(filter_u30, from_u30, 30);
+define_x128_filter!(filter_u31, from_u31, 31);
+define_x128_filter!(filter_u32, from_u32, 32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(37)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_filter_matches_values(#[case] read_n: usize) {
+        for nbits in 0..=32 {
+            let mask = (1u64 << nbits).wrapping_sub(1) as u32;
+            let values: [u32; X128] = core::array::from_fn(|i| {
+                if i % 7 == 0 {
+                    mask
+                } else {
+                    ((i as u32).wrapping_mul(2654435761) >> 3) & mask
+                }
+            });
+
+            let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+            let details = crate::compress(read_n, &values, &mut compressed);
+            let nbits = details.compressed_bit_length as usize;
+
+            for (lo, span) in [(0, 0), (0, u32::MAX), (mask / 4, mask / 2), (mask, 0)] {
+                let expected = values[..read_n]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| v.wrapping_sub(lo) <= span)
+                    .fold(0u128, |acc, (i, _)| acc | (1 << i));
+
+                let selected =
+                    unsafe { filter_nbits(nbits, compressed.as_ptr(), read_n, lo, span) };
+                assert_eq!(
+                    selected, expected,
+                    "filter mismatch, read_n:{read_n} nbits:{nbits} lo:{lo} span:{span}"
+                );
+            }
+        }
+    }
+}
@@ -1,19 +1,32 @@
 use crate::uint32::{X128_MAX_OUTPUT_LEN, compressed_size};
-use crate::{CompressionDetails, X128};
+use crate::util::{as_unsigned_i32, as_unsigned_i32_mut, zigzag_encode_i32};
+use crate::{CompressionDetails, X64, X128};
 
+mod aggregate_x128;
 pub(super) mod data;
+mod filter_x128;
 mod pack_x128;
-pub(super) mod pack_x64_full;
-pub(super) mod pack_x64_partial;
+pub(crate) mod pack_x64_full;
+pub(crate) mod pack_x64_partial;
+mod unpack_widen_x128;
 mod unpack_x128;
-pub(super) mod unpack_x64_full;
-pub(super) mod unpack_x64_partial;
+pub(crate) mod unpack_x64_full;
+pub(crate) mod unpack_x64_partial;
 mod util;
 
 #[inline]
 /// Returns `true` if the runtime CPU can safely execute the AVX2 backed implementation.
+///
+/// Without the `std` feature this is decided by the target features enabled at compile time.
 pub fn can_use() -> bool {
-    std::arch::is_x86_feature_detected!("avx2")
+    #[cfg(feature = "std")]
+    {
+        std::arch::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
 }
 
 #[target_feature(enable = "avx2")]
@@ -35,6 +48,37 @@ pub unsafe fn pack_x128(
     CompressionDetails {
         compressed_bit_length: nbits as u8,
         bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack the two x64 halves of a block of 128 32-bit integers and write the compressed
+/// block to `out`, the right half is only read when `pack_n` is greater than 64.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_halves_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    [left, right]: [&[u32; X64]; 2],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = left
+        .iter()
+        .chain(right)
+        .take(pack_n)
+        .fold(0, |a, b| a.max(*b));
+    let nbits = 32 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits_halves(nbits as usize, out.as_mut_ptr(), [left, right], pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
     }
 }
 
@@ -46,18 +90,21 @@ pub unsafe fn pack_x128(
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `pack_n` must be less than or equal to 128.
 pub unsafe fn pack_delta_x128(
-    mut last_value: u32,
+    last_value: u32,
     out: &mut [u8; X128_MAX_OUTPUT_LEN],
-    block: &mut [u32; X128],
+    block: &[u32; X128],
     pack_n: usize,
 ) -> CompressionDetails {
-    for v in block.iter_mut() {
-        let value = *v;
-        *v = value.wrapping_sub(last_value);
-        last_value = value;
-    }
+    let nbits = pack_x128::delta_bit_length(last_value, block, pack_n);
+
+    unsafe { pack_x128::to_nbits_delta(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
 
-    unsafe { pack_x128(out, block, pack_n) }
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
 }
 
 #[target_feature(enable = "avx2")]
@@ -68,18 +115,109 @@ pub unsafe fn pack_delta_x128(
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `pack_n` must be less than or equal to 128.
 pub unsafe fn pack_delta1_x128(
-    mut last_value: u32,
+    last_value: u32,
     out: &mut [u8; X128_MAX_OUTPUT_LEN],
-    block: &mut [u32; X128],
+    block: &[u32; X128],
     pack_n: usize,
 ) -> CompressionDetails {
-    for v in block.iter_mut() {
-        let value = *v;
-        *v = value.wrapping_sub(last_value).wrapping_sub(1);
-        last_value = value;
+    let nbits = pack_x128::delta1_bit_length(last_value, block, pack_n);
+
+    unsafe { pack_x128::to_nbits_delta1(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
     }
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the bit length required to pack the first `pack_n` integers of the block.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_x128(block: &[u32; X128], pack_n: usize) -> u8 {
+    pack_x128::plain_bit_length(block, pack_n) as u8
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta_x128(last_value: u32, block: &[u32; X128], pack_n: usize) -> u8 {
+    pack_x128::delta_bit_length(last_value, block, pack_n) as u8
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta1_x128(last_value: u32, block: &[u32; X128], pack_n: usize) -> u8 {
+    pack_x128::delta1_bit_length(last_value, block, pack_n) as u8
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 32-bit integers and write the compressed block to `out` after
+/// applying Delta encoding, unless one of the integers is smaller than the integer
+/// before it, in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta_x128(
+    last_value: u32,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u32; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let nbits = pack_x128::checked_delta_bit_length(last_value, block, pack_n)?;
+
+    unsafe { pack_x128::to_nbits_delta(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
 
-    unsafe { pack_x128(out, block, pack_n) }
+    Ok(CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    })
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 32-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding, unless one of the integers is not greater than the integer
+/// before it, in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta1_x128(
+    last_value: u32,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u32; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let nbits = pack_x128::checked_delta1_bit_length(last_value, block, pack_n)?;
+
+    unsafe { pack_x128::to_nbits_delta1(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
+
+    Ok(CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    })
 }
 
 #[target_feature(enable = "avx2")]
@@ -88,8 +226,8 @@ pub unsafe fn pack_delta1_x128(
 /// # Safety
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `32`.
 pub unsafe fn unpack_x128(
     nbits: u8,
@@ -101,6 +239,66 @@ pub unsafe fn unpack_x128(
     compressed_size(nbits as usize, read_n)
 }
 
+#[target_feature(enable = "avx2")]
+/// Unpack a compressed block of 128 32-bit integers into the two x64 halves of a block,
+/// the right half is only written when `read_n` is greater than 64 or `nbits` is `0`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_halves_x128(
+    nbits: u8,
+    input: &[u8],
+    halves: [&mut [u32; X64]; 2],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_halves(nbits as usize, input.as_ptr(), halves, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 integers which were packed as 16-bit integers, widening them to
+/// 32-bit integers as they are written to `block`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `16`.
+pub unsafe fn unpack_u16_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [u32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_widen_x128::from_nbits_u16(nbits as usize, input.as_ptr(), block, read_n) };
+    crate::uint16::compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 integers which were packed as 8-bit integers, widening them to
+/// 32-bit integers as they are written to `block`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_u8_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [u32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_widen_x128::from_nbits_u8(nbits as usize, input.as_ptr(), block, read_n) };
+    crate::uint8::compressed_size(nbits as usize, read_n)
+}
+
 #[target_feature(enable = "avx2")]
 /// Pack a block of 128 32-bit integers and write the compressed block to `out` after
 /// applying Delta encoding.
@@ -108,8 +306,8 @@ pub unsafe fn unpack_x128(
 /// # Safety
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `32`.
 pub unsafe fn unpack_delta_x128(
     nbits: u8,
@@ -131,8 +329,8 @@ pub unsafe fn unpack_delta_x128(
 /// # Safety
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be less than or equal to 128.
-/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
-///   a given bit length.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
 /// - `nbits` must be no greater than `32`.
 pub unsafe fn unpack_delta1_x128(
     nbits: u8,
@@ -146,3 +344,398 @@ pub unsafe fn unpack_delta1_x128(
     };
     compressed_size(nbits as usize, read_n)
 }
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 32-bit integers which have been Delta encoded and return the
+/// position of the first value greater than or equal to `target`, writing the recovered
+/// values to `block`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_delta_seek_x128(
+    nbits: u8,
+    last_value: u32,
+    target: u32,
+    input: &[u8],
+    block: &mut [u32; X128],
+    read_n: usize,
+) -> Option<usize> {
+    unsafe {
+        unpack_x128::from_nbits_delta_seek(
+            nbits as usize,
+            last_value,
+            target,
+            input.as_ptr(),
+            Some(block),
+            read_n,
+        )
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Search a block of 128 32-bit integers which have been Delta encoded for the position
+/// of the first value greater than or equal to `target` without writing the values out.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
+///   a given bit length.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn search_delta_x128(
+    nbits: u8,
+    last_value: u32,
+    target: u32,
+    input: &[u8],
+    read_n: usize,
+) -> Option<usize> {
+    unsafe {
+        unpack_x128::from_nbits_delta_seek(
+            nbits as usize,
+            last_value,
+            target,
+            input.as_ptr(),
+            None,
+            read_n,
+        )
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Sum the first `read_n` values of a block of 128 32-bit integers without writing the
+/// values out.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
+///   a given bit length.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn sum_x128(nbits: u8, input: &[u8], read_n: usize) -> u64 {
+    unsafe { aggregate_x128::sum_nbits(nbits as usize, input.as_ptr(), read_n) }
+}
+
+#[target_feature(enable = "avx2")]
+/// Find the smallest and largest of the first `read_n` values of a block of 128 32-bit
+/// integers without writing the values out.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be between 1 and 128.
+/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
+///   a given bit length.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn min_max_x128(nbits: u8, input: &[u8], read_n: usize) -> (u32, u32) {
+    unsafe { aggregate_x128::min_max_nbits(nbits as usize, input.as_ptr(), read_n) }
+}
+
+#[target_feature(enable = "avx2")]
+/// Select the first `read_n` values of a block of 128 32-bit integers which are within
+/// `lo..=lo + span`, returning a bitmask of their positions without writing the values out.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the _maximum_ possible length of the packed values for
+///   a given bit length.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn filter_x128(nbits: u8, input: &[u8], read_n: usize, lo: u32, span: u32) -> u128 {
+    unsafe { filter_x128::filter_nbits(nbits as usize, input.as_ptr(), read_n, lo, span) }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 signed 32-bit integers and write the compressed block to `out`
+/// after applying ZigZag encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i32; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = block
+        .iter()
+        .take(pack_n)
+        .fold(0, |a, b| a.max(zigzag_encode_i32(*b)));
+    let nbits = 32 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits_zigzag(nbits as usize, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 signed 32-bit integers and write the compressed block to `out`
+/// after applying Delta encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_delta_x128(
+    last_value: i32,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i32; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let last_value = last_value as u32;
+    let block = as_unsigned_i32(block);
+    let nbits = pack_x128::zigzag_delta_bit_length(last_value, block, pack_n);
+
+    unsafe { pack_x128::to_nbits_zigzag_delta(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 signed 32-bit integers and write the compressed block to `out`
+/// after applying Delta-1 encoding followed by ZigZag encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_zigzag_delta1_x128(
+    last_value: i32,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i32; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let last_value = last_value as u32;
+    let block = as_unsigned_i32(block);
+    let nbits = pack_x128::zigzag_delta1_bit_length(last_value, block, pack_n);
+
+    unsafe {
+        pack_x128::to_nbits_zigzag_delta1(nbits, last_value, out.as_mut_ptr(), block, pack_n)
+    };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 signed 32-bit integers and reverse the ZigZag encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_zigzag_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [i32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_zigzag(nbits as usize, 0, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 signed 32-bit integers, reverse the ZigZag encoding and add
+/// `base` to each element, wrapping on overflow.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_zigzag_offset_x128(
+    nbits: u8,
+    base: i32,
+    input: &[u8],
+    block: &mut [i32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits_zigzag(nbits as usize, base, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 signed 32-bit integers, reverse the ZigZag encoding
+/// and then the Delta encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_zigzag_delta_x128(
+    nbits: u8,
+    last_value: i32,
+    input: &[u8],
+    block: &mut [i32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_zigzag_delta(
+            nbits as usize,
+            last_value,
+            input.as_ptr(),
+            block,
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 signed 32-bit integers, reverse the ZigZag encoding
+/// and then the Delta-1 encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_zigzag_delta1_x128(
+    nbits: u8,
+    last_value: i32,
+    input: &[u8],
+    block: &mut [i32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_zigzag_delta1(
+            nbits as usize,
+            last_value,
+            input.as_ptr(),
+            block,
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 32-bit integers and write the compressed block to `out` after
+/// subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u32; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 32 - (max - reference).leading_zeros();
+
+    unsafe { pack_x128::to_nbits_for(nbits as usize, reference, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: reference as u64,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 signed 32-bit integers and write the compressed block to `out`
+/// after subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_signed_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[i32; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).copied().max().unwrap_or(0);
+    let nbits = 32 - (max.wrapping_sub(reference) as u32).leading_zeros();
+
+    unsafe {
+        pack_x128::to_nbits_for(
+            nbits as usize,
+            reference as u32,
+            out.as_mut_ptr(),
+            as_unsigned_i32(block),
+            pack_n,
+        )
+    };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: reference as i64 as u64,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 32-bit integers and add the `reference` value back to each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_for_x128(
+    nbits: u8,
+    reference: u32,
+    input: &[u8],
+    block: &mut [u32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(nbits as usize, reference, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 signed 32-bit integers and add the `reference` value back to
+/// each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `32`.
+pub unsafe fn unpack_signed_for_x128(
+    nbits: u8,
+    reference: i32,
+    input: &[u8],
+    block: &mut [i32; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(
+            nbits as usize,
+            reference as u32,
+            input.as_ptr(),
+            as_unsigned_i32_mut(block),
+            read_n,
+        )
+    };
+    compressed_size(nbits as usize, read_n)
+}
@@ -1,6 +1,10 @@
+use core::arch::x86_64::*;
+
 use super::data::load_u32x64;
+use super::util::{bit_length, find_first_descent, sub_reference, zigzag_encode};
 use super::{pack_x64_full, pack_x64_partial};
 use crate::uint32::{max_compressed_size, split_block};
+use crate::util::as_unsigned_i32;
 use crate::{X64, X128};
 
 #[inline]
@@ -13,27 +17,40 @@ use crate::{X64, X128};
 /// - `nbits` must be between 0 and 32.
 /// - `pack_n` must be no greater than 128.
 pub unsafe fn to_nbits(nbits: usize, out: *mut u8, block: &[u32; X128], pack_n: usize) {
+    unsafe { to_nbits_halves(nbits, out, split_block(block), pack_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the two x64 halves of a block of integers to `nbits` bit length elements.
+///
+/// The right half is only read when `pack_n` is greater than 64.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_halves(nbits: usize, out: *mut u8, halves: [&[u32; X64]; 2], pack_n: usize) {
     debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
     debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
     #[allow(clippy::type_complexity)]
-    const LUT: [unsafe fn(out: *mut u8, &[u32; X128], usize); 33] = [
+    const LUT: [unsafe fn(out: *mut u8, [&[u32; X64]; 2], usize); 33] = [
         to_u0, to_u1, to_u2, to_u3, to_u4, to_u5, to_u6, to_u7, to_u8, to_u9, to_u10, to_u11,
         to_u12, to_u13, to_u14, to_u15, to_u16, to_u17, to_u18, to_u19, to_u20, to_u21, to_u22,
         to_u23, to_u24, to_u25, to_u26, to_u27, to_u28, to_u29, to_u30, to_u31, to_u32,
     ];
     let func = unsafe { LUT.get_unchecked(nbits) };
-    unsafe { func(out, block, pack_n) };
+    unsafe { func(out, halves, pack_n) };
 }
 
 #[target_feature(enable = "avx2")]
-unsafe fn to_u0(_out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+unsafe fn to_u0(_out: *mut u8, _halves: [&[u32; X64]; 2], _pack_n: usize) {}
 
 macro_rules! define_x128_packer {
     ($func_name:ident, $bit_length:expr) => {
         #[target_feature(enable = "avx2")]
-        unsafe fn $func_name(out: *mut u8, block: &[u32; X128], pack_n: usize) {
-            let [left, right] = split_block(block);
-
+        unsafe fn $func_name(out: *mut u8, [left, right]: [&[u32; X64]; 2], pack_n: usize) {
             let left = load_u32x64(left);
             if pack_n <= 64 {
                 unsafe { pack_x64_partial::$func_name(out.add(0), left, pack_n) };
@@ -94,10 +111,811 @@ define_x128_packer!(to_u30, 30);
 define_x128_packer!(to_u31, 31);
 define_x128_packer!(to_u32, 32);
 
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of signed integers to `nbits` bit length elements after
+/// applying ZigZag encoding.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag(nbits: usize, out: *mut u8, block: &[i32; X128], pack_n: usize) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *mut u8, &[i32; X128], usize); 33] = [
+        to_u0_zigzag,
+        to_u1_zigzag,
+        to_u2_zigzag,
+        to_u3_zigzag,
+        to_u4_zigzag,
+        to_u5_zigzag,
+        to_u6_zigzag,
+        to_u7_zigzag,
+        to_u8_zigzag,
+        to_u9_zigzag,
+        to_u10_zigzag,
+        to_u11_zigzag,
+        to_u12_zigzag,
+        to_u13_zigzag,
+        to_u14_zigzag,
+        to_u15_zigzag,
+        to_u16_zigzag,
+        to_u17_zigzag,
+        to_u18_zigzag,
+        to_u19_zigzag,
+        to_u20_zigzag,
+        to_u21_zigzag,
+        to_u22_zigzag,
+        to_u23_zigzag,
+        to_u24_zigzag,
+        to_u25_zigzag,
+        to_u26_zigzag,
+        to_u27_zigzag,
+        to_u28_zigzag,
+        to_u29_zigzag,
+        to_u30_zigzag,
+        to_u31_zigzag,
+        to_u32_zigzag,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_zigzag(_out: *mut u8, _block: &[i32; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_zigzag {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(out: *mut u8, block: &[i32; X128], pack_n: usize) {
+            let [left, right] = split_block(as_unsigned_i32(block));
+
+            let left = zigzag_encode(load_u32x64(left));
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u32x64(right));
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u32x64(right));
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_zigzag!(to_u1_zigzag, to_u1, 1);
+define_x128_packer_zigzag!(to_u2_zigzag, to_u2, 2);
+define_x128_packer_zigzag!(to_u3_zigzag, to_u3, 3);
+define_x128_packer_zigzag!(to_u4_zigzag, to_u4, 4);
+define_x128_packer_zigzag!(to_u5_zigzag, to_u5, 5);
+define_x128_packer_zigzag!(to_u6_zigzag, to_u6, 6);
+define_x128_packer_zigzag!(to_u7_zigzag, to_u7, 7);
+define_x128_packer_zigzag!(to_u8_zigzag, to_u8, 8);
+define_x128_packer_zigzag!(to_u9_zigzag, to_u9, 9);
+define_x128_packer_zigzag!(to_u10_zigzag, to_u10, 10);
+define_x128_packer_zigzag!(to_u11_zigzag, to_u11, 11);
+define_x128_packer_zigzag!(to_u12_zigzag, to_u12, 12);
+define_x128_packer_zigzag!(to_u13_zigzag, to_u13, 13);
+define_x128_packer_zigzag!(to_u14_zigzag, to_u14, 14);
+define_x128_packer_zigzag!(to_u15_zigzag, to_u15, 15);
+define_x128_packer_zigzag!(to_u16_zigzag, to_u16, 16);
+define_x128_packer_zigzag!(to_u17_zigzag, to_u17, 17);
+define_x128_packer_zigzag!(to_u18_zigzag, to_u18, 18);
+define_x128_packer_zigzag!(to_u19_zigzag, to_u19, 19);
+define_x128_packer_zigzag!(to_u20_zigzag, to_u20, 20);
+define_x128_packer_zigzag!(to_u21_zigzag, to_u21, 21);
+define_x128_packer_zigzag!(to_u22_zigzag, to_u22, 22);
+define_x128_packer_zigzag!(to_u23_zigzag, to_u23, 23);
+define_x128_packer_zigzag!(to_u24_zigzag, to_u24, 24);
+define_x128_packer_zigzag!(to_u25_zigzag, to_u25, 25);
+define_x128_packer_zigzag!(to_u26_zigzag, to_u26, 26);
+define_x128_packer_zigzag!(to_u27_zigzag, to_u27, 27);
+define_x128_packer_zigzag!(to_u28_zigzag, to_u28, 28);
+define_x128_packer_zigzag!(to_u29_zigzag, to_u29, 29);
+define_x128_packer_zigzag!(to_u30_zigzag, to_u30, 30);
+define_x128_packer_zigzag!(to_u31_zigzag, to_u31, 31);
+define_x128_packer_zigzag!(to_u32_zigzag, to_u32, 32);
+
+macro_rules! define_x128_packer_delta {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr, $encode_func_name:ident) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(last_value: u32, out: *mut u8, block: &[u32; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let mut left = load_u32x64(left);
+            let previous = $encode_func_name(_mm256_set1_epi32(last_value as i32), &mut left);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let mut right = load_u32x64(right);
+                $encode_func_name(previous, &mut right);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let mut right = load_u32x64(right);
+                $encode_func_name(previous, &mut right);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Returns the bit length required to pack the first `pack_n` elements of the block.
+pub fn plain_bit_length(block: &[u32; X128], pack_n: usize) -> usize {
+    let [left, right] = split_block(block);
+
+    let mut nbits = bit_length(&load_u32x64(left), pack_n.min(X64));
+    if pack_n > X64 {
+        nbits = nbits.max(bit_length(&load_u32x64(right), pack_n - X64));
+    }
+    nbits as usize
+}
+
+macro_rules! define_x128_bit_length {
+    ($func_name:ident, $encode_func_name:ident) => {
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        /// Returns the bit length required to pack the first `pack_n` elements of the block
+        /// once encoded.
+        pub fn $func_name(last_value: u32, block: &[u32; X128], pack_n: usize) -> usize {
+            let [left, right] = split_block(block);
+
+            let mut left = load_u32x64(left);
+            let previous = $encode_func_name(_mm256_set1_epi32(last_value as i32), &mut left);
+            let mut nbits = bit_length(&left, pack_n.min(X64));
+            if pack_n > X64 {
+                let mut right = load_u32x64(right);
+                $encode_func_name(previous, &mut right);
+                nbits = nbits.max(bit_length(&right, pack_n - X64));
+            }
+            nbits as usize
+        }
+    };
+}
+
+macro_rules! define_x128_checked_bit_length {
+    ($func_name:ident, $encode_func_name:ident, $strict:literal) => {
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        /// Returns the bit length required to pack the first `pack_n` elements of the block
+        /// once encoded, or the position of the first of those elements which is out of order
+        /// for the encoding, the first element is compared against `last_value`.
+        pub fn $func_name(
+            last_value: u32,
+            block: &[u32; X128],
+            pack_n: usize,
+        ) -> Result<usize, usize> {
+            let [left, right] = split_block(block);
+
+            let values = load_u32x64(left);
+            let mut deltas = values;
+            let previous = $encode_func_name(_mm256_set1_epi32(last_value as i32), &mut deltas);
+            if let Some(position) =
+                find_first_descent(&values, &deltas, $strict).filter(|&position| position < pack_n)
+            {
+                return Err(position);
+            }
+            let mut nbits = bit_length(&deltas, pack_n.min(X64));
+            if pack_n > X64 {
+                let values = load_u32x64(right);
+                let mut deltas = values;
+                $encode_func_name(previous, &mut deltas);
+                if let Some(position) = find_first_descent(&values, &deltas, $strict)
+                    .map(|position| X64 + position)
+                    .filter(|&position| position < pack_n)
+                {
+                    return Err(position);
+                }
+                nbits = nbits.max(bit_length(&deltas, pack_n - X64));
+            }
+            Ok(nbits as usize)
+        }
+    };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_delta(
+    nbits: usize,
+    last_value: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_delta,
+        to_u1_delta,
+        to_u2_delta,
+        to_u3_delta,
+        to_u4_delta,
+        to_u5_delta,
+        to_u6_delta,
+        to_u7_delta,
+        to_u8_delta,
+        to_u9_delta,
+        to_u10_delta,
+        to_u11_delta,
+        to_u12_delta,
+        to_u13_delta,
+        to_u14_delta,
+        to_u15_delta,
+        to_u16_delta,
+        to_u17_delta,
+        to_u18_delta,
+        to_u19_delta,
+        to_u20_delta,
+        to_u21_delta,
+        to_u22_delta,
+        to_u23_delta,
+        to_u24_delta,
+        to_u25_delta,
+        to_u26_delta,
+        to_u27_delta,
+        to_u28_delta,
+        to_u29_delta,
+        to_u30_delta,
+        to_u31_delta,
+        to_u32_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_delta(_last_value: u32, _out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+
+define_x128_packer_delta!(to_u1_delta, to_u1, 1, encode_delta);
+define_x128_packer_delta!(to_u2_delta, to_u2, 2, encode_delta);
+define_x128_packer_delta!(to_u3_delta, to_u3, 3, encode_delta);
+define_x128_packer_delta!(to_u4_delta, to_u4, 4, encode_delta);
+define_x128_packer_delta!(to_u5_delta, to_u5, 5, encode_delta);
+define_x128_packer_delta!(to_u6_delta, to_u6, 6, encode_delta);
+define_x128_packer_delta!(to_u7_delta, to_u7, 7, encode_delta);
+define_x128_packer_delta!(to_u8_delta, to_u8, 8, encode_delta);
+define_x128_packer_delta!(to_u9_delta, to_u9, 9, encode_delta);
+define_x128_packer_delta!(to_u10_delta, to_u10, 10, encode_delta);
+define_x128_packer_delta!(to_u11_delta, to_u11, 11, encode_delta);
+define_x128_packer_delta!(to_u12_delta, to_u12, 12, encode_delta);
+define_x128_packer_delta!(to_u13_delta, to_u13, 13, encode_delta);
+define_x128_packer_delta!(to_u14_delta, to_u14, 14, encode_delta);
+define_x128_packer_delta!(to_u15_delta, to_u15, 15, encode_delta);
+define_x128_packer_delta!(to_u16_delta, to_u16, 16, encode_delta);
+define_x128_packer_delta!(to_u17_delta, to_u17, 17, encode_delta);
+define_x128_packer_delta!(to_u18_delta, to_u18, 18, encode_delta);
+define_x128_packer_delta!(to_u19_delta, to_u19, 19, encode_delta);
+define_x128_packer_delta!(to_u20_delta, to_u20, 20, encode_delta);
+define_x128_packer_delta!(to_u21_delta, to_u21, 21, encode_delta);
+define_x128_packer_delta!(to_u22_delta, to_u22, 22, encode_delta);
+define_x128_packer_delta!(to_u23_delta, to_u23, 23, encode_delta);
+define_x128_packer_delta!(to_u24_delta, to_u24, 24, encode_delta);
+define_x128_packer_delta!(to_u25_delta, to_u25, 25, encode_delta);
+define_x128_packer_delta!(to_u26_delta, to_u26, 26, encode_delta);
+define_x128_packer_delta!(to_u27_delta, to_u27, 27, encode_delta);
+define_x128_packer_delta!(to_u28_delta, to_u28, 28, encode_delta);
+define_x128_packer_delta!(to_u29_delta, to_u29, 29, encode_delta);
+define_x128_packer_delta!(to_u30_delta, to_u30, 30, encode_delta);
+define_x128_packer_delta!(to_u31_delta, to_u31, 31, encode_delta);
+define_x128_packer_delta!(to_u32_delta, to_u32, 32, encode_delta);
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta-1 encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_delta1(
+    nbits: usize,
+    last_value: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_delta1,
+        to_u1_delta1,
+        to_u2_delta1,
+        to_u3_delta1,
+        to_u4_delta1,
+        to_u5_delta1,
+        to_u6_delta1,
+        to_u7_delta1,
+        to_u8_delta1,
+        to_u9_delta1,
+        to_u10_delta1,
+        to_u11_delta1,
+        to_u12_delta1,
+        to_u13_delta1,
+        to_u14_delta1,
+        to_u15_delta1,
+        to_u16_delta1,
+        to_u17_delta1,
+        to_u18_delta1,
+        to_u19_delta1,
+        to_u20_delta1,
+        to_u21_delta1,
+        to_u22_delta1,
+        to_u23_delta1,
+        to_u24_delta1,
+        to_u25_delta1,
+        to_u26_delta1,
+        to_u27_delta1,
+        to_u28_delta1,
+        to_u29_delta1,
+        to_u30_delta1,
+        to_u31_delta1,
+        to_u32_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_delta1(_last_value: u32, _out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+
+define_x128_packer_delta!(to_u1_delta1, to_u1, 1, encode_delta1);
+define_x128_packer_delta!(to_u2_delta1, to_u2, 2, encode_delta1);
+define_x128_packer_delta!(to_u3_delta1, to_u3, 3, encode_delta1);
+define_x128_packer_delta!(to_u4_delta1, to_u4, 4, encode_delta1);
+define_x128_packer_delta!(to_u5_delta1, to_u5, 5, encode_delta1);
+define_x128_packer_delta!(to_u6_delta1, to_u6, 6, encode_delta1);
+define_x128_packer_delta!(to_u7_delta1, to_u7, 7, encode_delta1);
+define_x128_packer_delta!(to_u8_delta1, to_u8, 8, encode_delta1);
+define_x128_packer_delta!(to_u9_delta1, to_u9, 9, encode_delta1);
+define_x128_packer_delta!(to_u10_delta1, to_u10, 10, encode_delta1);
+define_x128_packer_delta!(to_u11_delta1, to_u11, 11, encode_delta1);
+define_x128_packer_delta!(to_u12_delta1, to_u12, 12, encode_delta1);
+define_x128_packer_delta!(to_u13_delta1, to_u13, 13, encode_delta1);
+define_x128_packer_delta!(to_u14_delta1, to_u14, 14, encode_delta1);
+define_x128_packer_delta!(to_u15_delta1, to_u15, 15, encode_delta1);
+define_x128_packer_delta!(to_u16_delta1, to_u16, 16, encode_delta1);
+define_x128_packer_delta!(to_u17_delta1, to_u17, 17, encode_delta1);
+define_x128_packer_delta!(to_u18_delta1, to_u18, 18, encode_delta1);
+define_x128_packer_delta!(to_u19_delta1, to_u19, 19, encode_delta1);
+define_x128_packer_delta!(to_u20_delta1, to_u20, 20, encode_delta1);
+define_x128_packer_delta!(to_u21_delta1, to_u21, 21, encode_delta1);
+define_x128_packer_delta!(to_u22_delta1, to_u22, 22, encode_delta1);
+define_x128_packer_delta!(to_u23_delta1, to_u23, 23, encode_delta1);
+define_x128_packer_delta!(to_u24_delta1, to_u24, 24, encode_delta1);
+define_x128_packer_delta!(to_u25_delta1, to_u25, 25, encode_delta1);
+define_x128_packer_delta!(to_u26_delta1, to_u26, 26, encode_delta1);
+define_x128_packer_delta!(to_u27_delta1, to_u27, 27, encode_delta1);
+define_x128_packer_delta!(to_u28_delta1, to_u28, 28, encode_delta1);
+define_x128_packer_delta!(to_u29_delta1, to_u29, 29, encode_delta1);
+define_x128_packer_delta!(to_u30_delta1, to_u30, 30, encode_delta1);
+define_x128_packer_delta!(to_u31_delta1, to_u31, 31, encode_delta1);
+define_x128_packer_delta!(to_u32_delta1, to_u32, 32, encode_delta1);
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta encoding followed by ZigZag encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// The signed elements are provided as their unsigned bit pattern, see
+/// [as_unsigned_i32](crate::util::as_unsigned_i32).
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag_delta(
+    nbits: usize,
+    last_value: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_zigzag_delta,
+        to_u1_zigzag_delta,
+        to_u2_zigzag_delta,
+        to_u3_zigzag_delta,
+        to_u4_zigzag_delta,
+        to_u5_zigzag_delta,
+        to_u6_zigzag_delta,
+        to_u7_zigzag_delta,
+        to_u8_zigzag_delta,
+        to_u9_zigzag_delta,
+        to_u10_zigzag_delta,
+        to_u11_zigzag_delta,
+        to_u12_zigzag_delta,
+        to_u13_zigzag_delta,
+        to_u14_zigzag_delta,
+        to_u15_zigzag_delta,
+        to_u16_zigzag_delta,
+        to_u17_zigzag_delta,
+        to_u18_zigzag_delta,
+        to_u19_zigzag_delta,
+        to_u20_zigzag_delta,
+        to_u21_zigzag_delta,
+        to_u22_zigzag_delta,
+        to_u23_zigzag_delta,
+        to_u24_zigzag_delta,
+        to_u25_zigzag_delta,
+        to_u26_zigzag_delta,
+        to_u27_zigzag_delta,
+        to_u28_zigzag_delta,
+        to_u29_zigzag_delta,
+        to_u30_zigzag_delta,
+        to_u31_zigzag_delta,
+        to_u32_zigzag_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_zigzag_delta(
+    _last_value: u32,
+    _out: *mut u8,
+    _block: &[u32; X128],
+    _pack_n: usize,
+) {
+}
+
+define_x128_packer_delta!(to_u1_zigzag_delta, to_u1, 1, encode_zigzag_delta);
+define_x128_packer_delta!(to_u2_zigzag_delta, to_u2, 2, encode_zigzag_delta);
+define_x128_packer_delta!(to_u3_zigzag_delta, to_u3, 3, encode_zigzag_delta);
+define_x128_packer_delta!(to_u4_zigzag_delta, to_u4, 4, encode_zigzag_delta);
+define_x128_packer_delta!(to_u5_zigzag_delta, to_u5, 5, encode_zigzag_delta);
+define_x128_packer_delta!(to_u6_zigzag_delta, to_u6, 6, encode_zigzag_delta);
+define_x128_packer_delta!(to_u7_zigzag_delta, to_u7, 7, encode_zigzag_delta);
+define_x128_packer_delta!(to_u8_zigzag_delta, to_u8, 8, encode_zigzag_delta);
+define_x128_packer_delta!(to_u9_zigzag_delta, to_u9, 9, encode_zigzag_delta);
+define_x128_packer_delta!(to_u10_zigzag_delta, to_u10, 10, encode_zigzag_delta);
+define_x128_packer_delta!(to_u11_zigzag_delta, to_u11, 11, encode_zigzag_delta);
+define_x128_packer_delta!(to_u12_zigzag_delta, to_u12, 12, encode_zigzag_delta);
+define_x128_packer_delta!(to_u13_zigzag_delta, to_u13, 13, encode_zigzag_delta);
+define_x128_packer_delta!(to_u14_zigzag_delta, to_u14, 14, encode_zigzag_delta);
+define_x128_packer_delta!(to_u15_zigzag_delta, to_u15, 15, encode_zigzag_delta);
+define_x128_packer_delta!(to_u16_zigzag_delta, to_u16, 16, encode_zigzag_delta);
+define_x128_packer_delta!(to_u17_zigzag_delta, to_u17, 17, encode_zigzag_delta);
+define_x128_packer_delta!(to_u18_zigzag_delta, to_u18, 18, encode_zigzag_delta);
+define_x128_packer_delta!(to_u19_zigzag_delta, to_u19, 19, encode_zigzag_delta);
+define_x128_packer_delta!(to_u20_zigzag_delta, to_u20, 20, encode_zigzag_delta);
+define_x128_packer_delta!(to_u21_zigzag_delta, to_u21, 21, encode_zigzag_delta);
+define_x128_packer_delta!(to_u22_zigzag_delta, to_u22, 22, encode_zigzag_delta);
+define_x128_packer_delta!(to_u23_zigzag_delta, to_u23, 23, encode_zigzag_delta);
+define_x128_packer_delta!(to_u24_zigzag_delta, to_u24, 24, encode_zigzag_delta);
+define_x128_packer_delta!(to_u25_zigzag_delta, to_u25, 25, encode_zigzag_delta);
+define_x128_packer_delta!(to_u26_zigzag_delta, to_u26, 26, encode_zigzag_delta);
+define_x128_packer_delta!(to_u27_zigzag_delta, to_u27, 27, encode_zigzag_delta);
+define_x128_packer_delta!(to_u28_zigzag_delta, to_u28, 28, encode_zigzag_delta);
+define_x128_packer_delta!(to_u29_zigzag_delta, to_u29, 29, encode_zigzag_delta);
+define_x128_packer_delta!(to_u30_zigzag_delta, to_u30, 30, encode_zigzag_delta);
+define_x128_packer_delta!(to_u31_zigzag_delta, to_u31, 31, encode_zigzag_delta);
+define_x128_packer_delta!(to_u32_zigzag_delta, to_u32, 32, encode_zigzag_delta);
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after applying
+/// Delta-1 encoding followed by ZigZag encoding.
+///
+/// The encoding is applied to the registers as they are loaded, the block is left untouched.
+///
+/// The signed elements are provided as their unsigned bit pattern, see
+/// [as_unsigned_i32](crate::util::as_unsigned_i32).
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag_delta1(
+    nbits: usize,
+    last_value: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_zigzag_delta1,
+        to_u1_zigzag_delta1,
+        to_u2_zigzag_delta1,
+        to_u3_zigzag_delta1,
+        to_u4_zigzag_delta1,
+        to_u5_zigzag_delta1,
+        to_u6_zigzag_delta1,
+        to_u7_zigzag_delta1,
+        to_u8_zigzag_delta1,
+        to_u9_zigzag_delta1,
+        to_u10_zigzag_delta1,
+        to_u11_zigzag_delta1,
+        to_u12_zigzag_delta1,
+        to_u13_zigzag_delta1,
+        to_u14_zigzag_delta1,
+        to_u15_zigzag_delta1,
+        to_u16_zigzag_delta1,
+        to_u17_zigzag_delta1,
+        to_u18_zigzag_delta1,
+        to_u19_zigzag_delta1,
+        to_u20_zigzag_delta1,
+        to_u21_zigzag_delta1,
+        to_u22_zigzag_delta1,
+        to_u23_zigzag_delta1,
+        to_u24_zigzag_delta1,
+        to_u25_zigzag_delta1,
+        to_u26_zigzag_delta1,
+        to_u27_zigzag_delta1,
+        to_u28_zigzag_delta1,
+        to_u29_zigzag_delta1,
+        to_u30_zigzag_delta1,
+        to_u31_zigzag_delta1,
+        to_u32_zigzag_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_zigzag_delta1(
+    _last_value: u32,
+    _out: *mut u8,
+    _block: &[u32; X128],
+    _pack_n: usize,
+) {
+}
+
+define_x128_packer_delta!(to_u1_zigzag_delta1, to_u1, 1, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u2_zigzag_delta1, to_u2, 2, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u3_zigzag_delta1, to_u3, 3, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u4_zigzag_delta1, to_u4, 4, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u5_zigzag_delta1, to_u5, 5, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u6_zigzag_delta1, to_u6, 6, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u7_zigzag_delta1, to_u7, 7, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u8_zigzag_delta1, to_u8, 8, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u9_zigzag_delta1, to_u9, 9, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u10_zigzag_delta1, to_u10, 10, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u11_zigzag_delta1, to_u11, 11, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u12_zigzag_delta1, to_u12, 12, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u13_zigzag_delta1, to_u13, 13, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u14_zigzag_delta1, to_u14, 14, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u15_zigzag_delta1, to_u15, 15, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u16_zigzag_delta1, to_u16, 16, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u17_zigzag_delta1, to_u17, 17, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u18_zigzag_delta1, to_u18, 18, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u19_zigzag_delta1, to_u19, 19, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u20_zigzag_delta1, to_u20, 20, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u21_zigzag_delta1, to_u21, 21, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u22_zigzag_delta1, to_u22, 22, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u23_zigzag_delta1, to_u23, 23, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u24_zigzag_delta1, to_u24, 24, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u25_zigzag_delta1, to_u25, 25, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u26_zigzag_delta1, to_u26, 26, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u27_zigzag_delta1, to_u27, 27, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u28_zigzag_delta1, to_u28, 28, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u29_zigzag_delta1, to_u29, 29, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u30_zigzag_delta1, to_u30, 30, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u31_zigzag_delta1, to_u31, 31, encode_zigzag_delta1);
+define_x128_packer_delta!(to_u32_zigzag_delta1, to_u32, 32, encode_zigzag_delta1);
+
+define_x128_bit_length!(delta_bit_length, encode_delta);
+define_x128_bit_length!(delta1_bit_length, encode_delta1);
+define_x128_bit_length!(zigzag_delta_bit_length, encode_zigzag_delta);
+define_x128_bit_length!(zigzag_delta1_bit_length, encode_zigzag_delta1);
+
+define_x128_checked_bit_length!(checked_delta_bit_length, encode_delta, false);
+define_x128_checked_bit_length!(checked_delta1_bit_length, encode_delta1, true);
+
+#[target_feature(enable = "avx2")]
+/// Delta encode the 32-bit elements held in the provided registers, returning the last
+/// register before encoding to carry into the next set of registers.
+///
+/// The last element of `previous` is subtracted from the first element of the block.
+fn encode_delta(mut previous: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let values = block[i];
+
+        // [previous[7], values[0], ..., values[6]]
+        let carried = _mm256_permute2x128_si256::<0x21>(previous, values);
+        let shifted = _mm256_alignr_epi8::<12>(values, carried);
+
+        block[i] = _mm256_sub_epi32(values, shifted);
+        previous = values;
+    }
+
+    previous
+}
+
+#[target_feature(enable = "avx2")]
+/// Delta-1 encode the 32-bit elements held in the provided registers, returning the last
+/// register before encoding to carry into the next set of registers.
+fn encode_delta1(previous: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    let ones = _mm256_set1_epi32(1);
+
+    let previous = encode_delta(previous, block);
+    for register in block.iter_mut() {
+        *register = _mm256_sub_epi32(*register, ones);
+    }
+
+    previous
+}
+
+#[target_feature(enable = "avx2")]
+/// Delta encode followed by ZigZag encode the signed 32-bit elements held in the provided
+/// registers, returning the last register before encoding.
+fn encode_zigzag_delta(previous: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    let previous = encode_delta(previous, block);
+    *block = zigzag_encode(*block);
+    previous
+}
+
+#[target_feature(enable = "avx2")]
+/// Delta-1 encode followed by ZigZag encode the signed 32-bit elements held in the provided
+/// registers, returning the last register before encoding.
+fn encode_zigzag_delta1(previous: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    let previous = encode_delta1(previous, block);
+    *block = zigzag_encode(*block);
+    previous
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Bitpack the provided block of integers to `nbits` bit length elements after
+/// subtracting the `reference` value from each element.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+/// - `reference` must be no greater than any of the first `pack_n` elements.
+pub unsafe fn to_nbits_for(
+    nbits: usize,
+    reference: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_for, to_u1_for, to_u2_for, to_u3_for, to_u4_for, to_u5_for, to_u6_for, to_u7_for,
+        to_u8_for, to_u9_for, to_u10_for, to_u11_for, to_u12_for, to_u13_for, to_u14_for,
+        to_u15_for, to_u16_for, to_u17_for, to_u18_for, to_u19_for, to_u20_for, to_u21_for,
+        to_u22_for, to_u23_for, to_u24_for, to_u25_for, to_u26_for, to_u27_for, to_u28_for,
+        to_u29_for, to_u30_for, to_u31_for, to_u32_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, out, block, pack_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn to_u0_for(_reference: u32, _out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_for {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(reference: u32, out: *mut u8, block: &[u32; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let left = sub_reference(load_u32x64(left), reference);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u32x64(right), reference);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u32x64(right), reference);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_for!(to_u1_for, to_u1, 1);
+define_x128_packer_for!(to_u2_for, to_u2, 2);
+define_x128_packer_for!(to_u3_for, to_u3, 3);
+define_x128_packer_for!(to_u4_for, to_u4, 4);
+define_x128_packer_for!(to_u5_for, to_u5, 5);
+define_x128_packer_for!(to_u6_for, to_u6, 6);
+define_x128_packer_for!(to_u7_for, to_u7, 7);
+define_x128_packer_for!(to_u8_for, to_u8, 8);
+define_x128_packer_for!(to_u9_for, to_u9, 9);
+define_x128_packer_for!(to_u10_for, to_u10, 10);
+define_x128_packer_for!(to_u11_for, to_u11, 11);
+define_x128_packer_for!(to_u12_for, to_u12, 12);
+define_x128_packer_for!(to_u13_for, to_u13, 13);
+define_x128_packer_for!(to_u14_for, to_u14, 14);
+define_x128_packer_for!(to_u15_for, to_u15, 15);
+define_x128_packer_for!(to_u16_for, to_u16, 16);
+define_x128_packer_for!(to_u17_for, to_u17, 17);
+define_x128_packer_for!(to_u18_for, to_u18, 18);
+define_x128_packer_for!(to_u19_for, to_u19, 19);
+define_x128_packer_for!(to_u20_for, to_u20, 20);
+define_x128_packer_for!(to_u21_for, to_u21, 21);
+define_x128_packer_for!(to_u22_for, to_u22, 22);
+define_x128_packer_for!(to_u23_for, to_u23, 23);
+define_x128_packer_for!(to_u24_for, to_u24, 24);
+define_x128_packer_for!(to_u25_for, to_u25, 25);
+define_x128_packer_for!(to_u26_for, to_u26, 26);
+define_x128_packer_for!(to_u27_for, to_u27, 27);
+define_x128_packer_for!(to_u28_for, to_u28, 28);
+define_x128_packer_for!(to_u29_for, to_u29, 29);
+define_x128_packer_for!(to_u30_for, to_u30, 30);
+define_x128_packer_for!(to_u31_for, to_u31, 31);
+define_x128_packer_for!(to_u32_for, to_u32, 32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::uint32::X128_MAX_OUTPUT_LEN;
+    use crate::uint32::{X128_MAX_OUTPUT_LEN, scalar};
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
@@ -115,4 +933,68 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_bit_length_matches_scalar() {
+        let tester = crate::uint32::test_util::load_uint32_regression_layout();
+
+        for (len, _, input, _) in tester.iter_tests() {
+            let mut sorted = *input;
+            sorted.sort_unstable();
+
+            for block in [input, &sorted] {
+                let expected = unsafe { scalar::bit_length_x128(block, len) };
+                assert_eq!(unsafe { plain_bit_length(block, len) }, expected as usize);
+
+                for last_value in [0, block[0], block[0].wrapping_add(1), u32::MAX] {
+                    let expected = unsafe { scalar::bit_length_delta_x128(last_value, block, len) };
+                    let nbits = unsafe { delta_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected as usize, "delta len:{len}");
+
+                    let expected =
+                        unsafe { scalar::bit_length_delta1_x128(last_value, block, len) };
+                    let nbits = unsafe { delta1_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected as usize, "delta1 len:{len}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_checked_delta_bit_length_matches_scalar() {
+        let tester = crate::uint32::test_util::load_uint32_regression_layout();
+
+        for (len, _, input, _) in tester.iter_tests() {
+            let mut sorted = *input;
+            sorted.sort_unstable();
+            let mut strictly_sorted = sorted;
+            for i in 1..X128 {
+                strictly_sorted[i] =
+                    strictly_sorted[i].max(strictly_sorted[i - 1].saturating_add(1));
+            }
+
+            let mut descent = strictly_sorted;
+
+            descent[(len + 40) % X128] = 0;
+
+            for block in [input, &sorted, &strictly_sorted, &descent] {
+                for last_value in [0, block[0], u32::MAX] {
+                    let mut out = [0; X128_MAX_OUTPUT_LEN];
+                    let expected =
+                        unsafe { scalar::try_pack_delta_x128(last_value, &mut out, block, len) }
+                            .map(|details| details.compressed_bit_length as usize);
+                    let nbits = unsafe { checked_delta_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected, "delta len:{len}");
+
+                    let expected =
+                        unsafe { scalar::try_pack_delta1_x128(last_value, &mut out, block, len) }
+                            .map(|details| details.compressed_bit_length as usize);
+                    let nbits = unsafe { checked_delta1_bit_length(last_value, block, len) };
+                    assert_eq!(nbits, expected, "delta1 len:{len}");
+                }
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -19,7 +19,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [__m256i; 8]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: [__m256i; 2]) {
     let [d1, d2] = data;
 
     let cmp1 = _mm256_slli_epi16::<7>(d1);
@@ -30,7 +30,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [__m256i; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -47,7 +47,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 2-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u2_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: [__m256i; 2]) {
     let packed = pack_u8_to_u2_unordered(data);
     unsafe { _mm_storeu_si128(out.cast(), packed) };
 }
@@ -66,7 +66,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 3-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u3_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: [__m256i; 2]) {
     let mask = _mm256_set1_epi8(0b11);
 
     let lo_2bit = and_si256(data, mask);
@@ -82,7 +82,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [__m256i; 2]) {
     let hi_1bitmask2 = _mm256_movemask_epi8(hi_1bit2) as u32;
 
     let hi_merged_mask = ((hi_1bitmask2 as u64) << 32) | hi_1bitmask1 as u64;
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
 }
 
 #[target_feature(enable = "avx2")]
@@ -99,7 +99,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 4-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u4_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: [__m256i; 2]) {
     let packed = pack_u8_to_u4_unordered(data);
     unsafe { _mm256_storeu_si256(out.cast(), packed) }
 }
@@ -118,7 +118,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 5-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u5_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: [__m256i; 2]) {
     let mask = _mm256_set1_epi8(0b1111);
     let masked = and_si256(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -142,7 +142,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 6-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u6_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: [__m256i; 2]) {
     let mask = _mm256_set1_epi8(0b1111);
     let masked = and_si256(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -168,7 +168,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [__m256i; 8]) {
 #[target_feature(enable = "avx2")]
 /// Pack two registers containing 32 8-bit elements each into a 7-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u7_registers(out: *mut u8, data: [__m256i; 2]) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: [__m256i; 2]) {
     let mask = _mm256_set1_epi8(0b1111);
     let masked = and_si256(data, mask);
     unsafe { pack_u4_registers(out, masked) };
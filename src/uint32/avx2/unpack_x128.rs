@@ -1,8 +1,10 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
+use super::util::{add_reference, find_first_ge, zigzag_decode};
 use super::{unpack_x64_full, unpack_x64_partial};
 use crate::uint32::{max_compressed_size, split_block_mut};
+use crate::util::as_unsigned_i32_mut;
 use crate::{X64, X128};
 
 #[inline]
@@ -15,17 +17,37 @@ use crate::{X64, X128};
 /// - `nbits` must be between 0 and 32.
 /// - `read_n` must be no greater than 128.
 pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u32; X128], read_n: usize) {
+    unsafe { from_nbits_halves(nbits, input, split_block_mut(out), read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the `nbits` bit length elements of a block into its two x64 halves.
+///
+/// The right half is only written when `read_n` is greater than 64 or `nbits` is `0`.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_halves(
+    nbits: usize,
+    input: *const u8,
+    halves: [&mut [u32; X64]; 2],
+    read_n: usize,
+) {
     debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
     debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
     #[allow(clippy::type_complexity)]
-    const LUT: [unsafe fn(out: *const u8, &mut [u32; X128], usize); 33] = [
+    const LUT: [unsafe fn(out: *const u8, [&mut [u32; X64]; 2], usize); 33] = [
         from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8, from_u9,
         from_u10, from_u11, from_u12, from_u13, from_u14, from_u15, from_u16, from_u17, from_u18,
         from_u19, from_u20, from_u21, from_u22, from_u23, from_u24, from_u25, from_u26, from_u27,
         from_u28, from_u29, from_u30, from_u31, from_u32,
     ];
     let func = unsafe { LUT.get_unchecked(nbits) };
-    unsafe { func(input, out, read_n) };
+    unsafe { func(input, halves, read_n) };
 }
 
 #[inline]
@@ -108,7 +130,7 @@ pub unsafe fn from_nbits_delta1(
     debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
     #[allow(clippy::type_complexity)]
     const LUT: [unsafe fn(u32, out: *const u8, &mut [u32; X128], usize); 33] = [
-        from_u0_delta,
+        from_u0_delta1,
         from_u1_delta1,
         from_u2_delta1,
         from_u3_delta1,
@@ -147,26 +169,33 @@ pub unsafe fn from_nbits_delta1(
 }
 
 #[target_feature(enable = "avx2")]
-unsafe fn from_u0(_input: *const u8, out: &mut [u32; X128], _read_n: usize) {
-    out.fill(0);
+unsafe fn from_u0(_input: *const u8, [left, right]: [&mut [u32; X64]; 2], _read_n: usize) {
+    left.fill(0);
+    right.fill(0);
 }
 
 #[target_feature(enable = "avx2")]
-unsafe fn from_u0_delta(
-    _last_value: u32,
+unsafe fn from_u0_delta(last_value: u32, _input: *const u8, out: &mut [u32; X128], _read_n: usize) {
+    out.fill(last_value);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_delta1(
+    last_value: u32,
     _input: *const u8,
     out: &mut [u32; X128],
     _read_n: usize,
 ) {
-    out.fill(0);
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..X128 {
+        out[i] = (i as u32).wrapping_add(last_value).wrapping_add(1);
+    }
 }
 
 macro_rules! define_x128_unpacker {
     ($func_name:ident, $bit_length:expr) => {
         #[target_feature(enable = "avx2")]
-        unsafe fn $func_name(input: *const u8, out: &mut [u32; X128], read_n: usize) {
-            let [left, right] = split_block_mut(out);
-
+        unsafe fn $func_name(input: *const u8, [left, right]: [&mut [u32; X64]; 2], read_n: usize) {
             if read_n <= 64 {
                 let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
                 store_u32x64(left, unpacked);
@@ -347,15 +376,15 @@ fn decode_delta(mut last_value: __m256i, block: &mut [__m256i; 8]) -> __m256i {
     for i in 0..8 {
         let deltas = block[i];
 
-        let shift1 = _mm256_slli_si256(deltas, 4);
+        let shift1 = _mm256_slli_si256::<4>(deltas);
         let sum1 = _mm256_add_epi32(deltas, shift1);
 
-        let shift2 = _mm256_slli_si256(sum1, 8);
+        let shift2 = _mm256_slli_si256::<8>(sum1);
         let sum2 = _mm256_add_epi32(sum1, shift2);
 
-        let sum_low_lane = _mm256_shuffle_epi32(sum2, 0xFF);
-        let low_lane_broadcast = _mm256_permute2x128_si256(sum_low_lane, sum_low_lane, 0x00);
-        let cross_lane_add = _mm256_blend_epi32(_mm256_setzero_si256(), low_lane_broadcast, 0xF0);
+        let sum_low_lane = _mm256_shuffle_epi32::<0xFF>(sum2);
+        let low_lane_broadcast = _mm256_permute2x128_si256::<0x00>(sum_low_lane, sum_low_lane);
+        let cross_lane_add = _mm256_blend_epi32::<0xF0>(_mm256_setzero_si256(), low_lane_broadcast);
         let sum3 = _mm256_add_epi32(sum2, cross_lane_add);
 
         let result = _mm256_add_epi32(sum3, last_value);
@@ -377,16 +406,16 @@ fn decode_delta1(mut last_value: __m256i, block: &mut [__m256i; 8]) -> __m256i {
         let deltas = block[i];
 
         let deltas_plus_one = _mm256_add_epi32(deltas, ones);
-        let shift1 = _mm256_slli_si256(deltas_plus_one, 4);
+        let shift1 = _mm256_slli_si256::<4>(deltas_plus_one);
         let sum1 = _mm256_add_epi32(deltas_plus_one, shift1);
 
-        let shift2 = _mm256_slli_si256(sum1, 8);
+        let shift2 = _mm256_slli_si256::<8>(sum1);
         let sum2 = _mm256_add_epi32(sum1, shift2);
 
-        let sum_low_lane = _mm256_shuffle_epi32(sum2, 0xFF);
-        let low_lane_broadcast = _mm256_permute2x128_si256(sum_low_lane, sum_low_lane, 0x00);
+        let sum_low_lane = _mm256_shuffle_epi32::<0xFF>(sum2);
+        let low_lane_broadcast = _mm256_permute2x128_si256::<0x00>(sum_low_lane, sum_low_lane);
 
-        let cross_lane_add = _mm256_blend_epi32(_mm256_setzero_si256(), low_lane_broadcast, 0xF0);
+        let cross_lane_add = _mm256_blend_epi32::<0xF0>(_mm256_setzero_si256(), low_lane_broadcast);
 
         let sum3 = _mm256_add_epi32(sum2, cross_lane_add);
         let result = _mm256_add_epi32(sum3, last_value);
@@ -399,14 +428,644 @@ fn decode_delta1(mut last_value: __m256i, block: &mut [__m256i; 8]) -> __m256i {
     last_value
 }
 
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements which have been delta-encoded,
+/// returning the position of the first value greater than or equal to `target`.
+///
+/// The values are compared while still held in registers after reversing the Delta encoding.
+/// When `out` is provided, every value of the block is written to it, otherwise unpacking
+/// stops at the first half of the block holding a match.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta_seek(
+    nbits: usize,
+    last_value: u32,
+    target: u32,
+    input: *const u8,
+    out: Option<&mut [u32; X128]>,
+    read_n: usize,
+) -> Option<usize> {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, u32, *const u8, Option<&mut [u32; X128]>, usize) -> Option<usize>;
+        33] = [
+        from_u0_delta_seek,
+        from_u1_delta_seek,
+        from_u2_delta_seek,
+        from_u3_delta_seek,
+        from_u4_delta_seek,
+        from_u5_delta_seek,
+        from_u6_delta_seek,
+        from_u7_delta_seek,
+        from_u8_delta_seek,
+        from_u9_delta_seek,
+        from_u10_delta_seek,
+        from_u11_delta_seek,
+        from_u12_delta_seek,
+        from_u13_delta_seek,
+        from_u14_delta_seek,
+        from_u15_delta_seek,
+        from_u16_delta_seek,
+        from_u17_delta_seek,
+        from_u18_delta_seek,
+        from_u19_delta_seek,
+        from_u20_delta_seek,
+        from_u21_delta_seek,
+        from_u22_delta_seek,
+        from_u23_delta_seek,
+        from_u24_delta_seek,
+        from_u25_delta_seek,
+        from_u26_delta_seek,
+        from_u27_delta_seek,
+        from_u28_delta_seek,
+        from_u29_delta_seek,
+        from_u30_delta_seek,
+        from_u31_delta_seek,
+        from_u32_delta_seek,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, target, input, out, read_n) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_delta_seek(
+    last_value: u32,
+    target: u32,
+    _input: *const u8,
+    out: Option<&mut [u32; X128]>,
+    read_n: usize,
+) -> Option<usize> {
+    if let Some(out) = out {
+        out.fill(last_value);
+    }
+    (read_n > 0 && last_value >= target).then_some(0)
+}
+
+macro_rules! define_x128_unpacker_delta_seek {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(
+            last_value: u32,
+            target: u32,
+            input: *const u8,
+            mut out: Option<&mut [u32; X128]>,
+            read_n: usize,
+        ) -> Option<usize> {
+            let mut last_value = _mm256_set1_epi32(last_value as i32);
+            let mut found = None;
+
+            let num_halves = if read_n <= X64 { 1 } else { 2 };
+            for half in 0..num_halves {
+                let start = half * X64;
+                let is_full = read_n > start + X64 || read_n == X128;
+                let input = unsafe { input.add(half * max_compressed_size::<X64>($bit_length)) };
+
+                let mut unpacked = if is_full {
+                    unsafe { unpack_x64_full::$unpack_func_name(input) }
+                } else {
+                    unsafe { unpack_x64_partial::$unpack_func_name(input, read_n - start) }
+                };
+                last_value = decode_delta(last_value, &mut unpacked);
+
+                if let Some(out) = out.as_deref_mut() {
+                    store_u32x64(split_block_mut(out)[half], unpacked);
+                }
+
+                // The last value of a full half is known after reversing the delta encoding,
+                // halves ending below the target can be skipped without comparing.
+                let half_last_value = _mm256_cvtsi256_si32(last_value) as u32;
+                if found.is_some() || (is_full && half_last_value < target) {
+                    continue;
+                }
+
+                found = find_first_ge(&unpacked, target)
+                    .map(|position| start + position)
+                    .filter(|&index| index < read_n);
+                if found.is_some() && out.is_none() {
+                    break;
+                }
+            }
+
+            found
+        }
+    };
+}
+
+define_x128_unpacker_delta_seek!(from_u1_delta_seek, from_u1, 1);
+define_x128_unpacker_delta_seek!(from_u2_delta_seek, from_u2, 2);
+define_x128_unpacker_delta_seek!(from_u3_delta_seek, from_u3, 3);
+define_x128_unpacker_delta_seek!(from_u4_delta_seek, from_u4, 4);
+define_x128_unpacker_delta_seek!(from_u5_delta_seek, from_u5, 5);
+define_x128_unpacker_delta_seek!(from_u6_delta_seek, from_u6, 6);
+define_x128_unpacker_delta_seek!(from_u7_delta_seek, from_u7, 7);
+define_x128_unpacker_delta_seek!(from_u8_delta_seek, from_u8, 8);
+define_x128_unpacker_delta_seek!(from_u9_delta_seek, from_u9, 9);
+define_x128_unpacker_delta_seek!(from_u10_delta_seek, from_u10, 10);
+define_x128_unpacker_delta_seek!(from_u11_delta_seek, from_u11, 11);
+define_x128_unpacker_delta_seek!(from_u12_delta_seek, from_u12, 12);
+define_x128_unpacker_delta_seek!(from_u13_delta_seek, from_u13, 13);
+define_x128_unpacker_delta_seek!(from_u14_delta_seek, from_u14, 14);
+define_x128_unpacker_delta_seek!(from_u15_delta_seek, from_u15, 15);
+define_x128_unpacker_delta_seek!(from_u16_delta_seek, from_u16, 16);
+define_x128_unpacker_delta_seek!(from_u17_delta_seek, from_u17, 17);
+define_x128_unpacker_delta_seek!(from_u18_delta_seek, from_u18, 18);
+define_x128_unpacker_delta_seek!(from_u19_delta_seek, from_u19, 19);
+define_x128_unpacker_delta_seek!(from_u20_delta_seek, from_u20, 20);
+define_x128_unpacker_delta_seek!(from_u21_delta_seek, from_u21, 21);
+define_x128_unpacker_delta_seek!(from_u22_delta_seek, from_u22, 22);
+define_x128_unpacker_delta_seek!(from_u23_delta_seek, from_u23, 23);
+define_x128_unpacker_delta_seek!(from_u24_delta_seek, from_u24, 24);
+define_x128_unpacker_delta_seek!(from_u25_delta_seek, from_u25, 25);
+define_x128_unpacker_delta_seek!(from_u26_delta_seek, from_u26, 26);
+define_x128_unpacker_delta_seek!(from_u27_delta_seek, from_u27, 27);
+define_x128_unpacker_delta_seek!(from_u28_delta_seek, from_u28, 28);
+define_x128_unpacker_delta_seek!(from_u29_delta_seek, from_u29, 29);
+define_x128_unpacker_delta_seek!(from_u30_delta_seek, from_u30, 30);
+define_x128_unpacker_delta_seek!(from_u31_delta_seek, from_u31, 31);
+define_x128_unpacker_delta_seek!(from_u32_delta_seek, from_u32, 32);
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and add `base` to each element, wrapping on overflow.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag(
+    nbits: usize,
+    base: i32,
+    input: *const u8,
+    out: &mut [i32; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, input: *const u8, &mut [i32; X128], usize); 33] = [
+        from_u0_zigzag,
+        from_u1_zigzag,
+        from_u2_zigzag,
+        from_u3_zigzag,
+        from_u4_zigzag,
+        from_u5_zigzag,
+        from_u6_zigzag,
+        from_u7_zigzag,
+        from_u8_zigzag,
+        from_u9_zigzag,
+        from_u10_zigzag,
+        from_u11_zigzag,
+        from_u12_zigzag,
+        from_u13_zigzag,
+        from_u14_zigzag,
+        from_u15_zigzag,
+        from_u16_zigzag,
+        from_u17_zigzag,
+        from_u18_zigzag,
+        from_u19_zigzag,
+        from_u20_zigzag,
+        from_u21_zigzag,
+        from_u22_zigzag,
+        from_u23_zigzag,
+        from_u24_zigzag,
+        from_u25_zigzag,
+        from_u26_zigzag,
+        from_u27_zigzag,
+        from_u28_zigzag,
+        from_u29_zigzag,
+        from_u30_zigzag,
+        from_u31_zigzag,
+        from_u32_zigzag,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(base as u32, input, out, read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and then the Delta encoding.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag_delta(
+    nbits: usize,
+    last_value: i32,
+    input: *const u8,
+    out: &mut [i32; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, input: *const u8, &mut [u32; X128], usize); 33] = [
+        from_u0_delta,
+        from_u1_zigzag_delta,
+        from_u2_zigzag_delta,
+        from_u3_zigzag_delta,
+        from_u4_zigzag_delta,
+        from_u5_zigzag_delta,
+        from_u6_zigzag_delta,
+        from_u7_zigzag_delta,
+        from_u8_zigzag_delta,
+        from_u9_zigzag_delta,
+        from_u10_zigzag_delta,
+        from_u11_zigzag_delta,
+        from_u12_zigzag_delta,
+        from_u13_zigzag_delta,
+        from_u14_zigzag_delta,
+        from_u15_zigzag_delta,
+        from_u16_zigzag_delta,
+        from_u17_zigzag_delta,
+        from_u18_zigzag_delta,
+        from_u19_zigzag_delta,
+        from_u20_zigzag_delta,
+        from_u21_zigzag_delta,
+        from_u22_zigzag_delta,
+        from_u23_zigzag_delta,
+        from_u24_zigzag_delta,
+        from_u25_zigzag_delta,
+        from_u26_zigzag_delta,
+        from_u27_zigzag_delta,
+        from_u28_zigzag_delta,
+        from_u29_zigzag_delta,
+        from_u30_zigzag_delta,
+        from_u31_zigzag_delta,
+        from_u32_zigzag_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value as u32, input, as_unsigned_i32_mut(out), read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements, reverse the ZigZag
+/// encoding and then the Delta-1 encoding.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_zigzag_delta1(
+    nbits: usize,
+    last_value: i32,
+    input: *const u8,
+    out: &mut [i32; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, input: *const u8, &mut [u32; X128], usize); 33] = [
+        from_u0_delta1,
+        from_u1_zigzag_delta1,
+        from_u2_zigzag_delta1,
+        from_u3_zigzag_delta1,
+        from_u4_zigzag_delta1,
+        from_u5_zigzag_delta1,
+        from_u6_zigzag_delta1,
+        from_u7_zigzag_delta1,
+        from_u8_zigzag_delta1,
+        from_u9_zigzag_delta1,
+        from_u10_zigzag_delta1,
+        from_u11_zigzag_delta1,
+        from_u12_zigzag_delta1,
+        from_u13_zigzag_delta1,
+        from_u14_zigzag_delta1,
+        from_u15_zigzag_delta1,
+        from_u16_zigzag_delta1,
+        from_u17_zigzag_delta1,
+        from_u18_zigzag_delta1,
+        from_u19_zigzag_delta1,
+        from_u20_zigzag_delta1,
+        from_u21_zigzag_delta1,
+        from_u22_zigzag_delta1,
+        from_u23_zigzag_delta1,
+        from_u24_zigzag_delta1,
+        from_u25_zigzag_delta1,
+        from_u26_zigzag_delta1,
+        from_u27_zigzag_delta1,
+        from_u28_zigzag_delta1,
+        from_u29_zigzag_delta1,
+        from_u30_zigzag_delta1,
+        from_u31_zigzag_delta1,
+        from_u32_zigzag_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value as u32, input, as_unsigned_i32_mut(out), read_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_zigzag(base: u32, _input: *const u8, out: &mut [i32; X128], _read_n: usize) {
+    out.fill(base as i32);
+}
+
+macro_rules! define_x128_unpacker_zigzag {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(base: u32, input: *const u8, out: &mut [i32; X128], read_n: usize) {
+            let [left, right] = split_block_mut(as_unsigned_i32_mut(out));
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u32x64(left, add_reference(zigzag_decode(unpacked), base));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, add_reference(zigzag_decode(unpacked), base));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u32x64(right, add_reference(zigzag_decode(unpacked), base));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, add_reference(zigzag_decode(unpacked), base));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u32x64(right, add_reference(zigzag_decode(unpacked), base));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_zigzag!(from_u1_zigzag, from_u1, 1);
+define_x128_unpacker_zigzag!(from_u2_zigzag, from_u2, 2);
+define_x128_unpacker_zigzag!(from_u3_zigzag, from_u3, 3);
+define_x128_unpacker_zigzag!(from_u4_zigzag, from_u4, 4);
+define_x128_unpacker_zigzag!(from_u5_zigzag, from_u5, 5);
+define_x128_unpacker_zigzag!(from_u6_zigzag, from_u6, 6);
+define_x128_unpacker_zigzag!(from_u7_zigzag, from_u7, 7);
+define_x128_unpacker_zigzag!(from_u8_zigzag, from_u8, 8);
+define_x128_unpacker_zigzag!(from_u9_zigzag, from_u9, 9);
+define_x128_unpacker_zigzag!(from_u10_zigzag, from_u10, 10);
+define_x128_unpacker_zigzag!(from_u11_zigzag, from_u11, 11);
+define_x128_unpacker_zigzag!(from_u12_zigzag, from_u12, 12);
+define_x128_unpacker_zigzag!(from_u13_zigzag, from_u13, 13);
+define_x128_unpacker_zigzag!(from_u14_zigzag, from_u14, 14);
+define_x128_unpacker_zigzag!(from_u15_zigzag, from_u15, 15);
+define_x128_unpacker_zigzag!(from_u16_zigzag, from_u16, 16);
+define_x128_unpacker_zigzag!(from_u17_zigzag, from_u17, 17);
+define_x128_unpacker_zigzag!(from_u18_zigzag, from_u18, 18);
+define_x128_unpacker_zigzag!(from_u19_zigzag, from_u19, 19);
+define_x128_unpacker_zigzag!(from_u20_zigzag, from_u20, 20);
+define_x128_unpacker_zigzag!(from_u21_zigzag, from_u21, 21);
+define_x128_unpacker_zigzag!(from_u22_zigzag, from_u22, 22);
+define_x128_unpacker_zigzag!(from_u23_zigzag, from_u23, 23);
+define_x128_unpacker_zigzag!(from_u24_zigzag, from_u24, 24);
+define_x128_unpacker_zigzag!(from_u25_zigzag, from_u25, 25);
+define_x128_unpacker_zigzag!(from_u26_zigzag, from_u26, 26);
+define_x128_unpacker_zigzag!(from_u27_zigzag, from_u27, 27);
+define_x128_unpacker_zigzag!(from_u28_zigzag, from_u28, 28);
+define_x128_unpacker_zigzag!(from_u29_zigzag, from_u29, 29);
+define_x128_unpacker_zigzag!(from_u30_zigzag, from_u30, 30);
+define_x128_unpacker_zigzag!(from_u31_zigzag, from_u31, 31);
+define_x128_unpacker_zigzag!(from_u32_zigzag, from_u32, 32);
+
+// ZigZag Delta encoding
+define_x128_unpacker_delta!(from_u1_zigzag_delta, from_u1, 1, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u2_zigzag_delta, from_u2, 2, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u3_zigzag_delta, from_u3, 3, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u4_zigzag_delta, from_u4, 4, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u5_zigzag_delta, from_u5, 5, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u6_zigzag_delta, from_u6, 6, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u7_zigzag_delta, from_u7, 7, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u8_zigzag_delta, from_u8, 8, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u9_zigzag_delta, from_u9, 9, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u10_zigzag_delta, from_u10, 10, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u11_zigzag_delta, from_u11, 11, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u12_zigzag_delta, from_u12, 12, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u13_zigzag_delta, from_u13, 13, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u14_zigzag_delta, from_u14, 14, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u15_zigzag_delta, from_u15, 15, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u16_zigzag_delta, from_u16, 16, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u17_zigzag_delta, from_u17, 17, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u18_zigzag_delta, from_u18, 18, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u19_zigzag_delta, from_u19, 19, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u20_zigzag_delta, from_u20, 20, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u21_zigzag_delta, from_u21, 21, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u22_zigzag_delta, from_u22, 22, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u23_zigzag_delta, from_u23, 23, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u24_zigzag_delta, from_u24, 24, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u25_zigzag_delta, from_u25, 25, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u26_zigzag_delta, from_u26, 26, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u27_zigzag_delta, from_u27, 27, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u28_zigzag_delta, from_u28, 28, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u29_zigzag_delta, from_u29, 29, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u30_zigzag_delta, from_u30, 30, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u31_zigzag_delta, from_u31, 31, decode_zigzag_delta);
+define_x128_unpacker_delta!(from_u32_zigzag_delta, from_u32, 32, decode_zigzag_delta);
+
+// ZigZag Delta-1 encoding
+define_x128_unpacker_delta!(from_u1_zigzag_delta1, from_u1, 1, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u2_zigzag_delta1, from_u2, 2, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u3_zigzag_delta1, from_u3, 3, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u4_zigzag_delta1, from_u4, 4, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u5_zigzag_delta1, from_u5, 5, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u6_zigzag_delta1, from_u6, 6, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u7_zigzag_delta1, from_u7, 7, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u8_zigzag_delta1, from_u8, 8, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u9_zigzag_delta1, from_u9, 9, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u10_zigzag_delta1, from_u10, 10, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u11_zigzag_delta1, from_u11, 11, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u12_zigzag_delta1, from_u12, 12, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u13_zigzag_delta1, from_u13, 13, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u14_zigzag_delta1, from_u14, 14, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u15_zigzag_delta1, from_u15, 15, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u16_zigzag_delta1, from_u16, 16, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u17_zigzag_delta1, from_u17, 17, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u18_zigzag_delta1, from_u18, 18, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u19_zigzag_delta1, from_u19, 19, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u20_zigzag_delta1, from_u20, 20, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u21_zigzag_delta1, from_u21, 21, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u22_zigzag_delta1, from_u22, 22, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u23_zigzag_delta1, from_u23, 23, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u24_zigzag_delta1, from_u24, 24, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u25_zigzag_delta1, from_u25, 25, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u26_zigzag_delta1, from_u26, 26, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u27_zigzag_delta1, from_u27, 27, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u28_zigzag_delta1, from_u28, 28, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u29_zigzag_delta1, from_u29, 29, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u30_zigzag_delta1, from_u30, 30, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u31_zigzag_delta1, from_u31, 31, decode_zigzag_delta1);
+define_x128_unpacker_delta!(from_u32_zigzag_delta1, from_u32, 32, decode_zigzag_delta1);
+
+#[target_feature(enable = "avx2")]
+fn decode_zigzag_delta(last_value: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    *block = zigzag_decode(*block);
+    decode_delta(last_value, block)
+}
+
+#[target_feature(enable = "avx2")]
+fn decode_zigzag_delta1(last_value: __m256i, block: &mut [__m256i; 8]) -> __m256i {
+    *block = zigzag_decode(*block);
+    decode_delta1(last_value, block)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u32,
+    input: *const u8,
+    out: &mut [u32; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, input: *const u8, &mut [u32; X128], usize); 33] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+        from_u9_for,
+        from_u10_for,
+        from_u11_for,
+        from_u12_for,
+        from_u13_for,
+        from_u14_for,
+        from_u15_for,
+        from_u16_for,
+        from_u17_for,
+        from_u18_for,
+        from_u19_for,
+        from_u20_for,
+        from_u21_for,
+        from_u22_for,
+        from_u23_for,
+        from_u24_for,
+        from_u25_for,
+        from_u26_for,
+        from_u27_for,
+        from_u28_for,
+        from_u29_for,
+        from_u30_for,
+        from_u31_for,
+        from_u32_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_for(reference: u32, _input: *const u8, out: &mut [u32; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(
+            reference: u32,
+            input: *const u8,
+            out: &mut [u32; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u32x64(left, add_reference(unpacked, reference));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u32x64(right, add_reference(unpacked, reference));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u32x64(right, add_reference(unpacked, reference));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+define_x128_unpacker_for!(from_u9_for, from_u9, 9);
+define_x128_unpacker_for!(from_u10_for, from_u10, 10);
+define_x128_unpacker_for!(from_u11_for, from_u11, 11);
+define_x128_unpacker_for!(from_u12_for, from_u12, 12);
+define_x128_unpacker_for!(from_u13_for, from_u13, 13);
+define_x128_unpacker_for!(from_u14_for, from_u14, 14);
+define_x128_unpacker_for!(from_u15_for, from_u15, 15);
+define_x128_unpacker_for!(from_u16_for, from_u16, 16);
+define_x128_unpacker_for!(from_u17_for, from_u17, 17);
+define_x128_unpacker_for!(from_u18_for, from_u18, 18);
+define_x128_unpacker_for!(from_u19_for, from_u19, 19);
+define_x128_unpacker_for!(from_u20_for, from_u20, 20);
+define_x128_unpacker_for!(from_u21_for, from_u21, 21);
+define_x128_unpacker_for!(from_u22_for, from_u22, 22);
+define_x128_unpacker_for!(from_u23_for, from_u23, 23);
+define_x128_unpacker_for!(from_u24_for, from_u24, 24);
+define_x128_unpacker_for!(from_u25_for, from_u25, 25);
+define_x128_unpacker_for!(from_u26_for, from_u26, 26);
+define_x128_unpacker_for!(from_u27_for, from_u27, 27);
+define_x128_unpacker_for!(from_u28_for, from_u28, 28);
+define_x128_unpacker_for!(from_u29_for, from_u29, 29);
+define_x128_unpacker_for!(from_u30_for, from_u30, 30);
+define_x128_unpacker_for!(from_u31_for, from_u31, 31);
+define_x128_unpacker_for!(from_u32_for, from_u32, 32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+    use crate::uint32::avx2::pack_x128::{to_nbits, to_nbits_for, to_nbits_zigzag};
+    use crate::uint32::avx2::{pack_zigzag_delta_x128, pack_zigzag_delta1_x128};
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_decode_delta() {
-        let expected_values: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let expected_values: [u32; X64] = core::array::from_fn(|i| i as u32);
         let mut values = [1; X64];
         values[0] = 0;
 
@@ -414,21 +1073,21 @@ mod tests {
         let mut block = unsafe { load_u32x64(&values) };
         unsafe { decode_delta(initial_value, &mut block) };
 
-        let result = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(block) };
+        let result = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(block) };
         assert_eq!(result, expected_values);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_decode_delta1() {
-        let expected_values: [u32; X64] = std::array::from_fn(|i| i as u32 + 1);
+        let expected_values: [u32; X64] = core::array::from_fn(|i| i as u32 + 1);
         let values = [0; X64];
 
         let initial_value = unsafe { _mm256_set1_epi32(0) };
         let mut block = unsafe { load_u32x64(&values) };
         unsafe { decode_delta1(initial_value, &mut block) };
 
-        let result = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(block) };
+        let result = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(block) };
         assert_eq!(result, expected_values);
     }
 
@@ -449,4 +1108,159 @@ mod tests {
             )
         }
     }
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_zigzag_roundtrip(
+        #[values(0, 1, 2, 7, 15, 16, 17, 31, 32)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, -1200, i32::MAX)] base: i32,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u32::MAX >> (32 - nbits)
+        };
+        let expected: [i32; X128] = core::array::from_fn(|i| {
+            let encoded = (i as u32).wrapping_mul(2654435761) & mask;
+            crate::util::zigzag_decode_i32(encoded)
+        });
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_zigzag(nbits, packed.as_mut_ptr(), &expected, n);
+            from_nbits_zigzag(nbits, base, packed.as_ptr(), &mut unpacked, n);
+        }
+        let expected = expected.map(|v| v.wrapping_add(base));
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_zigzag_delta_roundtrip(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, -9, 311)] last_value: i32,
+    ) {
+        let expected: [i32; X128] = core::array::from_fn(|i| {
+            let step = (i as i32 % 5) * 7;
+            if i % 2 == 0 {
+                last_value - step
+            } else {
+                last_value + step
+            }
+        });
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+
+        let details = unsafe { pack_zigzag_delta_x128(last_value, &mut packed, &expected, n) };
+        unsafe {
+            from_nbits_zigzag_delta(
+                details.compressed_bit_length as usize,
+                last_value,
+                packed.as_ptr(),
+                &mut unpacked,
+                n,
+            )
+        };
+        assert_eq!(unpacked[..n], expected[..n]);
+
+        let expected: [i32; X128] = core::array::from_fn(|i| last_value - 3 * (i as i32 + 1));
+        let details = unsafe { pack_zigzag_delta1_x128(last_value, &mut packed, &expected, n) };
+        unsafe {
+            from_nbits_zigzag_delta1(
+                details.compressed_bit_length as usize,
+                last_value,
+                packed.as_ptr(),
+                &mut unpacked,
+                n,
+            )
+        };
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_zero_bit_delta_keeps_last_value() {
+        let packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+
+        unsafe { from_nbits_delta(0, 7, packed.as_ptr(), &mut unpacked, X128) };
+        assert_eq!(unpacked, [7; X128]);
+
+        let expected: [u32; X128] = core::array::from_fn(|i| i as u32 + 8);
+        unsafe { from_nbits_delta1(0, 7, packed.as_ptr(), &mut unpacked, X128) };
+        assert_eq!(unpacked, expected);
+    }
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_for_roundtrip(
+        #[values(0, 1, 9, 17, 32)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u32::MAX >> (32 - nbits)
+        };
+        let reference = u32::MAX - mask;
+        let expected: [u32; X128] =
+            core::array::from_fn(|i| reference + ((i as u32).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_delta_seek(
+        #[values(0, 1, 9, 17, 32)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u32::MAX >> (32 - nbits)
+        };
+        let deltas: [u32; X128] = core::array::from_fn(|i| (i as u32).wrapping_mul(151) & mask);
+        let initial_value = 1000;
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut expected = [0; X128];
+        unsafe {
+            to_nbits(nbits, packed.as_mut_ptr(), &deltas, n);
+            from_nbits_delta(nbits, initial_value, packed.as_ptr(), &mut expected, n);
+        }
+
+        let last = expected[n - 1];
+        for target in [0, initial_value, expected[n / 2], last, last + 1, u32::MAX] {
+            let expected_position = expected[..n].iter().position(|&value| value >= target);
+
+            let mut unpacked = [0; X128];
+            let position = unsafe {
+                from_nbits_delta_seek(
+                    nbits,
+                    initial_value,
+                    target,
+                    packed.as_ptr(),
+                    Some(&mut unpacked),
+                    n,
+                )
+            };
+            assert_eq!(position, expected_position, "target:{target}");
+            assert_eq!(unpacked[..n], expected[..n]);
+
+            let position = unsafe {
+                from_nbits_delta_seek(nbits, initial_value, target, packed.as_ptr(), None, n)
+            };
+            assert_eq!(position, expected_position, "target:{target}");
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -17,8 +17,8 @@ pub unsafe fn from_u1(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
 
     let ones = _mm256_set1_epi8(0b1);
     let mut packed1 = expand_mask_epi8(mask as __mmask32);
@@ -43,7 +43,7 @@ pub unsafe fn from_u2(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 64 8-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8) -> [__m256i; 2] {
     let packed = unsafe { _mm_loadu_si128(input.cast()) };
     unpack_u2_to_u8_unordered(packed)
 }
@@ -62,11 +62,11 @@ pub unsafe fn from_u3(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 64 8-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8) -> [__m256i; 2] {
     let packed_2bit = unsafe { _mm_loadu_si128(input.add(0).cast()) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let bit = _mm256_set1_epi8(0b100);
     let mut hi_bits_packed1 = expand_mask_epi8(hi_bitmask as __mmask32);
@@ -94,7 +94,7 @@ pub unsafe fn from_u4(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
     let packed = unsafe { _mm256_loadu_si256(input.cast()) };
     unpack_u4_to_u8_unordered(packed)
 }
@@ -113,7 +113,7 @@ pub unsafe fn from_u5(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8) -> [__m256i; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u1_registers(input.add(32)) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
@@ -134,7 +134,7 @@ pub unsafe fn from_u6(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8) -> [__m256i; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(32)) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
@@ -155,7 +155,7 @@ pub unsafe fn from_u7(input: *const u8) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8) -> [__m256i; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(32)) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
@@ -675,7 +675,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -734,7 +734,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -795,7 +795,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -851,7 +851,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
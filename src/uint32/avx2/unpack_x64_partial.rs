@@ -1,26 +1,27 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
+use crate::util::read_partial;
 
 #[target_feature(enable = "avx2")]
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
 
     let ones = _mm256_set1_epi8(0b1);
     let mut packed1 = expand_mask_epi8(mask as __mmask32);
@@ -35,7 +36,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> [__m256i; 2] {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m256i; 8] {
@@ -47,10 +48,10 @@ pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
-    let hi_offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(hi_offset).cast()) };
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _mm256_set1_epi8(0b01);
     let mut lo_bits_packed1 = expand_mask_epi8(mask1 as __mmask32);
@@ -74,7 +75,7 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m256i; 8] {
@@ -85,12 +86,12 @@ pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _mm256_set1_epi8(0b001);
     let mut b0_bits_packed1 = expand_mask_epi8(mask1 as __mmask32);
@@ -122,25 +123,26 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
-    let ordered = unsafe { _mm256_loadu_si256(input.cast()) };
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+    let ordered = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let ordered = unsafe { _mm256_loadu_si256(ordered.as_ptr().cast()) };
     let interleaved = _mm256_permute4x64_epi64::<0xD8>(ordered);
 
     let low_mask = _mm256_set1_epi8(0x0F);
     let low_nibbles = _mm256_and_si256(interleaved, low_mask);
-    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16(interleaved, 4), low_mask);
+    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16::<4>(interleaved), low_mask);
 
     let d1 = _mm256_unpacklo_epi8(low_nibbles, high_nibbles);
     let d2 = _mm256_unpackhi_epi8(low_nibbles, high_nibbles);
@@ -152,7 +154,7 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [__m256i; 2] {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m256i; 8] {
@@ -164,10 +166,10 @@ pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
 }
@@ -176,7 +178,7 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m256i; 8] {
@@ -188,9 +190,9 @@ pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
@@ -200,7 +202,7 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m256i; 8] {
@@ -212,9 +214,9 @@ pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m256i; 8] {
 #[target_feature(enable = "avx2")]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = slli_epi16::<4, 2>(hi_bits);
     or_si256_all(hi_bits, lo_bits)
@@ -224,12 +226,12 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [__m256i; 2] {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_si256x2(input) };
+    let packed = unsafe { load_si256x2(read_partial::<64>(input, read_n).as_ptr()) };
     unpack_u8_to_u32_ordered(packed)
 }
 
@@ -237,16 +239,16 @@ pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 4>(hi_bits);
 
@@ -258,12 +260,12 @@ pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -279,12 +281,12 @@ pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -300,16 +302,16 @@ pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 4>(hi_bits);
 
@@ -321,12 +323,12 @@ pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -342,12 +344,12 @@ pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -363,12 +365,12 @@ pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x2(input.add(0)) };
+    let lo_bits = unsafe { load_si256x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -384,12 +386,12 @@ pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_si256x4(input.add(0)) };
+    let packed = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     unpack_u16_to_u32_ordered(packed)
 }
 
@@ -397,16 +399,16 @@ pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 17-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(17)` bytes from.
+/// - `input` must be safe to read `compressed_size(17, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 8>(hi_bits);
 
@@ -417,12 +419,12 @@ pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 18-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(18)` bytes from.
+/// - `input` must be safe to read `compressed_size(18, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -437,12 +439,12 @@ pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 19-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(19)` bytes from.
+/// - `input` must be safe to read `compressed_size(19, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -457,16 +459,16 @@ pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 20-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(20)` bytes from.
+/// - `input` must be safe to read `compressed_size(20, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 8>(hi_bits);
 
@@ -477,12 +479,12 @@ pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 21-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(21)` bytes from.
+/// - `input` must be safe to read `compressed_size(21, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -497,12 +499,12 @@ pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 22-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(22)` bytes from.
+/// - `input` must be safe to read `compressed_size(22, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -517,12 +519,12 @@ pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 23-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(23)` bytes from.
+/// - `input` must be safe to read `compressed_size(23, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -537,16 +539,16 @@ pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 24-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(24)` bytes from.
+/// - `input` must be safe to read `compressed_size(24, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 8>(hi_bits);
 
@@ -557,20 +559,20 @@ pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 25-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(25)` bytes from.
+/// - `input` must be safe to read `compressed_size(25, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_epi16::<8, 4>(hi_1bits);
 
@@ -585,16 +587,16 @@ pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 26-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(26)` bytes from.
+/// - `input` must be safe to read `compressed_size(26, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -613,16 +615,16 @@ pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 27-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(27)` bytes from.
+/// - `input` must be safe to read `compressed_size(27, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -641,20 +643,20 @@ pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 28-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(28)` bytes from.
+/// - `input` must be safe to read `compressed_size(28, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_epi16::<8, 4>(hi_1bits);
 
@@ -669,16 +671,16 @@ pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 29-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(29)` bytes from.
+/// - `input` must be safe to read `compressed_size(29, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -697,16 +699,16 @@ pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 30-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(30)` bytes from.
+/// - `input` must be safe to read `compressed_size(30, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -725,15 +727,15 @@ pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// Unpack the 31-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(31)` bytes from.
+/// - `input` must be safe to read `compressed_size(31, read_n)` bytes from.
 /// - The runtime CPU must support the `avx2` instructions.
 pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si256x4(input.add(0)) };
+    let lo_bits = unsafe { load_si256x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_si256x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_si256x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -758,7 +760,7 @@ pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [__m256i; 8] {
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u32(input: *const u8, read_n: usize) -> [__m256i; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_si256x8(input) }
+    unsafe { load_si256x8(read_partial::<256>(input, read_n * 4).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -811,7 +813,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -870,7 +872,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -929,7 +931,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -993,7 +995,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
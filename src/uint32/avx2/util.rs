@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline]
 #[allow(non_snake_case)]
@@ -425,9 +425,199 @@ pub(super) fn slli_epi32<const IMM8: i32, const N: usize>(mut data: [__m256i; N]
     data
 }
 
+#[target_feature(enable = "avx2")]
+/// ZigZag encode the signed 32-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [__m256i; 8]) -> [__m256i; 8] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = _mm256_xor_si256(_mm256_slli_epi32::<1>(v), _mm256_srai_epi32::<31>(v));
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Reverse the ZigZag encoding of the 32-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [__m256i; 8]) -> [__m256i; 8] {
+    let zero = _mm256_setzero_si256();
+    let one = _mm256_set1_epi32(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        let v = data[i];
+        data[i] = _mm256_xor_si256(
+            _mm256_srli_epi32::<1>(v),
+            _mm256_sub_epi32(zero, _mm256_and_si256(v, one)),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Subtract the frame of reference from each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m256i; 8], reference: u32) -> [__m256i; 8] {
+    let reference = _mm256_set1_epi32(reference as i32);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = _mm256_sub_epi32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Add the frame of reference back to each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m256i; 8], reference: u32) -> [__m256i; 8] {
+    let reference = _mm256_set1_epi32(reference as i32);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = _mm256_add_epi32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the position of the first 32-bit element held in the provided registers which
+/// is greater than or equal to `target`.
+pub(super) fn find_first_ge(data: &[__m256i; 8], target: u32) -> Option<usize> {
+    let target = _mm256_set1_epi32(target as i32);
+
+    for (i, register) in data.iter().enumerate() {
+        let ge = _mm256_cmpeq_epi32(_mm256_max_epu32(*register, target), *register);
+        let mask = _mm256_movemask_ps(_mm256_castsi256_ps(ge)) as u32;
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the number of bits required to represent the largest of the first `n` 32-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m256i; 8], n: usize) -> u32 {
+    let limit = _mm256_set1_epi32(n as i32 - 1);
+    let step = _mm256_set1_epi32(8);
+    let mut positions = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+
+    let mut acc = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi32(positions, limit);
+        acc = _mm256_or_si256(acc, _mm256_andnot_si256(excluded, *register));
+        positions = _mm256_add_epi32(positions, step);
+    }
+
+    acc = _mm256_or_si256(acc, _mm256_permute2x128_si256::<0x01>(acc, acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0x4E>(acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0xB1>(acc));
+
+    32 - (_mm256_cvtsi256_si32(acc) as u32).leading_zeros()
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the sum of the first `n` 32-bit elements held in the provided registers.
+pub(super) fn sum(data: &[__m256i; 8], n: usize) -> u64 {
+    let limit = _mm256_set1_epi32(n as i32 - 1);
+    let step = _mm256_set1_epi32(8);
+    let mut positions = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+    let low = _mm256_set1_epi64x(u32::MAX as i64);
+
+    // The elements are widened to 64-bit lanes before being added so the sum can not overflow.
+    let mut acc = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi32(positions, limit);
+        let selected = _mm256_andnot_si256(excluded, *register);
+        acc = _mm256_add_epi64(acc, _mm256_and_si256(selected, low));
+        acc = _mm256_add_epi64(acc, _mm256_srli_epi64::<32>(selected));
+        positions = _mm256_add_epi32(positions, step);
+    }
+
+    let acc = _mm_add_epi64(
+        _mm256_castsi256_si128(acc),
+        _mm256_extracti128_si256::<1>(acc),
+    );
+    _mm_cvtsi128_si64(acc) as u64 + _mm_extract_epi64::<1>(acc) as u64
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the smallest and largest of the first `n` 32-bit elements held in the provided
+/// registers.
+pub(super) fn min_max(data: &[__m256i; 8], n: usize) -> (u32, u32) {
+    let limit = _mm256_set1_epi32(n as i32 - 1);
+    let step = _mm256_set1_epi32(8);
+    let mut positions = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+
+    let mut min = _mm256_set1_epi32(-1);
+    let mut max = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi32(positions, limit);
+        min = _mm256_min_epu32(min, _mm256_or_si256(excluded, *register));
+        max = _mm256_max_epu32(max, _mm256_andnot_si256(excluded, *register));
+        positions = _mm256_add_epi32(positions, step);
+    }
+
+    min = _mm256_min_epu32(min, _mm256_permute2x128_si256::<0x01>(min, min));
+    min = _mm256_min_epu32(min, _mm256_shuffle_epi32::<0x4E>(min));
+    min = _mm256_min_epu32(min, _mm256_shuffle_epi32::<0xB1>(min));
+    max = _mm256_max_epu32(max, _mm256_permute2x128_si256::<0x01>(max, max));
+    max = _mm256_max_epu32(max, _mm256_shuffle_epi32::<0x4E>(max));
+    max = _mm256_max_epu32(max, _mm256_shuffle_epi32::<0xB1>(max));
+
+    (
+        _mm256_cvtsi256_si32(min) as u32,
+        _mm256_cvtsi256_si32(max) as u32,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns a bitmask of the 32-bit elements held in the provided registers which are within
+/// `lo..=lo + span`, the bit at position `i` being set when the `i`th element is selected.
+pub(super) fn select_range(data: &[__m256i; 8], lo: u32, span: u32) -> u64 {
+    let lo = _mm256_set1_epi32(lo as i32);
+    let span = _mm256_set1_epi32(span as i32);
+
+    let mut mask = 0;
+    for (i, register) in data.iter().enumerate() {
+        let offset = _mm256_sub_epi32(*register, lo);
+        let selected = _mm256_cmpeq_epi32(_mm256_min_epu32(offset, span), offset);
+        let bits = _mm256_movemask_ps(_mm256_castsi256_ps(selected)) as u8;
+        mask |= (bits as u64) << (i * 8);
+    }
+    mask
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the position of the first 32-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[__m256i; 8],
+    deltas: &[__m256i; 8],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let max = _mm256_max_epu32(*value, *delta);
+        let mask = if strict {
+            _mm256_movemask_ps(_mm256_castsi256_ps(_mm256_cmpeq_epi32(max, *delta))) as u32
+        } else {
+            !_mm256_movemask_ps(_mm256_castsi256_ps(_mm256_cmpeq_epi32(max, *value))) as u32 & 0xFF
+        };
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use std::cmp;
+    use core::cmp;
 
     use super::*;
     use crate::X64;
@@ -452,7 +642,7 @@ mod tests {
             expected[i] = i as u16;
         }
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
@@ -468,7 +658,7 @@ mod tests {
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
         assert_eq!(view[..8], [0, 16, 1, 17, 2, 18, 3, 19]);
         assert_eq!(view[8..][..8], [4, 20, 5, 21, 6, 22, 7, 23]);
         assert_eq!(view[16..][..8], [8, 24, 9, 25, 10, 26, 11, 27]);
@@ -487,7 +677,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u16_ordered(data) };
         let unpacked = unsafe { unpack_u16_to_u32_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -504,7 +694,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
         let unpacked = unsafe { unpack_u16_to_u32_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -526,7 +716,7 @@ mod tests {
             expected[i] = cmp::min(i as u8, u8::MAX);
         }
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
@@ -542,7 +732,7 @@ mod tests {
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
         assert_eq!(view[..8], [0, 32, 16, 48, 1, 33, 17, 49]);
         assert_eq!(view[8..][..8], [2, 34, 18, 50, 3, 35, 19, 51]);
         assert_eq!(view[16..][..8], [4, 36, 20, 52, 5, 37, 21, 53]);
@@ -562,7 +752,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u32_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -579,7 +769,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
         let unpacked = unsafe { unpack_u8_to_u32_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -596,69 +786,137 @@ mod tests {
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_pack_u32_to_u16_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT,);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_pack_u32_to_u8_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT,);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_si256x2(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_si256x2(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "avx2"), ignore)]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_si256x2(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i32; X64] = core::array::from_fn(|i| match i {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            2 => -1,
+            _ => (i as i32 - 32) * 101,
+        });
+        let expected: [u32; X64] = input.map(crate::util::zigzag_encode_i32);
+
+        let data = unsafe { core::mem::transmute::<[i32; X64], [__m256i; 8]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [i32; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u32::MAX - 80;
+        let input: [u32; X64] = core::array::from_fn(|i| reference + i as u32);
+        let expected: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m256i; 8]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::first(0, Some(0))]
+    #[case::middle(u32::MAX - 40, Some(23))]
+    #[case::exact(u32::MAX - 63 + 10, Some(10))]
+    #[case::last(u32::MAX, Some(63))]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_find_first_ge(#[case] target: u32, #[case] expected: Option<usize>) {
+        let input: [u32; X64] = core::array::from_fn(|i| u32::MAX - 63 + i as u32);
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m256i; 8]>(input) };
+        assert_eq!(unsafe { find_first_ge(&data, target) }, expected);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m256i; 8]>([0; X64]) };
+        assert_eq!(unsafe { find_first_ge(&data, 1) }, None);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(8, 8)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 32)]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u32; X64] = core::array::from_fn(|i| 1 << (i % 32));
+        input[X64 - 1] = u32::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m256i; 8]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
 }
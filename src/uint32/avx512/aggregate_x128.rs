@@ -0,0 +1,224 @@
+//! Aggregating the values of a packed block while they are held in registers.
+//!
+//! The halves of the block are unpacked with the regular x64 kernels and reduced straight
+//! away, the unpacked values are never stored.
+use super::util::{min_max, sum};
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint32::max_compressed_size;
+use crate::{X64, X128};
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the sum of the first `read_n` elements of a block of `nbits` bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn sum_nbits(nbits: usize, input: *const u8, read_n: usize) -> u64 {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    const LUT: [unsafe fn(*const u8, usize) -> u64; 33] = [
+        sum_u0, sum_u1, sum_u2, sum_u3, sum_u4, sum_u5, sum_u6, sum_u7, sum_u8, sum_u9, sum_u10,
+        sum_u11, sum_u12, sum_u13, sum_u14, sum_u15, sum_u16, sum_u17, sum_u18, sum_u19, sum_u20,
+        sum_u21, sum_u22, sum_u23, sum_u24, sum_u25, sum_u26, sum_u27, sum_u28, sum_u29, sum_u30,
+        sum_u31, sum_u32,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, read_n) }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the smallest and largest of the first `read_n` elements of a block of `nbits`
+/// bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 32.
+/// - `read_n` must be between 1 and 128.
+pub unsafe fn min_max_nbits(nbits: usize, input: *const u8, read_n: usize) -> (u32, u32) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(
+        read_n > 0 && read_n <= X128,
+        "BUG: invalid read_n provided: {read_n}"
+    );
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(*const u8, usize) -> (u32, u32); 33] = [
+        min_max_u0,
+        min_max_u1,
+        min_max_u2,
+        min_max_u3,
+        min_max_u4,
+        min_max_u5,
+        min_max_u6,
+        min_max_u7,
+        min_max_u8,
+        min_max_u9,
+        min_max_u10,
+        min_max_u11,
+        min_max_u12,
+        min_max_u13,
+        min_max_u14,
+        min_max_u15,
+        min_max_u16,
+        min_max_u17,
+        min_max_u18,
+        min_max_u19,
+        min_max_u20,
+        min_max_u21,
+        min_max_u22,
+        min_max_u23,
+        min_max_u24,
+        min_max_u25,
+        min_max_u26,
+        min_max_u27,
+        min_max_u28,
+        min_max_u29,
+        min_max_u30,
+        min_max_u31,
+        min_max_u32,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, read_n) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn sum_u0(_input: *const u8, _read_n: usize) -> u64 {
+    0
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn min_max_u0(_input: *const u8, _read_n: usize) -> (u32, u32) {
+    (0, 0)
+}
+
+macro_rules! define_x128_aggregator {
+    ($sum_name:ident, $min_max_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        unsafe fn $sum_name(input: *const u8, read_n: usize) -> u64 {
+            let mut total = 0;
+            for start in (0..read_n).step_by(X64) {
+                let n = (read_n - start).min(X64);
+                let input =
+                    unsafe { input.add(start / X64 * max_compressed_size::<X64>($bit_length)) };
+
+                let is_full = read_n > start + X64 || read_n == X128;
+                let unpacked = if is_full {
+                    unsafe { unpack_x64_full::$unpack_func_name(input) }
+                } else {
+                    unsafe { unpack_x64_partial::$unpack_func_name(input, n) }
+                };
+                total += sum(&unpacked, n);
+            }
+            total
+        }
+
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        unsafe fn $min_max_name(input: *const u8, read_n: usize) -> (u32, u32) {
+            let (mut min, mut max) = (u32::MAX, 0);
+            for start in (0..read_n).step_by(X64) {
+                let n = (read_n - start).min(X64);
+                let input =
+                    unsafe { input.add(start / X64 * max_compressed_size::<X64>($bit_length)) };
+
+                let is_full = read_n > start + X64 || read_n == X128;
+                let unpacked = if is_full {
+                    unsafe { unpack_x64_full::$unpack_func_name(input) }
+                } else {
+                    unsafe { unpack_x64_partial::$unpack_func_name(input, n) }
+                };
+                let (half_min, half_max) = min_max(&unpacked, n);
+                min = min.min(half_min);
+                max = max.max(half_max);
+            }
+            (min, max)
+        }
+    };
+}
+
+define_x128_aggregator!(sum_u1, min_max_u1, from_u1, 1);
+define_x128_aggregator!(sum_u2, min_max_u2, from_u2, 2);
+define_x128_aggregator!(sum_u3, min_max_u3, from_u3, 3);
+define_x128_aggregator!(sum_u4, min_max_u4, from_u4, 4);
+define_x128_aggregator!(sum_u5, min_max_u5, from_u5, 5);
+define_x128_aggregator!(sum_u6, min_max_u6, from_u6, 6);
+define_x128_aggregator!(sum_u7, min_max_u7, from_u7, 7);
+define_x128_aggregator!(sum_u8, min_max_u8, from_u8, 8);
+define_x128_aggregator!(sum_u9, min_max_u9, from_u9, 9);
+define_x128_aggregator!(sum_u10, min_max_u10, from_u10, 10);
+define_x128_aggregator!(sum_u11, min_max_u11, from_u11, 11);
+define_x128_aggregator!(sum_u12, min_max_u12, from_u12, 12);
+define_x128_aggregator!(sum_u13, min_max_u13, from_u13, 13);
+define_x128_aggregator!(sum_u14, min_max_u14, from_u14, 14);
+define_x128_aggregator!(sum_u15, min_max_u15, from_u15, 15);
+define_x128_aggregator!(sum_u16, min_max_u16, from_u16, 16);
+define_x128_aggregator!(sum_u17, min_max_u17, from_u17, 17);
+define_x128_aggregator!(sum_u18, min_max_u18, from_u18, 18);
+define_x128_aggregator!(sum_u19, min_max_u19, from_u19, 19);
+define_x128_aggregator!(sum_u20, min_max_u20, from_u20, 20);
+define_x128_aggregator!(sum_u21, min_max_u21, from_u21, 21);
+define_x128_aggregator!(sum_u22, min_max_u22, from_u22, 22);
+define_x128_aggregator!(sum_u23, min_max_u23, from_u23, 23);
+define_x128_aggregator!(sum_u24, min_max_u24, from_u24, 24);
+define_x128_aggregator!(sum_u25, min_max_u25, from_u25, 25);
+define_x128_aggregator!(sum_u26, min_max_u26, from_u26, 26);
+define_x128_aggregator!(sum_u27, min_max_u27, from_u27, 27);
+define_x128_aggregator!(sum_u28, min_max_u28, from_u28, 28);
+define_x128_aggregator!(sum_u29, min_max_u29, from_u29, 29);
+define_x128_aggregator!(sum_u30, min_max_u30, from_u30, 30);
+define_x128_aggregator!(sum_u31, min_max_u31, from_u31, 31);
+define_x128_aggregator!(sum_u32, min_max_u32, from_u32, 32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    #[rstest::rstest]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    #[case::one(1)]
+    #[case::partial_left(37)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_aggregates_match_values(#[case] read_n: usize) {
+        for nbits in 0..=32 {
+            let mask = (1u64 << nbits).wrapping_sub(1) as u32;
+            let values: [u32; X128] = core::array::from_fn(|i| {
+                if i % 7 == 0 {
+                    mask
+                } else {
+                    ((i as u32).wrapping_mul(2654435761) >> 3) & mask
+                }
+            });
+
+            let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+            let details = crate::compress(read_n, &values, &mut compressed);
+            let nbits = details.compressed_bit_length as usize;
+
+            let selected = &values[..read_n];
+            let expected_sum = selected.iter().map(|&v| v as u64).sum::<u64>();
+            let expected_min = *selected.iter().min().unwrap();
+            let expected_max = *selected.iter().max().unwrap();
+
+            let total = unsafe { sum_nbits(nbits, compressed.as_ptr(), read_n) };
+            assert_eq!(
+                total, expected_sum,
+                "sum mismatch, read_n:{read_n} nbits:{nbits}"
+            );
+            let min_max = unsafe { min_max_nbits(nbits, compressed.as_ptr(), read_n) };
+            assert_eq!(
+                min_max,
+                (expected_min, expected_max),
+                "min_max mismatch, read_n:{read_n} nbits:{nbits}"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -19,10 +19,10 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
     let bits = _mm512_slli_epi16::<7>(data);
     let mask = _mm512_movepi8_mask(bits);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -41,7 +41,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u2_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: __m512i) {
     let packed = pack_u8_to_u2_unordered(data);
     unsafe { _mm_storeu_si128(out.cast(), packed) };
 }
@@ -62,7 +62,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u3_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: __m512i) {
     let mask = _mm512_set1_epi8(0b11);
 
     let lo_2bit = _mm512_and_si512(data, mask);
@@ -72,7 +72,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: __m512i) {
     let hi_1bit = _mm512_slli_epi16::<5>(data);
     let hi_1bitmask = _mm512_movepi8_mask(hi_1bit);
 
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_1bitmask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_1bitmask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -91,7 +91,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u4_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: __m512i) {
     let packed = pack_u8_to_u4_unordered(data);
     unsafe { _mm256_storeu_si256(out.cast(), packed) };
 }
@@ -112,7 +112,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u5_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: __m512i) {
     let mask = _mm512_set1_epi8(0b1111);
     let masked = _mm512_and_si512(data, mask);
     unsafe { pack_u4_registers(out.add(0), masked) };
@@ -138,7 +138,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u6_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: __m512i) {
     let lo_4bitmask = _mm512_set1_epi8(0b1111);
     let hi_2bitmask = _mm512_set1_epi8(0b0011);
 
@@ -165,7 +165,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [__m512i; 4]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u7_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: __m512i) {
     let mask = _mm512_set1_epi8(0b1111);
     let masked = _mm512_and_si512(data, mask);
     unsafe { pack_u4_registers(out, masked) };
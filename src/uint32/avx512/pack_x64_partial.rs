@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -19,10 +19,10 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [__m512i; 4], _pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: __m512i) {
     let bits = _mm512_slli_epi16::<7>(data);
     let mask = _mm512_movepi8_mask(bits);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -41,7 +41,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [__m512i; 4], pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u2_registers(out: *mut u8, data: __m512i, pack_n: usize) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let lo_bits = _mm512_slli_epi16::<7>(data);
     let hi_bits = _mm512_slli_epi16::<6>(data);
 
@@ -49,8 +49,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let hi_mask = _mm512_movepi8_mask(hi_bits);
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -69,7 +69,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [__m512i; 4], pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u3_registers(out: *mut u8, data: __m512i, pack_n: usize) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let b0_bits = _mm512_slli_epi16::<7>(data);
     let b1_bits = _mm512_slli_epi16::<6>(data);
     let b2_bits = _mm512_slli_epi16::<5>(data);
@@ -79,9 +79,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let b2_mask = _mm512_movepi8_mask(b2_bits);
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
@@ -100,7 +100,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [__m512i; 4], _pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-pub(super) unsafe fn pack_u4_registers(out: *mut u8, data: __m512i) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: __m512i) {
     let madd_multiplier = _mm512_set1_epi16(0x1001);
     let nibbles = _mm512_maddubs_epi16(data, madd_multiplier);
     let ordered = _mm512_cvtepi16_epi8(nibbles);
@@ -123,7 +123,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [__m512i; 4], pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u5_registers(out: *mut u8, data: __m512i, pack_n: usize) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let mask = _mm512_set1_epi8(0b1111);
     let masked = _mm512_and_si512(data, mask);
     unsafe { pack_u4_registers(out.add(0), masked) };
@@ -150,7 +150,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [__m512i; 4], pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u6_registers(out: *mut u8, data: __m512i, pack_n: usize) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let mask = _mm512_set1_epi8(0b1111);
     let masked = _mm512_and_si512(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -177,7 +177,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [__m512i; 4], pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u7_registers(out: *mut u8, data: __m512i, pack_n: usize) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: __m512i, pack_n: usize) {
     let mask = _mm512_set1_epi8(0b1111);
     let masked = _mm512_and_si512(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
@@ -9,7 +9,7 @@ use super::util::*;
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u1(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u1(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u1_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -17,8 +17,8 @@ pub(super) unsafe fn from_u1(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
     let ones = _mm512_set1_epi8(1);
     _mm512_maskz_mov_epi8(mask, ones)
 }
@@ -29,7 +29,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u2(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u2(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u2_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -37,7 +37,7 @@ pub(super) unsafe fn from_u2(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8) -> __m512i {
     let packed_2bit = unsafe { _mm_loadu_si128(input.add(0).cast()) };
     unpack_u2_to_u8_unordered(packed_2bit)
 }
@@ -48,7 +48,7 @@ unsafe fn unpack_u2_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u3(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u3(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u3_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -56,11 +56,11 @@ pub(super) unsafe fn from_u3(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8) -> __m512i {
     let packed_2bit = unsafe { _mm_loadu_si128(input.add(0).cast()) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let bit = _mm512_set1_epi8(0b100);
     let hi_1bits = _mm512_maskz_mov_epi8(hi_bitmask, bit);
@@ -74,7 +74,7 @@ unsafe fn unpack_u3_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u4(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u4(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u4_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -82,7 +82,7 @@ pub(super) unsafe fn from_u4(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 4-bit nibbles provided
 /// by `input`.
-unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
     let packed = unsafe { _mm256_loadu_si256(input.cast()) };
     unpack_u4_to_u8_unordered(packed)
 }
@@ -93,7 +93,7 @@ unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u5(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u5(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u5_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -101,7 +101,7 @@ pub(super) unsafe fn from_u5(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 5-bit block provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8) -> __m512i {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u1_registers(input.add(32)) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
@@ -114,7 +114,7 @@ unsafe fn unpack_u5_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u6(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u6(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u6_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -122,7 +122,7 @@ pub(super) unsafe fn from_u6(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 6-bit block provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8) -> __m512i {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(32)) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
@@ -135,7 +135,7 @@ unsafe fn unpack_u6_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u7(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u7(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { unpack_u7_registers(input) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -143,7 +143,7 @@ pub(super) unsafe fn from_u7(input: *const u8) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 6-bit block provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8) -> __m512i {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8) -> __m512i {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(32)) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
@@ -156,7 +156,7 @@ unsafe fn unpack_u7_registers(input: *const u8) -> __m512i {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u8(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u8(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { _mm512_loadu_epi8(input.cast()) };
     unpack_u8_to_u32_unordered(packed)
 }
@@ -167,7 +167,7 @@ pub(super) unsafe fn from_u8(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u9(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u9(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -185,7 +185,7 @@ pub(super) unsafe fn from_u9(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u10(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u10(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -203,7 +203,7 @@ pub(super) unsafe fn from_u10(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u11(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u11(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -221,7 +221,7 @@ pub(super) unsafe fn from_u11(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u12(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u12(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -239,7 +239,7 @@ pub(super) unsafe fn from_u12(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u13(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u13(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -257,7 +257,7 @@ pub(super) unsafe fn from_u13(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u14(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u14(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -275,7 +275,7 @@ pub(super) unsafe fn from_u14(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u15(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u15(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
     let lo_bits = unpack_u8_to_u16_unordered(lo_bits);
 
@@ -293,7 +293,7 @@ pub(super) unsafe fn from_u15(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u16(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u16(input: *const u8) -> [__m512i; 4] {
     let packed = unsafe { load_si512x2(input) };
     unpack_u16_to_u32_unordered(packed)
 }
@@ -304,7 +304,7 @@ pub(super) unsafe fn from_u16(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(17)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u17(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u17(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -321,7 +321,7 @@ pub(super) unsafe fn from_u17(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(18)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u18(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u18(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -338,7 +338,7 @@ pub(super) unsafe fn from_u18(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(19)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u19(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u19(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -355,7 +355,7 @@ pub(super) unsafe fn from_u19(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(20)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u20(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u20(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -372,7 +372,7 @@ pub(super) unsafe fn from_u20(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(21)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u21(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u21(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -389,7 +389,7 @@ pub(super) unsafe fn from_u21(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(22)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u22(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u22(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -406,7 +406,7 @@ pub(super) unsafe fn from_u22(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(23)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u23(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u23(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -423,7 +423,7 @@ pub(super) unsafe fn from_u23(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(24)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u24(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u24(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -440,7 +440,7 @@ pub(super) unsafe fn from_u24(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(25)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u25(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u25(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -464,7 +464,7 @@ pub(super) unsafe fn from_u25(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(26)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u26(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u26(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -488,7 +488,7 @@ pub(super) unsafe fn from_u26(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(27)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u27(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u27(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -512,7 +512,7 @@ pub(super) unsafe fn from_u27(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(28)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u28(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u28(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -536,7 +536,7 @@ pub(super) unsafe fn from_u28(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(29)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u29(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u29(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -560,7 +560,7 @@ pub(super) unsafe fn from_u29(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(30)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u30(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u30(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -584,7 +584,7 @@ pub(super) unsafe fn from_u30(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(31)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u31(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u31(input: *const u8) -> [__m512i; 4] {
     let lo_bits = unsafe { load_si512x2(input.add(0)) };
     let lo_bits = unpack_u16_to_u32_unordered(lo_bits);
 
@@ -608,7 +608,7 @@ pub(super) unsafe fn from_u31(input: *const u8) -> [__m512i; 4] {
 /// # Safety
 /// - `input` must be safe to read `max_compressed_size::<X64>(32)` bytes from.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u32(input: *const u8) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u32(input: *const u8) -> [__m512i; 4] {
     unsafe { load_si512x4(input) }
 }
 
@@ -665,7 +665,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -727,7 +727,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -788,7 +788,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -844,7 +844,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,26 +1,27 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 use super::data::*;
 use super::util::*;
+use crate::util::read_partial;
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u1(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> __m512i {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input.add(0), read_n.div_ceil(8)) });
     let ones = _mm512_set1_epi8(1);
     _mm512_maskz_mov_epi8(mask, ones)
 }
@@ -29,10 +30,10 @@ unsafe fn unpack_u1_registers(input: *const u8) -> __m512i {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u2_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
@@ -41,10 +42,10 @@ pub(super) unsafe fn from_u2(input: *const u8, read_n: usize) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> __m512i {
-    let offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(offset).cast()) };
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> __m512i {
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _mm512_set1_epi8(0b01);
     let lo_bits_packed = _mm512_maskz_mov_epi8(mask1, bit);
@@ -59,10 +60,10 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u3_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
@@ -71,12 +72,12 @@ pub(super) unsafe fn from_u3(input: *const u8, read_n: usize) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> __m512i {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> __m512i {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _mm512_set1_epi8(0b001);
     let b0_bits_packed = _mm512_maskz_mov_epi8(mask1, bit);
@@ -95,20 +96,21 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u4(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 4-bit nibbles provided
 /// by `input`.
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
-    let packed = unsafe { _mm256_loadu_si256(input.cast()) };
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> __m512i {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed = unsafe { _mm256_loadu_si256(packed.as_ptr().cast()) };
     let wide = _mm512_cvtepu8_epi16(packed);
     let lo = _mm512_and_si512(wide, _mm512_set1_epi16(0x000F));
     let hi = _mm512_slli_epi16(_mm512_srli_epi16(wide, 4), 8);
@@ -119,10 +121,10 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> __m512i {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u5_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
@@ -131,10 +133,10 @@ pub(super) unsafe fn from_u5(input: *const u8, read_n: usize) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 5-bit block provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> __m512i {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
 }
@@ -143,10 +145,10 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u6_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
@@ -155,9 +157,9 @@ pub(super) unsafe fn from_u6(input: *const u8, read_n: usize) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 6-bit block provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> __m512i {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
@@ -167,10 +169,10 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
     let packed = unsafe { unpack_u7_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
@@ -179,9 +181,9 @@ pub(super) unsafe fn from_u7(input: *const u8, read_n: usize) -> [__m512i; 4] {
 #[target_feature(enable = "avx512f", enable = "avx512bw")]
 /// Unpack eight registers containing 64 8-bit elements from a 6-bit block provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> __m512i {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> __m512i {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = _mm512_slli_epi16::<4>(hi_bits);
     _mm512_or_si512(hi_bits, lo_bits)
@@ -191,12 +193,12 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> __m512i {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { _mm512_loadu_epi8(input.cast()) };
+    let packed = unsafe { _mm512_loadu_epi8(read_partial::<64>(input, read_n).as_ptr().cast()) };
     unpack_u8_to_u32_ordered(packed)
 }
 
@@ -204,16 +206,17 @@ pub(super) unsafe fn from_u8(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 2>(hi_bits);
 
@@ -225,12 +228,13 @@ pub(super) unsafe fn from_u9(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -246,12 +250,13 @@ pub(super) unsafe fn from_u10(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -267,16 +272,17 @@ pub(super) unsafe fn from_u11(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_epi16::<8, 2>(hi_bits);
 
@@ -288,12 +294,13 @@ pub(super) unsafe fn from_u12(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -309,12 +316,13 @@ pub(super) unsafe fn from_u13(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -330,12 +338,13 @@ pub(super) unsafe fn from_u14(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { _mm512_loadu_epi8(input.add(0).cast()) };
+    let lo_bits =
+        unsafe { _mm512_loadu_epi8(read_partial::<64>(input.add(0), read_n).as_ptr().cast()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -351,12 +360,12 @@ pub(super) unsafe fn from_u15(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_si512x2(input) };
+    let packed = unsafe { load_si512x2(read_partial::<128>(input, read_n * 2).as_ptr()) };
     unpack_u16_to_u32_ordered(packed)
 }
 
@@ -364,16 +373,16 @@ pub(super) unsafe fn from_u16(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 17-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(17)` bytes from.
+/// - `input` must be safe to read `compressed_size(17, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u17(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u17(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 4>(hi_bits);
 
@@ -384,12 +393,12 @@ pub(super) unsafe fn from_u17(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 18-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(18)` bytes from.
+/// - `input` must be safe to read `compressed_size(18, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u18(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u18(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -404,12 +413,12 @@ pub(super) unsafe fn from_u18(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 19-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(19)` bytes from.
+/// - `input` must be safe to read `compressed_size(19, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u19(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u19(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -424,16 +433,16 @@ pub(super) unsafe fn from_u19(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 20-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(20)` bytes from.
+/// - `input` must be safe to read `compressed_size(20, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u20(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u20(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 4>(hi_bits);
 
@@ -444,12 +453,12 @@ pub(super) unsafe fn from_u20(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 21-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(21)` bytes from.
+/// - `input` must be safe to read `compressed_size(21, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u21(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u21(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -464,12 +473,12 @@ pub(super) unsafe fn from_u21(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 22-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(22)` bytes from.
+/// - `input` must be safe to read `compressed_size(22, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u22(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u22(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -484,12 +493,12 @@ pub(super) unsafe fn from_u22(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 23-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(23)` bytes from.
+/// - `input` must be safe to read `compressed_size(23, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u23(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u23(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -504,16 +513,22 @@ pub(super) unsafe fn from_u23(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 24-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(24)` bytes from.
+/// - `input` must be safe to read `compressed_size(24, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u24(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u24(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_epi32::<16, 4>(hi_bits);
 
@@ -524,20 +539,26 @@ pub(super) unsafe fn from_u24(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 25-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(25)` bytes from.
+/// - `input` must be safe to read `compressed_size(25, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u25(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u25(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_epi16::<8, 2>(hi_1bits);
 
@@ -552,16 +573,22 @@ pub(super) unsafe fn from_u25(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 26-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(26)` bytes from.
+/// - `input` must be safe to read `compressed_size(26, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u26(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u26(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -580,16 +607,22 @@ pub(super) unsafe fn from_u26(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 27-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(27)` bytes from.
+/// - `input` must be safe to read `compressed_size(27, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u27(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u27(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -608,20 +641,26 @@ pub(super) unsafe fn from_u27(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 28-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(28)` bytes from.
+/// - `input` must be safe to read `compressed_size(28, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u28(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u28(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_epi16::<8, 2>(hi_1bits);
 
@@ -636,16 +675,22 @@ pub(super) unsafe fn from_u28(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 29-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(29)` bytes from.
+/// - `input` must be safe to read `compressed_size(29, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u29(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u29(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -664,16 +709,22 @@ pub(super) unsafe fn from_u29(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 30-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(30)` bytes from.
+/// - `input` must be safe to read `compressed_size(30, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u30(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u30(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -692,16 +743,22 @@ pub(super) unsafe fn from_u30(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 31-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(31)` bytes from.
+/// - `input` must be safe to read `compressed_size(31, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u31(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u31(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_si512x2(input.add(0)) };
+    let lo_bits = unsafe { load_si512x2(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { _mm512_loadu_epi8(input.add(offset).cast()) };
+    let hi_8bits = unsafe {
+        _mm512_loadu_epi8(
+            read_partial::<64>(input.add(offset), read_n)
+                .as_ptr()
+                .cast(),
+        )
+    };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -720,12 +777,12 @@ pub(super) unsafe fn from_u31(input: *const u8, read_n: usize) -> [__m512i; 4] {
 /// Unpack the 32-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(32)` bytes from.
+/// - `input` must be safe to read `compressed_size(32, read_n)` bytes from.
 /// - `read_n` must be between `0` and `64`.
 /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
-pub(super) unsafe fn from_u32(input: *const u8, read_n: usize) -> [__m512i; 4] {
+pub(crate) unsafe fn from_u32(input: *const u8, read_n: usize) -> [__m512i; 4] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_si512x4(input) }
+    unsafe { load_si512x4(read_partial::<256>(input, read_n * 4).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -781,7 +838,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -843,7 +900,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -905,7 +962,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -969,7 +1026,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
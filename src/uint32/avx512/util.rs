@@ -1,4 +1,4 @@
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 pub const _MM_TERNLOG_A: i32 = 0xF0; // 11110000
 pub const _MM_TERNLOG_B: i32 = 0xCC; // 11001100
@@ -336,9 +336,167 @@ pub(super) fn slli_epi16<const IMM8: u32, const N: usize>(mut data: [__m512i; N]
     data
 }
 
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// ZigZag encode the signed 32-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [__m512i; 4]) -> [__m512i; 4] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        let v = data[i];
+        data[i] = _mm512_xor_si512(_mm512_slli_epi32::<1>(v), _mm512_srai_epi32::<31>(v));
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Reverse the ZigZag encoding of the 32-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [__m512i; 4]) -> [__m512i; 4] {
+    let zero = _mm512_setzero_si512();
+    let one = _mm512_set1_epi32(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        let v = data[i];
+        data[i] = _mm512_xor_si512(
+            _mm512_srli_epi32::<1>(v),
+            _mm512_sub_epi32(zero, _mm512_and_si512(v, one)),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Subtract the frame of reference from each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m512i; 4], reference: u32) -> [__m512i; 4] {
+    let reference = _mm512_set1_epi32(reference as i32);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = _mm512_sub_epi32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Add the frame of reference back to each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m512i; 4], reference: u32) -> [__m512i; 4] {
+    let reference = _mm512_set1_epi32(reference as i32);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = _mm512_add_epi32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the position of the first 32-bit element held in the provided registers which
+/// is greater than or equal to `target`.
+pub(super) fn find_first_ge(data: &[__m512i; 4], target: u32) -> Option<usize> {
+    let target = _mm512_set1_epi32(target as i32);
+
+    for (i, register) in data.iter().enumerate() {
+        let mask = _mm512_cmpge_epu32_mask(*register, target);
+        if mask != 0 {
+            return Some(i * 16 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the number of bits required to represent the largest of the first `n` 32-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m512i; 4], n: usize) -> u32 {
+    let mut acc = _mm512_setzero_si512();
+    for (i, register) in data.iter().enumerate() {
+        let selected = n.saturating_sub(i * 16).min(16);
+        let mask = ((1u32 << selected) - 1) as __mmask16;
+        acc = _mm512_or_si512(acc, _mm512_maskz_mov_epi32(mask, *register));
+    }
+
+    32 - (_mm512_reduce_or_epi32(acc) as u32).leading_zeros()
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the sum of the first `n` 32-bit elements held in the provided registers.
+pub(super) fn sum(data: &[__m512i; 4], n: usize) -> u64 {
+    let low = _mm512_set1_epi64(u32::MAX as i64);
+
+    // The elements are widened to 64-bit lanes before being added so the sum can not overflow.
+    let mut acc = _mm512_setzero_si512();
+    for (i, register) in data.iter().enumerate() {
+        let selected = n.saturating_sub(i * 16).min(16);
+        let mask = ((1u32 << selected) - 1) as __mmask16;
+        let register = _mm512_maskz_mov_epi32(mask, *register);
+        acc = _mm512_add_epi64(acc, _mm512_and_si512(register, low));
+        acc = _mm512_add_epi64(acc, _mm512_srli_epi64::<32>(register));
+    }
+
+    _mm512_reduce_add_epi64(acc) as u64
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the smallest and largest of the first `n` 32-bit elements held in the provided
+/// registers.
+pub(super) fn min_max(data: &[__m512i; 4], n: usize) -> (u32, u32) {
+    let mut min = _mm512_set1_epi32(-1);
+    let mut max = _mm512_setzero_si512();
+    for (i, register) in data.iter().enumerate() {
+        let selected = n.saturating_sub(i * 16).min(16);
+        let mask = ((1u32 << selected) - 1) as __mmask16;
+        min = _mm512_mask_min_epu32(min, mask, min, *register);
+        max = _mm512_mask_max_epu32(max, mask, max, *register);
+    }
+
+    (_mm512_reduce_min_epu32(min), _mm512_reduce_max_epu32(max))
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns a bitmask of the 32-bit elements held in the provided registers which are within
+/// `lo..=lo + span`, the bit at position `i` being set when the `i`th element is selected.
+pub(super) fn select_range(data: &[__m512i; 4], lo: u32, span: u32) -> u64 {
+    let lo = _mm512_set1_epi32(lo as i32);
+    let span = _mm512_set1_epi32(span as i32);
+
+    let mut mask = 0;
+    for (i, register) in data.iter().enumerate() {
+        let offset = _mm512_sub_epi32(*register, lo);
+        let bits = _mm512_cmple_epu32_mask(offset, span);
+        mask |= (bits as u64) << (i * 16);
+    }
+    mask
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the position of the first 32-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[__m512i; 4],
+    deltas: &[__m512i; 4],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let mask = if strict {
+            _mm512_cmple_epu32_mask(*value, *delta)
+        } else {
+            _mm512_cmplt_epu32_mask(*value, *delta)
+        };
+        if mask != 0 {
+            return Some(i * 16 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use std::cmp;
+    use core::cmp;
 
     use super::*;
     use crate::X64;
@@ -366,7 +524,7 @@ mod tests {
             expected[i] = i as u16;
         }
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
@@ -385,7 +543,7 @@ mod tests {
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
         assert_eq!(
             view[..16],
             [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23]
@@ -412,7 +570,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u16_ordered(data) };
         let unpacked = unsafe { unpack_u16_to_u32_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -432,7 +590,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
         let unpacked = unsafe { unpack_u16_to_u32_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -457,7 +615,7 @@ mod tests {
             expected[i] = cmp::min(i as u8, u8::MAX);
         }
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
@@ -476,7 +634,7 @@ mod tests {
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(packed) };
         assert_eq!(
             view[..16],
             [0, 32, 16, 48, 1, 33, 17, 49, 2, 34, 18, 50, 3, 35, 19, 51]
@@ -503,7 +661,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u32_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -523,7 +681,7 @@ mod tests {
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
         let unpacked = unsafe { unpack_u8_to_u32_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -543,7 +701,7 @@ mod tests {
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
@@ -553,12 +711,12 @@ mod tests {
         ignore
     )]
     fn test_pack_u32_to_u16_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT,);
     }
 
@@ -568,12 +726,12 @@ mod tests {
         ignore
     )]
     fn test_pack_u32_to_u8_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT,);
     }
 
@@ -583,13 +741,13 @@ mod tests {
         ignore
     )]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data =
             unsafe { _mm512_loadu_epi8(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr().cast()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 2], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
@@ -599,13 +757,13 @@ mod tests {
         ignore
     )]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { _mm512_loadu_epi8(expected.as_ptr().cast()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
@@ -615,13 +773,93 @@ mod tests {
         ignore
     )]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { _mm512_loadu_epi8(expected.as_ptr().cast()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_zigzag_encode_decode() {
+        let input: [i32; X64] = core::array::from_fn(|i| match i {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            2 => -1,
+            _ => (i as i32 - 32) * 101,
+        });
+        let expected: [u32; X64] = input.map(crate::util::zigzag_encode_i32);
+
+        let data = unsafe { core::mem::transmute::<[i32; X64], [__m512i; 4]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [i32; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_sub_add_reference() {
+        let reference = u32::MAX - 80;
+        let input: [u32; X64] = core::array::from_fn(|i| reference + i as u32);
+        let expected: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m512i; 4]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(subtracted) };
         assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::first(0, Some(0))]
+    #[case::middle(u32::MAX - 40, Some(23))]
+    #[case::exact(u32::MAX - 63 + 10, Some(10))]
+    #[case::last(u32::MAX, Some(63))]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_find_first_ge(#[case] target: u32, #[case] expected: Option<usize>) {
+        let input: [u32; X64] = core::array::from_fn(|i| u32::MAX - 63 + i as u32);
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m512i; 4]>(input) };
+        assert_eq!(unsafe { find_first_ge(&data, target) }, expected);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m512i; 4]>([0; X64]) };
+        assert_eq!(unsafe { find_first_ge(&data, 1) }, None);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(16, 16)]
+    #[case::partial(21, 21)]
+    #[case::full(X64, 32)]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u32; X64] = core::array::from_fn(|i| 1 << (i % 32));
+        input[X64 - 1] = u32::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [__m512i; 4]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
     }
 }
@@ -0,0 +1,172 @@
+//! Estimating the compressed size of a block without packing it.
+//!
+//! Choosing between the plain, Delta and Delta-1 encodings per block only requires the bit
+//! length each of them would produce, these routines compute it with the same OR reduction
+//! used by the packing kernels but skip writing any output.
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint32::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint32::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint32::neon;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+use crate::uint32::sse41;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+use crate::uint32::wasm_simd128;
+use crate::uint32::{compressed_size, scalar};
+use crate::{Backend, EncodingCosts, X128};
+
+/// Returns the bit length [crate::compress] would report for the first `n` values of
+/// the block.
+pub fn bit_length(n: usize, block: &[u32; X128]) -> u8 {
+    assert!(n <= X128, "provided n is is greater than 128");
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::bit_length_x128(block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::bit_length_x128(block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::bit_length_x128(block, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::bit_length_x128(block, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe { wasm_simd128::bit_length_x128(block, n) },
+        _ => unsafe { scalar::bit_length_x128(block, n) },
+    }
+}
+
+/// Returns the bit length [crate::compress_delta] would report for the first `n` values
+/// of the block.
+pub fn bit_length_delta(initial_value: u32, n: usize, block: &[u32; X128]) -> u8 {
+    assert!(n <= X128, "provided n is is greater than 128");
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::bit_length_delta_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::bit_length_delta_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::bit_length_delta_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::bit_length_delta_x128(initial_value, block, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::bit_length_delta_x128(initial_value, block, n)
+        },
+        _ => unsafe { scalar::bit_length_delta_x128(initial_value, block, n) },
+    }
+}
+
+/// Returns the bit length [crate::compress_delta1] would report for the first `n` values
+/// of the block.
+pub fn bit_length_delta1(initial_value: u32, n: usize, block: &[u32; X128]) -> u8 {
+    assert!(n <= X128, "provided n is is greater than 128");
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe { avx512::bit_length_delta1_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::bit_length_delta1_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::bit_length_delta1_x128(initial_value, block, n) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::bit_length_delta1_x128(initial_value, block, n) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::bit_length_delta1_x128(initial_value, block, n)
+        },
+        _ => unsafe { scalar::bit_length_delta1_x128(initial_value, block, n) },
+    }
+}
+
+/// Returns the number of bytes the first `n` values of the block compress to with each of
+/// the encodings, `initial_value` is used by the Delta and Delta-1 encodings.
+///
+/// Delta-1 encoding requires a gap of at least `1` between each succeeding value, blocks
+/// which break this wrap around and report the cost of the full 32-bit width.
+pub fn encoding_costs(initial_value: u32, n: usize, block: &[u32; X128]) -> EncodingCosts {
+    let plain = bit_length(n, block);
+    let delta = bit_length_delta(initial_value, n, block);
+    let delta1 = bit_length_delta1(initial_value, n, block);
+
+    EncodingCosts {
+        plain: compressed_size(plain as usize, n),
+        delta: compressed_size(delta as usize, n),
+        delta1: compressed_size(delta1 as usize, n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(7)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_estimates_match_compression(#[case] n: usize) {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        for block in sample_blocks() {
+            let initial_value = block[0].saturating_sub(1);
+
+            let plain = crate::compress(n, &block, &mut compressed);
+            let delta = crate::compress_delta_ref(initial_value, n, &block, &mut compressed);
+            let delta1 = crate::compress_delta1_ref(initial_value, n, &block, &mut compressed);
+
+            assert_eq!(bit_length(n, &block), plain.compressed_bit_length);
+            assert_eq!(
+                bit_length_delta(initial_value, n, &block),
+                delta.compressed_bit_length,
+            );
+            assert_eq!(
+                bit_length_delta1(initial_value, n, &block),
+                delta1.compressed_bit_length,
+            );
+            assert_eq!(
+                encoding_costs(initial_value, n, &block),
+                EncodingCosts {
+                    plain: plain.bytes_written,
+                    delta: delta.bytes_written,
+                    delta1: delta1.bytes_written,
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_estimates_ignore_unselected_values() {
+        let mut block = [0; X128];
+        block[..10].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        block[10..].fill(u32::MAX);
+
+        assert_eq!(bit_length(10, &block), 4);
+        assert_eq!(bit_length_delta(0, 10, &block), 1);
+        assert_eq!(bit_length_delta1(0, 10, &block), 0);
+    }
+
+    fn sample_blocks() -> Vec<[u32; X128]> {
+        crate::test_utils::load_sample_u32_doc_id_data_x128()
+    }
+}
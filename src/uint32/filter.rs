@@ -0,0 +1,205 @@
+//! Evaluating predicates against the values of a compressed block without decompressing it.
+//!
+//! Column filters only need to know which positions of a block match, these routines compare
+//! the values while still held in the registers of the unpacking kernels and return a bitmask
+//! of the matching positions, the bit at position `i` being set when the `i`th value matches.
+//!
+//! Predicates which select either none or all of the values representable at the bit length
+//! of the block, common for low cardinality columns, are answered without unpacking the block.
+use core::ops::RangeInclusive;
+
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint32::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint32::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint32::neon;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+use crate::uint32::sse41;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+use crate::uint32::wasm_simd128;
+use crate::uint32::{max_compressed_size, scalar};
+use crate::{Backend, X128};
+
+/// Returns a bitmask of the positions of a compressed block holding a value equal to `value`.
+///
+/// This requires that the values contained were originally compressed with [crate::compress].
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+pub fn filter_eq(n: usize, compressed_bit_length: u8, input: &[u8], value: u32) -> u128 {
+    filter(n, compressed_bit_length, input, Some(value..=value))
+}
+
+/// Returns a bitmask of the positions of a compressed block holding a value less than `value`.
+///
+/// See [filter_eq] for more details.
+pub fn filter_lt(n: usize, compressed_bit_length: u8, input: &[u8], value: u32) -> u128 {
+    let range = value.checked_sub(1).map(|hi| 0..=hi);
+    filter(n, compressed_bit_length, input, range)
+}
+
+/// Returns a bitmask of the positions of a compressed block holding a value within `lo..hi`,
+/// which includes `lo` but excludes `hi`.
+///
+/// See [filter_eq] for more details.
+pub fn filter_range(n: usize, compressed_bit_length: u8, input: &[u8], lo: u32, hi: u32) -> u128 {
+    let range = hi.checked_sub(1).filter(|&hi| lo <= hi).map(|hi| lo..=hi);
+    filter(n, compressed_bit_length, input, range)
+}
+
+fn filter(
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    range: Option<RangeInclusive<u32>>,
+) -> u128 {
+    assert!(
+        compressed_bit_length <= 32,
+        "compressed bitlength must be no more than 32"
+    );
+    assert!(
+        input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    // The values of the block can not exceed the largest value of its bit length.
+    let max_value = (u32::MAX as u64 >> (32 - compressed_bit_length)) as u32;
+    let Some((lo, hi)) = range
+        .map(RangeInclusive::into_inner)
+        .filter(|&(lo, _)| lo <= max_value)
+    else {
+        return 0;
+    };
+    let hi = hi.min(max_value);
+    if n == 0 {
+        return 0;
+    } else if lo == 0 && hi == max_value {
+        return u128::MAX >> (X128 - n);
+    }
+
+    let span = hi - lo;
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe {
+            avx512::filter_x128(compressed_bit_length, input, n, lo, span)
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe { avx2::filter_x128(compressed_bit_length, input, n, lo, span) },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe { sse41::filter_x128(compressed_bit_length, input, n, lo, span) },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe { neon::filter_x128(compressed_bit_length, input, n, lo, span) },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::filter_x128(compressed_bit_length, input, n, lo, span)
+        },
+        _ => unsafe { scalar::filter_x128(compressed_bit_length, input, n, lo, span) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    fn expected_mask(values: &[u32], predicate: impl Fn(u32) -> bool) -> u128 {
+        values
+            .iter()
+            .enumerate()
+            .filter(|&(_, &v)| predicate(v))
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+
+    #[rstest::rstest]
+    fn test_filters_match_values(
+        #[values(0, 1, 37, 64, 65, 100, X128)] n: usize,
+        #[values(0, 1, 3, 8, 12, 16, 24, 32)] nbits: u32,
+    ) {
+        let max_value = (1u64 << nbits).wrapping_sub(1) as u32;
+        let values: [u32; X128] =
+            core::array::from_fn(|i| (i as u32).wrapping_mul(2654435761) % (max_value.max(1)));
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+        let bit_length = details.compressed_bit_length;
+        let values = &values[..n];
+
+        for value in [0, 1, max_value / 3, max_value, u32::MAX] {
+            assert_eq!(
+                filter_eq(n, bit_length, &compressed, value),
+                expected_mask(values, |v| v == value),
+                "filter_eq mismatch, value:{value}",
+            );
+            assert_eq!(
+                filter_lt(n, bit_length, &compressed, value),
+                expected_mask(values, |v| v < value),
+                "filter_lt mismatch, value:{value}",
+            );
+        }
+
+        for (lo, hi) in [
+            (0, 1),
+            (1, max_value / 2),
+            (max_value / 4, u32::MAX),
+            (5, 5),
+            (9, 2),
+        ] {
+            assert_eq!(
+                filter_range(n, bit_length, &compressed, lo, hi),
+                expected_mask(values, |v| lo <= v && v < hi),
+                "filter_range mismatch, lo:{lo} hi:{hi}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_real_data_sample() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        for select_n in [1, 7, 64, 65, 100, X128] {
+            for sample in sample_data.iter() {
+                let details = crate::compress(select_n, sample, &mut compressed);
+                let values = &sample[..select_n];
+                let pivot = values[select_n / 2];
+
+                assert_eq!(
+                    filter_eq(select_n, details.compressed_bit_length, &compressed, pivot),
+                    expected_mask(values, |v| v == pivot),
+                );
+                assert_eq!(
+                    filter_lt(select_n, details.compressed_bit_length, &compressed, pivot),
+                    expected_mask(values, |v| v < pivot),
+                );
+                assert_eq!(
+                    filter_range(
+                        select_n,
+                        details.compressed_bit_length,
+                        &compressed,
+                        pivot / 2,
+                        pivot + 1
+                    ),
+                    expected_mask(values, |v| pivot / 2 <= v && v <= pivot),
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "provided n is is greater than 128")]
+    fn test_filter_invalid_n() {
+        let compressed = [0; X128_MAX_OUTPUT_LEN];
+        filter_eq(X128 + 1, 0, &compressed, 0);
+    }
+}
@@ -1,19 +1,57 @@
 //! 32-bit integer bitpacking routines
+#[cfg(any(
+    all(
+        target_arch = "x86_64",
+        any(feature = "avx2", feature = "avx512", feature = "sse41")
+    ),
+    all(target_arch = "aarch64", feature = "neon"),
+    all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        feature = "simd128"
+    ),
+))]
+use crate::Backend;
 use crate::core::{CompressibleArray, CompressionDetails};
-use crate::{X64, X128};
+use crate::{Codec, X64, X128};
 
 #[cfg(target_endian = "big")]
 compile_error!("big endian machines are not supported");
 
+mod aggregate;
 #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
 pub mod avx2;
 #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
 pub mod avx512;
+mod estimate;
+mod filter;
 #[cfg(all(target_arch = "aarch64", feature = "neon"))]
 pub mod neon;
+mod patched;
+mod random_access;
 pub mod scalar;
+mod search;
+mod slice;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+pub mod sse41;
 #[cfg(test)]
 mod test_util;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+pub mod wasm_simd128;
+mod widen;
+
+pub use self::aggregate::{last_value_delta, last_value_delta1, min_max, sum};
+pub use self::estimate::{bit_length, bit_length_delta, bit_length_delta1, encoding_costs};
+pub use self::filter::{filter_eq, filter_lt, filter_range};
+pub use self::patched::{X128_MAX_PATCHED_OUTPUT_LEN, compress_patched, decompress_patched};
+pub use self::random_access::get;
+pub use self::search::{decompress_delta_seek, search_delta};
+pub use self::slice::{compress_slice, decompress_into_slice};
+pub use self::widen::{decompress_from_u8, decompress_from_u16};
 
 /// The maximum output size of a compressed buffer for a [X128] block, assuming worst case compression.
 pub const X128_MAX_OUTPUT_LEN: usize = <[u32; X128] as CompressibleArray>::MAX_OUTPUT_SIZE;
@@ -56,80 +94,371 @@ impl CompressibleArray for [u32; X128] {
     type InitialValue = u32;
     const MAX_OUTPUT_SIZE: usize = X128 * size_of::<u32>();
 
-    fn compress(n: usize, input: &Self, output: &mut Self::CompressedBuffer) -> CompressionDetails {
+    fn compress_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe { avx512::pack_x128(output, input, n) };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_x128(output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe { wasm_simd128::pack_x128(output, input, n) },
+            _ => unsafe { scalar::pack_x128(output, input, n) },
         }
+    }
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe { avx2::pack_x128(output, input, n) };
-        }
+    fn compress_delta_ref_with(
+        codec: Codec,
+        initial_value: u32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe { neon::pack_x128(output, input, n) };
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::pack_delta_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::pack_delta_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
         }
+    }
+
+    fn compress_delta_checked_with(
+        codec: Codec,
+        initial_value: u32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        unsafe { scalar::pack_x128(output, input, n) }
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::try_pack_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::try_pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::try_pack_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::try_pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::try_pack_delta_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::try_pack_delta_x128(initial_value, output, input, n) },
+        }?;
+
+        Ok(CompressionDetails {
+            last_value,
+            ..details
+        })
     }
 
-    fn compress_delta(
+    fn compress_delta1_ref_with(
+        codec: Codec,
         initial_value: u32,
         n: usize,
-        input: &mut Self,
+        input: &Self,
         output: &mut Self::CompressedBuffer,
     ) -> CompressionDetails {
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe { avx512::pack_delta_x128(initial_value, output, input, n) };
-        }
-
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe { avx2::pack_delta_x128(initial_value, output, input, n) };
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::pack_delta1_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::pack_delta1_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
         }
+    }
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe { neon::pack_delta_x128(initial_value, output, input, n) };
-        }
+    fn compress_delta1_checked_with(
+        codec: Codec,
+        initial_value: u32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        unsafe { scalar::pack_delta_x128(initial_value, output, input, n) }
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::try_pack_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::try_pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::try_pack_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::try_pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::try_pack_delta1_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::try_pack_delta1_x128(initial_value, output, input, n) },
+        }?;
+
+        Ok(CompressionDetails {
+            last_value,
+            ..details
+        })
     }
 
-    fn compress_delta1(
-        initial_value: u32,
+    fn compress_for_with(
+        codec: Codec,
         n: usize,
-        input: &mut Self,
+        input: &Self,
         output: &mut Self::CompressedBuffer,
     ) -> CompressionDetails {
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe { avx512::pack_delta1_x128(initial_value, output, input, n) };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_for_x128(output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe { wasm_simd128::pack_for_x128(output, input, n) },
+            _ => unsafe { scalar::pack_for_x128(output, input, n) },
         }
+    }
+    fn decompress_unpadded_with(
+        codec: Codec,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe { avx2::pack_delta1_x128(initial_value, output, input, n) };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::unpack_x128(compressed_bit_length, input, output, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::unpack_x128(compressed_bit_length, input, output, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_x128(compressed_bit_length, input, output, n)
+            },
+            _ => unsafe { scalar::unpack_x128(compressed_bit_length, input, output, n) },
         }
+    }
+
+    fn decompress_delta_unpadded_with(
+        codec: Codec,
+        initial_value: u32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe { neon::pack_delta1_x128(initial_value, output, input, n) };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
         }
+    }
+
+    fn decompress_delta1_unpadded_with(
+        codec: Codec,
+        initial_value: u32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        unsafe { scalar::pack_delta1_x128(initial_value, output, input, n) }
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+        }
     }
 
-    fn decompress(n: usize, compressed_bit_length: u8, input: &[u8], output: &mut Self) -> usize {
+    fn decompress_for_with(
+        codec: Codec,
+        reference: u32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
         assert!(
             compressed_bit_length <= 32,
             "compressed bitlength must be no more than 32"
@@ -140,26 +469,262 @@ impl CompressibleArray for [u32; X128] {
         );
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe { avx512::unpack_x128(compressed_bit_length, input, output, n) };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            _ => unsafe {
+                scalar::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
         }
+    }
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe { avx2::unpack_x128(compressed_bit_length, input, output, n) };
+    fn decompress_with_offset_with(
+        codec: Codec,
+        base: u32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_for_with(codec, base, n, compressed_bit_length, input, output)
+    }
+
+    fn encoding_bit_lengths(initial_value: u32, n: usize, input: &Self) -> [u8; 3] {
+        [
+            bit_length(n, input),
+            bit_length_delta(initial_value, n, input),
+            bit_length_delta1(initial_value, n, input),
+        ]
+    }
+
+    fn last_value(initial_value: u32, n: usize, input: &Self) -> u64 {
+        n.checked_sub(1).map_or(initial_value, |i| input[i]) as u64
+    }
+
+    fn encode_delta_in_place(mut last_value: u32, input: &mut Self, delta1: bool) {
+        for value in input.iter_mut() {
+            let current = *value;
+            *value = current.wrapping_sub(last_value).wrapping_sub(delta1 as u32);
+            last_value = current;
         }
+    }
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe { neon::unpack_x128(compressed_bit_length, input, output, n) };
+    fn wrapping_offset(value: u32, base: u32) -> u32 {
+        value.wrapping_add(base)
+    }
+
+    fn compressed_buffer_mut(output: &mut [u8]) -> &mut Self::CompressedBuffer {
+        (&mut output[..Self::MAX_OUTPUT_SIZE]).try_into().unwrap()
+    }
+}
+
+/// Signed integers are ZigZag encoded as part of packing, mapping values of a small
+/// magnitude, positive or negative, to a small bit length.
+///
+/// The Delta and Delta-1 variants ZigZag encode the differences between values, so unlike the
+/// unsigned implementations the input does not need to be sorted.
+impl CompressibleArray for [i32; X128] {
+    type CompressedBuffer = [u8; Self::MAX_OUTPUT_SIZE];
+    type InitialValue = i32;
+    const MAX_OUTPUT_SIZE: usize = X128 * size_of::<i32>();
+
+    fn compress_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_zigzag_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_zigzag_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_zigzag_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_zigzag_x128(output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe { wasm_simd128::pack_zigzag_x128(output, input, n) },
+            _ => unsafe { scalar::pack_zigzag_x128(output, input, n) },
+        }
+    }
+
+    fn compress_delta_ref_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::pack_zigzag_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::pack_zigzag_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::pack_zigzag_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::pack_zigzag_delta_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::pack_zigzag_delta_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::pack_zigzag_delta_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
         }
+    }
 
-        unsafe { scalar::unpack_x128(compressed_bit_length, input, output, n) }
+    fn compress_delta_checked_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        Ok(Self::compress_delta_ref_with(
+            codec,
+            initial_value,
+            n,
+            input,
+            output,
+        ))
     }
 
-    fn decompress_delta(
-        initial_value: u32,
+    fn compress_delta1_ref_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::pack_zigzag_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::pack_zigzag_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::pack_zigzag_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::pack_zigzag_delta1_x128(initial_value, output, input, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::pack_zigzag_delta1_x128(initial_value, output, input, n)
+            },
+            _ => unsafe { scalar::pack_zigzag_delta1_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
+        }
+    }
+
+    fn compress_delta1_checked_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        Ok(Self::compress_delta1_ref_with(
+            codec,
+            initial_value,
+            n,
+            input,
+            output,
+        ))
+    }
+
+    fn compress_for_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_signed_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_signed_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe { sse41::pack_signed_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_signed_for_x128(output, input, n) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe { wasm_simd128::pack_signed_for_x128(output, input, n) },
+            _ => unsafe { scalar::pack_signed_for_x128(output, input, n) },
+        }
+    }
+    fn decompress_unpadded_with(
+        codec: Codec,
         n: usize,
         compressed_bit_length: u8,
         input: &[u8],
@@ -170,37 +735,213 @@ impl CompressibleArray for [u32; X128] {
             "compressed bitlength must be no more than 32"
         );
         assert!(
-            input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
-            "input buffer is too small/incorrectly padded to safely decompress",
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
         );
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe {
-                avx512::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
-            };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_zigzag_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_zigzag_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_zigzag_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_zigzag_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_zigzag_x128(compressed_bit_length, input, output, n)
+            },
+            _ => unsafe { scalar::unpack_zigzag_x128(compressed_bit_length, input, output, n) },
         }
+    }
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe {
-                avx2::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
-            };
-        }
+    fn decompress_delta_unpadded_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe {
-                neon::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
-            };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_zigzag_delta_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
         }
+    }
+
+    fn decompress_delta1_unpadded_with(
+        codec: Codec,
+        initial_value: i32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        unsafe { scalar::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n) }
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_zigzag_delta1_x128(
+                    compressed_bit_length,
+                    initial_value,
+                    input,
+                    output,
+                    n,
+                )
+            },
+        }
     }
 
-    fn decompress_delta1(
-        initial_value: u32,
+    fn decompress_for_with(
+        codec: Codec,
+        reference: i32,
         n: usize,
         compressed_bit_length: u8,
         input: &[u8],
@@ -216,31 +957,129 @@ impl CompressibleArray for [u32; X128] {
         );
         assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
-        if avx512::can_use() {
-            return unsafe {
-                avx512::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
-            };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_signed_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_signed_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_signed_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_signed_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_signed_for_x128(
+                    compressed_bit_length,
+                    reference,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_signed_for_x128(compressed_bit_length, reference, input, output, n)
+            },
         }
+    }
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
-        if avx2::can_use() {
-            return unsafe {
-                avx2::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
-            };
-        }
+    fn decompress_with_offset_with(
+        codec: Codec,
+        base: i32,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 32,
+            "compressed bitlength must be no more than 32"
+        );
+        assert!(
+            input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+            "input buffer is too small/incorrectly padded to safely decompress",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
 
-        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-        if neon::can_use() {
-            return unsafe {
-                neon::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
-            };
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_zigzag_offset_x128(compressed_bit_length, base, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_zigzag_offset_x128(compressed_bit_length, base, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+            Backend::Sse41 => unsafe {
+                sse41::unpack_zigzag_offset_x128(compressed_bit_length, base, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_zigzag_offset_x128(compressed_bit_length, base, input, output, n)
+            },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "simd128"
+            ))]
+            Backend::WasmSimd128 => unsafe {
+                wasm_simd128::unpack_zigzag_offset_x128(
+                    compressed_bit_length,
+                    base,
+                    input,
+                    output,
+                    n,
+                )
+            },
+            _ => unsafe {
+                scalar::unpack_zigzag_offset_x128(compressed_bit_length, base, input, output, n)
+            },
         }
+    }
+
+    fn encoding_bit_lengths(initial_value: i32, n: usize, input: &Self) -> [u8; 3] {
+        assert!(n <= X128, "provided n is is greater than 128");
 
         unsafe {
-            scalar::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            [
+                scalar::bit_length_zigzag_x128(input, n),
+                scalar::bit_length_zigzag_delta_x128(initial_value, input, n),
+                scalar::bit_length_zigzag_delta1_x128(initial_value, input, n),
+            ]
+        }
+    }
+
+    fn last_value(initial_value: i32, n: usize, input: &Self) -> u64 {
+        n.checked_sub(1).map_or(initial_value, |i| input[i]) as i64 as u64
+    }
+
+    fn encode_delta_in_place(mut last_value: i32, input: &mut Self, delta1: bool) {
+        for value in input.iter_mut() {
+            let current = *value;
+            *value = current.wrapping_sub(last_value).wrapping_sub(delta1 as i32);
+            last_value = current;
         }
     }
+
+    fn wrapping_offset(value: i32, base: i32) -> i32 {
+        value.wrapping_add(base)
+    }
+
+    fn compressed_buffer_mut(output: &mut [u8]) -> &mut Self::CompressedBuffer {
+        (&mut output[..Self::MAX_OUTPUT_SIZE]).try_into().unwrap()
+    }
 }
 
 #[inline]
@@ -276,7 +1115,7 @@ mod tests {
         let original_values = values;
 
         let mut compressed = [0; X128_MAX_OUTPUT_LEN];
-        let details = crate::compress(X128, &mut values, &mut compressed);
+        let details = crate::compress(X128, &values, &mut compressed);
 
         let mut decompressed = [0; X128];
         let bytes_read = crate::decompress(
@@ -298,7 +1137,7 @@ mod tests {
         let original_values = values;
 
         let mut compressed = [0; X128_MAX_OUTPUT_LEN];
-        let details = crate::compress(X128, &mut values, &mut compressed);
+        let details = crate::compress(X128, &values, &mut compressed);
 
         let mut decompressed = [0; X128];
         let bytes_read = crate::decompress(
@@ -324,7 +1163,7 @@ mod tests {
         let original_values = values;
 
         let mut compressed = [0; X128_MAX_OUTPUT_LEN];
-        let details = crate::compress(X128, &mut values, &mut compressed);
+        let details = crate::compress(X128, &values, &mut compressed);
 
         let mut decompressed = [0; X128];
         let bytes_read = crate::decompress(
@@ -342,11 +1181,11 @@ mod tests {
     #[case::all_zeroes(0)]
     #[case::all_max(u32::MAX)]
     fn test_compress_and_decompress_edge_cases(#[case] value: u32) {
-        let mut values = [value; 128];
+        let values = [value; 128];
         let original_values = values;
 
         let mut compressed = [0; X128_MAX_OUTPUT_LEN];
-        let details = crate::compress(X128, &mut values, &mut compressed);
+        let details = crate::compress(X128, &values, &mut compressed);
 
         let mut decompressed = [0; X128];
         let bytes_read = crate::decompress(
@@ -444,6 +1283,7 @@ mod tests {
 
             let details = crate::compress_delta(last_value, X128, input, &mut compressed);
             assert_eq!(details.compressed_bit_length, 1);
+            assert!(input[1..].iter().all(|&delta| delta == 1));
 
             let read = crate::decompress_delta(
                 last_value,
@@ -472,6 +1312,111 @@ mod tests {
 
             let details = crate::compress_delta1(last_value, X128, input, &mut compressed);
             assert_eq!(details.compressed_bit_length, 0);
+            assert_eq!(input, &[0; X128]);
+
+            let read = crate::decompress_delta1(
+                last_value,
+                X128,
+                details.compressed_bit_length,
+                &compressed,
+                &mut decompressed,
+            );
+            assert_eq!(read, details.bytes_written);
+            assert_eq!(decompressed.as_slice(), original.as_slice());
+
+            last_value = *original.last().unwrap();
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::all_zeroes(0, 0)]
+    #[case::all_negative_one(-1, 1)]
+    #[case::all_min(i32::MIN, 32)]
+    #[case::all_max(i32::MAX, 32)]
+    fn test_compress_and_decompress_signed_edge_cases(
+        #[case] value: i32,
+        #[case] expected_bit_length: u8,
+    ) {
+        let values = [value; X128];
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, expected_bit_length);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress(
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed, values);
+    }
+
+    #[rstest::rstest]
+    fn test_compress_and_decompress_signed(#[values(1, 37, 64, 100, X128)] n: usize) {
+        let values: [i32; X128] = core::array::from_fn(|i| {
+            let magnitude = (i as i32).wrapping_mul(97);
+            if i % 2 == 0 { magnitude } else { -magnitude }
+        });
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress(
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], values[..n]);
+    }
+
+    #[rstest::rstest]
+    fn test_compress_and_decompress_signed_delta(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, -50, 1200)] last_value: i32,
+    ) {
+        let values: [i32; X128] = core::array::from_fn(|i| {
+            let step = (i as i32 % 7) * 3;
+            if i % 3 == 0 { -step } else { step }
+        });
+
+        let mut input = values;
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_delta(last_value, n, &mut input, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_delta(
+            last_value,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], values[..n]);
+    }
+
+    #[test]
+    fn test_sequentially_decreasing_signed_delta1() {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+
+        let mut values: Vec<i32> = (0..896).map(|v: i32| v * -3).collect();
+        let mut last_value = 0;
+        for chunk in values.chunks_exact_mut(X128) {
+            let original = chunk.to_vec();
+            let input: &mut [i32; X128] = chunk.try_into().unwrap();
+
+            let details = crate::compress_delta1(last_value, X128, input, &mut compressed);
+            assert_eq!(details.compressed_bit_length, 3);
 
             let read = crate::decompress_delta1(
                 last_value,
@@ -486,4 +1431,200 @@ mod tests {
             last_value = *original.last().unwrap();
         }
     }
+
+    #[test]
+    fn test_constant_signed_delta_keeps_last_value() {
+        let mut values = [-7i32; X128];
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_delta(-7, X128, &mut values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 0);
+
+        let mut decompressed = [0; X128];
+        crate::decompress_delta(-7, X128, 0, &compressed, &mut decompressed);
+        assert_eq!(decompressed, [-7; X128]);
+    }
+
+    #[test]
+    fn test_empty_delta_reports_initial_value() {
+        let values = [5u32; X128];
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_delta_ref(42, 0, &values, &mut compressed);
+        assert_eq!(details.last_value, 42);
+
+        let details = crate::compress_delta1_ref(-3i32, 0, &[5i32; X128], &mut compressed);
+        assert_eq!(details.last_value as i32, -3);
+
+        let details = crate::compress(0, &values, &mut compressed);
+        assert_eq!(details.last_value, 0);
+    }
+
+    #[rstest::rstest]
+    fn test_compress_and_decompress_for(#[values(1, 37, 64, 100, X128)] n: usize) {
+        let values: [u32; X128] = core::array::from_fn(|i| 1990 + (i as u32 * 7) % 37);
+        let min = values[..n].iter().copied().min().unwrap();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(n, &values, &mut compressed);
+        assert_eq!(details.reference, min as u64);
+        assert!(details.compressed_bit_length <= 6);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_for(
+            details.reference as u32,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], values[..n]);
+    }
+
+    #[rstest::rstest]
+    #[case::all_zeroes(0)]
+    #[case::all_max(u32::MAX)]
+    fn test_compress_and_decompress_for_constant(#[case] value: u32) {
+        let values = [value; X128];
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 0);
+        assert_eq!(details.bytes_written, 0);
+
+        let mut decompressed = [0; X128];
+        crate::decompress_for(value, X128, 0, &compressed, &mut decompressed);
+        assert_eq!(decompressed, values);
+    }
+
+    #[test]
+    fn test_compress_and_decompress_for_full_range() {
+        let mut values = [u32::MAX; X128];
+        values[3] = 0;
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 32);
+        assert_eq!(details.reference, 0);
+
+        let mut decompressed = [0; X128];
+        crate::decompress_for(
+            0,
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(decompressed, values);
+    }
+
+    #[rstest::rstest]
+    #[case::negative(-1200)]
+    #[case::straddles_zero(-18)]
+    #[case::near_min(i32::MIN)]
+    #[case::near_max(i32::MAX - 40)]
+    fn test_compress_and_decompress_signed_for(#[case] base: i32) {
+        let values: [i32; X128] = core::array::from_fn(|i| base + (i as i32 * 7) % 37);
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(X128, &values, &mut compressed);
+        assert_eq!(details.reference as i32, base);
+        assert_eq!(details.compressed_bit_length, 6);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_for(
+            details.reference as i32,
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed, values);
+    }
+
+    #[rstest::rstest]
+    fn test_decompress_with_offset(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, 1000, u32::MAX - 3)] base: u32,
+    ) {
+        let values: [u32; X128] = core::array::from_fn(|i| (i as u32 * 7) % 37);
+        let expected = values.map(|v| v.wrapping_add(base));
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_with_offset(
+            base,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    fn test_decompress_delta_with_offset(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, 1000, u32::MAX - 3)] base: u32,
+    ) {
+        let values: [u32; X128] = core::array::from_fn(|i| 5 + i as u32 * 3);
+        let expected = values.map(|v| v.wrapping_add(base));
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+
+        let details = crate::compress_delta(2, n, &mut { values }, &mut compressed);
+        let bytes_read = crate::decompress_delta_with_offset(
+            2,
+            base,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], expected[..n]);
+
+        let details = crate::compress_delta1(2, n, &mut { values }, &mut compressed);
+        let bytes_read = crate::decompress_delta1_with_offset(
+            2,
+            base,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], expected[..n]);
+    }
+
+    #[rstest::rstest]
+    #[case::negative(-1200)]
+    #[case::wraps(i32::MAX - 3)]
+    fn test_decompress_signed_with_offset(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[case] base: i32,
+    ) {
+        let values: [i32; X128] = core::array::from_fn(|i| (i as i32 * 7) % 37 - 18);
+        let expected = values.map(|v| v.wrapping_add(base));
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_with_offset(
+            base,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], expected[..n]);
+    }
 }
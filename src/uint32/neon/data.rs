@@ -1,7 +1,7 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::arch::aarch64::*;
-use std::mem::MaybeUninit;
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
 
 use super::polyfill::*;
 use crate::X64;
@@ -14,7 +14,7 @@ pub(super) fn load_u32x64(block: &[u32; X64]) -> [uint32x4_t; 16] {
     for i in 0..16 {
         data[i].write(unsafe { _neon_load_u32(ptr.add(i * 4)) });
     }
-    unsafe { std::mem::transmute::<[MaybeUninit<uint32x4_t>; 16], [uint32x4_t; 16]>(data) }
+    unsafe { core::mem::transmute::<[MaybeUninit<uint32x4_t>; 16], [uint32x4_t; 16]>(data) }
 }
 
 #[target_feature(enable = "neon")]
@@ -71,7 +71,7 @@ pub(super) unsafe fn load_u16x8x8(ptr: *const u8) -> [uint16x8_t; 8] {
     for i in 0..8 {
         data[i].write(unsafe { _neon_load_u16(ptr.add(i * 8)) });
     }
-    unsafe { std::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
+    unsafe { core::mem::transmute::<[MaybeUninit<uint16x8_t>; 8], [uint16x8_t; 8]>(data) }
 }
 
 #[target_feature(enable = "neon")]
@@ -82,5 +82,5 @@ pub(super) unsafe fn load_u32x4x16(ptr: *const u8) -> [uint32x4_t; 16] {
     for i in 0..16 {
         data[i].write(unsafe { _neon_load_u32(ptr.add(i * 4)) });
     }
-    unsafe { std::mem::transmute::<[MaybeUninit<uint32x4_t>; 16], [uint32x4_t; 16]>(data) }
+    unsafe { core::mem::transmute::<[MaybeUninit<uint32x4_t>; 16], [uint32x4_t; 16]>(data) }
 }
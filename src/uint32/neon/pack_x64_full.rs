@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -20,7 +20,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [uint32x4_t; 16]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let [d1, d2, d3, d4] = data;
 
     let select_mask = _neon_set1_u8(0b1);
@@ -31,7 +31,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
 
     let mask = _neon_nonzero_mask_u8([cmp1, cmp2, cmp3, cmp4]);
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -48,7 +48,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 2-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let packed = pack_u8_to_u2_unordered(data);
     unsafe { _neon_store_u8(out.cast(), packed) };
 }
@@ -67,7 +67,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 3-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let mask = _neon_set1_u8(0b11);
 
     let lo_2bit = and_u8(data, mask);
@@ -80,7 +80,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let hi_1bit4 = _neon_srli_u8::<2>(data[3]);
 
     let mask = _neon_nonzero_mask_u8([hi_1bit1, hi_1bit2, hi_1bit3, hi_1bit4]);
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -97,7 +97,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 4-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u4_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let packed = pack_u8_to_u4_unordered(data);
     unsafe { _neon_store_u8(out.add(0), packed[0]) };
     unsafe { _neon_store_u8(out.add(16), packed[1]) };
@@ -117,7 +117,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 5-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u5_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -141,7 +141,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 6-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u6_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -165,7 +165,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [uint32x4_t; 16]) {
 #[target_feature(enable = "neon")]
 /// Pack two registers containing 32 8-bit elements each into a 7-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u7_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
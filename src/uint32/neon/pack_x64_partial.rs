@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -17,10 +17,10 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [uint32x4_t; 16], _pack_n: usize
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let select_mask = _neon_set1_u8(0b1);
     let mask = test_nonzero_mask(data, select_mask);
-    unsafe { std::ptr::write_unaligned(out.cast(), mask) };
+    unsafe { core::ptr::write_unaligned(out.cast(), mask) };
 }
 
 #[inline]
@@ -47,7 +47,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [uint32x4_t; 16], pack_n: usize)
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 2-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
     let lo_select_mask = _neon_set1_u8(0b01);
     let hi_select_mask = _neon_set1_u8(0b10);
 
@@ -55,8 +55,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize)
     let hi_mask = test_nonzero_mask(data, hi_select_mask);
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -70,7 +70,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [uint32x4_t; 16], pack_n: usize)
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 3-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
     let b0_select_mask = _neon_set1_u8(0b001);
     let b1_select_mask = _neon_set1_u8(0b010);
     let b2_select_mask = _neon_set1_u8(0b100);
@@ -80,9 +80,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize)
     let b2_mask = test_nonzero_mask(data, b2_select_mask);
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_mask) };
 }
 
 #[target_feature(enable = "neon")]
@@ -96,7 +96,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [uint32x4_t; 16], _pack_n: usize
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 4-bit
 /// bitmap and write to `out`.
-pub(super) unsafe fn pack_u4_registers(out: *mut u8, data: [uint8x16_t; 4]) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: [uint8x16_t; 4]) {
     let packed = _neon_pack_nibbles([data[0], data[1]], [data[2], data[3]]);
     unsafe { _neon_store_u8(out.add(0), packed[0]) };
     unsafe { _neon_store_u8(out.add(16), packed[1]) };
@@ -113,7 +113,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [uint32x4_t; 16], pack_n: usize)
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 5-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u5_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -135,7 +135,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [uint32x4_t; 16], pack_n: usize)
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 6-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u6_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -157,7 +157,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [uint32x4_t; 16], pack_n: usize)
 #[target_feature(enable = "neon")]
 /// Pack four registers containing 32 8-bit elements each into a 7-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u7_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: [uint8x16_t; 4], pack_n: usize) {
     let mask = _neon_set1_u8(0b1111);
     let masked = and_u8(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -447,7 +447,7 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_to_u1() {
-        let data: [u32; X64] = std::array::from_fn(|i| (i % 2) as u32);
+        let data: [u32; X64] = core::array::from_fn(|i| (i % 2) as u32);
         let data = unsafe { load_u32x64(&data) };
 
         let mut out = [0; X128_MAX_OUTPUT_LEN / 2];
@@ -1,4 +1,4 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
@@ -19,8 +19,8 @@ pub unsafe fn from_u1(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [uint8x16_t; 4] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8) -> [uint8x16_t; 4] {
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
     _neon_mov_maskz_u8(mask)
 }
 
@@ -39,7 +39,7 @@ pub unsafe fn from_u2(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 64 8-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8) -> [uint8x16_t; 4] {
     let packed = unsafe { _neon_load_u8(input) };
     unpack_u2_to_u8_unordered(packed)
 }
@@ -59,11 +59,11 @@ pub unsafe fn from_u3(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 64 8-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8) -> [uint8x16_t; 4] {
     let packed_2bit = unsafe { _neon_load_u8(input.add(0)) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let mut hi_1bits = _neon_mov_maskz_u8(hi_bitmask);
     hi_1bits = slli_u8::<2, 4>(hi_1bits);
@@ -85,7 +85,7 @@ pub unsafe fn from_u4(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-unsafe fn unpack_u4_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8) -> [uint8x16_t; 4] {
     let packed1 = unsafe { _neon_load_u8(input.add(0)) };
     let packed2 = unsafe { _neon_load_u8(input.add(16)) };
     unpack_u4_to_u8_unordered([packed1, packed2])
@@ -106,7 +106,7 @@ pub unsafe fn from_u5(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8) -> [uint8x16_t; 4] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u1_registers(input.add(32)) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
@@ -128,7 +128,7 @@ pub unsafe fn from_u6(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8) -> [uint8x16_t; 4] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(32)) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
@@ -150,7 +150,7 @@ pub unsafe fn from_u7(input: *const u8) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8) -> [uint8x16_t; 4] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(32)) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
@@ -668,7 +668,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -727,7 +727,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,19 +1,20 @@
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::data::*;
 use super::polyfill::*;
 use super::util::*;
+use crate::util::read_partial;
 
 #[inline]
 #[target_feature(enable = "neon")]
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
@@ -21,8 +22,8 @@ pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [uint8x16_t; 4] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
     _neon_mov_maskz_u8(mask)
 }
 
@@ -31,7 +32,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> [uint8x16_t; 4] {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -43,10 +44,10 @@ pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
-    let hi_offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(hi_offset).cast()) };
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let lo_bits_packed = _neon_mov_maskz_u8(mask1);
     let mut hi_bits_packed = _neon_mov_maskz_u8(mask2);
@@ -60,7 +61,7 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     let packed = unsafe { unpack_u3_registers(input, read_n) };
@@ -71,12 +72,12 @@ pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let b0_bits_packed = _neon_mov_maskz_u8(mask1);
 
@@ -97,11 +98,11 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
@@ -109,9 +110,10 @@ pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [uint8x16_t; 4] {
-    let packed1 = unsafe { _neon_load_u8(input.add(0)) };
-    let packed2 = unsafe { _neon_load_u8(input.add(16)) };
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed1 = unsafe { _neon_load_u8(packed.as_ptr().add(0)) };
+    let packed2 = unsafe { _neon_load_u8(packed.as_ptr().add(16)) };
     let [d1, d2] = _neon_unpack_nibbles(packed1);
     let [d3, d4] = _neon_unpack_nibbles(packed2);
     [d1, d2, d3, d4]
@@ -122,7 +124,7 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [uint8x16_t; 4] {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -134,10 +136,10 @@ pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
     or_u8_all(hi_bits, lo_bits)
 }
@@ -147,7 +149,7 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -159,9 +161,9 @@ pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
     or_u8_all(hi_bits, lo_bits)
@@ -172,7 +174,7 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -184,9 +186,9 @@ pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 #[target_feature(enable = "neon")]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = slli_u8::<4, 4>(hi_bits);
     or_u8_all(hi_bits, lo_bits)
@@ -197,11 +199,11 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [uint8x16_t; 4
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_u8x16x4(input) };
+    let packed = unsafe { load_u8x16x4(read_partial::<64>(input, read_n).as_ptr()) };
     unpack_u8_to_u32_ordered(packed)
 }
 
@@ -210,15 +212,15 @@ pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16::<8, 8>(hi_bits);
 
@@ -231,11 +233,11 @@ pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -252,11 +254,11 @@ pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -273,15 +275,15 @@ pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16::<8, 8>(hi_bits);
 
@@ -294,11 +296,11 @@ pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -315,11 +317,11 @@ pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -336,11 +338,11 @@ pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -357,11 +359,11 @@ pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_u16x8x8(input.add(0)) };
+    let packed = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     unpack_u16_to_u32_ordered(packed)
 }
 
@@ -370,15 +372,15 @@ pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 17-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(17)` bytes from.
+/// - `input` must be safe to read `compressed_size(17, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32::<16, 16>(hi_bits);
 
@@ -390,11 +392,11 @@ pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 18-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(18)` bytes from.
+/// - `input` must be safe to read `compressed_size(18, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -410,11 +412,11 @@ pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 19-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(19)` bytes from.
+/// - `input` must be safe to read `compressed_size(19, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -430,15 +432,15 @@ pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 20-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(20)` bytes from.
+/// - `input` must be safe to read `compressed_size(20, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32::<16, 16>(hi_bits);
 
@@ -450,11 +452,11 @@ pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 21-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(21)` bytes from.
+/// - `input` must be safe to read `compressed_size(21, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -470,11 +472,11 @@ pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 22-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(22)` bytes from.
+/// - `input` must be safe to read `compressed_size(22, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -490,11 +492,11 @@ pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 23-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(23)` bytes from.
+/// - `input` must be safe to read `compressed_size(23, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -510,15 +512,15 @@ pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 24-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(24)` bytes from.
+/// - `input` must be safe to read `compressed_size(24, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32::<16, 16>(hi_bits);
 
@@ -530,19 +532,19 @@ pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 25-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(25)` bytes from.
+/// - `input` must be safe to read `compressed_size(25, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_u16::<8, 8>(hi_1bits);
 
@@ -558,15 +560,15 @@ pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 26-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(26)` bytes from.
+/// - `input` must be safe to read `compressed_size(26, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -586,15 +588,15 @@ pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 27-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(27)` bytes from.
+/// - `input` must be safe to read `compressed_size(27, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -614,19 +616,19 @@ pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 28-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(28)` bytes from.
+/// - `input` must be safe to read `compressed_size(28, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_u16::<8, 8>(hi_1bits);
 
@@ -642,15 +644,15 @@ pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 29-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(29)` bytes from.
+/// - `input` must be safe to read `compressed_size(29, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -670,15 +672,15 @@ pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 30-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(30)` bytes from.
+/// - `input` must be safe to read `compressed_size(30, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -698,14 +700,14 @@ pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// Unpack the 31-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(31)` bytes from.
+/// - `input` must be safe to read `compressed_size(31, read_n)` bytes from.
 pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x8x8(input.add(0)) };
+    let lo_bits = unsafe { load_u16x8x8(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x16x4(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x16x4(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -730,7 +732,7 @@ pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u32(input: *const u8, read_n: usize) -> [uint32x4_t; 16] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_u32x4x16(input) }
+    unsafe { load_u32x4x16(read_partial::<256>(input, read_n * 4).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -781,7 +783,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -840,7 +842,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -899,7 +901,8 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+            let unpacked =
+                unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
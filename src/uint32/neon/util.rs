@@ -1,6 +1,6 @@
 #![allow(clippy::needless_range_loop)]
 
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 use super::polyfill::*;
 
@@ -499,6 +499,186 @@ pub(super) fn slli_u32<const IMM8: i32, const N: usize>(
     data
 }
 
+#[target_feature(enable = "neon")]
+/// ZigZag encode the signed 32-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [uint32x4_t; 16]) -> [uint32x4_t; 16] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        let v = data[i];
+        data[i] = veorq_u32(
+            vshlq_n_u32::<1>(v),
+            vreinterpretq_u32_s32(vshrq_n_s32::<31>(vreinterpretq_s32_u32(v))),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Reverse the ZigZag encoding of the 32-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [uint32x4_t; 16]) -> [uint32x4_t; 16] {
+    let one = vdupq_n_u32(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        let v = data[i];
+        data[i] = veorq_u32(
+            vshrq_n_u32::<1>(v),
+            vreinterpretq_u32_s32(vnegq_s32(vreinterpretq_s32_u32(vandq_u32(v, one)))),
+        );
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Subtract the frame of reference from each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [uint32x4_t; 16], reference: u32) -> [uint32x4_t; 16] {
+    let reference = vdupq_n_u32(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = vsubq_u32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Add the frame of reference back to each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [uint32x4_t; 16], reference: u32) -> [uint32x4_t; 16] {
+    let reference = vdupq_n_u32(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = vaddq_u32(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the position of the first 32-bit element held in the provided registers which
+/// is greater than or equal to `target`.
+pub(super) fn find_first_ge(data: &[uint32x4_t; 16], target: u32) -> Option<usize> {
+    let target = vdupq_n_u32(target);
+
+    for (i, register) in data.iter().enumerate() {
+        let ge = vcgeq_u32(*register, target);
+        // Narrow each 32-bit lane of the mask to 16 bits so it fits within a single u64.
+        let mask = vget_lane_u64::<0>(vreinterpret_u64_u16(vmovn_u32(ge)));
+        if mask != 0 {
+            return Some(i * 4 + mask.trailing_zeros() as usize / 16);
+        }
+    }
+    None
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the number of bits required to represent the largest of the first `n` 32-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[uint32x4_t; 16], n: usize) -> u32 {
+    const POSITIONS: [u32; 4] = [0, 1, 2, 3];
+
+    let limit = vdupq_n_u32(n as u32);
+    let step = vdupq_n_u32(4);
+    let mut positions = unsafe { vld1q_u32(POSITIONS.as_ptr()) };
+
+    let mut acc = vdupq_n_u32(0);
+    for register in data {
+        let selected = vcltq_u32(positions, limit);
+        acc = vmaxq_u32(acc, vandq_u32(selected, *register));
+        positions = vaddq_u32(positions, step);
+    }
+
+    32 - vmaxvq_u32(acc).leading_zeros()
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the sum of the first `n` 32-bit elements held in the provided registers.
+pub(super) fn sum(data: &[uint32x4_t; 16], n: usize) -> u64 {
+    const POSITIONS: [u32; 4] = [0, 1, 2, 3];
+
+    let limit = vdupq_n_u32(n as u32);
+    let step = vdupq_n_u32(4);
+    let mut positions = unsafe { vld1q_u32(POSITIONS.as_ptr()) };
+
+    // Adjacent elements are added pairwise into 64-bit lanes so the sum can not overflow.
+    let mut acc = vdupq_n_u64(0);
+    for register in data {
+        let selected = vcltq_u32(positions, limit);
+        acc = vpadalq_u32(acc, vandq_u32(selected, *register));
+        positions = vaddq_u32(positions, step);
+    }
+
+    vaddvq_u64(acc)
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the smallest and largest of the first `n` 32-bit elements held in the provided
+/// registers.
+pub(super) fn min_max(data: &[uint32x4_t; 16], n: usize) -> (u32, u32) {
+    const POSITIONS: [u32; 4] = [0, 1, 2, 3];
+
+    let limit = vdupq_n_u32(n as u32);
+    let step = vdupq_n_u32(4);
+    let mut positions = unsafe { vld1q_u32(POSITIONS.as_ptr()) };
+
+    let mut min = vdupq_n_u32(u32::MAX);
+    let mut max = vdupq_n_u32(0);
+    for register in data {
+        let selected = vcltq_u32(positions, limit);
+        min = vminq_u32(min, vornq_u32(*register, selected));
+        max = vmaxq_u32(max, vandq_u32(selected, *register));
+        positions = vaddq_u32(positions, step);
+    }
+
+    (vminvq_u32(min), vmaxvq_u32(max))
+}
+
+#[target_feature(enable = "neon")]
+/// Returns a bitmask of the 32-bit elements held in the provided registers which are within
+/// `lo..=lo + span`, the bit at position `i` being set when the `i`th element is selected.
+pub(super) fn select_range(data: &[uint32x4_t; 16], lo: u32, span: u32) -> u64 {
+    const BITS: [u32; 4] = [1, 2, 4, 8];
+
+    let lo = vdupq_n_u32(lo);
+    let span = vdupq_n_u32(span);
+    let bits = unsafe { vld1q_u32(BITS.as_ptr()) };
+
+    let mut mask = 0;
+    for (i, register) in data.iter().enumerate() {
+        let selected = vcleq_u32(vsubq_u32(*register, lo), span);
+        mask |= (vaddvq_u32(vandq_u32(selected, bits)) as u64) << (i * 4);
+    }
+    mask
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the position of the first 32-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[uint32x4_t; 16],
+    deltas: &[uint32x4_t; 16],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let descent = if strict {
+            vcleq_u32(*value, *delta)
+        } else {
+            vcltq_u32(*value, *delta)
+        };
+        // Narrow each 32-bit lane of the mask to 16 bits so it fits within a single u64.
+        let mask = vget_lane_u64::<0>(vreinterpret_u64_u16(vmovn_u32(descent)));
+        if mask != 0 {
+            return Some(i * 4 + mask.trailing_zeros() as usize / 16);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,90 +693,90 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u16_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
         let packed = unsafe { pack_u16_to_u8_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u8_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
         let unpacked = unsafe { unpack_u8_to_u16_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u16_to_u32_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [uint16x8_t; 8]>(input) };
         let unpacked = unsafe { unpack_u16_to_u32_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u32);
-        let view = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u8_to_u32_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
         let unpacked = unsafe { unpack_u8_to_u32_ordered(data) };
 
-        let expected = std::array::from_fn(|i| i as u32);
-        let view = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u16_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
         assert_eq!(view[..8], [0, 16, 1, 17, 2, 18, 3, 19]);
         assert_eq!(view[8..][..8], [4, 20, 5, 21, 6, 22, 7, 23]);
         assert_eq!(view[16..][..8], [8, 24, 9, 25, 10, 26, 11, 27]);
@@ -605,12 +785,12 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u8_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
         assert_eq!(view[..8], [0, 32, 16, 48, 1, 33, 17, 49]);
         assert_eq!(view[8..][..8], [2, 34, 18, 50, 3, 35, 19, 51]);
         assert_eq!(view[16..][..8], [4, 36, 20, 52, 5, 37, 21, 53]);
@@ -620,75 +800,143 @@ mod tests {
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u16_to_u32_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
-        let data = unsafe { std::mem::transmute::<[u32; X64], [uint32x4_t; 16]>(input) };
+        let data = unsafe { core::mem::transmute::<[u32; X64], [uint32x4_t; 16]>(input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
         let unpacked = unsafe { unpack_u16_to_u32_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u16_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_pack_u32_to_u8_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = unsafe { load_u32x64(&input) };
         let packed = unsafe { pack_u32_to_u8_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_u8x16x4(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unsafe { unpack_u8_to_u16_unordered(data) };
 
-        let view = unsafe { std::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint16x8_t; 8], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_u8x16x4(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u4_unordered(data) };
         let unpacked = unsafe { unpack_u4_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     #[cfg_attr(not(target_feature = "neon"), ignore)]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_u8x16x4(expected.as_ptr()) };
         let packed = unsafe { pack_u8_to_u2_unordered(data) };
         let unpacked = unsafe { unpack_u2_to_u8_unordered(packed) };
 
-        let view = unsafe { std::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i32; X64] = core::array::from_fn(|i| match i {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            2 => -1,
+            _ => (i as i32 - 32) * 101,
+        });
+        let expected: [u32; X64] = input.map(crate::util::zigzag_encode_i32);
+
+        let data = unsafe { core::mem::transmute::<[i32; X64], [uint32x4_t; 16]>(input) };
+        let encoded = unsafe { zigzag_encode(data) };
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(encoded) };
         assert_eq!(view, expected);
+
+        let decoded = unsafe { zigzag_decode(encoded) };
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [i32; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u32::MAX - 80;
+        let input: [u32; X64] = core::array::from_fn(|i| reference + i as u32);
+        let expected: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [uint32x4_t; 16]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::first(0, Some(0))]
+    #[case::middle(u32::MAX - 40, Some(23))]
+    #[case::exact(u32::MAX - 63 + 10, Some(10))]
+    #[case::last(u32::MAX, Some(63))]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_find_first_ge(#[case] target: u32, #[case] expected: Option<usize>) {
+        let input: [u32; X64] = core::array::from_fn(|i| u32::MAX - 63 + i as u32);
+        let data = unsafe { core::mem::transmute::<[u32; X64], [uint32x4_t; 16]>(input) };
+        assert_eq!(unsafe { find_first_ge(&data, target) }, expected);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [uint32x4_t; 16]>([0; X64]) };
+        assert_eq!(unsafe { find_first_ge(&data, 1) }, None);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(4, 4)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 32)]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u32; X64] = core::array::from_fn(|i| 1 << (i % 32));
+        input[X64 - 1] = u32::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [uint32x4_t; 16]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
     }
 }
@@ -0,0 +1,320 @@
+//! Patched frame-of-reference (PFOR) style encoding for blocks with a few outliers.
+//!
+//! A single large value forces a regular block to be packed at the bit length of that value,
+//! patched blocks instead pack every value at a smaller base bit length and store the
+//! outliers separately as an exception list of their positions and remaining high bits.
+//!
+//! The layout of a patched block is:
+//!
+//! - A 3 byte header holding the number of exceptions, the bit length of the packed
+//!   positions and the bit length of the packed high bits.
+//! - The low bits of every value packed at the base bit length.
+//! - The exception positions, packed as a `u8` block.
+//! - The exception high bits, packed as a `u32` block.
+use crate::core::CompressionDetails;
+use crate::uint32::{X128_MAX_OUTPUT_LEN, compressed_size, max_compressed_size};
+use crate::{X128, uint8};
+
+const HEADER_LEN: usize = 3;
+/// The bit length assumed for the exception positions by the cost model, which is enough
+/// to address any position within a [X128] block.
+const POSITION_BIT_LENGTH: usize = 7;
+
+/// The maximum output size of a patched [X128] block, assuming worst case compression.
+pub const X128_MAX_PATCHED_OUTPUT_LEN: usize = HEADER_LEN + X128_MAX_OUTPUT_LEN;
+
+/// Compress the input using patched bitpacking and write the compressed data to `out`.
+///
+/// The base bit length is selected to minimise the total size of the block, values which
+/// do not fit within the base bit length are stored in an exception list which is applied
+/// when decompressing. When no outliers exist, the block is only the header larger than
+/// the output of [crate::compress].
+///
+/// `n` should be the number of elements to select from the input to compress.
+///
+/// The returned [CompressionDetails::compressed_bit_length] is the base bit length of the
+/// block and must be provided to [decompress_patched].
+pub fn compress_patched(
+    n: usize,
+    input: &[u32; X128],
+    out: &mut [u8; X128_MAX_PATCHED_OUTPUT_LEN],
+) -> CompressionDetails {
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    let base_bit_length = select_base_bit_length(n, input);
+    let low_mask = u32::MAX
+        .checked_shr(32 - base_bit_length as u32)
+        .unwrap_or(0);
+    let low_bits: [u32; X128] = core::array::from_fn(|i| input[i] & low_mask);
+
+    let body: &mut [u8; X128_MAX_OUTPUT_LEN] = (&mut out[HEADER_LEN..]).try_into().unwrap();
+    let base = crate::compress(n, &low_bits, body);
+    let base_bit_length = base.compressed_bit_length as u32;
+
+    let mut positions = [0u8; X128];
+    let mut high_bits = [0u32; X128];
+    let mut num_exceptions = 0;
+    for (i, value) in input.iter().take(n).enumerate() {
+        let high = value.checked_shr(base_bit_length).unwrap_or(0);
+        if high != 0 {
+            positions[num_exceptions] = i as u8;
+            high_bits[num_exceptions] = high;
+            num_exceptions += 1;
+        }
+    }
+
+    let mut offset = HEADER_LEN + base.bytes_written;
+
+    let mut scratch = [0; uint8::X128_MAX_OUTPUT_LEN];
+    let details = crate::compress(num_exceptions, &positions, &mut scratch);
+    out[offset..][..details.bytes_written].copy_from_slice(&scratch[..details.bytes_written]);
+    offset += details.bytes_written;
+    let position_bit_length = details.compressed_bit_length;
+
+    let mut scratch = [0; X128_MAX_OUTPUT_LEN];
+    let details = crate::compress(num_exceptions, &high_bits, &mut scratch);
+    out[offset..][..details.bytes_written].copy_from_slice(&scratch[..details.bytes_written]);
+    offset += details.bytes_written;
+    let high_bit_length = details.compressed_bit_length;
+
+    out[0] = num_exceptions as u8;
+    out[1] = position_bit_length;
+    out[2] = high_bit_length;
+
+    CompressionDetails {
+        compressed_bit_length: base.compressed_bit_length,
+        bytes_written: offset,
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+/// Decompress a block compressed with [compress_patched], writing the recovered values
+/// to `out`.
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the base bit length of the compressed block as
+///   reported by the [CompressionDetails] after compressing the block.
+///
+/// Returns the number of bytes read from the input.
+pub fn decompress_patched(
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut [u32; X128],
+) -> usize {
+    assert!(
+        compressed_bit_length <= 32,
+        "compressed bitlength must be no more than 32"
+    );
+    assert!(
+        input.len() >= HEADER_LEN + max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    let num_exceptions = input[0] as usize;
+    let position_bit_length = input[1];
+    let high_bit_length = input[2];
+    // The high bits of an exception are shifted above the base bit length, together they
+    // must fit within the 32-bit value.
+    assert!(
+        num_exceptions <= n
+            && position_bit_length <= 8
+            && compressed_bit_length as u32 + high_bit_length as u32 <= 32,
+        "patched block header is corrupt"
+    );
+
+    let mut offset = HEADER_LEN;
+    offset += crate::decompress(n, compressed_bit_length, &input[offset..], out);
+
+    if num_exceptions == 0 {
+        return offset;
+    }
+
+    let positions_len = uint8::compressed_size(position_bit_length as usize, num_exceptions);
+    let high_bits_len = compressed_size(high_bit_length as usize, num_exceptions);
+    assert!(
+        input.len() >= offset + positions_len + high_bits_len,
+        "input buffer is too small to hold the exception list",
+    );
+
+    // The exception list is not padded, so it is copied into a buffer large enough
+    // to safely decompress from.
+    let mut scratch = [0; X128_MAX_OUTPUT_LEN];
+
+    let mut positions = [0u8; X128];
+    scratch[..positions_len].copy_from_slice(&input[offset..][..positions_len]);
+    crate::decompress(
+        num_exceptions,
+        position_bit_length,
+        &scratch,
+        &mut positions,
+    );
+    offset += positions_len;
+
+    let mut high_bits = [0u32; X128];
+    scratch[..high_bits_len].copy_from_slice(&input[offset..][..high_bits_len]);
+    crate::decompress(num_exceptions, high_bit_length, &scratch, &mut high_bits);
+    offset += high_bits_len;
+
+    for (&position, &high) in positions.iter().zip(high_bits.iter()).take(num_exceptions) {
+        let position = position as usize;
+        assert!(position < n, "patched block exception position is corrupt");
+        // A 32-bit base leaves no room for high bits, which the header check limits to 0.
+        out[position] |= high.unbounded_shl(compressed_bit_length as u32);
+    }
+
+    offset
+}
+
+/// Select the base bit length which produces the smallest patched block for the
+/// first `n` values of the input.
+fn select_base_bit_length(n: usize, input: &[u32; X128]) -> usize {
+    let mut histogram = [0usize; 33];
+    for value in input.iter().take(n) {
+        histogram[32 - value.leading_zeros() as usize] += 1;
+    }
+
+    let max_bit_length = histogram.iter().rposition(|count| *count != 0).unwrap_or(0);
+
+    let mut best_bit_length = max_bit_length;
+    let mut best_cost = compressed_size(max_bit_length, n);
+    let mut num_exceptions = 0;
+    for bit_length in (0..max_bit_length).rev() {
+        num_exceptions += histogram[bit_length + 1];
+
+        let cost = compressed_size(bit_length, n)
+            + uint8::compressed_size(POSITION_BIT_LENGTH, num_exceptions)
+            + compressed_size(max_bit_length - bit_length, num_exceptions);
+        if cost < best_cost {
+            best_cost = cost;
+            best_bit_length = bit_length;
+        }
+    }
+
+    best_bit_length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case::one_exception(&[(77, 90876324)], 4)]
+    #[case::two_exception(&[(69, 3252352), (125, 874632124)], 4)]
+    #[case::many_exception(
+        &[(0, 874632124), (1, 252151), (7, 555), (69, 3234), (100, 522332525), (125, 874632124)],
+        4
+    )]
+    fn test_compress_and_decompress_exceptions(
+        #[case] exceptions: &[(usize, u32)],
+        #[case] expected_bit_length: u8,
+    ) {
+        let mut values = [8u32; X128];
+        for &(position, value) in exceptions {
+            values[position] = value;
+        }
+
+        let mut compressed = [0; X128_MAX_PATCHED_OUTPUT_LEN];
+        let details = compress_patched(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, expected_bit_length);
+
+        let mut plain = [0; X128_MAX_OUTPUT_LEN];
+        let plain_details = crate::compress(X128, &values, &mut plain);
+        assert!(details.bytes_written < plain_details.bytes_written);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = decompress_patched(
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed, values);
+    }
+
+    #[rstest::rstest]
+    #[case::all_zeroes(0)]
+    #[case::all_max(u32::MAX)]
+    fn test_compress_and_decompress_no_exceptions(#[case] value: u32) {
+        let values = [value; X128];
+
+        let mut compressed = [0; X128_MAX_PATCHED_OUTPUT_LEN];
+        let details = compress_patched(X128, &values, &mut compressed);
+        assert_eq!(compressed[0], 0, "no exceptions should be produced");
+
+        let mut decompressed = [0; X128];
+        let bytes_read = decompress_patched(
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(
+            bytes_read,
+            HEADER_LEN + compressed_size(details.compressed_bit_length as usize, X128)
+        );
+        assert_eq!(decompressed, values);
+    }
+
+    #[test]
+    fn test_compress_and_decompress_real_data_sample() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; X128_MAX_PATCHED_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for select_n in [1, 7, 64, 65, 100, X128] {
+            for sample in sample_data.iter() {
+                let details = compress_patched(select_n, sample, &mut compressed);
+                let read = decompress_patched(
+                    select_n,
+                    details.compressed_bit_length,
+                    &compressed,
+                    &mut decompressed,
+                );
+                assert_eq!(details.bytes_written, read);
+                assert_eq!(
+                    sample[..select_n],
+                    decompressed[..select_n],
+                    "select_n:{select_n}, details:{details:?}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exceptions_outside_n_are_ignored() {
+        let mut values = [3u32; X128];
+        values[100] = u32::MAX;
+
+        let mut compressed = [0; X128_MAX_PATCHED_OUTPUT_LEN];
+        let details = compress_patched(64, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 2);
+        assert_eq!(compressed[0], 0);
+
+        let mut decompressed = [0; X128];
+        decompress_patched(
+            64,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(decompressed[..64], values[..64]);
+    }
+
+    #[rstest::rstest]
+    #[should_panic(expected = "patched block header is corrupt")]
+    #[case::full_base(32, 1)]
+    #[should_panic(expected = "patched block header is corrupt")]
+    #[case::overlapping_high_bits(20, 13)]
+    fn test_corrupt_high_bit_length(#[case] base_bit_length: u8, #[case] high_bit_length: u8) {
+        let mut compressed = [0; X128_MAX_PATCHED_OUTPUT_LEN];
+        compressed[..HEADER_LEN].copy_from_slice(&[1, POSITION_BIT_LENGTH as u8, high_bit_length]);
+        decompress_patched(X128, base_bit_length, &compressed, &mut [0; X128]);
+    }
+}
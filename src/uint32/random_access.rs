@@ -0,0 +1,173 @@
+//! Random access to single values of a compressed block.
+//!
+//! Each [X64] half of a block is packed either in full, where the kernels are free to
+//! shuffle the elements across lanes, or partially with the element order maintained.
+//! The lane functions below map an element position to the lane it occupies within
+//! the unordered layouts.
+use crate::uint32::{compressed_size, max_compressed_size};
+use crate::util::{HalfLayout, unpack_lane};
+use crate::{X64, X128};
+
+/// Get the value at `index` of a compressed block without decompressing the rest of
+/// the block.
+///
+/// Only the bytes holding the bits of the selected value are read from the input, which
+/// is considerably cheaper than [crate::decompress] when only a few values of the block
+/// are needed, e.g. when probing skip lists.
+///
+/// - `nbits` should be the bit length of the compressed block as reported by the
+///   [crate::CompressionDetails] after compressing the block.
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `index` is the position of the element to read and must be less than `n`.
+///
+/// Unlike [crate::decompress], the input does not need to be padded, it only needs to hold
+/// the `compressed_size(nbits, n)` bytes of the block.
+///
+/// The returned value is the value as it was packed, blocks compressed with one of the
+/// delta modes will return the delta rather than the original value.
+pub fn get(nbits: u8, n: usize, input: &[u8], index: usize) -> u32 {
+    assert!(nbits <= 32, "compressed bitlength must be no more than 32");
+    assert!(n <= X128, "provided n is is greater than 128");
+    assert!(
+        index < n,
+        "provided index is out of bounds of the compressed block"
+    );
+    let nbits = nbits as usize;
+    assert!(
+        input.len() >= compressed_size(nbits, n),
+        "input buffer is too small to hold the compressed block",
+    );
+
+    let (half, position, pack_n) = if index < X64 {
+        (input, index, n.min(X64))
+    } else {
+        (
+            &input[max_compressed_size::<X64>(nbits)..],
+            index - X64,
+            n - X64,
+        )
+    };
+
+    let is_full = (index < X64 && n > X64) || n == X128;
+    let (layout, u8_lane, u16_lane, split_lane) = if is_full {
+        let u16_lane = unordered_u16_lane(position);
+        let split_lane = unordered_split_lane(u16_lane);
+        let u8_lane = unordered_u8_lane(position);
+        (HalfLayout::Unordered, u8_lane, u16_lane, split_lane)
+    } else {
+        let layout = HalfLayout::Ordered { pack_n };
+        (layout, position, position, position)
+    };
+    let stride = layout.lane_stride();
+
+    let read_u16 = |lane: usize| u16::from_le_bytes([half[lane * 2], half[lane * 2 + 1]]) as u32;
+    match nbits {
+        0 => 0,
+        1..=8 => unpack_lane(half, nbits, u8_lane, layout) as u32,
+        9..=15 => {
+            let hi = unpack_lane(&half[stride..], nbits - 8, split_lane, layout) as u32;
+            half[split_lane] as u32 | (hi << 8)
+        },
+        16 => read_u16(u16_lane),
+        17..=24 => {
+            let hi = unpack_lane(&half[stride * 2..], nbits - 16, u8_lane, layout) as u32;
+            read_u16(u16_lane) | (hi << 16)
+        },
+        25..=31 => {
+            let mid = half[stride * 2 + split_lane] as u32;
+            let hi = unpack_lane(&half[stride * 3..], nbits - 24, split_lane, layout) as u32;
+            read_u16(u16_lane) | (mid << 16) | (hi << 24)
+        },
+        _ => u32::from_le_bytes(half[position * 4..][..4].try_into().unwrap()),
+    }
+}
+
+/// Returns the lane of the element at `position` after packing the half to 8-bit
+/// elements, the inverse of `PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT`.
+const fn unordered_u8_lane(position: usize) -> usize {
+    const GROUP_OFFSETS: [usize; 4] = [0, 2, 1, 3];
+    let group = GROUP_OFFSETS[position / 16];
+    let within_group = position % 16;
+    (within_group / 8) * 32 + (within_group % 8) * 4 + group
+}
+
+/// Returns the lane of the element at `position` after packing the half to 16-bit
+/// elements, the inverse of `PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT`.
+const fn unordered_u16_lane(position: usize) -> usize {
+    let within_half = position % 32;
+    let register = (position / 32) * 2 + (within_half % 16) / 8;
+    register * 16 + (within_half % 8) * 2 + within_half / 16
+}
+
+/// Returns the 8-bit lane a 16-bit `lane` is moved to when splitting the 16-bit
+/// elements into their high and low bytes, the inverse of
+/// `PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT`.
+const fn unordered_split_lane(lane: usize) -> usize {
+    let within_half = lane % 32;
+    (within_half / 16) * 32 + (within_half % 16) * 2 + lane / 32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+    use crate::uint32::test_util::{
+        PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT,
+        PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT,
+        PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT,
+        load_uint32_regression_layout,
+    };
+
+    #[test]
+    fn test_unordered_lanes_match_layout() {
+        for position in 0..X64 {
+            let lane = unordered_u8_lane(position);
+            assert_eq!(
+                PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT[lane] as usize,
+                position
+            );
+
+            let lane = unordered_u16_lane(position);
+            assert_eq!(
+                PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT[lane] as usize,
+                position
+            );
+
+            let split_lane = unordered_split_lane(position);
+            assert_eq!(
+                PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT[split_lane] as usize,
+                position
+            );
+        }
+    }
+
+    #[test]
+    fn test_v1_layout_regression() {
+        let tester = load_uint32_regression_layout();
+
+        let mut padded = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        for (len, bit_len, _, input) in tester.iter_tests() {
+            padded[..input.len()].copy_from_slice(input);
+            crate::decompress(len, bit_len, &padded, &mut decompressed);
+
+            for (index, expected) in decompressed[..len].iter().enumerate() {
+                assert_eq!(
+                    get(bit_len, len, input, index),
+                    *expected,
+                    "value does not match decompress, length:{len} bit_len:{bit_len} index:{index}",
+                );
+            }
+        }
+    }
+
+    #[rstest::rstest]
+    #[should_panic(expected = "provided index is out of bounds of the compressed block")]
+    #[case(64, 64)]
+    #[should_panic(expected = "input buffer is too small to hold the compressed block")]
+    #[case(128, 0)]
+    fn test_get_invalid_args(#[case] n: usize, #[case] index: usize) {
+        let input = [0; 64 * 4];
+        get(32, n, &input, index);
+    }
+}
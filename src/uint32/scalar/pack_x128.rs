@@ -1,6 +1,8 @@
 use super::data::load_u32x64;
+use super::util::{sub_reference, zigzag_encode};
 use super::{pack_x64_full, pack_x64_partial};
 use crate::uint32::{max_compressed_size, split_block};
+use crate::util::as_unsigned_i32;
 use crate::{X64, X128};
 
 #[inline]
@@ -11,25 +13,36 @@ use crate::{X64, X128};
 /// - `nbits` must be between 0 and 32.
 /// - `pack_n` must be no greater than 128.
 pub unsafe fn to_nbits(nbits: usize, out: *mut u8, block: &[u32; X128], pack_n: usize) {
+    unsafe { to_nbits_halves(nbits, out, split_block(block), pack_n) };
+}
+
+#[inline]
+/// Bitpack the two x64 halves of a block of integers to `nbits` bit length elements.
+///
+/// The right half is only read when `pack_n` is greater than 64.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_halves(nbits: usize, out: *mut u8, halves: [&[u32; X64]; 2], pack_n: usize) {
     debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
     debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
     #[allow(clippy::type_complexity)]
-    const LUT: [unsafe fn(out: *mut u8, &[u32; X128], usize); 33] = [
+    const LUT: [unsafe fn(out: *mut u8, [&[u32; X64]; 2], usize); 33] = [
         to_u0, to_u1, to_u2, to_u3, to_u4, to_u5, to_u6, to_u7, to_u8, to_u9, to_u10, to_u11,
         to_u12, to_u13, to_u14, to_u15, to_u16, to_u17, to_u18, to_u19, to_u20, to_u21, to_u22,
         to_u23, to_u24, to_u25, to_u26, to_u27, to_u28, to_u29, to_u30, to_u31, to_u32,
     ];
     let func = unsafe { LUT.get_unchecked(nbits) };
-    unsafe { func(out, block, pack_n) };
+    unsafe { func(out, halves, pack_n) };
 }
 
-unsafe fn to_u0(_out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+unsafe fn to_u0(_out: *mut u8, _halves: [&[u32; X64]; 2], _pack_n: usize) {}
 
 macro_rules! define_x128_packer {
     ($func_name:ident, $bit_length:expr) => {
-        unsafe fn $func_name(out: *mut u8, block: &[u32; X128], pack_n: usize) {
-            let [left, right] = split_block(block);
-
+        unsafe fn $func_name(out: *mut u8, [left, right]: [&[u32; X64]; 2], pack_n: usize) {
             let left = load_u32x64(left);
             if pack_n <= 64 {
                 unsafe { pack_x64_partial::$func_name(out.add(0), left, pack_n) };
@@ -90,6 +103,221 @@ define_x128_packer!(to_u30, 30);
 define_x128_packer!(to_u31, 31);
 define_x128_packer!(to_u32, 32);
 
+#[inline]
+/// Bitpack the provided block of signed integers to `nbits` bit length elements after
+/// applying ZigZag encoding.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits_zigzag(nbits: usize, out: *mut u8, block: &[i32; X128], pack_n: usize) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *mut u8, &[i32; X128], usize); 33] = [
+        to_u0_zigzag,
+        to_u1_zigzag,
+        to_u2_zigzag,
+        to_u3_zigzag,
+        to_u4_zigzag,
+        to_u5_zigzag,
+        to_u6_zigzag,
+        to_u7_zigzag,
+        to_u8_zigzag,
+        to_u9_zigzag,
+        to_u10_zigzag,
+        to_u11_zigzag,
+        to_u12_zigzag,
+        to_u13_zigzag,
+        to_u14_zigzag,
+        to_u15_zigzag,
+        to_u16_zigzag,
+        to_u17_zigzag,
+        to_u18_zigzag,
+        to_u19_zigzag,
+        to_u20_zigzag,
+        to_u21_zigzag,
+        to_u22_zigzag,
+        to_u23_zigzag,
+        to_u24_zigzag,
+        to_u25_zigzag,
+        to_u26_zigzag,
+        to_u27_zigzag,
+        to_u28_zigzag,
+        to_u29_zigzag,
+        to_u30_zigzag,
+        to_u31_zigzag,
+        to_u32_zigzag,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(out, block, pack_n) };
+}
+
+unsafe fn to_u0_zigzag(_out: *mut u8, _block: &[i32; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_zigzag {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(out: *mut u8, block: &[i32; X128], pack_n: usize) {
+            let [left, right] = split_block(as_unsigned_i32(block));
+
+            let left = zigzag_encode(load_u32x64(left));
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u32x64(right));
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = zigzag_encode(load_u32x64(right));
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_zigzag!(to_u1_zigzag, to_u1, 1);
+define_x128_packer_zigzag!(to_u2_zigzag, to_u2, 2);
+define_x128_packer_zigzag!(to_u3_zigzag, to_u3, 3);
+define_x128_packer_zigzag!(to_u4_zigzag, to_u4, 4);
+define_x128_packer_zigzag!(to_u5_zigzag, to_u5, 5);
+define_x128_packer_zigzag!(to_u6_zigzag, to_u6, 6);
+define_x128_packer_zigzag!(to_u7_zigzag, to_u7, 7);
+define_x128_packer_zigzag!(to_u8_zigzag, to_u8, 8);
+define_x128_packer_zigzag!(to_u9_zigzag, to_u9, 9);
+define_x128_packer_zigzag!(to_u10_zigzag, to_u10, 10);
+define_x128_packer_zigzag!(to_u11_zigzag, to_u11, 11);
+define_x128_packer_zigzag!(to_u12_zigzag, to_u12, 12);
+define_x128_packer_zigzag!(to_u13_zigzag, to_u13, 13);
+define_x128_packer_zigzag!(to_u14_zigzag, to_u14, 14);
+define_x128_packer_zigzag!(to_u15_zigzag, to_u15, 15);
+define_x128_packer_zigzag!(to_u16_zigzag, to_u16, 16);
+define_x128_packer_zigzag!(to_u17_zigzag, to_u17, 17);
+define_x128_packer_zigzag!(to_u18_zigzag, to_u18, 18);
+define_x128_packer_zigzag!(to_u19_zigzag, to_u19, 19);
+define_x128_packer_zigzag!(to_u20_zigzag, to_u20, 20);
+define_x128_packer_zigzag!(to_u21_zigzag, to_u21, 21);
+define_x128_packer_zigzag!(to_u22_zigzag, to_u22, 22);
+define_x128_packer_zigzag!(to_u23_zigzag, to_u23, 23);
+define_x128_packer_zigzag!(to_u24_zigzag, to_u24, 24);
+define_x128_packer_zigzag!(to_u25_zigzag, to_u25, 25);
+define_x128_packer_zigzag!(to_u26_zigzag, to_u26, 26);
+define_x128_packer_zigzag!(to_u27_zigzag, to_u27, 27);
+define_x128_packer_zigzag!(to_u28_zigzag, to_u28, 28);
+define_x128_packer_zigzag!(to_u29_zigzag, to_u29, 29);
+define_x128_packer_zigzag!(to_u30_zigzag, to_u30, 30);
+define_x128_packer_zigzag!(to_u31_zigzag, to_u31, 31);
+define_x128_packer_zigzag!(to_u32_zigzag, to_u32, 32);
+
+#[inline]
+/// Bitpack the provided block of integers to `nbits` bit length elements after
+/// subtracting the `reference` value from each element.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - `nbits` must be between 0 and 32.
+/// - `pack_n` must be no greater than 128.
+/// - `reference` must be no greater than any of the first `pack_n` elements.
+pub unsafe fn to_nbits_for(
+    nbits: usize,
+    reference: u32,
+    out: *mut u8,
+    block: &[u32; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 32, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u32, out: *mut u8, &[u32; X128], usize); 33] = [
+        to_u0_for, to_u1_for, to_u2_for, to_u3_for, to_u4_for, to_u5_for, to_u6_for, to_u7_for,
+        to_u8_for, to_u9_for, to_u10_for, to_u11_for, to_u12_for, to_u13_for, to_u14_for,
+        to_u15_for, to_u16_for, to_u17_for, to_u18_for, to_u19_for, to_u20_for, to_u21_for,
+        to_u22_for, to_u23_for, to_u24_for, to_u25_for, to_u26_for, to_u27_for, to_u28_for,
+        to_u29_for, to_u30_for, to_u31_for, to_u32_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, out, block, pack_n) };
+}
+
+unsafe fn to_u0_for(_reference: u32, _out: *mut u8, _block: &[u32; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_for {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(reference: u32, out: *mut u8, block: &[u32; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let left = sub_reference(load_u32x64(left), reference);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u32x64(right), reference);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u32x64(right), reference);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_for!(to_u1_for, to_u1, 1);
+define_x128_packer_for!(to_u2_for, to_u2, 2);
+define_x128_packer_for!(to_u3_for, to_u3, 3);
+define_x128_packer_for!(to_u4_for, to_u4, 4);
+define_x128_packer_for!(to_u5_for, to_u5, 5);
+define_x128_packer_for!(to_u6_for, to_u6, 6);
+define_x128_packer_for!(to_u7_for, to_u7, 7);
+define_x128_packer_for!(to_u8_for, to_u8, 8);
+define_x128_packer_for!(to_u9_for, to_u9, 9);
+define_x128_packer_for!(to_u10_for, to_u10, 10);
+define_x128_packer_for!(to_u11_for, to_u11, 11);
+define_x128_packer_for!(to_u12_for, to_u12, 12);
+define_x128_packer_for!(to_u13_for, to_u13, 13);
+define_x128_packer_for!(to_u14_for, to_u14, 14);
+define_x128_packer_for!(to_u15_for, to_u15, 15);
+define_x128_packer_for!(to_u16_for, to_u16, 16);
+define_x128_packer_for!(to_u17_for, to_u17, 17);
+define_x128_packer_for!(to_u18_for, to_u18, 18);
+define_x128_packer_for!(to_u19_for, to_u19, 19);
+define_x128_packer_for!(to_u20_for, to_u20, 20);
+define_x128_packer_for!(to_u21_for, to_u21, 21);
+define_x128_packer_for!(to_u22_for, to_u22, 22);
+define_x128_packer_for!(to_u23_for, to_u23, 23);
+define_x128_packer_for!(to_u24_for, to_u24, 24);
+define_x128_packer_for!(to_u25_for, to_u25, 25);
+define_x128_packer_for!(to_u26_for, to_u26, 26);
+define_x128_packer_for!(to_u27_for, to_u27, 27);
+define_x128_packer_for!(to_u28_for, to_u28, 28);
+define_x128_packer_for!(to_u29_for, to_u29, 29);
+define_x128_packer_for!(to_u30_for, to_u30, 30);
+define_x128_packer_for!(to_u31_for, to_u31, 31);
+define_x128_packer_for!(to_u32_for, to_u32, 32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,7 +16,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [u32x8; 8]) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
     let [d1, d2] = data;
 
     let cmp1 = _scalar_slli_u8x32::<7>(d1);
@@ -27,7 +27,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 2-bit elements.
@@ -42,7 +42,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 2-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2]) {
     let packed = pack_u8_to_u2_unordered(data);
     unsafe { _scalar_store_u8x16(out.cast(), packed) };
 }
@@ -59,7 +59,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 3-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2]) {
     let mask = _scalar_set1_u8(0b11);
 
     let lo_2bit = and_u8x32(data, mask);
@@ -73,7 +73,7 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2]) {
     let hi_1bitmask2 = _scalar_mask_u8x32(hi_1bit2);
 
     let hi_merged_mask = ((hi_1bitmask2 as u64) << 32) | hi_1bitmask1 as u64;
-    unsafe { std::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(16).cast(), hi_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 4-bit elements.
@@ -88,7 +88,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 4-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u4_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: [u8x32; 2]) {
     let packed = pack_u8_to_u4_unordered(data);
     unsafe { _scalar_store_u8x32(out, packed) };
 }
@@ -105,7 +105,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 5-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u5_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: [u8x32; 2]) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -127,7 +127,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 6-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u6_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: [u8x32; 2]) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -151,7 +151,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [u32x8; 8]) {
 #[inline]
 /// Pack two registers containing 32 8-bit elements each into a 7-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u7_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: [u8x32; 2]) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
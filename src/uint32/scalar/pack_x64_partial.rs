@@ -13,7 +13,7 @@ pub(crate) unsafe fn to_u1(out: *mut u8, block: [u32x8; 8], _pack_n: usize) {
 /// bitmap and write to `out`.
 ///
 /// Any non-zero value will be treated as a set bit.
-unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
     let [d1, d2] = data;
 
     let cmp1 = _scalar_slli_u8x32::<7>(d1);
@@ -24,7 +24,7 @@ unsafe fn pack_u1_registers(out: *mut u8, data: [u8x32; 2]) {
 
     let merged_mask = ((mask2 as u64) << 32) | mask1 as u64;
     // We assume LE endianness
-    unsafe { std::ptr::write_unaligned(out.cast(), merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.cast(), merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 2-bit elements.
@@ -36,7 +36,7 @@ pub(crate) unsafe fn to_u2(out: *mut u8, block: [u32x8; 8], pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 2-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
+pub(crate) unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let [d1, d2] = data;
 
     let lo_cmp1 = _scalar_slli_u8x32::<7>(d1);
@@ -53,8 +53,8 @@ unsafe fn pack_u2_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let hi_merged_mask = ((hi_mask2 as u64) << 32) | hi_mask1 as u64;
 
     let hi_offset = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), lo_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(hi_offset).cast(), hi_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 3-bit elements.
@@ -66,7 +66,7 @@ pub(crate) unsafe fn to_u3(out: *mut u8, block: [u32x8; 8], pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 3-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
+pub(crate) unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let [d1, d2] = data;
 
     let b0_cmp1 = _scalar_slli_u8x32::<7>(d1);
@@ -88,9 +88,9 @@ unsafe fn pack_u3_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let b2_merged_mask = ((b2_mask2 as u64) << 32) | b2_mask1 as u64;
 
     let step = pack_n.div_ceil(8);
-    unsafe { std::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
-    unsafe { std::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(0).cast(), b0_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step).cast(), b1_merged_mask) };
+    unsafe { core::ptr::write_unaligned(out.add(step * 2).cast(), b2_merged_mask) };
 }
 
 /// Bitpack the provided block of integers to 4-bit elements.
@@ -102,7 +102,7 @@ pub(crate) unsafe fn to_u4(out: *mut u8, block: [u32x8; 8], _pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 4-bit
 /// bitmap and write to `out`.
-pub(super) unsafe fn pack_u4_registers(out: *mut u8, data: [u8x32; 2]) {
+pub(crate) unsafe fn pack_u4_registers(out: *mut u8, data: [u8x32; 2]) {
     let [d1, d2] = data;
 
     let mut packed = u8x32::ZERO;
@@ -126,7 +126,7 @@ pub(crate) unsafe fn to_u5(out: *mut u8, block: [u32x8; 8], pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 5-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u5_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
+pub(crate) unsafe fn pack_u5_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -146,7 +146,7 @@ pub(crate) unsafe fn to_u6(out: *mut u8, block: [u32x8; 8], pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 6-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u6_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
+pub(crate) unsafe fn pack_u6_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -166,7 +166,7 @@ pub(crate) unsafe fn to_u7(out: *mut u8, block: [u32x8; 8], pack_n: usize) {
 #[inline]
 /// Pack four registers containing 32 8-bit elements each into a 7-bit
 /// bitmap and write to `out`.
-unsafe fn pack_u7_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
+pub(crate) unsafe fn pack_u7_registers(out: *mut u8, data: [u8x32; 2], pack_n: usize) {
     let mask = _scalar_set1_u8(0b1111);
     let masked = and_u8x32(data, mask);
     unsafe { pack_u4_registers(out, masked) };
@@ -1,4 +1,4 @@
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 
 #[allow(non_camel_case_types)]
 #[repr(align(32))]
@@ -12,13 +12,13 @@ impl u32x8 {
 
 impl From<u16x16> for u32x8 {
     fn from(value: u16x16) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u8x32> for u32x8 {
     fn from(value: u8x32) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -48,13 +48,19 @@ impl u16x16 {
 
 impl From<u32x8> for u16x16 {
     fn from(value: u32x8) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u8x32> for u16x16 {
     fn from(value: u8x32) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
+    }
+}
+
+impl From<crate::uint16::scalar::polyfill::u16x16> for u16x16 {
+    fn from(value: crate::uint16::scalar::polyfill::u16x16) -> Self {
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -84,13 +90,13 @@ impl u8x32 {
 
 impl From<u32x8> for u8x32 {
     fn from(value: u32x8) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u16x16> for u8x32 {
     fn from(value: u16x16) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -116,13 +122,13 @@ pub(crate) struct u32x4([u32; 4]);
 
 impl From<u16x8> for u32x4 {
     fn from(value: u16x8) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u8x16> for u32x4 {
     fn from(value: u8x16) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -152,13 +158,13 @@ impl u16x8 {
 
 impl From<u32x4> for u16x8 {
     fn from(value: u32x4) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u8x16> for u16x8 {
     fn from(value: u8x16) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -188,13 +194,13 @@ impl u8x16 {
 
 impl From<u32x4> for u8x16 {
     fn from(value: u32x4) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
 impl From<u16x8> for u8x16 {
     fn from(value: u16x8) -> Self {
-        unsafe { std::mem::transmute(value) }
+        unsafe { core::mem::transmute(value) }
     }
 }
 
@@ -255,7 +261,7 @@ pub(crate) unsafe fn _scalar_load_u16x16(ptr: *const u16) -> u16x16 {
 /// The provided `ptr` must be safe to read `32` elements.
 pub(crate) unsafe fn _scalar_load_u8x32(ptr: *const u8) -> u8x32 {
     let mut block = u8x32::ZERO;
-    unsafe { std::ptr::copy_nonoverlapping(ptr, block.0.as_mut_ptr(), 32) };
+    unsafe { core::ptr::copy_nonoverlapping(ptr, block.0.as_mut_ptr(), 32) };
     block
 }
 
@@ -266,7 +272,7 @@ pub(crate) unsafe fn _scalar_load_u8x32(ptr: *const u8) -> u8x32 {
 /// The provided `ptr` must be safe to read `16` elements.
 pub(crate) unsafe fn _scalar_load_u8x16(ptr: *const u8) -> u8x16 {
     let mut block = u8x16::ZERO;
-    unsafe { std::ptr::copy_nonoverlapping(ptr, block.0.as_mut_ptr(), 16) };
+    unsafe { core::ptr::copy_nonoverlapping(ptr, block.0.as_mut_ptr(), 16) };
     block
 }
 
@@ -276,7 +282,7 @@ pub(crate) unsafe fn _scalar_load_u8x16(ptr: *const u8) -> u8x16 {
 /// # Safety
 /// The provided `ptr` must be safe to write `8` elements.
 pub(crate) unsafe fn _scalar_store_u32x8(ptr: *mut u32, reg: u32x8) {
-    unsafe { std::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 8) };
+    unsafe { core::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 8) };
 }
 
 #[inline]
@@ -285,7 +291,7 @@ pub(crate) unsafe fn _scalar_store_u32x8(ptr: *mut u32, reg: u32x8) {
 /// # Safety
 /// The provided `ptr` must be safe to write `16` elements.
 pub(crate) unsafe fn _scalar_store_u16x16(ptr: *mut u16, reg: u16x16) {
-    unsafe { std::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 16) };
+    unsafe { core::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 16) };
 }
 
 #[inline]
@@ -294,7 +300,7 @@ pub(crate) unsafe fn _scalar_store_u16x16(ptr: *mut u16, reg: u16x16) {
 /// # Safety
 /// The provided `ptr` must be safe to write `32` elements.
 pub(crate) unsafe fn _scalar_store_u8x32(ptr: *mut u8, reg: u8x32) {
-    unsafe { std::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 32) };
+    unsafe { core::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 32) };
 }
 
 #[inline]
@@ -303,7 +309,7 @@ pub(crate) unsafe fn _scalar_store_u8x32(ptr: *mut u8, reg: u8x32) {
 /// # Safety
 /// The provided `ptr` must be safe to write `16` elements.
 pub(crate) unsafe fn _scalar_store_u8x16(ptr: *mut u8, reg: u8x16) {
-    unsafe { std::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 16) };
+    unsafe { core::ptr::copy_nonoverlapping(reg.0.as_ptr(), ptr, 16) };
 }
 
 #[inline]
@@ -353,8 +359,8 @@ pub(crate) fn _scalar_cvteu8_u16x16(a: u16x16) -> u8x16 {
 pub(crate) fn _scalar_combine_u32x4(a: u32x4, b: u32x4) -> u32x8 {
     let mut block = u32x8::ZERO;
     let ptr = block.0.as_mut_ptr();
-    unsafe { std::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 4) };
-    unsafe { std::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(4), 4) };
+    unsafe { core::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 4) };
+    unsafe { core::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(4), 4) };
     block
 }
 
@@ -363,8 +369,8 @@ pub(crate) fn _scalar_combine_u32x4(a: u32x4, b: u32x4) -> u32x8 {
 pub(crate) fn _scalar_combine_u16x8(a: u16x8, b: u16x8) -> u16x16 {
     let mut block = u16x16::ZERO;
     let ptr = block.0.as_mut_ptr();
-    unsafe { std::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 8) };
-    unsafe { std::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(8), 8) };
+    unsafe { core::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 8) };
+    unsafe { core::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(8), 8) };
     block
 }
 
@@ -373,8 +379,8 @@ pub(crate) fn _scalar_combine_u16x8(a: u16x8, b: u16x8) -> u16x16 {
 pub(crate) fn _scalar_combine_u8x16(a: u8x16, b: u8x16) -> u8x32 {
     let mut block = u8x32::ZERO;
     let ptr = block.0.as_mut_ptr();
-    unsafe { std::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 16) };
-    unsafe { std::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(16), 16) };
+    unsafe { core::ptr::copy_nonoverlapping(a.0.as_ptr(), ptr.add(0), 16) };
+    unsafe { core::ptr::copy_nonoverlapping(b.0.as_ptr(), ptr.add(16), 16) };
     block
 }
 
@@ -483,7 +489,7 @@ pub(crate) fn _scalar_extract_u16x16<const HALF: usize>(a: u16x16) -> u16x8 {
     let offset = HALF * 8;
     let ptr = unsafe { a.0.as_ptr().add(offset) };
     let mut out = u16x8::ZERO;
-    unsafe { std::ptr::copy_nonoverlapping(ptr, out.0.as_mut_ptr(), 8) };
+    unsafe { core::ptr::copy_nonoverlapping(ptr, out.0.as_mut_ptr(), 8) };
     out
 }
 
@@ -496,7 +502,7 @@ pub(crate) fn _scalar_extract_u8x32<const HALF: usize>(a: u8x32) -> u8x16 {
     let offset = HALF * 16;
     let ptr = unsafe { a.0.as_ptr().add(offset) };
     let mut out = u8x16::ZERO;
-    unsafe { std::ptr::copy_nonoverlapping(ptr, out.0.as_mut_ptr(), 16) };
+    unsafe { core::ptr::copy_nonoverlapping(ptr, out.0.as_mut_ptr(), 16) };
     out
 }
 
@@ -506,12 +512,12 @@ pub(crate) fn _scalar_extract_u8x32<const HALF: usize>(a: u8x32) -> u8x16 {
 /// Return a bitmask taking the most significant bit from each lane in the register
 /// of `u8` values.
 pub(crate) fn _scalar_mask_u8x32(a: u8x32) -> u32 {
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
     let lo = _scalar_extract_u8x32::<0>(a);
     let hi = _scalar_extract_u8x32::<1>(a);
 
-    let lo_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(lo) };
-    let hi_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(hi) };
+    let lo_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(lo) };
+    let hi_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(hi) };
 
     // SAFETY: SSE2 is assumed by LLVM by default, but just in case we only enable via the cfg.
     let lo_mask = unsafe { _mm_movemask_epi8(lo_reg) } as u32;
@@ -548,24 +554,24 @@ pub(crate) fn _scalar_mov_maskz_u8x32(mask: u32, a: u8x32) -> u8x32 {
 // that being said, it didn't do a great job vectorizing even if it isn't unrolled.
 #[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
 pub(crate) fn _scalar_blend_every_other_u8(a: u8x32, b: u8x32) -> u8x32 {
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
     let lo_a = _scalar_extract_u8x32::<0>(a);
     let lo_b = _scalar_extract_u8x32::<0>(b);
     let hi_a = _scalar_extract_u8x32::<1>(a);
     let hi_b = _scalar_extract_u8x32::<1>(b);
 
-    let lo_a_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(lo_a) };
-    let lo_b_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(lo_b) };
-    let hi_a_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(hi_a) };
-    let hi_b_reg = unsafe { std::mem::transmute::<u8x16, __m128i>(hi_b) };
+    let lo_a_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(lo_a) };
+    let lo_b_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(lo_b) };
+    let hi_a_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(hi_a) };
+    let hi_b_reg = unsafe { core::mem::transmute::<u8x16, __m128i>(hi_b) };
 
     let mask = unsafe { _mm_set1_epi16(0xFF_00u16 as i16) };
 
     let r1_reg = unsafe { _mm_blendv_epi8(lo_a_reg, lo_b_reg, mask) };
     let r2_reg = unsafe { _mm_blendv_epi8(hi_a_reg, hi_b_reg, mask) };
 
-    let r1 = unsafe { std::mem::transmute::<__m128i, u8x16>(r1_reg) };
-    let r2 = unsafe { std::mem::transmute::<__m128i, u8x16>(r2_reg) };
+    let r1 = unsafe { core::mem::transmute::<__m128i, u8x16>(r1_reg) };
+    let r2 = unsafe { core::mem::transmute::<__m128i, u8x16>(r2_reg) };
 
     _scalar_combine_u8x16(r1, r2)
 }
@@ -581,22 +587,22 @@ pub(crate) fn _scalar_blend_every_other_u8(a: u8x32, b: u8x32) -> u8x32 {
 
 #[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
 pub(crate) fn _scalar_blend_every_other_u16(a: u16x16, b: u16x16) -> u16x16 {
-    use std::arch::x86_64::*;
+    use core::arch::x86_64::*;
     let lo_a = _scalar_extract_u16x16::<0>(a);
     let lo_b = _scalar_extract_u16x16::<0>(b);
     let hi_a = _scalar_extract_u16x16::<1>(a);
     let hi_b = _scalar_extract_u16x16::<1>(b);
 
-    let lo_a_reg = unsafe { std::mem::transmute::<u16x8, __m128i>(lo_a) };
-    let lo_b_reg = unsafe { std::mem::transmute::<u16x8, __m128i>(lo_b) };
-    let hi_a_reg = unsafe { std::mem::transmute::<u16x8, __m128i>(hi_a) };
-    let hi_b_reg = unsafe { std::mem::transmute::<u16x8, __m128i>(hi_b) };
+    let lo_a_reg = unsafe { core::mem::transmute::<u16x8, __m128i>(lo_a) };
+    let lo_b_reg = unsafe { core::mem::transmute::<u16x8, __m128i>(lo_b) };
+    let hi_a_reg = unsafe { core::mem::transmute::<u16x8, __m128i>(hi_a) };
+    let hi_b_reg = unsafe { core::mem::transmute::<u16x8, __m128i>(hi_b) };
 
     let r1_reg = unsafe { _mm_blend_epi16::<0b10101010>(lo_a_reg, lo_b_reg) };
     let r2_reg = unsafe { _mm_blend_epi16::<0b10101010>(hi_a_reg, hi_b_reg) };
 
-    let r1 = unsafe { std::mem::transmute::<__m128i, u16x8>(r1_reg) };
-    let r2 = unsafe { std::mem::transmute::<__m128i, u16x8>(r2_reg) };
+    let r1 = unsafe { core::mem::transmute::<__m128i, u16x8>(r1_reg) };
+    let r2 = unsafe { core::mem::transmute::<__m128i, u16x8>(r2_reg) };
 
     _scalar_combine_u16x8(r1, r2)
 }
@@ -0,0 +1,412 @@
+//! Unpacking blocks which were packed as narrower integers straight into 32-bit registers.
+//!
+//! The narrower unpacking routines produce registers holding the elements in order, which are
+//! widened before being stored so the output is written in a single pass.
+use super::data::*;
+use super::polyfill::{u16x16, u32x8};
+use super::util::{unpack_u8_to_u32_ordered, unpack_u16_to_u32_ordered};
+use crate::uint8::scalar::{unpack_x64_full as u8_full, unpack_x64_partial as u8_partial};
+use crate::uint16::scalar::polyfill::u16x16 as source_u16x16;
+use crate::uint16::scalar::{unpack_x64_full as u16_full, unpack_x64_partial as u16_partial};
+use crate::uint32::split_block_mut;
+use crate::{X64, X128, uint8, uint16};
+
+#[inline]
+/// Unpack a block of `nbits` bit length elements which were packed as 16-bit integers,
+/// widening them to 32-bit integers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 16.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_u16(nbits: usize, input: *const u8, out: &mut [u32; X128], read_n: usize) {
+    debug_assert!(nbits <= 16, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *const u8, &mut [u32; X128], usize); 17] = [
+        from_u0,
+        from_u1_u16,
+        from_u2_u16,
+        from_u3_u16,
+        from_u4_u16,
+        from_u5_u16,
+        from_u6_u16,
+        from_u7_u16,
+        from_u8_u16,
+        from_u9_u16,
+        from_u10_u16,
+        from_u11_u16,
+        from_u12_u16,
+        from_u13_u16,
+        from_u14_u16,
+        from_u15_u16,
+        from_u16_u16,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+#[inline]
+/// Unpack a block of `nbits` bit length elements which were packed as 8-bit integers,
+/// widening them to 32-bit integers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_u8(nbits: usize, input: *const u8, out: &mut [u32; X128], read_n: usize) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *const u8, &mut [u32; X128], usize); 9] = [
+        from_u0, from_u1_u8, from_u2_u8, from_u3_u8, from_u4_u8, from_u5_u8, from_u6_u8,
+        from_u7_u8, from_u8_u8,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+unsafe fn from_u0(_input: *const u8, out: &mut [u32; X128], _read_n: usize) {
+    out.fill(0);
+}
+
+/// Widen the registers produced by the 16-bit unpacking routines to 32-bit elements.
+fn widen_u16(data: [source_u16x16; 4]) -> [u32x8; 8] {
+    unpack_u16_to_u32_ordered(data.map(u16x16::from))
+}
+
+macro_rules! define_x128_unpacker_widen {
+    (
+        $func_name:ident,
+        $unpack_func_name:ident,
+        $bit_length:expr,
+        $source:ident,
+        $full:ident,
+        $partial:ident,
+        $widen_func_name:ident
+    ) => {
+        unsafe fn $func_name(input: *const u8, out: &mut [u32; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+            let offset = $source::max_compressed_size::<X64>($bit_length);
+
+            if read_n <= 64 {
+                let unpacked = unsafe { $partial::$unpack_func_name(input.add(0), read_n) };
+                store_u32x64(left, $widen_func_name(unpacked));
+            } else if read_n < 128 {
+                let unpacked = unsafe { $full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, $widen_func_name(unpacked));
+                let unpacked =
+                    unsafe { $partial::$unpack_func_name(input.add(offset), read_n - X64) };
+                store_u32x64(right, $widen_func_name(unpacked));
+            } else {
+                let unpacked = unsafe { $full::$unpack_func_name(input.add(0)) };
+                store_u32x64(left, $widen_func_name(unpacked));
+                let unpacked = unsafe { $full::$unpack_func_name(input.add(offset)) };
+                store_u32x64(right, $widen_func_name(unpacked));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_widen!(
+    from_u1_u16,
+    from_u1,
+    1,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u2_u16,
+    from_u2,
+    2,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u3_u16,
+    from_u3,
+    3,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u4_u16,
+    from_u4,
+    4,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u5_u16,
+    from_u5,
+    5,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u6_u16,
+    from_u6,
+    6,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u7_u16,
+    from_u7,
+    7,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u8_u16,
+    from_u8,
+    8,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u9_u16,
+    from_u9,
+    9,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u10_u16,
+    from_u10,
+    10,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u11_u16,
+    from_u11,
+    11,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u12_u16,
+    from_u12,
+    12,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u13_u16,
+    from_u13,
+    13,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u14_u16,
+    from_u14,
+    14,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u15_u16,
+    from_u15,
+    15,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+define_x128_unpacker_widen!(
+    from_u16_u16,
+    from_u16,
+    16,
+    uint16,
+    u16_full,
+    u16_partial,
+    widen_u16
+);
+
+define_x128_unpacker_widen!(
+    from_u1_u8,
+    from_u1,
+    1,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u2_u8,
+    from_u2,
+    2,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u3_u8,
+    from_u3,
+    3,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u4_u8,
+    from_u4,
+    4,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u5_u8,
+    from_u5,
+    5,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u6_u8,
+    from_u6,
+    6,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u7_u8,
+    from_u7,
+    7,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+define_x128_unpacker_widen!(
+    from_u8_u8,
+    from_u8,
+    8,
+    uint8,
+    u8_full,
+    u8_partial,
+    unpack_u8_to_u32_ordered
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(37)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_from_nbits_u16(#[case] read_n: usize) {
+        for nbits in 0..=16 {
+            let mask = (1u32 << nbits).wrapping_sub(1) as u16;
+            let values: [u16; X128] = std::array::from_fn(|i| {
+                if i % 7 == 0 {
+                    mask
+                } else {
+                    ((i as u32).wrapping_mul(2654435761) >> 11) as u16 & mask
+                }
+            });
+
+            let mut compressed = [0; crate::uint16::X128_MAX_OUTPUT_LEN];
+            let details = crate::compress(read_n, &values, &mut compressed);
+
+            let mut decompressed = [0; X128];
+            unsafe {
+                from_nbits_u16(
+                    details.compressed_bit_length as usize,
+                    compressed.as_ptr(),
+                    &mut decompressed,
+                    read_n,
+                )
+            };
+            let expected = values.map(u32::from);
+            assert_eq!(
+                decompressed[..read_n],
+                expected[..read_n],
+                "values do not match, read_n:{read_n} nbits:{nbits}"
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0)]
+    #[case::one(1)]
+    #[case::partial_left(37)]
+    #[case::full_left(64)]
+    #[case::partial_right(65)]
+    #[case::partial_right_large(100)]
+    #[case::full(X128)]
+    fn test_from_nbits_u8(#[case] read_n: usize) {
+        for nbits in 0..=8 {
+            let mask = (1u32 << nbits).wrapping_sub(1) as u8;
+            let values: [u8; X128] = std::array::from_fn(|i| {
+                if i % 7 == 0 {
+                    mask
+                } else {
+                    ((i as u32).wrapping_mul(2654435761) >> 11) as u8 & mask
+                }
+            });
+
+            let mut compressed = [0; crate::uint8::X128_MAX_OUTPUT_LEN];
+            let details = crate::compress(read_n, &values, &mut compressed);
+
+            let mut decompressed = [0; X128];
+            unsafe {
+                from_nbits_u8(
+                    details.compressed_bit_length as usize,
+                    compressed.as_ptr(),
+                    &mut decompressed,
+                    read_n,
+                )
+            };
+            let expected = values.map(u32::from);
+            assert_eq!(
+                decompressed[..read_n],
+                expected[..read_n],
+                "values do not match, read_n:{read_n} nbits:{nbits}"
+            );
+        }
+    }
+}
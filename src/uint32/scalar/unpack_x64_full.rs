@@ -14,8 +14,8 @@ pub unsafe fn from_u1(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 64 8-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
+    let mask: u64 = unsafe { core::ptr::read_unaligned(input.add(0).cast()) };
 
     let ones = _scalar_set1_u8(0b1);
     let packed1 = _scalar_mov_maskz_u8x32(mask as u32, ones);
@@ -34,7 +34,7 @@ pub unsafe fn from_u2(input: *const u8) -> [u32x8; 8] {
 
 /// Unpack eight registers containing 64 8-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8) -> [u8x32; 2] {
     let packed = unsafe { _scalar_load_u8x16(input) };
     unpack_u2_to_u8_unordered(packed)
 }
@@ -51,11 +51,11 @@ pub unsafe fn from_u3(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 64 8-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8) -> [u8x32; 2] {
     let packed_2bit = unsafe { _scalar_load_u8x16(input.add(0)) };
     let lo_2bits = unpack_u2_to_u8_unordered(packed_2bit);
 
-    let hi_bitmask: u64 = unsafe { std::ptr::read_unaligned(input.add(16).cast()) };
+    let hi_bitmask: u64 = unsafe { core::ptr::read_unaligned(input.add(16).cast()) };
 
     let ones = _scalar_set1_u8(0b100);
     let hi_1bits1 = _scalar_mov_maskz_u8x32(hi_bitmask as u32, ones);
@@ -76,7 +76,7 @@ pub unsafe fn from_u4(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
     let packed = unsafe { _scalar_load_u8x32(input) };
     unpack_u4_to_u8_unordered(packed)
 }
@@ -93,7 +93,7 @@ pub unsafe fn from_u5(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8) -> [u8x32; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u1_registers(input.add(32)) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
@@ -112,7 +112,7 @@ pub unsafe fn from_u6(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8) -> [u8x32; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(32)) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
@@ -131,7 +131,7 @@ pub unsafe fn from_u7(input: *const u8) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8) -> [u8x32; 2] {
     let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(32)) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
@@ -599,7 +599,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -657,7 +657,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr()) };
-        let unpacked = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 }
@@ -1,23 +1,24 @@
 use super::data::*;
 use super::polyfill::*;
 use super::util::*;
+use crate::util::read_partial;
 
 /// Unpack the 1-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+/// - `input` must be safe to read `compressed_size(1, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u1(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u1_registers(input) };
+    let packed = unsafe { unpack_u1_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 1-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
-    let mask: u64 = unsafe { std::ptr::read_unaligned(input.cast()) };
+pub(crate) unsafe fn unpack_u1_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+    let mask = u64::from_le_bytes(unsafe { read_partial(input, read_n.div_ceil(8)) });
 
     let ones = _scalar_set1_u8(1);
     let packed1 = _scalar_mov_maskz_u8x32(mask as u32, ones);
@@ -29,7 +30,7 @@ unsafe fn unpack_u1_registers(input: *const u8) -> [u8x32; 2] {
 /// Unpack the 2-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+/// - `input` must be safe to read `compressed_size(2, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -40,10 +41,10 @@ pub unsafe fn from_u2(input: *const u8, read_n: usize) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 2-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
-    let hi_offset = read_n.div_ceil(8);
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(hi_offset).cast()) };
+pub(crate) unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+    let step = read_n.div_ceil(8);
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
 
     let bit = _scalar_set1_u8(0b01);
     let lo_bits_packed1 = _scalar_mov_maskz_u8x32(mask1 as u32, bit);
@@ -61,7 +62,7 @@ unsafe fn unpack_u2_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 3-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+/// - `input` must be safe to read `compressed_size(3, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [u32x8; 8] {
     let packed = unsafe { unpack_u3_registers(input, read_n) };
@@ -71,12 +72,12 @@ pub unsafe fn from_u3(input: *const u8, read_n: usize) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 3-bit bitmap provided
 /// by `input`.
-unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let step = read_n.div_ceil(8);
 
-    let mask1: u64 = unsafe { std::ptr::read_unaligned(input.add(0).cast()) };
-    let mask2: u64 = unsafe { std::ptr::read_unaligned(input.add(step).cast()) };
-    let mask3: u64 = unsafe { std::ptr::read_unaligned(input.add(step * 2).cast()) };
+    let mask1 = u64::from_le_bytes(unsafe { read_partial(input.add(0), step) });
+    let mask2 = u64::from_le_bytes(unsafe { read_partial(input.add(step), step) });
+    let mask3 = u64::from_le_bytes(unsafe { read_partial(input.add(step * 2), step) });
 
     let bit = _scalar_set1_u8(0b001);
     let b0_bits_packed1 = _scalar_mov_maskz_u8x32(mask1 as u32, bit);
@@ -101,19 +102,20 @@ unsafe fn unpack_u3_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 4-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+/// - `input` must be safe to read `compressed_size(4, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u4(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { unpack_u4_registers(input) };
+    let packed = unsafe { unpack_u4_registers(input, read_n) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 4-bit nibbles provided
 /// by `input`.
-pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
-    let packed = unsafe { _scalar_load_u8x32(input.cast()) };
+pub(crate) unsafe fn unpack_u4_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+    let packed = unsafe { read_partial::<32>(input, read_n.div_ceil(2)) };
+    let packed = unsafe { _scalar_load_u8x32(packed.as_ptr().cast()) };
 
     let mut d1 = u8x32::ZERO;
     let mut d2 = u8x32::ZERO;
@@ -134,7 +136,7 @@ pub(super) unsafe fn unpack_u4_registers(input: *const u8) -> [u8x32; 2] {
 /// Unpack the 5-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+/// - `input` must be safe to read `compressed_size(5, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -145,10 +147,10 @@ pub unsafe fn from_u5(input: *const u8, read_n: usize) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 5-bit integers provided
 /// by `input`.
-unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
-    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
+    let mut hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
 }
@@ -156,7 +158,7 @@ unsafe fn unpack_u5_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 6-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+/// - `input` must be safe to read `compressed_size(6, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -167,9 +169,9 @@ pub unsafe fn from_u6(input: *const u8, read_n: usize) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 6-bit integers provided
 /// by `input`.
-unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u2_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
@@ -178,7 +180,7 @@ unsafe fn unpack_u6_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 7-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+/// - `input` must be safe to read `compressed_size(7, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
@@ -189,9 +191,9 @@ pub unsafe fn from_u7(input: *const u8, read_n: usize) -> [u32x8; 8] {
 #[inline]
 /// Unpack eight registers containing 8 32-bit elements from a 7-bit integers provided
 /// by `input`.
-unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
+pub(crate) unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
     let offset = read_n.div_ceil(2);
-    let lo_bits = unsafe { unpack_u4_registers(input.add(0)) };
+    let lo_bits = unsafe { unpack_u4_registers(input.add(0), read_n) };
     let mut hi_bits = unsafe { unpack_u3_registers(input.add(offset), read_n) };
     hi_bits = slli_u8x32::<4, 2>(hi_bits);
     or_u8x32_all(hi_bits, lo_bits)
@@ -200,26 +202,26 @@ unsafe fn unpack_u7_registers(input: *const u8, read_n: usize) -> [u8x32; 2] {
 /// Unpack the 8-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+/// - `input` must be safe to read `compressed_size(8, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u8(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_u8x32x2(input) };
+    let packed = unsafe { load_u8x32x2(read_partial::<64>(input, read_n).as_ptr()) };
     unpack_u8_to_u32_ordered(packed)
 }
 
 /// Unpack the 9-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(9)` bytes from.
+/// - `input` must be safe to read `compressed_size(9, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16x16::<8, 4>(hi_bits);
 
@@ -230,11 +232,11 @@ pub unsafe fn from_u9(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 10-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(10)` bytes from.
+/// - `input` must be safe to read `compressed_size(10, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -249,11 +251,11 @@ pub unsafe fn from_u10(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 11-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(11)` bytes from.
+/// - `input` must be safe to read `compressed_size(11, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -268,15 +270,15 @@ pub unsafe fn from_u11(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 12-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(12)` bytes from.
+/// - `input` must be safe to read `compressed_size(12, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u16_ordered(hi_bits);
     hi_bits = slli_u16x16::<8, 4>(hi_bits);
 
@@ -287,11 +289,11 @@ pub unsafe fn from_u12(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 13-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(13)` bytes from.
+/// - `input` must be safe to read `compressed_size(13, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -306,11 +308,11 @@ pub unsafe fn from_u13(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 14-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(14)` bytes from.
+/// - `input` must be safe to read `compressed_size(14, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -325,11 +327,11 @@ pub unsafe fn from_u14(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 15-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(15)` bytes from.
+/// - `input` must be safe to read `compressed_size(15, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u8x32x2(input.add(0)) };
+    let lo_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(0), read_n).as_ptr()) };
     let lo_bits = unpack_u8_to_u16_ordered(lo_bits);
 
     let offset = read_n;
@@ -344,26 +346,26 @@ pub unsafe fn from_u15(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 16-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(16)` bytes from.
+/// - `input` must be safe to read `compressed_size(16, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u16(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let packed = unsafe { load_u16x16x4(input.add(0)) };
+    let packed = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     unpack_u16_to_u32_ordered(packed)
 }
 
 /// Unpack the 17-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(17)` bytes from.
+/// - `input` must be safe to read `compressed_size(17, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32x8::<16, 8>(hi_bits);
 
@@ -373,11 +375,11 @@ pub unsafe fn from_u17(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 18-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(18)` bytes from.
+/// - `input` must be safe to read `compressed_size(18, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -391,11 +393,11 @@ pub unsafe fn from_u18(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 19-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(19)` bytes from.
+/// - `input` must be safe to read `compressed_size(19, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -409,15 +411,15 @@ pub unsafe fn from_u19(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 20-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(20)` bytes from.
+/// - `input` must be safe to read `compressed_size(20, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32x8::<16, 8>(hi_bits);
 
@@ -427,11 +429,11 @@ pub unsafe fn from_u20(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 21-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(21)` bytes from.
+/// - `input` must be safe to read `compressed_size(21, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -445,11 +447,11 @@ pub unsafe fn from_u21(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 22-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(22)` bytes from.
+/// - `input` must be safe to read `compressed_size(22, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -463,11 +465,11 @@ pub unsafe fn from_u22(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 23-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(23)` bytes from.
+/// - `input` must be safe to read `compressed_size(23, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
@@ -481,15 +483,15 @@ pub unsafe fn from_u23(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 24-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(24)` bytes from.
+/// - `input` must be safe to read `compressed_size(24, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let offset = read_n * 2;
-    let hi_bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let mut hi_bits = unpack_u8_to_u32_ordered(hi_bits);
     hi_bits = slli_u32x8::<16, 8>(hi_bits);
 
@@ -499,19 +501,19 @@ pub unsafe fn from_u24(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 25-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(25)` bytes from.
+/// - `input` must be safe to read `compressed_size(25, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u1_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_u16x16::<8, 4>(hi_1bits);
 
@@ -525,15 +527,15 @@ pub unsafe fn from_u25(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 26-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(26)` bytes from.
+/// - `input` must be safe to read `compressed_size(26, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -551,15 +553,15 @@ pub unsafe fn from_u26(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 27-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(27)` bytes from.
+/// - `input` must be safe to read `compressed_size(27, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -577,19 +579,19 @@ pub unsafe fn from_u27(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 28-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(28)` bytes from.
+/// - `input` must be safe to read `compressed_size(28, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
-    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset)) };
+    let hi_1bits = unsafe { unpack_u4_registers(input.add(offset), read_n) };
     let mut hi_1bits = unpack_u8_to_u16_ordered(hi_1bits);
     hi_1bits = slli_u16x16::<8, 4>(hi_1bits);
 
@@ -603,15 +605,15 @@ pub unsafe fn from_u28(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 29-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(29)` bytes from.
+/// - `input` must be safe to read `compressed_size(29, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -629,15 +631,15 @@ pub unsafe fn from_u29(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 30-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(30)` bytes from.
+/// - `input` must be safe to read `compressed_size(30, read_n)` bytes from.
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -655,14 +657,14 @@ pub unsafe fn from_u30(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// Unpack the 31-bit integers from the input pointer and return the result registers.
 ///
 /// # Safety
-/// - `input` must be safe to read `max_compressed_size::<X64>(31)` bytes from.
+/// - `input` must be safe to read `compressed_size(31, read_n)` bytes from.
 pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    let lo_bits = unsafe { load_u16x16x4(input.add(0)) };
+    let lo_bits = unsafe { load_u16x16x4(read_partial::<128>(input.add(0), read_n * 2).as_ptr()) };
     let lo_bits = unpack_u16_to_u32_ordered(lo_bits);
 
     let mut offset = read_n * 2;
-    let hi_8bits = unsafe { load_u8x32x2(input.add(offset)) };
+    let hi_8bits = unsafe { load_u8x32x2(read_partial::<64>(input.add(offset), read_n).as_ptr()) };
     let hi_8bits = unpack_u8_to_u16_ordered(hi_8bits);
 
     offset += read_n;
@@ -685,7 +687,7 @@ pub unsafe fn from_u31(input: *const u8, read_n: usize) -> [u32x8; 8] {
 /// - `read_n` must be between no larger than `64`.
 pub unsafe fn from_u32(input: *const u8, read_n: usize) -> [u32x8; 8] {
     debug_assert!(read_n <= 64, "read_n must be less than or equal to 64.");
-    unsafe { load_u32x8x8(input) }
+    unsafe { load_u32x8x8(read_partial::<256>(input, read_n * 4).as_ptr()) }
 }
 
 #[cfg(test)]
@@ -735,7 +737,7 @@ mod tests {
         let saturated_bytes = [u8::MAX; X128_MAX_OUTPUT_LEN / 2];
 
         let unpacked = unsafe { unpacker(saturated_bytes.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
 
         let expected_value = (2u64.pow(bit_len as u32) - 1) as u32;
         assert_eq!(unpacked, [expected_value; X64]);
@@ -793,7 +795,7 @@ mod tests {
         unsafe { packer(packed.as_mut_ptr(), data, X64) };
 
         let unpacked = unsafe { unpacker(packed.as_ptr(), X64) };
-        let unpacked = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let unpacked = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
         assert_eq!(unpacked, values);
     }
 
@@ -851,7 +853,7 @@ mod tests {
             packed[max_compressed_size::<X64>(bit_len as usize)..].fill(0);
 
             let unpacked = unsafe { unpacker(packed.as_ptr(), length) };
-            let unpacked = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+            let unpacked = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
             assert_eq!(unpacked[..length], values[..length], "length:{length}");
         }
     }
@@ -383,6 +383,80 @@ pub(super) fn slli_u32x8<const IMM8: u32, const N: usize>(mut data: [u32x8; N])
     data
 }
 
+/// ZigZag encode the signed 32-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [u32x8; 8]) -> [u32x8; 8] {
+    for i in 0..8 {
+        for j in 0..8 {
+            data[i][j] = crate::util::zigzag_encode_i32(data[i][j] as i32);
+        }
+    }
+    data
+}
+
+/// Reverse the ZigZag encoding of the 32-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [u32x8; 8]) -> [u32x8; 8] {
+    for i in 0..8 {
+        for j in 0..8 {
+            data[i][j] = crate::util::zigzag_decode_i32(data[i][j]) as u32;
+        }
+    }
+    data
+}
+
+/// Subtract the frame of reference from each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [u32x8; 8], reference: u32) -> [u32x8; 8] {
+    for i in 0..8 {
+        for j in 0..8 {
+            data[i][j] = data[i][j].wrapping_sub(reference);
+        }
+    }
+    data
+}
+
+/// Add the frame of reference back to each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [u32x8; 8], reference: u32) -> [u32x8; 8] {
+    for i in 0..8 {
+        for j in 0..8 {
+            data[i][j] = data[i][j].wrapping_add(reference);
+        }
+    }
+    data
+}
+
+/// Returns the sum of the first `n` 32-bit elements held in the provided registers.
+pub(super) fn sum(data: &[u32x8; 8], n: usize) -> u64 {
+    let mut acc = 0;
+    for i in 0..n {
+        acc += data[i / 8][i % 8] as u64;
+    }
+    acc
+}
+
+/// Returns the smallest and largest of the first `n` 32-bit elements held in the provided
+/// registers.
+pub(super) fn min_max(data: &[u32x8; 8], n: usize) -> (u32, u32) {
+    let mut min = u32::MAX;
+    let mut max = 0;
+    for i in 0..n {
+        min = min.min(data[i / 8][i % 8]);
+        max = max.max(data[i / 8][i % 8]);
+    }
+    (min, max)
+}
+
+/// Returns a bitmask of the 32-bit elements held in the provided registers which are within
+/// `lo..=lo + span`, the bit at position `i` being set when the `i`th element is selected.
+pub(super) fn select_range(data: &[u32x8; 8], lo: u32, span: u32) -> u64 {
+    let mut mask = 0;
+    for i in 0..64 {
+        let selected = data[i / 8][i % 8].wrapping_sub(lo) <= span;
+        mask |= (selected as u64) << i;
+    }
+    mask
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,84 +470,84 @@ mod tests {
 
     #[test]
     fn test_pack_u32_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u16_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_pack_u32_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u8_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_pack_u16_to_u8_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
         let packed = pack_u16_to_u8_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u8);
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u8_to_u16_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
         let unpacked = unpack_u8_to_u16_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u16);
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u16_to_u32_ordered() {
-        let input = std::array::from_fn(|i| i as u16);
+        let input = core::array::from_fn(|i| i as u16);
 
-        let data = unsafe { std::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
+        let data = unsafe { core::mem::transmute::<[u16; X64], [u16x16; 4]>(input) };
         let unpacked = unpack_u16_to_u32_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u32);
-        let view = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u8_to_u32_ordered() {
-        let input = std::array::from_fn(|i| i as u8);
+        let input = core::array::from_fn(|i| i as u8);
 
-        let data = unsafe { std::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
+        let data = unsafe { core::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
         let unpacked = unpack_u8_to_u32_ordered(data);
 
-        let expected = std::array::from_fn(|i| i as u32);
-        let view = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_pack_u32_to_u16_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u16_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
         assert_eq!(view[..8], [0, 16, 1, 17, 2, 18, 3, 19]);
         assert_eq!(view[8..][..8], [4, 20, 5, 21, 6, 22, 7, 23]);
         assert_eq!(view[16..][..8], [8, 24, 9, 25, 10, 26, 11, 27]);
@@ -481,12 +555,12 @@ mod tests {
 
     #[test]
     fn test_pack_u32_to_u8_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u8_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
         assert_eq!(view[..8], [0, 32, 16, 48, 1, 33, 17, 49]);
         assert_eq!(view[8..][..8], [2, 34, 18, 50, 3, 35, 19, 51]);
         assert_eq!(view[16..][..8], [4, 36, 20, 52, 5, 37, 21, 53]);
@@ -495,70 +569,106 @@ mod tests {
 
     #[test]
     fn test_unpack_u16_to_u32_unordered() {
-        let input = std::array::from_fn(|i| i as u32);
+        let input = core::array::from_fn(|i| i as u32);
 
-        let data = unsafe { std::mem::transmute::<[u32; X64], [u32x8; 8]>(input) };
+        let data = unsafe { core::mem::transmute::<[u32; X64], [u32x8; 8]>(input) };
         let packed = pack_u32_to_u16_unordered(data);
         let unpacked = unpack_u16_to_u32_unordered(packed);
 
-        let view = unsafe { std::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(unpacked) };
         assert_eq!(view, input);
     }
 
     #[test]
     fn test_unpack_u8_to_u16_unordered_layout() {
-        let expected: [u16; X64] = std::array::from_fn(|i| i as u16);
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
 
         let data = unsafe { load_u8x32x2(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
         let unpacked = unpack_u8_to_u16_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_pack_u32_to_u16_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u16_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[u16x16; 4], [u16; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT);
     }
 
     #[test]
     fn test_pack_u32_to_u8_unordered_layout() {
-        let input: [u32; X64] = std::array::from_fn(|i| i as u32);
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
 
         let data = load_u32x64(&input);
         let packed = pack_u32_to_u8_unordered(data);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(packed) };
         assert_eq!(view, PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT);
     }
 
     #[test]
     fn test_unpack_u4_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 16);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
 
         let data = unsafe { load_u8x32x2(expected.as_ptr()) };
         let packed = pack_u8_to_u4_unordered(data);
         let unpacked = unpack_u4_to_u8_unordered(packed);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
         assert_eq!(view, expected);
     }
 
     #[test]
     fn test_unpack_u2_to_u8_unordered() {
-        let expected: [u8; X64] = std::array::from_fn(|i| i as u8 % 4);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
 
         let data = unsafe { load_u8x32x2(expected.as_ptr()) };
         let packed = pack_u8_to_u2_unordered(data);
         let unpacked = unpack_u2_to_u8_unordered(packed);
 
-        let view = unsafe { std::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    fn test_zigzag_encode_decode() {
+        let input: [i32; X64] = core::array::from_fn(|i| match i {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            2 => -1,
+            _ => (i as i32 - 32) * 101,
+        });
+        let expected: [u32; X64] = input.map(crate::util::zigzag_encode_i32);
+
+        let data = unsafe { core::mem::transmute::<[i32; X64], [u32x8; 8]>(input) };
+        let encoded = zigzag_encode(data);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = zigzag_decode(encoded);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [i32; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    fn test_sub_add_reference() {
+        let reference = u32::MAX - 80;
+        let input: [u32; X64] = core::array::from_fn(|i| reference + i as u32);
+        let expected: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [u32x8; 8]>(input) };
+        let subtracted = sub_reference(data, reference);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(subtracted) };
         assert_eq!(view, expected);
+
+        let added = add_reference(subtracted, reference);
+        let view = unsafe { core::mem::transmute::<[u32x8; 8], [u32; X64]>(added) };
+        assert_eq!(view, input);
     }
 }
@@ -0,0 +1,281 @@
+//! Fused decompression and search of Delta encoded blocks.
+//!
+//! Posting lists typically advance to the first document ID greater than or equal to a target,
+//! rather than decompressing a block and then scanning it in a separate pass, these routines
+//! compare the values while still held in registers after reversing the Delta encoding.
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+use crate::uint32::avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::uint32::avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+use crate::uint32::neon;
+#[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+use crate::uint32::sse41;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "simd128"
+))]
+use crate::uint32::wasm_simd128;
+use crate::uint32::{max_compressed_size, scalar};
+use crate::{Backend, X128};
+
+/// Decompress the input block containing the packed values, reverse the Delta encoding and
+/// then write the decompressed values to `out`, returning the position of the first value
+/// greater than or equal to `target`.
+///
+/// This requires that the values contained were originally compressed with
+/// [crate::compress_delta].
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// Returns `None` if none of the `n` values are greater than or equal to `target`.
+pub fn decompress_delta_seek(
+    initial_value: u32,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    out: &mut [u32; X128],
+    target: u32,
+) -> Option<usize> {
+    assert!(
+        compressed_bit_length <= 32,
+        "compressed bitlength must be no more than 32"
+    );
+    assert!(
+        input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe {
+            avx512::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe {
+            avx2::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe {
+            sse41::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe {
+            neon::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+        _ => unsafe {
+            scalar::unpack_delta_seek_x128(
+                compressed_bit_length,
+                initial_value,
+                target,
+                input,
+                out,
+                n,
+            )
+        },
+    }
+}
+
+/// Search the input block containing the packed values for the position of the first value
+/// greater than or equal to `target`, without writing the decompressed values out.
+///
+/// This requires that the values contained were originally compressed with
+/// [crate::compress_delta].
+///
+/// - `n` should be the number of elements that the compressed buffer holds.
+/// - `compressed_bit_length` should be the bit length of the compressed block values
+///   as reported by the [crate::CompressionDetails] after compressing the block.
+///
+/// - `known_last_value` is the last of the `n` values if the caller already has it, such as
+///   the [crate::CompressionDetails::last_value] of the block or a skip list entry. When it
+///   is smaller than `target` no value can match and nothing is unpacked.
+///
+/// Unpacking stops at the first half of the block holding a match, and halves whose last
+/// value is smaller than `target` are skipped without comparing their values.
+///
+/// Returns `None` if none of the `n` values are greater than or equal to `target`.
+pub fn search_delta(
+    initial_value: u32,
+    n: usize,
+    compressed_bit_length: u8,
+    input: &[u8],
+    target: u32,
+    known_last_value: Option<u32>,
+) -> Option<usize> {
+    assert!(
+        compressed_bit_length <= 32,
+        "compressed bitlength must be no more than 32"
+    );
+    assert!(
+        input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+        "input buffer is too small/incorrectly padded to safely decompress",
+    );
+    assert!(n <= X128, "provided n is is greater than 128",);
+
+    if known_last_value.is_some_and(|last_value| last_value < target) {
+        return None;
+    }
+
+    match Backend::detect() {
+        #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+        Backend::Avx512 => unsafe {
+            avx512::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+        Backend::Avx2 => unsafe {
+            avx2::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+        #[cfg(all(target_arch = "x86_64", feature = "sse41"))]
+        Backend::Sse41 => unsafe {
+            sse41::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+        #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+        Backend::Neon => unsafe {
+            neon::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "simd128"
+        ))]
+        Backend::WasmSimd128 => unsafe {
+            wasm_simd128::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+        _ => unsafe {
+            scalar::search_delta_x128(compressed_bit_length, initial_value, target, input, n)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint32::X128_MAX_OUTPUT_LEN;
+
+    #[test]
+    fn test_search_real_data_sample() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+        let mut seeked = [0; X128];
+        for select_n in [1, 7, 64, 65, 100, X128] {
+            for sample in sample_data.iter() {
+                let initial_value = sample[0].saturating_sub(1);
+                let mut block = *sample;
+                let details =
+                    crate::compress_delta(initial_value, select_n, &mut block, &mut compressed);
+                crate::decompress_delta(
+                    initial_value,
+                    select_n,
+                    details.compressed_bit_length,
+                    &compressed,
+                    &mut decompressed,
+                );
+
+                let targets = [
+                    0,
+                    sample[0],
+                    sample[select_n / 2],
+                    sample[select_n / 2] + 1,
+                    sample[select_n - 1],
+                    sample[select_n - 1] + 1,
+                ];
+                for target in targets {
+                    let expected = sample[..select_n].iter().position(|&v| v >= target);
+
+                    let position = decompress_delta_seek(
+                        initial_value,
+                        select_n,
+                        details.compressed_bit_length,
+                        &compressed,
+                        &mut seeked,
+                        target,
+                    );
+                    assert_eq!(position, expected, "select_n:{select_n}, target:{target}");
+                    assert_eq!(seeked[..select_n], decompressed[..select_n]);
+
+                    for known_last_value in [None, Some(details.last_value as u32)] {
+                        let position = search_delta(
+                            initial_value,
+                            select_n,
+                            details.compressed_bit_length,
+                            &compressed,
+                            target,
+                            known_last_value,
+                        );
+                        assert_eq!(position, expected, "select_n:{select_n}, target:{target}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_empty_block() {
+        let compressed = [0; X128_MAX_OUTPUT_LEN];
+        assert_eq!(search_delta(5, 0, 0, &compressed, 0, None), None);
+        assert_eq!(search_delta(5, 1, 0, &compressed, 5, None), Some(0));
+        assert_eq!(search_delta(5, 1, 0, &compressed, 6, None), None);
+    }
+
+    #[test]
+    fn test_search_known_last_value_skips_block() {
+        // Every value of the block is 20, the block is only skipped when the known last
+        // value is trusted without unpacking it.
+        let compressed = [0; X128_MAX_OUTPUT_LEN];
+        assert_eq!(search_delta(20, X128, 0, &compressed, 10, None), Some(0));
+        assert_eq!(search_delta(20, X128, 0, &compressed, 10, Some(9)), None);
+        assert_eq!(
+            search_delta(20, X128, 0, &compressed, 10, Some(20)),
+            Some(0)
+        );
+    }
+}
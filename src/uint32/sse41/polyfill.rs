@@ -0,0 +1,479 @@
+use core::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Broadcast the single 32-bit element across all lanes in the register.
+pub(super) fn _sse_set1_u32(value: u32) -> __m128i {
+    _mm_set1_epi32(value as i32)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Broadcast the single 16-bit element across all lanes in the register.
+pub(super) fn _sse_set1_u16(value: u16) -> __m128i {
+    _mm_set1_epi16(value as i16)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Broadcast the single 8-bit element across all lanes in the register.
+pub(super) fn _sse_set1_u8(value: u8) -> __m128i {
+    _mm_set1_epi8(value as i8)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Load 32-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `4` elements.
+pub(super) unsafe fn _sse_load_u32(ptr: *const u32) -> __m128i {
+    unsafe { _mm_loadu_si128(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Load 16-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `8` elements.
+pub(super) unsafe fn _sse_load_u16(ptr: *const u16) -> __m128i {
+    unsafe { _mm_loadu_si128(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Load 8-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `16` elements.
+pub(super) unsafe fn _sse_load_u8(ptr: *const u8) -> __m128i {
+    unsafe { _mm_loadu_si128(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Store 4, 32-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `4` elements.
+pub(super) unsafe fn _sse_store_u32(ptr: *mut u32, reg: __m128i) {
+    unsafe { _mm_storeu_si128(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Store 8, 16-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `8` elements.
+pub(super) unsafe fn _sse_store_u16(ptr: *mut u16, reg: __m128i) {
+    unsafe { _mm_storeu_si128(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Store 16, 8-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `16` elements.
+pub(super) unsafe fn _sse_store_u8(ptr: *mut u8, reg: __m128i) {
+    unsafe { _mm_storeu_si128(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Pack the two provided registers of 32-bit elements to 16-bit elements using truncation.
+pub(super) fn _sse_pack_u32(a: __m128i, b: __m128i) -> __m128i {
+    // The saturating pack only truncates once the upper half of each element is cleared.
+    let mask = _mm_set1_epi32(0xFFFF);
+    _mm_packus_epi32(_mm_and_si128(a, mask), _mm_and_si128(b, mask))
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Pack the two provided registers of 16-bit elements to 8-bit elements using truncation.
+pub(super) fn _sse_pack_u16x16(a: __m128i, b: __m128i) -> __m128i {
+    let mask = _mm_set1_epi16(0x00FF);
+    _mm_packus_epi16(_mm_and_si128(a, mask), _mm_and_si128(b, mask))
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Convert the provided 8-bit elements in register `a` to 16-bit integers via extension.
+pub(super) fn _sse_cvteu8_u16(a: __m128i) -> [__m128i; 2] {
+    [
+        _mm_cvtepu8_epi16(a),
+        _mm_cvtepu8_epi16(_mm_unpackhi_epi64(a, a)),
+    ]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Convert the provided 16-bit elements in register `a` to 32-bit integers via extension.
+pub(super) fn _sse_cvteu16_u32(a: __m128i) -> [__m128i; 2] {
+    [
+        _mm_cvtepu16_epi32(a),
+        _mm_cvtepu16_epi32(_mm_unpackhi_epi64(a, a)),
+    ]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Convert the provided 32-bit elements in registers `a` and `b` into a single register
+/// of 16-bit elements using truncation.
+pub(super) fn _sse_cvteu32_u16(a: __m128i, b: __m128i) -> __m128i {
+    _sse_pack_u32(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Convert the provided 16-bit elements in registers `a` and `b` into a single register
+/// of 8-bit elements using truncation.
+pub(super) fn _sse_cvteu16_u8(a: __m128i, b: __m128i) -> __m128i {
+    _sse_pack_u16x16(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _sse_and_u32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_and_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _sse_and_u16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_and_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _sse_and_u8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_and_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _sse_or_u32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_or_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _sse_or_u16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_or_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _sse_or_u8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_or_si128(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift right on the 32-bit elements of the provided register `a`.
+pub(super) fn _sse_srli_u32<const IMM8: i32>(a: __m128i) -> __m128i {
+    _mm_srli_epi32::<IMM8>(a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift right on the 16-bit elements of the provided register `a`.
+pub(super) fn _sse_srli_u16<const IMM8: i32>(a: __m128i) -> __m128i {
+    _mm_srli_epi16::<IMM8>(a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift right on the 8-bit elements of the provided register `a`.
+pub(super) fn _sse_srli_u8<const IMM8: i32>(a: __m128i) -> __m128i {
+    // There is no 8-bit shift, the bits shifted in from the neighbouring element are cleared.
+    let mask = _mm_set1_epi8((0xFFu8 >> IMM8) as i8);
+    _mm_and_si128(_mm_srli_epi16::<IMM8>(a), mask)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift left on the 32-bit elements of the provided register `a`.
+pub(super) fn _sse_slli_u32<const IMM8: i32>(a: __m128i) -> __m128i {
+    _mm_slli_epi32::<IMM8>(a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift left on the 16-bit elements of the provided register `a`.
+pub(super) fn _sse_slli_u16<const IMM8: i32>(a: __m128i) -> __m128i {
+    _mm_slli_epi16::<IMM8>(a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Perform a bitwise shift left on the 8-bit elements of the provided register `a`.
+pub(super) fn _sse_slli_u8<const IMM8: i32>(a: __m128i) -> __m128i {
+    let mask = _mm_set1_epi8((0xFFu8 << IMM8) as i8);
+    _mm_and_si128(_mm_slli_epi16::<IMM8>(a), mask)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Compare the unsigned 32-bit elements of `a` and `b`, setting every bit of the elements
+/// where `a` is greater than or equal to `b`.
+pub(super) fn _sse_cmpge_u32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_cmpeq_epi32(_mm_max_epu32(a, b), a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Compare the unsigned 32-bit elements of `a` and `b`, setting every bit of the elements
+/// where `a` is less than or equal to `b`.
+pub(super) fn _sse_cmple_u32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_cmpeq_epi32(_mm_min_epu32(a, b), a)
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Compare the unsigned 32-bit elements of `a` and `b`, setting every bit of the elements
+/// where `a` is less than `b`.
+pub(super) fn _sse_cmplt_u32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_xor_si128(_sse_cmpge_u32(a, b), _mm_set1_epi32(-1))
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Return a bitmask with a set bit indicating the 32-bit element at the same index has its
+/// most significant bit set.
+pub(super) fn _sse_movemask_u32(a: __m128i) -> u32 {
+    _mm_movemask_ps(_mm_castsi128_ps(a)) as u32
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Returns the largest of the 32-bit elements of the provided register.
+pub(super) fn _sse_reduce_max_u32(a: __m128i) -> u32 {
+    let a = _mm_max_epu32(a, _mm_shuffle_epi32::<0b01_00_11_10>(a));
+    let a = _mm_max_epu32(a, _mm_shuffle_epi32::<0b10_11_00_01>(a));
+    _mm_cvtsi128_si32(a) as u32
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Returns the smallest of the 32-bit elements of the provided register.
+pub(super) fn _sse_reduce_min_u32(a: __m128i) -> u32 {
+    let a = _mm_min_epu32(a, _mm_shuffle_epi32::<0b01_00_11_10>(a));
+    let a = _mm_min_epu32(a, _mm_shuffle_epi32::<0b10_11_00_01>(a));
+    _mm_cvtsi128_si32(a) as u32
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Return a bitmask with a set bit indicating the element at the same index is non-zero.
+pub fn _sse_nonzero_mask_u8(regs: [__m128i; 4]) -> u64 {
+    let zeroes = _mm_setzero_si128();
+    let mut mask = 0;
+    for (i, reg) in regs.into_iter().enumerate() {
+        let zero = _mm_movemask_epi8(_mm_cmpeq_epi8(reg, zeroes)) as u64;
+        mask |= (!zero & 0xFFFF) << (i * 16);
+    }
+    mask
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+/// Broadcast a u64 bitmask to 64 8-bit elements, where each element is set
+/// to the corresponding bit in the input mask.
+pub(super) fn _sse_mov_maskz_u8(mask: u64) -> [__m128i; 4] {
+    let mask = _mm_set1_epi64x(mask as i64);
+    let bits = _mm_set1_epi64x(0x8040_2010_0804_0201u64 as i64);
+    let ones = _mm_set1_epi8(1);
+
+    let s0 = _mm_shuffle_epi8(
+        mask,
+        _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1),
+    );
+    let s1 = _mm_shuffle_epi8(
+        mask,
+        _mm_setr_epi8(2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3),
+    );
+    let s2 = _mm_shuffle_epi8(
+        mask,
+        _mm_setr_epi8(4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5),
+    );
+    let s3 = _mm_shuffle_epi8(
+        mask,
+        _mm_setr_epi8(6, 6, 6, 6, 6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7),
+    );
+
+    let d1 = _mm_min_epu8(_mm_and_si128(s0, bits), ones);
+    let d2 = _mm_min_epu8(_mm_and_si128(s1, bits), ones);
+    let d3 = _mm_min_epu8(_mm_and_si128(s2, bits), ones);
+    let d4 = _mm_min_epu8(_mm_and_si128(s3, bits), ones);
+
+    [d1, d2, d3, d4]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn _sse_pack_nibbles(a: [__m128i; 2], b: [__m128i; 2]) -> [__m128i; 2] {
+    let mask = _mm_set1_epi8(0x0F);
+
+    let a_even = _sse_pack_u16x16(a[0], a[1]);
+    let a_odd = _mm_packus_epi16(_mm_srli_epi16::<8>(a[0]), _mm_srli_epi16::<8>(a[1]));
+    let packed1 = _mm_or_si128(_mm_and_si128(a_even, mask), _sse_slli_u8::<4>(a_odd));
+
+    let b_even = _sse_pack_u16x16(b[0], b[1]);
+    let b_odd = _mm_packus_epi16(_mm_srli_epi16::<8>(b[0]), _mm_srli_epi16::<8>(b[1]));
+    let packed2 = _mm_or_si128(_mm_and_si128(b_even, mask), _sse_slli_u8::<4>(b_odd));
+
+    [packed1, packed2]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn _sse_unpack_nibbles(packed: __m128i) -> [__m128i; 2] {
+    let mask = _mm_set1_epi8(0x0F);
+
+    let lo = _mm_and_si128(packed, mask); // low nibbles (even positions)
+    let hi = _sse_srli_u8::<4>(packed); // high nibbles (odd positions)
+    let d1 = _mm_unpacklo_epi8(lo, hi);
+    let d2 = _mm_unpackhi_epi8(lo, hi);
+
+    [d1, d2]
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn _sse_blend_every_other_u8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_blendv_epi8(b, a, _mm_set1_epi16(0x00FF))
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(super) fn _sse_blend_every_other_u16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_blend_epi16::<0b1010_1010>(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_pack_u32() {
+        let a = unsafe { _sse_set1_u32(0xFFFF_0004) };
+        let b = unsafe { _sse_set1_u32(2) };
+        let result = unsafe { _sse_pack_u32(a, b) };
+        let view = unsafe { core::mem::transmute::<__m128i, [u16; 8]>(result) };
+        assert_eq!(
+            view,
+            [
+                4, 4, 4, 4, // a_lo
+                2, 2, 2, 2, // b_lo
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_pack_u16() {
+        let a = unsafe { _sse_set1_u16(0xFF04) };
+        let b = unsafe { _sse_set1_u16(2) };
+        let result = unsafe { _sse_pack_u16x16(a, b) };
+        let view = unsafe { core::mem::transmute::<__m128i, [u8; 16]>(result) };
+        assert_eq!(
+            view,
+            [
+                4, 4, 4, 4, 4, 4, 4, 4, // a_lo
+                2, 2, 2, 2, 2, 2, 2, 2, // b_lo
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_cvteu16_u32() {
+        let a = unsafe { _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, -1) };
+        let result = unsafe { _sse_cvteu16_u32(a) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 2], [u32; 8]>(result) };
+        assert_eq!(view, [1, 2, 3, 4, 5, 6, 7, u16::MAX as u32]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_shift_u8() {
+        let a = unsafe { _sse_set1_u8(0b1011_0110) };
+        let view = unsafe { core::mem::transmute::<__m128i, [u8; 16]>(_sse_srli_u8::<2>(a)) };
+        assert_eq!(view, [0b0010_1101; 16]);
+        let view = unsafe { core::mem::transmute::<__m128i, [u8; 16]>(_sse_slli_u8::<2>(a)) };
+        assert_eq!(view, [0b1101_1000; 16]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_unsigned_compare_u32() {
+        let a = unsafe { _mm_setr_epi32(0, 5, u32::MAX as i32, 7) };
+        let b = unsafe { _mm_setr_epi32(1, 5, 3, u32::MAX as i32) };
+        assert_eq!(unsafe { _sse_movemask_u32(_sse_cmpge_u32(a, b)) }, 0b0110);
+        assert_eq!(unsafe { _sse_movemask_u32(_sse_cmple_u32(a, b)) }, 0b1011);
+        assert_eq!(unsafe { _sse_movemask_u32(_sse_cmplt_u32(a, b)) }, 0b1001);
+        assert_eq!(unsafe { _sse_reduce_max_u32(a) }, u32::MAX);
+        assert_eq!(unsafe { _sse_reduce_min_u32(b) }, 1);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_nonzero_mask_u8() {
+        let a = unsafe { _sse_set1_u8(0) };
+        assert_eq!(unsafe { _sse_nonzero_mask_u8([a, a, a, a]) }, 0);
+
+        let a = unsafe { _sse_set1_u8(u8::MAX) };
+        assert_eq!(unsafe { _sse_nonzero_mask_u8([a, a, a, a]) }, u64::MAX);
+
+        let mut bytes = [0u8; 64];
+        for i in (0..64).step_by(3) {
+            bytes[i] = i as u8 + 1;
+        }
+        let regs = unsafe { core::mem::transmute::<[u8; 64], [__m128i; 4]>(bytes) };
+        let expected = (0..64).step_by(3).fold(0u64, |mask, i| mask | (1 << i));
+        assert_eq!(unsafe { _sse_nonzero_mask_u8(regs) }, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_mov_maskz_u8() {
+        let result = unsafe { _sse_mov_maskz_u8(u64::MAX) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; 64]>(result) };
+        assert_eq!(view, [1; 64]);
+
+        let result = unsafe { _sse_mov_maskz_u8(0) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; 64]>(result) };
+        assert_eq!(view, [0; 64]);
+
+        let mask = 0xA5F0_0F5A_1234_8001;
+        let result = unsafe { _sse_mov_maskz_u8(mask) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 4], [u8; 64]>(result) };
+        for (i, value) in view.into_iter().enumerate() {
+            assert_eq!(value as u64, (mask >> i) & 1, "bit {i}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "sse4.1"), ignore)]
+    fn test_nibbles_round_trip() {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i % 16) as u8;
+        }
+        let regs = unsafe { core::mem::transmute::<[u8; 32], [__m128i; 2]>(bytes) };
+        let [packed, _] = unsafe { _sse_pack_nibbles(regs, regs) };
+        let unpacked = unsafe { _sse_unpack_nibbles(packed) };
+        let view = unsafe { core::mem::transmute::<[__m128i; 2], [u8; 32]>(unpacked) };
+        assert_eq!(view, bytes);
+    }
+}
@@ -0,0 +1,387 @@
+use core::arch::wasm32::*;
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Broadcast the single 32-bit element across all lanes in the register.
+pub(super) fn _wasm_set1_u32(value: u32) -> v128 {
+    u32x4_splat(value)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Broadcast the single 16-bit element across all lanes in the register.
+pub(super) fn _wasm_set1_u16(value: u16) -> v128 {
+    u16x8_splat(value)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Broadcast the single 8-bit element across all lanes in the register.
+pub(super) fn _wasm_set1_u8(value: u8) -> v128 {
+    u8x16_splat(value)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Load 32-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `4` elements.
+pub(super) unsafe fn _wasm_load_u32(ptr: *const u32) -> v128 {
+    unsafe { v128_load(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Load 16-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `8` elements.
+pub(super) unsafe fn _wasm_load_u16(ptr: *const u16) -> v128 {
+    unsafe { v128_load(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Load 8-bit elements from the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to read `16` elements.
+pub(super) unsafe fn _wasm_load_u8(ptr: *const u8) -> v128 {
+    unsafe { v128_load(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Store 4, 32-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `4` elements.
+pub(super) unsafe fn _wasm_store_u32(ptr: *mut u32, reg: v128) {
+    unsafe { v128_store(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Store 8, 16-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `8` elements.
+pub(super) unsafe fn _wasm_store_u16(ptr: *mut u16, reg: v128) {
+    unsafe { v128_store(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Store 16, 8-bit elements in the provided `ptr`.
+///
+/// # Safety
+/// The provided `ptr` must be safe to write `16` elements.
+pub(super) unsafe fn _wasm_store_u8(ptr: *mut u8, reg: v128) {
+    unsafe { v128_store(ptr.cast(), reg) };
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Pack the two provided registers of 32-bit elements to 16-bit elements using truncation.
+pub(super) fn _wasm_pack_u32(a: v128, b: v128) -> v128 {
+    i16x8_shuffle::<0, 2, 4, 6, 8, 10, 12, 14>(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Pack the two provided registers of 16-bit elements to 8-bit elements using truncation.
+pub(super) fn _wasm_pack_u16x16(a: v128, b: v128) -> v128 {
+    i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Convert the provided 8-bit elements in register `a` to 16-bit integers via extension.
+pub(super) fn _wasm_cvteu8_u16(a: v128) -> [v128; 2] {
+    [u16x8_extend_low_u8x16(a), u16x8_extend_high_u8x16(a)]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Convert the provided 16-bit elements in register `a` to 32-bit integers via extension.
+pub(super) fn _wasm_cvteu16_u32(a: v128) -> [v128; 2] {
+    [u32x4_extend_low_u16x8(a), u32x4_extend_high_u16x8(a)]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Convert the provided 32-bit elements in registers `a` and `b` into a single register
+/// of 16-bit elements using truncation.
+pub(super) fn _wasm_cvteu32_u16(a: v128, b: v128) -> v128 {
+    _wasm_pack_u32(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Convert the provided 16-bit elements in registers `a` and `b` into a single register
+/// of 8-bit elements using truncation.
+pub(super) fn _wasm_cvteu16_u8(a: v128, b: v128) -> v128 {
+    _wasm_pack_u16x16(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _wasm_and_u32(a: v128, b: v128) -> v128 {
+    v128_and(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _wasm_and_u16(a: v128, b: v128) -> v128 {
+    v128_and(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on the provided registers `a` and `b`.
+pub(super) fn _wasm_and_u8(a: v128, b: v128) -> v128 {
+    v128_and(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _wasm_or_u32(a: v128, b: v128) -> v128 {
+    v128_or(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _wasm_or_u16(a: v128, b: v128) -> v128 {
+    v128_or(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on the provided registers `a` and `b`.
+pub(super) fn _wasm_or_u8(a: v128, b: v128) -> v128 {
+    v128_or(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift right on the 32-bit elements of the provided register `a`.
+pub(super) fn _wasm_srli_u32<const IMM8: i32>(a: v128) -> v128 {
+    u32x4_shr(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift right on the 16-bit elements of the provided register `a`.
+pub(super) fn _wasm_srli_u16<const IMM8: i32>(a: v128) -> v128 {
+    u16x8_shr(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift right on the 8-bit elements of the provided register `a`.
+pub(super) fn _wasm_srli_u8<const IMM8: i32>(a: v128) -> v128 {
+    u8x16_shr(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift left on the 32-bit elements of the provided register `a`.
+pub(super) fn _wasm_slli_u32<const IMM8: i32>(a: v128) -> v128 {
+    u32x4_shl(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift left on the 16-bit elements of the provided register `a`.
+pub(super) fn _wasm_slli_u16<const IMM8: i32>(a: v128) -> v128 {
+    u16x8_shl(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise shift left on the 8-bit elements of the provided register `a`.
+pub(super) fn _wasm_slli_u8<const IMM8: i32>(a: v128) -> v128 {
+    u8x16_shl(a, IMM8 as u32)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Return a bitmask with a set bit indicating the element at the same index is non-zero.
+pub fn _wasm_nonzero_mask_u8(regs: [v128; 4]) -> u64 {
+    let zeroes = u8x16_splat(0);
+    let mut mask = 0;
+    for (i, reg) in regs.into_iter().enumerate() {
+        let nonzero = u8x16_bitmask(i8x16_ne(reg, zeroes)) as u64;
+        mask |= nonzero << (i * 16);
+    }
+    mask
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Broadcast a u64 bitmask to 64 8-bit elements, where each element is set
+/// to the corresponding bit in the input mask.
+pub(super) fn _wasm_mov_maskz_u8(mask: u64) -> [v128; 4] {
+    let mask = u64x2_splat(mask);
+    let bits = u8x16(1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128);
+    let ones = u8x16_splat(1);
+
+    let s0 = i8x16_shuffle::<0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1>(mask, mask);
+    let s1 = i8x16_shuffle::<2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3>(mask, mask);
+    let s2 = i8x16_shuffle::<4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5>(mask, mask);
+    let s3 = i8x16_shuffle::<6, 6, 6, 6, 6, 6, 6, 6, 7, 7, 7, 7, 7, 7, 7, 7>(mask, mask);
+
+    let d1 = u8x16_min(v128_and(s0, bits), ones);
+    let d2 = u8x16_min(v128_and(s1, bits), ones);
+    let d3 = u8x16_min(v128_and(s2, bits), ones);
+    let d4 = u8x16_min(v128_and(s3, bits), ones);
+
+    [d1, d2, d3, d4]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn _wasm_pack_nibbles(a: [v128; 2], b: [v128; 2]) -> [v128; 2] {
+    let mask = u8x16_splat(0x0F);
+
+    let a_even =
+        i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(a[0], a[1]);
+    let a_odd =
+        i8x16_shuffle::<1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31>(a[0], a[1]);
+    let packed1 = v128_or(v128_and(a_even, mask), u8x16_shl(a_odd, 4));
+
+    let b_even =
+        i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(b[0], b[1]);
+    let b_odd =
+        i8x16_shuffle::<1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31>(b[0], b[1]);
+    let packed2 = v128_or(v128_and(b_even, mask), u8x16_shl(b_odd, 4));
+
+    [packed1, packed2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn _wasm_unpack_nibbles(packed: v128) -> [v128; 2] {
+    let mask = u8x16_splat(0x0F);
+
+    let lo = v128_and(packed, mask); // low nibbles (even positions)
+    let hi = u8x16_shr(packed, 4); // high nibbles (odd positions)
+    let d1 = i8x16_shuffle::<0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23>(lo, hi);
+    let d2 = i8x16_shuffle::<8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31>(lo, hi);
+
+    [d1, d2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn _wasm_blend_every_other_u8(a: v128, b: v128) -> v128 {
+    v128_bitselect(a, b, u16x8_splat(0x00FF))
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn _wasm_blend_every_other_u16(a: v128, b: v128) -> v128 {
+    v128_bitselect(a, b, u32x4_splat(0x0000_FFFF))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32() {
+        let a = _wasm_set1_u32(4);
+        let b = _wasm_set1_u32(2);
+        let result = _wasm_pack_u32(a, b);
+        let view = unsafe { core::mem::transmute::<v128, [u16; 8]>(result) };
+        assert_eq!(
+            view,
+            [
+                4, 4, 4, 4, // a_lo
+                2, 2, 2, 2, // b_lo
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u16() {
+        let a = _wasm_set1_u16(4);
+        let b = _wasm_set1_u16(2);
+        let result = _wasm_pack_u16x16(a, b);
+        let view = unsafe { core::mem::transmute::<v128, [u8; 16]>(result) };
+        assert_eq!(
+            view,
+            [
+                4, 4, 4, 4, 4, 4, 4, 4, // a_lo
+                2, 2, 2, 2, 2, 2, 2, 2, // b_lo
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_cvteu16_u32() {
+        let a = u16x8(1, 2, 3, 4, 5, 6, 7, u16::MAX);
+        let result = _wasm_cvteu16_u32(a);
+        let view = unsafe { core::mem::transmute::<[v128; 2], [u32; 8]>(result) };
+        assert_eq!(view, [1, 2, 3, 4, 5, 6, 7, u16::MAX as u32]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_nonzero_mask_u8() {
+        let a = _wasm_set1_u8(0);
+        assert_eq!(_wasm_nonzero_mask_u8([a, a, a, a]), 0);
+
+        let a = _wasm_set1_u8(u8::MAX);
+        assert_eq!(_wasm_nonzero_mask_u8([a, a, a, a]), u64::MAX);
+
+        let mut bytes = [0u8; 64];
+        for i in (0..64).step_by(3) {
+            bytes[i] = i as u8 + 1;
+        }
+        let regs = unsafe { core::mem::transmute::<[u8; 64], [v128; 4]>(bytes) };
+        let expected = (0..64).step_by(3).fold(0u64, |mask, i| mask | (1 << i));
+        assert_eq!(_wasm_nonzero_mask_u8(regs), expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_mov_maskz_u8() {
+        let result = _wasm_mov_maskz_u8(u64::MAX);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; 64]>(result) };
+        assert_eq!(view, [1; 64]);
+
+        let result = _wasm_mov_maskz_u8(0);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; 64]>(result) };
+        assert_eq!(view, [0; 64]);
+
+        let mask = 0xA5F0_0F5A_1234_8001;
+        let result = _wasm_mov_maskz_u8(mask);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; 64]>(result) };
+        for (i, value) in view.into_iter().enumerate() {
+            assert_eq!(value as u64, (mask >> i) & 1, "bit {i}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_nibbles_round_trip() {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i % 16) as u8;
+        }
+        let regs = unsafe { core::mem::transmute::<[u8; 32], [v128; 2]>(bytes) };
+        let [packed, _] = _wasm_pack_nibbles(regs, regs);
+        let unpacked = _wasm_unpack_nibbles(packed);
+        let view = unsafe { core::mem::transmute::<[v128; 2], [u8; 32]>(unpacked) };
+        assert_eq!(view, bytes);
+    }
+}
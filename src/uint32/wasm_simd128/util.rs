@@ -0,0 +1,855 @@
+#![allow(clippy::needless_range_loop)]
+
+use core::arch::wasm32::*;
+
+use super::polyfill::*;
+
+#[target_feature(enable = "simd128")]
+/// Pack 8 sets of registers containing 32-bit elements and produce 2 registers holding
+/// 8-bit elements.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u32_to_u8_ordered(data: [v128; 16]) -> [v128; 4] {
+    let partially_packed = pack_u32_to_u16_ordered(data);
+    pack_u16_to_u8_ordered(partially_packed)
+}
+
+#[target_feature(enable = "simd128")]
+/// Pack 8 sets of registers containing 32-bit elements and produce 4 registers holding
+/// 16-bit elements.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u32_to_u16_ordered(data: [v128; 16]) -> [v128; 8] {
+    [
+        _wasm_cvteu32_u16(data[0], data[1]),
+        _wasm_cvteu32_u16(data[2], data[3]),
+        _wasm_cvteu32_u16(data[4], data[5]),
+        _wasm_cvteu32_u16(data[6], data[7]),
+        _wasm_cvteu32_u16(data[8], data[9]),
+        _wasm_cvteu32_u16(data[10], data[11]),
+        _wasm_cvteu32_u16(data[12], data[13]),
+        _wasm_cvteu32_u16(data[14], data[15]),
+    ]
+}
+
+#[target_feature(enable = "simd128")]
+/// Pack 4 sets of registers containing 16-bit elements and produce 2 registers holding
+/// 9-bit elements.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u16_to_u8_ordered(data: [v128; 8]) -> [v128; 4] {
+    [
+        _wasm_cvteu16_u8(data[0], data[1]),
+        _wasm_cvteu16_u8(data[2], data[3]),
+        _wasm_cvteu16_u8(data[4], data[5]),
+        _wasm_cvteu16_u8(data[6], data[7]),
+    ]
+}
+
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 8 registers holding
+/// 32-bit elements.
+pub(super) fn unpack_u8_to_u32_ordered(data: [v128; 4]) -> [v128; 16] {
+    let partially_unpacked = unpack_u8_to_u16_ordered(data);
+    unpack_u16_to_u32_ordered(partially_unpacked)
+}
+
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_ordered(data: [v128; 4]) -> [v128; 8] {
+    let [d1, d2] = _wasm_cvteu8_u16(data[0]);
+    let [d3, d4] = _wasm_cvteu8_u16(data[1]);
+    let [d5, d6] = _wasm_cvteu8_u16(data[2]);
+    let [d7, d8] = _wasm_cvteu8_u16(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[target_feature(enable = "simd128")]
+/// Unpack 4 sets of registers containing 16-bit elements and produce 8 registers holding
+/// 32-bit elements.
+pub(super) fn unpack_u16_to_u32_ordered(data: [v128; 8]) -> [v128; 16] {
+    let [d1, d2] = _wasm_cvteu16_u32(data[0]);
+    let [d3, d4] = _wasm_cvteu16_u32(data[1]);
+    let [d5, d6] = _wasm_cvteu16_u32(data[2]);
+    let [d7, d8] = _wasm_cvteu16_u32(data[3]);
+    let [d9, d10] = _wasm_cvteu16_u32(data[4]);
+    let [d11, d12] = _wasm_cvteu16_u32(data[5]);
+    let [d13, d14] = _wasm_cvteu16_u32(data[6]);
+    let [d15, d16] = _wasm_cvteu16_u32(data[7]);
+
+    [
+        d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12, d13, d14, d15, d16,
+    ]
+}
+
+#[target_feature(enable = "simd128")]
+/// Pack 8 sets of registers containing 32-bit elements and produce 2 registers holding
+/// 8-bit elements.
+///
+/// The order of elements are _not_ maintained.
+pub(super) fn pack_u32_to_u8_unordered(data: [v128; 16]) -> [v128; 4] {
+    let d1 = pack_u32_to_u8_unordered_mini_block(data[0], data[8], data[4], data[12]);
+    let d2 = pack_u32_to_u8_unordered_mini_block(data[1], data[9], data[5], data[13]);
+    let d3 = pack_u32_to_u8_unordered_mini_block(data[2], data[10], data[6], data[14]);
+    let d4 = pack_u32_to_u8_unordered_mini_block(data[3], data[11], data[7], data[15]);
+
+    [d1, d2, d3, d4]
+}
+
+#[target_feature(enable = "simd128")]
+fn pack_u32_to_u8_unordered_mini_block(a: v128, b: v128, c: v128, d: v128) -> v128 {
+    let shift_1 = a;
+    let shift_2 = _wasm_slli_u32::<8>(b);
+    let shift_3 = _wasm_slli_u32::<16>(c);
+    let shift_4 = _wasm_slli_u32::<24>(d);
+
+    let mut accumulator = shift_1;
+    accumulator = _wasm_or_u32(accumulator, shift_2);
+    accumulator = _wasm_or_u32(accumulator, shift_3);
+    accumulator = _wasm_or_u32(accumulator, shift_4);
+
+    accumulator
+}
+
+#[target_feature(enable = "simd128")]
+/// Pack 8 sets of registers containing 32-bit elements and produce 4 registers holding
+/// 16-bit elements.
+///
+/// The order of elements are _not_ maintained.
+pub(super) fn pack_u32_to_u16_unordered(data: [v128; 16]) -> [v128; 8] {
+    let d1 = _wasm_or_u32(data[0], _wasm_slli_u32::<16>(data[4]));
+    let d2 = _wasm_or_u32(data[1], _wasm_slli_u32::<16>(data[5]));
+    let d3 = _wasm_or_u32(data[2], _wasm_slli_u32::<16>(data[6]));
+    let d4 = _wasm_or_u32(data[3], _wasm_slli_u32::<16>(data[7]));
+
+    let d5 = _wasm_or_u32(data[8], _wasm_slli_u32::<16>(data[12]));
+    let d6 = _wasm_or_u32(data[9], _wasm_slli_u32::<16>(data[13]));
+    let d7 = _wasm_or_u32(data[10], _wasm_slli_u32::<16>(data[14]));
+    let d8 = _wasm_or_u32(data[11], _wasm_slli_u32::<16>(data[15]));
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 8 registers holding
+/// 32-bit elements.
+pub(super) fn unpack_u8_to_u32_unordered(data: [v128; 4]) -> [v128; 16] {
+    let partially_unpacked = unpack_u8_to_u16_unordered(data);
+    unpack_u16_to_u32_unordered(partially_unpacked)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack 2 sets of registers containing 8-bit elements and produce 4 registers holding
+/// 16-bit elements.
+pub(super) fn unpack_u8_to_u16_unordered(data: [v128; 4]) -> [v128; 8] {
+    let zeroes = _wasm_set1_u8(0);
+    let d1 = _wasm_blend_every_other_u8(data[0], zeroes);
+    let d2 = _wasm_blend_every_other_u8(data[1], zeroes);
+    let d3 = _wasm_blend_every_other_u8(data[2], zeroes);
+    let d4 = _wasm_blend_every_other_u8(data[3], zeroes);
+
+    let d5 = _wasm_srli_u16::<8>(data[0]);
+    let d6 = _wasm_srli_u16::<8>(data[1]);
+    let d7 = _wasm_srli_u16::<8>(data[2]);
+    let d8 = _wasm_srli_u16::<8>(data[3]);
+
+    [d1, d2, d3, d4, d5, d6, d7, d8]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Unpack 4 sets of registers containing 16-bit elements and produce 8 registers holding
+/// 32-bit elements.
+pub(super) fn unpack_u16_to_u32_unordered(data: [v128; 8]) -> [v128; 16] {
+    let zeroes = _wasm_set1_u16(0);
+    let d1 = _wasm_blend_every_other_u16(data[0], zeroes);
+    let d2 = _wasm_blend_every_other_u16(data[1], zeroes);
+    let d3 = _wasm_blend_every_other_u16(data[2], zeroes);
+    let d4 = _wasm_blend_every_other_u16(data[3], zeroes);
+
+    let d5 = _wasm_srli_u32::<16>(data[0]);
+    let d6 = _wasm_srli_u32::<16>(data[1]);
+    let d7 = _wasm_srli_u32::<16>(data[2]);
+    let d8 = _wasm_srli_u32::<16>(data[3]);
+
+    let d9 = _wasm_blend_every_other_u16(data[4], zeroes);
+    let d10 = _wasm_blend_every_other_u16(data[5], zeroes);
+    let d11 = _wasm_blend_every_other_u16(data[6], zeroes);
+    let d12 = _wasm_blend_every_other_u16(data[7], zeroes);
+
+    let d13 = _wasm_srli_u32::<16>(data[4]);
+    let d14 = _wasm_srli_u32::<16>(data[5]);
+    let d15 = _wasm_srli_u32::<16>(data[6]);
+    let d16 = _wasm_srli_u32::<16>(data[7]);
+
+    [
+        d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11, d12, d13, d14, d15, d16,
+    ]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Given 8 sets of 32-bit registers, pack them into 4 sets of 16-bit registers, then
+/// split each 16-bit element into a high and low halves, returning the 8-bit halves in separate
+/// registers in their high and low forms respectively.
+///
+/// The order of elements is maintained.
+pub(super) fn pack_u32_to_u16_split_ordered(data: [v128; 16]) -> ([v128; 4], [v128; 4]) {
+    let packed_u16 = pack_u32_to_u16_ordered(data);
+
+    let mask = _wasm_set1_u16(0x00FF);
+    let lo_8bits = and_u16(packed_u16, mask);
+    let hi_8bits = srli_u16::<8, 8>(packed_u16);
+
+    let packed_lo_8bits = pack_u16_to_u8_ordered(lo_8bits);
+    let packed_hi_8bits = pack_u16_to_u8_ordered(hi_8bits);
+
+    (packed_hi_8bits, packed_lo_8bits)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Given 8 sets of 32-bit registers, pack them into 4 sets of 16-bit registers, then
+/// split each 16-bit element into a high and low halves, returning the 8-bit halves in separate
+/// registers in their high and low forms respectively.
+///
+/// The order of elements are not maintained.
+pub(super) fn pack_u32_to_u16_split_unordered(data: [v128; 16]) -> ([v128; 4], [v128; 4]) {
+    let packed = pack_u32_to_u16_unordered(data);
+
+    let lo_mask = _wasm_set1_u16(0x00FF);
+    let hi_mask = _wasm_set1_u16(0xFF00);
+
+    let lo_8bits_1a = _wasm_slli_u16::<8>(packed[4]);
+    let lo_8bits_1b = _wasm_slli_u16::<8>(packed[5]);
+    let lo_8bits_1c = _wasm_slli_u16::<8>(packed[6]);
+    let lo_8bits_1d = _wasm_slli_u16::<8>(packed[7]);
+
+    let lo_8bits_2a = _wasm_and_u16(packed[0], lo_mask);
+    let lo_8bits_2b = _wasm_and_u16(packed[1], lo_mask);
+    let lo_8bits_2c = _wasm_and_u16(packed[2], lo_mask);
+    let lo_8bits_2d = _wasm_and_u16(packed[3], lo_mask);
+
+    let lo_8bits_a = _wasm_or_u16(lo_8bits_1a, lo_8bits_2a);
+    let lo_8bits_b = _wasm_or_u16(lo_8bits_1b, lo_8bits_2b);
+    let lo_8bits_c = _wasm_or_u16(lo_8bits_1c, lo_8bits_2c);
+    let lo_8bits_d = _wasm_or_u16(lo_8bits_1d, lo_8bits_2d);
+
+    let lo = [lo_8bits_a, lo_8bits_b, lo_8bits_c, lo_8bits_d];
+
+    let hi_8bits_1a = _wasm_srli_u16::<8>(packed[0]);
+    let hi_8bits_1b = _wasm_srli_u16::<8>(packed[1]);
+    let hi_8bits_1c = _wasm_srli_u16::<8>(packed[2]);
+    let hi_8bits_1d = _wasm_srli_u16::<8>(packed[3]);
+    let hi_8bits_2a = _wasm_and_u16(packed[4], hi_mask);
+    let hi_8bits_2b = _wasm_and_u16(packed[5], hi_mask);
+    let hi_8bits_2c = _wasm_and_u16(packed[6], hi_mask);
+    let hi_8bits_2d = _wasm_and_u16(packed[7], hi_mask);
+
+    let hi_8bits_a = _wasm_or_u16(hi_8bits_1a, hi_8bits_2a);
+    let hi_8bits_b = _wasm_or_u16(hi_8bits_1b, hi_8bits_2b);
+    let hi_8bits_c = _wasm_or_u16(hi_8bits_1c, hi_8bits_2c);
+    let hi_8bits_d = _wasm_or_u16(hi_8bits_1d, hi_8bits_2d);
+
+    let hi = [hi_8bits_a, hi_8bits_b, hi_8bits_c, hi_8bits_d];
+
+    (hi, lo)
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn pack_u8_to_u4_unordered(data: [v128; 4]) -> [v128; 2] {
+    let shifted_1 = _wasm_slli_u8::<4>(data[2]);
+    let shifted_2 = _wasm_slli_u8::<4>(data[3]);
+    let d1 = _wasm_or_u8(data[0], shifted_1);
+    let d2 = _wasm_or_u8(data[1], shifted_2);
+    [d1, d2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn unpack_u4_to_u8_unordered(data: [v128; 2]) -> [v128; 4] {
+    let mask = _wasm_set1_u8(0x0F);
+    let lo_4bits_1 = _wasm_and_u8(data[0], mask);
+    let lo_4bits_2 = _wasm_and_u8(data[1], mask);
+    let hi_4bits_1 = _wasm_srli_u8::<4>(data[0]);
+    let hi_4bits_2 = _wasm_srli_u8::<4>(data[1]);
+    [lo_4bits_1, lo_4bits_2, hi_4bits_1, hi_4bits_2]
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn pack_u8_to_u2_unordered(data: [v128; 4]) -> v128 {
+    let [lo, hi] = pack_u8_to_u4_unordered(data);
+    v128_or(lo, u8x16_shl(hi, 2))
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+pub(super) fn unpack_u2_to_u8_unordered(data: v128) -> [v128; 4] {
+    let mask = u8x16_splat(0b0011_0011);
+    let lo = v128_and(data, mask);
+    let hi = v128_and(u8x16_shr(data, 2), mask);
+    unpack_u4_to_u8_unordered([lo, hi])
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u32<const N: usize>(mut data: [v128; N], mask: v128) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_and_u32(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u16<const N: usize>(mut data: [v128; N], mask: v128) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_and_u16(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise AND on all provided registers with another broadcast register.
+pub(super) fn and_u8<const N: usize>(mut data: [v128; N], mask: v128) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_and_u8(data[i], mask);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u32_all<const N: usize>(mut a: [v128; N], b: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        a[i] = _wasm_or_u32(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u16_all<const N: usize>(mut a: [v128; N], b: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        a[i] = _wasm_or_u16(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Perform a bitwise OR on all provided registers with another broadcast register.
+pub(super) fn or_u8_all<const N: usize>(mut a: [v128; N], b: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        a[i] = _wasm_or_u8(a[i], b[i]);
+    }
+    a
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers right by [IMM8] in 8-bit lanes.
+pub(super) fn srli_u8<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_srli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers right by [IMM8] in 16-bit lanes.
+pub(super) fn srli_u16<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_srli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers right by [IMM8] in 32-bit lanes.
+pub(super) fn srli_u32<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_srli_u32::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers left by [IMM8] in 8-bit lanes.
+pub(super) fn slli_u8<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_slli_u8::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers left by [IMM8] in 16-bit lanes.
+pub(super) fn slli_u16<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_slli_u16::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[inline]
+#[target_feature(enable = "simd128")]
+/// Shift all registers left by [IMM8] in 32-bit lanes.
+pub(super) fn slli_u32<const IMM8: i32, const N: usize>(mut data: [v128; N]) -> [v128; N] {
+    for i in 0..N {
+        data[i] = _wasm_slli_u32::<IMM8>(data[i]);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// ZigZag encode the signed 32-bit elements held in the provided registers.
+pub(super) fn zigzag_encode(mut data: [v128; 16]) -> [v128; 16] {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        let v = data[i];
+        data[i] = v128_xor(u32x4_shl(v, 1), i32x4_shr(v, 31));
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Reverse the ZigZag encoding of the 32-bit elements held in the provided registers.
+pub(super) fn zigzag_decode(mut data: [v128; 16]) -> [v128; 16] {
+    let one = u32x4_splat(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        let v = data[i];
+        data[i] = v128_xor(u32x4_shr(v, 1), i32x4_neg(v128_and(v, one)));
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Subtract the frame of reference from each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [v128; 16], reference: u32) -> [v128; 16] {
+    let reference = u32x4_splat(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = u32x4_sub(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Add the frame of reference back to each of the 32-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [v128; 16], reference: u32) -> [v128; 16] {
+    let reference = u32x4_splat(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = u32x4_add(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the position of the first 32-bit element held in the provided registers which
+/// is greater than or equal to `target`.
+pub(super) fn find_first_ge(data: &[v128; 16], target: u32) -> Option<usize> {
+    let target = u32x4_splat(target);
+
+    for (i, register) in data.iter().enumerate() {
+        let mask = u32x4_bitmask(u32x4_ge(*register, target));
+        if mask != 0 {
+            return Some(i * 4 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the number of bits required to represent the largest of the first `n` 32-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[v128; 16], n: usize) -> u32 {
+    let limit = u32x4_splat(n as u32);
+    let step = u32x4_splat(4);
+    let mut positions = u32x4(0, 1, 2, 3);
+
+    let mut acc = u32x4_splat(0);
+    for register in data {
+        let selected = u32x4_lt(positions, limit);
+        acc = u32x4_max(acc, v128_and(selected, *register));
+        positions = u32x4_add(positions, step);
+    }
+
+    let max = u32x4_extract_lane::<0>(acc)
+        .max(u32x4_extract_lane::<1>(acc))
+        .max(u32x4_extract_lane::<2>(acc))
+        .max(u32x4_extract_lane::<3>(acc));
+    32 - max.leading_zeros()
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the sum of the first `n` 32-bit elements held in the provided registers.
+pub(super) fn sum(data: &[v128; 16], n: usize) -> u64 {
+    let limit = u32x4_splat(n as u32);
+    let step = u32x4_splat(4);
+    let mut positions = u32x4(0, 1, 2, 3);
+
+    // The elements are widened to 64-bit lanes before being added so the sum can not overflow.
+    let mut acc = u64x2_splat(0);
+    for register in data {
+        let selected = v128_and(u32x4_lt(positions, limit), *register);
+        acc = u64x2_add(acc, u64x2_extend_low_u32x4(selected));
+        acc = u64x2_add(acc, u64x2_extend_high_u32x4(selected));
+        positions = u32x4_add(positions, step);
+    }
+
+    u64x2_extract_lane::<0>(acc) + u64x2_extract_lane::<1>(acc)
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the smallest and largest of the first `n` 32-bit elements held in the provided
+/// registers.
+pub(super) fn min_max(data: &[v128; 16], n: usize) -> (u32, u32) {
+    let limit = u32x4_splat(n as u32);
+    let step = u32x4_splat(4);
+    let mut positions = u32x4(0, 1, 2, 3);
+
+    let mut min = u32x4_splat(u32::MAX);
+    let mut max = u32x4_splat(0);
+    for register in data {
+        let selected = u32x4_lt(positions, limit);
+        min = u32x4_min(min, v128_or(*register, v128_not(selected)));
+        max = u32x4_max(max, v128_and(selected, *register));
+        positions = u32x4_add(positions, step);
+    }
+
+    let min = u32x4_extract_lane::<0>(min)
+        .min(u32x4_extract_lane::<1>(min))
+        .min(u32x4_extract_lane::<2>(min))
+        .min(u32x4_extract_lane::<3>(min));
+    let max = u32x4_extract_lane::<0>(max)
+        .max(u32x4_extract_lane::<1>(max))
+        .max(u32x4_extract_lane::<2>(max))
+        .max(u32x4_extract_lane::<3>(max));
+    (min, max)
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns a bitmask of the 32-bit elements held in the provided registers which are within
+/// `lo..=lo + span`, the bit at position `i` being set when the `i`th element is selected.
+pub(super) fn select_range(data: &[v128; 16], lo: u32, span: u32) -> u64 {
+    let lo = u32x4_splat(lo);
+    let span = u32x4_splat(span);
+
+    let mut mask = 0;
+    for (i, register) in data.iter().enumerate() {
+        let selected = u32x4_le(u32x4_sub(*register, lo), span);
+        mask |= (u32x4_bitmask(selected) as u64) << (i * 4);
+    }
+    mask
+}
+
+#[target_feature(enable = "simd128")]
+/// Returns the position of the first 32-bit element held in `values` which is smaller than
+/// the element before it, or not greater than it when `strict` is set, where `deltas` holds
+/// the Delta encoding of `values`, or the Delta-1 encoding when `strict` is set.
+///
+/// The difference only wraps around to a value greater than the element itself when the
+/// element before it is larger, so the order is checked without the previous elements.
+pub(super) fn find_first_descent(
+    values: &[v128; 16],
+    deltas: &[v128; 16],
+    strict: bool,
+) -> Option<usize> {
+    for (i, (value, delta)) in values.iter().zip(deltas).enumerate() {
+        let descent = if strict {
+            u32x4_le(*value, *delta)
+        } else {
+            u32x4_lt(*value, *delta)
+        };
+        let mask = u32x4_bitmask(descent);
+        if mask != 0 {
+            return Some(i * 4 + mask.trailing_zeros() as usize);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint32::test_util::{
+        PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT,
+        PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT,
+        PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT,
+    };
+    use crate::uint32::wasm_simd128::data::{load_u8x16x4, load_u32x64};
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u16_ordered() {
+        let input = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u16_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(packed) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u8_ordered() {
+        let input = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u8_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(packed) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u16_to_u8_ordered() {
+        let input = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [v128; 8]>(input) };
+        let packed = pack_u16_to_u8_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u8);
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(packed) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u8_to_u16_ordered() {
+        let input = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [v128; 4]>(input) };
+        let unpacked = unpack_u8_to_u16_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u16);
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u16_to_u32_ordered() {
+        let input = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { core::mem::transmute::<[u16; X64], [v128; 8]>(input) };
+        let unpacked = unpack_u16_to_u32_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u8_to_u32_ordered() {
+        let input = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [v128; 4]>(input) };
+        let unpacked = unpack_u8_to_u32_ordered(data);
+
+        let expected = core::array::from_fn(|i| i as u32);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u16_unordered() {
+        let input = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u16_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(packed) };
+        assert_eq!(view[..8], [0, 16, 1, 17, 2, 18, 3, 19]);
+        assert_eq!(view[8..][..8], [4, 20, 5, 21, 6, 22, 7, 23]);
+        assert_eq!(view[16..][..8], [8, 24, 9, 25, 10, 26, 11, 27]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u8_unordered() {
+        let input = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u8_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(packed) };
+        assert_eq!(view[..8], [0, 32, 16, 48, 1, 33, 17, 49]);
+        assert_eq!(view[8..][..8], [2, 34, 18, 50, 3, 35, 19, 51]);
+        assert_eq!(view[16..][..8], [4, 36, 20, 52, 5, 37, 21, 53]);
+        assert_eq!(view[24..][..8], [6, 38, 22, 54, 7, 39, 23, 55]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u16_to_u32_unordered() {
+        let input = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [v128; 16]>(input) };
+        let packed = pack_u32_to_u16_unordered(data);
+        let unpacked = unpack_u16_to_u32_unordered(packed);
+
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(unpacked) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u16_unordered_layout() {
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u16_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(packed) };
+        assert_eq!(view, PACK_U32_TO_U16_EXPECTED_UNORDERED_LAYOUT);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_pack_u32_to_u8_unordered_layout() {
+        let input: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = load_u32x64(&input);
+        let packed = pack_u32_to_u8_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(packed) };
+        assert_eq!(view, PACK_U32_TO_U8_EXPECTED_UNORDERED_LAYOUT);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u8_to_u16_unordered_layout() {
+        let expected: [u16; X64] = core::array::from_fn(|i| i as u16);
+
+        let data = unsafe { load_u8x16x4(PACK_U16_TO_U8_EXPECTED_UNORDERED_LAYOUT.as_ptr()) };
+        let unpacked = unpack_u8_to_u16_unordered(data);
+
+        let view = unsafe { core::mem::transmute::<[v128; 8], [u16; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u4_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 16);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = pack_u8_to_u4_unordered(data);
+        let unpacked = unpack_u4_to_u8_unordered(packed);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_unpack_u2_to_u8_unordered() {
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8 % 4);
+
+        let data = unsafe { load_u8x16x4(expected.as_ptr()) };
+        let packed = pack_u8_to_u2_unordered(data);
+        let unpacked = unpack_u2_to_u8_unordered(packed);
+
+        let view = unsafe { core::mem::transmute::<[v128; 4], [u8; X64]>(unpacked) };
+        assert_eq!(view, expected);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_zigzag_encode_decode() {
+        let input: [i32; X64] = core::array::from_fn(|i| match i {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            2 => -1,
+            _ => (i as i32 - 32) * 101,
+        });
+        let expected: [u32; X64] = input.map(crate::util::zigzag_encode_i32);
+
+        let data = unsafe { core::mem::transmute::<[i32; X64], [v128; 16]>(input) };
+        let encoded = zigzag_encode(data);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(encoded) };
+        assert_eq!(view, expected);
+
+        let decoded = zigzag_decode(encoded);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [i32; X64]>(decoded) };
+        assert_eq!(view, input);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u32::MAX - 80;
+        let input: [u32; X64] = core::array::from_fn(|i| reference + i as u32);
+        let expected: [u32; X64] = core::array::from_fn(|i| i as u32);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [v128; 16]>(input) };
+        let subtracted = sub_reference(data, reference);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = add_reference(subtracted, reference);
+        let view = unsafe { core::mem::transmute::<[v128; 16], [u32; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::first(0, Some(0))]
+    #[case::middle(u32::MAX - 40, Some(23))]
+    #[case::exact(u32::MAX - 63 + 10, Some(10))]
+    #[case::last(u32::MAX, Some(63))]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_find_first_ge(#[case] target: u32, #[case] expected: Option<usize>) {
+        let input: [u32; X64] = core::array::from_fn(|i| u32::MAX - 63 + i as u32);
+        let data = unsafe { core::mem::transmute::<[u32; X64], [v128; 16]>(input) };
+        assert_eq!(find_first_ge(&data, target), expected);
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [v128; 16]>([0; X64]) };
+        assert_eq!(find_first_ge(&data, 1), None);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(4, 4)]
+    #[case::partial(13, 13)]
+    #[case::full(X64, 32)]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u32; X64] = core::array::from_fn(|i| 1 << (i % 32));
+        input[X64 - 1] = u32::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u32; X64], [v128; 16]>(input) };
+        assert_eq!(bit_length(&data, n), expected);
+    }
+}
@@ -0,0 +1,98 @@
+use core::arch::x86_64::*;
+
+use crate::X64;
+
+#[target_feature(enable = "avx2")]
+/// Load 64 64-bit elements split into 2 sets of 8, 256 bit registers holding the
+/// low and high 32 bits of each element respectively.
+pub(super) fn load_u64x64(block: &[u64; X64]) -> [[__m256i; 8]; 2] {
+    split_u64x64(load_merged_u64x64(block))
+}
+
+#[target_feature(enable = "avx2")]
+/// Load 16, 256 bit registers holding 64 64-bit elements.
+pub(super) fn load_merged_u64x64(block: &[u64; X64]) -> [__m256i; 16] {
+    let ptr: *const __m256i = block.as_ptr().cast();
+    let mut data = [_mm256_setzero_si256(); 16];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = unsafe { _mm256_loadu_si256(ptr.add(i)) };
+    }
+
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Split 16, 256 bit registers holding 64 64-bit elements into 2 sets of 8, 256 bit
+/// registers holding the low and high 32 bits of each element respectively.
+pub(super) fn split_u64x64(data: [__m256i; 16]) -> [[__m256i; 8]; 2] {
+    let deinterleave = _mm256_setr_epi32(0, 2, 4, 6, 1, 3, 5, 7);
+
+    let mut lo = [_mm256_setzero_si256(); 8];
+    let mut hi = [_mm256_setzero_si256(); 8];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        // [lo0, lo1, lo2, lo3, hi0, hi1, hi2, hi3]
+        let d1 = _mm256_permutevar8x32_epi32(data[i * 2], deinterleave);
+        let d2 = _mm256_permutevar8x32_epi32(data[i * 2 + 1], deinterleave);
+
+        lo[i] = _mm256_permute2x128_si256::<0x20>(d1, d2);
+        hi[i] = _mm256_permute2x128_si256::<0x31>(d1, d2);
+    }
+
+    [lo, hi]
+}
+
+#[target_feature(enable = "avx2")]
+/// Merge the low and high 32 bits of each element held in 2 sets of 8, 256 bit registers
+/// producing 16, 256 bit registers holding 64 64-bit elements.
+pub(super) fn merge_u64x64(data: [[__m256i; 8]; 2]) -> [__m256i; 16] {
+    let [lo, hi] = data;
+    let mut merged = [_mm256_setzero_si256(); 16];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        // [e0, e1 | e4, e5] and [e2, e3 | e6, e7]
+        let interleaved_lo = _mm256_unpacklo_epi32(lo[i], hi[i]);
+        let interleaved_hi = _mm256_unpackhi_epi32(lo[i], hi[i]);
+
+        merged[i * 2] = _mm256_permute2x128_si256::<0x20>(interleaved_lo, interleaved_hi);
+        merged[i * 2 + 1] = _mm256_permute2x128_si256::<0x31>(interleaved_lo, interleaved_hi);
+    }
+
+    merged
+}
+
+#[target_feature(enable = "avx2")]
+/// Store 16, 256 bit registers holding 64 64-bit elements.
+pub(super) fn store_u64x64(block: &mut [u64; X64], data: [__m256i; 16]) {
+    let ptr: *mut __m256i = block.as_mut_ptr().cast();
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        unsafe { _mm256_storeu_si256(ptr.add(i), data[i]) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_load_merge_roundtrip() {
+        let expected: [u64; X64] = core::array::from_fn(|i| ((i as u64) << 32) | (i as u64 + 64));
+
+        let split = unsafe { load_u64x64(&expected) };
+        let lo = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(split[0]) };
+        let hi = unsafe { core::mem::transmute::<[__m256i; 8], [u32; X64]>(split[1]) };
+        assert_eq!(lo, core::array::from_fn(|i| i as u32 + 64));
+        assert_eq!(hi, core::array::from_fn(|i| i as u32));
+
+        let mut output = [0; X64];
+        unsafe { store_u64x64(&mut output, merge_u64x64(split)) };
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::uint64::{X128_MAX_OUTPUT_LEN, compressed_size};
+use crate::{CompressionDetails, X128};
+
+pub(super) mod data;
+mod pack_x128;
+pub(crate) mod pack_x64_full;
+pub(crate) mod pack_x64_partial;
+mod unpack_x128;
+pub(crate) mod unpack_x64_full;
+pub(crate) mod unpack_x64_partial;
+mod util;
+
+#[inline]
+/// Returns `true` if the runtime CPU can safely execute the AVX2 backed implementation.
+///
+/// Without the `std` feature this is decided by the target features enabled at compile time.
+pub fn can_use() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::arch::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u64; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 64 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits(nbits as usize, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out` after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_delta_x128(
+    last_value: u64,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u64; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let nbits = pack_x128::delta_bit_length(last_value, block, pack_n);
+
+    unsafe { pack_x128::to_nbits_delta(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_delta1_x128(
+    last_value: u64,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u64; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let nbits = pack_x128::delta1_bit_length(last_value, block, pack_n);
+
+    unsafe { pack_x128::to_nbits_delta1(nbits, last_value, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out`.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `64`.
+pub unsafe fn unpack_x128(
+    nbits: u8,
+    input: &[u8],
+    block: &mut [u64; X128],
+    read_n: usize,
+) -> usize {
+    unsafe { unpack_x128::from_nbits(nbits as usize, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out` after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `64`.
+pub unsafe fn unpack_delta_x128(
+    nbits: u8,
+    last_value: u64,
+    input: &[u8],
+    block: &mut [u64; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_delta(nbits as usize, last_value, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `64`.
+pub unsafe fn unpack_delta1_x128(
+    nbits: u8,
+    last_value: u64,
+    input: &[u8],
+    block: &mut [u64; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_delta1(nbits as usize, last_value, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+#[target_feature(enable = "avx2")]
+/// Pack a block of 128 64-bit integers and write the compressed block to `out` after
+/// subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u64; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 64 - (max - reference).leading_zeros();
+
+    unsafe { pack_x128::to_nbits_for(nbits as usize, reference, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference,
+        last_value: 0,
+    }
+}
+
+#[target_feature(enable = "avx2")]
+/// Unpack a block of 128 64-bit integers and add the `reference` value back to each element.
+///
+/// # Safety
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `64`.
+pub unsafe fn unpack_for_x128(
+    nbits: u8,
+    reference: u64,
+    input: &[u8],
+    block: &mut [u64; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(nbits as usize, reference, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
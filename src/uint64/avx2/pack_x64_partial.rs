@@ -0,0 +1,108 @@
+use core::arch::x86_64::*;
+
+use crate::uint32::avx2::pack_x64_partial as uint32;
+
+macro_rules! define_lo_packer {
+    ($func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        #[doc = concat!("Bitpack the block of integers to ", stringify!($bit_length), "-bit elements.")]
+        ///
+        /// The high 32 bits of every element are expected to be zero.
+        ///
+        /// # Safety
+        /// - `out` must be safe to write `max_compressed_size::<X64>(nbits)` bytes to.
+        /// - The runtime CPU must support the `avx2` instructions.
+        /// - `pack_n` must be no greater than `64`.
+        pub(crate) unsafe fn $func_name(out: *mut u8, block: [[__m256i; 8]; 2], pack_n: usize) {
+            let [lo, _hi] = block;
+            unsafe { uint32::$func_name(out, lo, pack_n) }
+        }
+    };
+}
+
+macro_rules! define_split_packer {
+    ($func_name:ident, $hi_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        #[doc = concat!("Bitpack the block of integers to ", stringify!($bit_length), "-bit elements.")]
+        ///
+        /// The low 32 bits of every element are written first, followed by the
+        /// remaining high bits.
+        ///
+        /// # Safety
+        /// - `out` must be safe to write `max_compressed_size::<X64>(nbits)` bytes to.
+        /// - The runtime CPU must support the `avx2` instructions.
+        /// - `pack_n` must be no greater than `64`.
+        pub(crate) unsafe fn $func_name(out: *mut u8, block: [[__m256i; 8]; 2], pack_n: usize) {
+            let [lo, hi] = block;
+            let hi_offset = crate::uint32::compressed_size(32, pack_n);
+            unsafe { uint32::to_u32(out, lo, pack_n) };
+            unsafe { uint32::$hi_func_name(out.add(hi_offset), hi, pack_n) };
+        }
+    };
+}
+
+define_lo_packer!(to_u1, 1);
+define_lo_packer!(to_u2, 2);
+define_lo_packer!(to_u3, 3);
+define_lo_packer!(to_u4, 4);
+define_lo_packer!(to_u5, 5);
+define_lo_packer!(to_u6, 6);
+define_lo_packer!(to_u7, 7);
+define_lo_packer!(to_u8, 8);
+define_lo_packer!(to_u9, 9);
+define_lo_packer!(to_u10, 10);
+define_lo_packer!(to_u11, 11);
+define_lo_packer!(to_u12, 12);
+define_lo_packer!(to_u13, 13);
+define_lo_packer!(to_u14, 14);
+define_lo_packer!(to_u15, 15);
+define_lo_packer!(to_u16, 16);
+define_lo_packer!(to_u17, 17);
+define_lo_packer!(to_u18, 18);
+define_lo_packer!(to_u19, 19);
+define_lo_packer!(to_u20, 20);
+define_lo_packer!(to_u21, 21);
+define_lo_packer!(to_u22, 22);
+define_lo_packer!(to_u23, 23);
+define_lo_packer!(to_u24, 24);
+define_lo_packer!(to_u25, 25);
+define_lo_packer!(to_u26, 26);
+define_lo_packer!(to_u27, 27);
+define_lo_packer!(to_u28, 28);
+define_lo_packer!(to_u29, 29);
+define_lo_packer!(to_u30, 30);
+define_lo_packer!(to_u31, 31);
+define_lo_packer!(to_u32, 32);
+
+define_split_packer!(to_u33, to_u1, 33);
+define_split_packer!(to_u34, to_u2, 34);
+define_split_packer!(to_u35, to_u3, 35);
+define_split_packer!(to_u36, to_u4, 36);
+define_split_packer!(to_u37, to_u5, 37);
+define_split_packer!(to_u38, to_u6, 38);
+define_split_packer!(to_u39, to_u7, 39);
+define_split_packer!(to_u40, to_u8, 40);
+define_split_packer!(to_u41, to_u9, 41);
+define_split_packer!(to_u42, to_u10, 42);
+define_split_packer!(to_u43, to_u11, 43);
+define_split_packer!(to_u44, to_u12, 44);
+define_split_packer!(to_u45, to_u13, 45);
+define_split_packer!(to_u46, to_u14, 46);
+define_split_packer!(to_u47, to_u15, 47);
+define_split_packer!(to_u48, to_u16, 48);
+define_split_packer!(to_u49, to_u17, 49);
+define_split_packer!(to_u50, to_u18, 50);
+define_split_packer!(to_u51, to_u19, 51);
+define_split_packer!(to_u52, to_u20, 52);
+define_split_packer!(to_u53, to_u21, 53);
+define_split_packer!(to_u54, to_u22, 54);
+define_split_packer!(to_u55, to_u23, 55);
+define_split_packer!(to_u56, to_u24, 56);
+define_split_packer!(to_u57, to_u25, 57);
+define_split_packer!(to_u58, to_u26, 58);
+define_split_packer!(to_u59, to_u27, 59);
+define_split_packer!(to_u60, to_u28, 60);
+define_split_packer!(to_u61, to_u29, 61);
+define_split_packer!(to_u62, to_u30, 62);
+define_split_packer!(to_u63, to_u31, 63);
+define_split_packer!(to_u64, to_u32, 64);
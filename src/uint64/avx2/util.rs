@@ -0,0 +1,102 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+/// Subtract the frame of reference from each of the 64-bit elements held in the provided
+/// registers, which are split into their low and high 32 bits.
+pub(super) fn sub_reference(data: [[__m256i; 8]; 2], reference: u64) -> [[__m256i; 8]; 2] {
+    let [mut lo, mut hi] = data;
+    let reference_lo = _mm256_set1_epi32(reference as u32 as i32);
+    let reference_hi = _mm256_set1_epi32((reference >> 32) as u32 as i32);
+    let sign = _mm256_set1_epi32(i32::MIN);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        // The low half borrows from the high half when it is smaller than the reference,
+        // the sign bits are flipped to get an unsigned comparison.
+        let borrow = _mm256_cmpgt_epi32(
+            _mm256_xor_si256(reference_lo, sign),
+            _mm256_xor_si256(lo[i], sign),
+        );
+        lo[i] = _mm256_sub_epi32(lo[i], reference_lo);
+        hi[i] = _mm256_add_epi32(_mm256_sub_epi32(hi[i], reference_hi), borrow);
+    }
+    [lo, hi]
+}
+
+#[target_feature(enable = "avx2")]
+/// Add the frame of reference back to each of the 64-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m256i; 16], reference: u64) -> [__m256i; 16] {
+    let reference = _mm256_set1_epi64x(reference as i64);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        data[i] = _mm256_add_epi64(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the number of bits required to represent the largest of the first `n` 64-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m256i; 16], n: usize) -> u32 {
+    let limit = _mm256_set1_epi64x(n as i64 - 1);
+    let step = _mm256_set1_epi64x(4);
+    let mut positions = _mm256_setr_epi64x(0, 1, 2, 3);
+
+    let mut acc = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi64(positions, limit);
+        acc = _mm256_or_si256(acc, _mm256_andnot_si256(excluded, *register));
+        positions = _mm256_add_epi64(positions, step);
+    }
+
+    acc = _mm256_or_si256(acc, _mm256_permute2x128_si256::<0x01>(acc, acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0x4E>(acc));
+
+    64 - (_mm256_extract_epi64::<0>(acc) as u64).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint64::avx2::data::{load_u64x64, merge_u64x64, store_u64x64};
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_sub_add_reference() {
+        // The reference low bits are larger than some of the elements low bits, forcing
+        // a borrow from the high bits.
+        let reference = (3 << 32) | 0xFFFF_FF00;
+        let input: [u64; X64] = core::array::from_fn(|i| reference + (i as u64 * 0x1_0000_0011));
+        let expected: [u64; X64] = input.map(|v| v - reference);
+
+        let data = unsafe { load_u64x64(&input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+
+        let mut output = [0; X64];
+        let merged = unsafe { merge_u64x64(subtracted) };
+        unsafe { store_u64x64(&mut output, merged) };
+        assert_eq!(output, expected);
+
+        let added = unsafe { add_reference(merged, reference) };
+        unsafe { store_u64x64(&mut output, added) };
+        assert_eq!(output, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(4, 4)]
+    #[case::partial(37, 37)]
+    #[case::full(X64, 64)]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u64; X64] = core::array::from_fn(|i| 1 << i);
+        input[X64 - 1] = u64::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u64; X64], [__m256i; 16]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
@@ -0,0 +1,111 @@
+use core::arch::x86_64::*;
+
+use crate::X64;
+
+#[target_feature(enable = "avx512f")]
+/// Load 64 64-bit elements split into 2 sets of 4, 512 bit registers holding the
+/// low and high 32 bits of each element respectively.
+pub(super) fn load_u64x64(block: &[u64; X64]) -> [[__m512i; 4]; 2] {
+    split_u64x64(load_merged_u64x64(block))
+}
+
+#[target_feature(enable = "avx512f")]
+/// Load 8, 512 bit registers holding 64 64-bit elements.
+pub(super) fn load_merged_u64x64(block: &[u64; X64]) -> [__m512i; 8] {
+    let ptr: *const __m512i = block.as_ptr().cast();
+    let mut data = [_mm512_setzero_si512(); 8];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = unsafe { _mm512_loadu_si512(ptr.add(i)) };
+    }
+
+    data
+}
+
+#[target_feature(enable = "avx512f")]
+/// Split 8, 512 bit registers holding 64 64-bit elements into 2 sets of 4, 512 bit
+/// registers holding the low and high 32 bits of each element respectively.
+pub(super) fn split_u64x64(data: [__m512i; 8]) -> [[__m512i; 4]; 2] {
+    #[rustfmt::skip]
+    let lo_idx = _mm512_setr_epi32(
+        0, 2, 4, 6, 8, 10, 12, 14,
+        16, 18, 20, 22, 24, 26, 28, 30,
+    );
+    #[rustfmt::skip]
+    let hi_idx = _mm512_setr_epi32(
+        1, 3, 5, 7, 9, 11, 13, 15,
+        17, 19, 21, 23, 25, 27, 29, 31,
+    );
+
+    let mut lo = [_mm512_setzero_si512(); 4];
+    let mut hi = [_mm512_setzero_si512(); 4];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        lo[i] = _mm512_permutex2var_epi32(data[i * 2], lo_idx, data[i * 2 + 1]);
+        hi[i] = _mm512_permutex2var_epi32(data[i * 2], hi_idx, data[i * 2 + 1]);
+    }
+
+    [lo, hi]
+}
+
+#[target_feature(enable = "avx512f")]
+/// Merge the low and high 32 bits of each element held in 2 sets of 4, 512 bit registers
+/// producing 8, 512 bit registers holding 64 64-bit elements.
+pub(super) fn merge_u64x64(data: [[__m512i; 4]; 2]) -> [__m512i; 8] {
+    let [lo, hi] = data;
+
+    #[rustfmt::skip]
+    let first_idx = _mm512_setr_epi32(
+        0, 16, 1, 17, 2, 18, 3, 19,
+        4, 20, 5, 21, 6, 22, 7, 23,
+    );
+    #[rustfmt::skip]
+    let second_idx = _mm512_setr_epi32(
+        8, 24, 9, 25, 10, 26, 11, 27,
+        12, 28, 13, 29, 14, 30, 15, 31,
+    );
+
+    let mut merged = [_mm512_setzero_si512(); 8];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        merged[i * 2] = _mm512_permutex2var_epi32(lo[i], first_idx, hi[i]);
+        merged[i * 2 + 1] = _mm512_permutex2var_epi32(lo[i], second_idx, hi[i]);
+    }
+
+    merged
+}
+
+#[target_feature(enable = "avx512f")]
+/// Store 8, 512 bit registers holding 64 64-bit elements.
+pub(super) fn store_u64x64(block: &mut [u64; X64], data: [__m512i; 8]) {
+    let ptr: *mut __m512i = block.as_mut_ptr().cast();
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        unsafe { _mm512_storeu_si512(ptr.add(i), data[i]) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx512f"), ignore)]
+    fn test_load_merge_roundtrip() {
+        let expected: [u64; X64] = core::array::from_fn(|i| ((i as u64) << 32) | (i as u64 + 64));
+
+        let split = unsafe { load_u64x64(&expected) };
+        let lo = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(split[0]) };
+        let hi = unsafe { core::mem::transmute::<[__m512i; 4], [u32; X64]>(split[1]) };
+        assert_eq!(lo, core::array::from_fn(|i| i as u32 + 64));
+        assert_eq!(hi, core::array::from_fn(|i| i as u32));
+
+        let mut output = [0; X64];
+        unsafe { store_u64x64(&mut output, merge_u64x64(split)) };
+        assert_eq!(output, expected);
+    }
+}
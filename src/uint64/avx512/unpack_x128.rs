@@ -0,0 +1,877 @@
+use core::arch::x86_64::*;
+
+use super::data::*;
+use super::util::add_reference;
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint64::{max_compressed_size, split_block_mut};
+use crate::{X64, X128};
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Unpack the provided block of `nbits` bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u64; X128], read_n: usize) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(input: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8, from_u9,
+        from_u10, from_u11, from_u12, from_u13, from_u14, from_u15, from_u16, from_u17, from_u18,
+        from_u19, from_u20, from_u21, from_u22, from_u23, from_u24, from_u25, from_u26, from_u27,
+        from_u28, from_u29, from_u30, from_u31, from_u32, from_u33, from_u34, from_u35, from_u36,
+        from_u37, from_u38, from_u39, from_u40, from_u41, from_u42, from_u43, from_u44, from_u45,
+        from_u46, from_u47, from_u48, from_u49, from_u50, from_u51, from_u52, from_u53, from_u54,
+        from_u55, from_u56, from_u57, from_u58, from_u59, from_u60, from_u61, from_u62, from_u63,
+        from_u64,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta(
+    nbits: usize,
+    last_value: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u64, input: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0_delta,
+        from_u1_delta,
+        from_u2_delta,
+        from_u3_delta,
+        from_u4_delta,
+        from_u5_delta,
+        from_u6_delta,
+        from_u7_delta,
+        from_u8_delta,
+        from_u9_delta,
+        from_u10_delta,
+        from_u11_delta,
+        from_u12_delta,
+        from_u13_delta,
+        from_u14_delta,
+        from_u15_delta,
+        from_u16_delta,
+        from_u17_delta,
+        from_u18_delta,
+        from_u19_delta,
+        from_u20_delta,
+        from_u21_delta,
+        from_u22_delta,
+        from_u23_delta,
+        from_u24_delta,
+        from_u25_delta,
+        from_u26_delta,
+        from_u27_delta,
+        from_u28_delta,
+        from_u29_delta,
+        from_u30_delta,
+        from_u31_delta,
+        from_u32_delta,
+        from_u33_delta,
+        from_u34_delta,
+        from_u35_delta,
+        from_u36_delta,
+        from_u37_delta,
+        from_u38_delta,
+        from_u39_delta,
+        from_u40_delta,
+        from_u41_delta,
+        from_u42_delta,
+        from_u43_delta,
+        from_u44_delta,
+        from_u45_delta,
+        from_u46_delta,
+        from_u47_delta,
+        from_u48_delta,
+        from_u49_delta,
+        from_u50_delta,
+        from_u51_delta,
+        from_u52_delta,
+        from_u53_delta,
+        from_u54_delta,
+        from_u55_delta,
+        from_u56_delta,
+        from_u57_delta,
+        from_u58_delta,
+        from_u59_delta,
+        from_u60_delta,
+        from_u61_delta,
+        from_u62_delta,
+        from_u63_delta,
+        from_u64_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, input, out, read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-1-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta1(
+    nbits: usize,
+    last_value: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u64, input: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0_delta1,
+        from_u1_delta1,
+        from_u2_delta1,
+        from_u3_delta1,
+        from_u4_delta1,
+        from_u5_delta1,
+        from_u6_delta1,
+        from_u7_delta1,
+        from_u8_delta1,
+        from_u9_delta1,
+        from_u10_delta1,
+        from_u11_delta1,
+        from_u12_delta1,
+        from_u13_delta1,
+        from_u14_delta1,
+        from_u15_delta1,
+        from_u16_delta1,
+        from_u17_delta1,
+        from_u18_delta1,
+        from_u19_delta1,
+        from_u20_delta1,
+        from_u21_delta1,
+        from_u22_delta1,
+        from_u23_delta1,
+        from_u24_delta1,
+        from_u25_delta1,
+        from_u26_delta1,
+        from_u27_delta1,
+        from_u28_delta1,
+        from_u29_delta1,
+        from_u30_delta1,
+        from_u31_delta1,
+        from_u32_delta1,
+        from_u33_delta1,
+        from_u34_delta1,
+        from_u35_delta1,
+        from_u36_delta1,
+        from_u37_delta1,
+        from_u38_delta1,
+        from_u39_delta1,
+        from_u40_delta1,
+        from_u41_delta1,
+        from_u42_delta1,
+        from_u43_delta1,
+        from_u44_delta1,
+        from_u45_delta1,
+        from_u46_delta1,
+        from_u47_delta1,
+        from_u48_delta1,
+        from_u49_delta1,
+        from_u50_delta1,
+        from_u51_delta1,
+        from_u52_delta1,
+        from_u53_delta1,
+        from_u54_delta1,
+        from_u55_delta1,
+        from_u56_delta1,
+        from_u57_delta1,
+        from_u58_delta1,
+        from_u59_delta1,
+        from_u60_delta1,
+        from_u61_delta1,
+        from_u62_delta1,
+        from_u63_delta1,
+        from_u64_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, input, out, read_n) };
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn from_u0(_input: *const u8, out: &mut [u64; X128], _read_n: usize) {
+    out.fill(0);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn from_u0_delta(last_value: u64, _input: *const u8, out: &mut [u64; X128], _read_n: usize) {
+    out.fill(last_value);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn from_u0_delta1(
+    last_value: u64,
+    _input: *const u8,
+    out: &mut [u64; X128],
+    _read_n: usize,
+) {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..X128 {
+        out[i] = (i as u64).wrapping_add(last_value).wrapping_add(1);
+    }
+}
+
+macro_rules! define_x128_unpacker {
+    ($func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        unsafe fn $func_name(input: *const u8, out: &mut [u64; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
+                store_u64x64(left, merge_u64x64(unpacked));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                store_u64x64(left, merge_u64x64(unpacked));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u64x64(right, merge_u64x64(unpacked));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                store_u64x64(left, merge_u64x64(unpacked));
+                let unpacked = unsafe {
+                    unpack_x64_full::$func_name(input.add(max_compressed_size::<X64>($bit_length)))
+                };
+                store_u64x64(right, merge_u64x64(unpacked));
+            }
+        }
+    };
+}
+
+macro_rules! define_x128_unpacker_delta {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr, $delta_func_name:ident) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        unsafe fn $func_name(
+            last_value: u64,
+            input: *const u8,
+            out: &mut [u64; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            let mut last_value = _mm512_set1_epi64(last_value as i64);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                let mut merged = merge_u64x64(unpacked);
+                $delta_func_name(last_value, &mut merged);
+                store_u64x64(left, merged);
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                let mut merged = merge_u64x64(unpacked);
+                last_value = $delta_func_name(last_value, &mut merged);
+                store_u64x64(left, merged);
+
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                let mut merged = merge_u64x64(unpacked);
+                $delta_func_name(last_value, &mut merged);
+                store_u64x64(right, merged);
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                let mut merged = merge_u64x64(unpacked);
+                last_value = $delta_func_name(last_value, &mut merged);
+                store_u64x64(left, merged);
+
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                let mut merged = merge_u64x64(unpacked);
+                $delta_func_name(last_value, &mut merged);
+                store_u64x64(right, merged);
+            }
+        }
+    };
+}
+
+define_x128_unpacker!(from_u1, 1);
+define_x128_unpacker!(from_u2, 2);
+define_x128_unpacker!(from_u3, 3);
+define_x128_unpacker!(from_u4, 4);
+define_x128_unpacker!(from_u5, 5);
+define_x128_unpacker!(from_u6, 6);
+define_x128_unpacker!(from_u7, 7);
+define_x128_unpacker!(from_u8, 8);
+define_x128_unpacker!(from_u9, 9);
+define_x128_unpacker!(from_u10, 10);
+define_x128_unpacker!(from_u11, 11);
+define_x128_unpacker!(from_u12, 12);
+define_x128_unpacker!(from_u13, 13);
+define_x128_unpacker!(from_u14, 14);
+define_x128_unpacker!(from_u15, 15);
+define_x128_unpacker!(from_u16, 16);
+define_x128_unpacker!(from_u17, 17);
+define_x128_unpacker!(from_u18, 18);
+define_x128_unpacker!(from_u19, 19);
+define_x128_unpacker!(from_u20, 20);
+define_x128_unpacker!(from_u21, 21);
+define_x128_unpacker!(from_u22, 22);
+define_x128_unpacker!(from_u23, 23);
+define_x128_unpacker!(from_u24, 24);
+define_x128_unpacker!(from_u25, 25);
+define_x128_unpacker!(from_u26, 26);
+define_x128_unpacker!(from_u27, 27);
+define_x128_unpacker!(from_u28, 28);
+define_x128_unpacker!(from_u29, 29);
+define_x128_unpacker!(from_u30, 30);
+define_x128_unpacker!(from_u31, 31);
+define_x128_unpacker!(from_u32, 32);
+define_x128_unpacker!(from_u33, 33);
+define_x128_unpacker!(from_u34, 34);
+define_x128_unpacker!(from_u35, 35);
+define_x128_unpacker!(from_u36, 36);
+define_x128_unpacker!(from_u37, 37);
+define_x128_unpacker!(from_u38, 38);
+define_x128_unpacker!(from_u39, 39);
+define_x128_unpacker!(from_u40, 40);
+define_x128_unpacker!(from_u41, 41);
+define_x128_unpacker!(from_u42, 42);
+define_x128_unpacker!(from_u43, 43);
+define_x128_unpacker!(from_u44, 44);
+define_x128_unpacker!(from_u45, 45);
+define_x128_unpacker!(from_u46, 46);
+define_x128_unpacker!(from_u47, 47);
+define_x128_unpacker!(from_u48, 48);
+define_x128_unpacker!(from_u49, 49);
+define_x128_unpacker!(from_u50, 50);
+define_x128_unpacker!(from_u51, 51);
+define_x128_unpacker!(from_u52, 52);
+define_x128_unpacker!(from_u53, 53);
+define_x128_unpacker!(from_u54, 54);
+define_x128_unpacker!(from_u55, 55);
+define_x128_unpacker!(from_u56, 56);
+define_x128_unpacker!(from_u57, 57);
+define_x128_unpacker!(from_u58, 58);
+define_x128_unpacker!(from_u59, 59);
+define_x128_unpacker!(from_u60, 60);
+define_x128_unpacker!(from_u61, 61);
+define_x128_unpacker!(from_u62, 62);
+define_x128_unpacker!(from_u63, 63);
+define_x128_unpacker!(from_u64, 64);
+
+// Delta encoding
+define_x128_unpacker_delta!(from_u1_delta, from_u1, 1, decode_delta);
+define_x128_unpacker_delta!(from_u2_delta, from_u2, 2, decode_delta);
+define_x128_unpacker_delta!(from_u3_delta, from_u3, 3, decode_delta);
+define_x128_unpacker_delta!(from_u4_delta, from_u4, 4, decode_delta);
+define_x128_unpacker_delta!(from_u5_delta, from_u5, 5, decode_delta);
+define_x128_unpacker_delta!(from_u6_delta, from_u6, 6, decode_delta);
+define_x128_unpacker_delta!(from_u7_delta, from_u7, 7, decode_delta);
+define_x128_unpacker_delta!(from_u8_delta, from_u8, 8, decode_delta);
+define_x128_unpacker_delta!(from_u9_delta, from_u9, 9, decode_delta);
+define_x128_unpacker_delta!(from_u10_delta, from_u10, 10, decode_delta);
+define_x128_unpacker_delta!(from_u11_delta, from_u11, 11, decode_delta);
+define_x128_unpacker_delta!(from_u12_delta, from_u12, 12, decode_delta);
+define_x128_unpacker_delta!(from_u13_delta, from_u13, 13, decode_delta);
+define_x128_unpacker_delta!(from_u14_delta, from_u14, 14, decode_delta);
+define_x128_unpacker_delta!(from_u15_delta, from_u15, 15, decode_delta);
+define_x128_unpacker_delta!(from_u16_delta, from_u16, 16, decode_delta);
+define_x128_unpacker_delta!(from_u17_delta, from_u17, 17, decode_delta);
+define_x128_unpacker_delta!(from_u18_delta, from_u18, 18, decode_delta);
+define_x128_unpacker_delta!(from_u19_delta, from_u19, 19, decode_delta);
+define_x128_unpacker_delta!(from_u20_delta, from_u20, 20, decode_delta);
+define_x128_unpacker_delta!(from_u21_delta, from_u21, 21, decode_delta);
+define_x128_unpacker_delta!(from_u22_delta, from_u22, 22, decode_delta);
+define_x128_unpacker_delta!(from_u23_delta, from_u23, 23, decode_delta);
+define_x128_unpacker_delta!(from_u24_delta, from_u24, 24, decode_delta);
+define_x128_unpacker_delta!(from_u25_delta, from_u25, 25, decode_delta);
+define_x128_unpacker_delta!(from_u26_delta, from_u26, 26, decode_delta);
+define_x128_unpacker_delta!(from_u27_delta, from_u27, 27, decode_delta);
+define_x128_unpacker_delta!(from_u28_delta, from_u28, 28, decode_delta);
+define_x128_unpacker_delta!(from_u29_delta, from_u29, 29, decode_delta);
+define_x128_unpacker_delta!(from_u30_delta, from_u30, 30, decode_delta);
+define_x128_unpacker_delta!(from_u31_delta, from_u31, 31, decode_delta);
+define_x128_unpacker_delta!(from_u32_delta, from_u32, 32, decode_delta);
+define_x128_unpacker_delta!(from_u33_delta, from_u33, 33, decode_delta);
+define_x128_unpacker_delta!(from_u34_delta, from_u34, 34, decode_delta);
+define_x128_unpacker_delta!(from_u35_delta, from_u35, 35, decode_delta);
+define_x128_unpacker_delta!(from_u36_delta, from_u36, 36, decode_delta);
+define_x128_unpacker_delta!(from_u37_delta, from_u37, 37, decode_delta);
+define_x128_unpacker_delta!(from_u38_delta, from_u38, 38, decode_delta);
+define_x128_unpacker_delta!(from_u39_delta, from_u39, 39, decode_delta);
+define_x128_unpacker_delta!(from_u40_delta, from_u40, 40, decode_delta);
+define_x128_unpacker_delta!(from_u41_delta, from_u41, 41, decode_delta);
+define_x128_unpacker_delta!(from_u42_delta, from_u42, 42, decode_delta);
+define_x128_unpacker_delta!(from_u43_delta, from_u43, 43, decode_delta);
+define_x128_unpacker_delta!(from_u44_delta, from_u44, 44, decode_delta);
+define_x128_unpacker_delta!(from_u45_delta, from_u45, 45, decode_delta);
+define_x128_unpacker_delta!(from_u46_delta, from_u46, 46, decode_delta);
+define_x128_unpacker_delta!(from_u47_delta, from_u47, 47, decode_delta);
+define_x128_unpacker_delta!(from_u48_delta, from_u48, 48, decode_delta);
+define_x128_unpacker_delta!(from_u49_delta, from_u49, 49, decode_delta);
+define_x128_unpacker_delta!(from_u50_delta, from_u50, 50, decode_delta);
+define_x128_unpacker_delta!(from_u51_delta, from_u51, 51, decode_delta);
+define_x128_unpacker_delta!(from_u52_delta, from_u52, 52, decode_delta);
+define_x128_unpacker_delta!(from_u53_delta, from_u53, 53, decode_delta);
+define_x128_unpacker_delta!(from_u54_delta, from_u54, 54, decode_delta);
+define_x128_unpacker_delta!(from_u55_delta, from_u55, 55, decode_delta);
+define_x128_unpacker_delta!(from_u56_delta, from_u56, 56, decode_delta);
+define_x128_unpacker_delta!(from_u57_delta, from_u57, 57, decode_delta);
+define_x128_unpacker_delta!(from_u58_delta, from_u58, 58, decode_delta);
+define_x128_unpacker_delta!(from_u59_delta, from_u59, 59, decode_delta);
+define_x128_unpacker_delta!(from_u60_delta, from_u60, 60, decode_delta);
+define_x128_unpacker_delta!(from_u61_delta, from_u61, 61, decode_delta);
+define_x128_unpacker_delta!(from_u62_delta, from_u62, 62, decode_delta);
+define_x128_unpacker_delta!(from_u63_delta, from_u63, 63, decode_delta);
+define_x128_unpacker_delta!(from_u64_delta, from_u64, 64, decode_delta);
+
+// Delta-1 encoding
+define_x128_unpacker_delta!(from_u1_delta1, from_u1, 1, decode_delta1);
+define_x128_unpacker_delta!(from_u2_delta1, from_u2, 2, decode_delta1);
+define_x128_unpacker_delta!(from_u3_delta1, from_u3, 3, decode_delta1);
+define_x128_unpacker_delta!(from_u4_delta1, from_u4, 4, decode_delta1);
+define_x128_unpacker_delta!(from_u5_delta1, from_u5, 5, decode_delta1);
+define_x128_unpacker_delta!(from_u6_delta1, from_u6, 6, decode_delta1);
+define_x128_unpacker_delta!(from_u7_delta1, from_u7, 7, decode_delta1);
+define_x128_unpacker_delta!(from_u8_delta1, from_u8, 8, decode_delta1);
+define_x128_unpacker_delta!(from_u9_delta1, from_u9, 9, decode_delta1);
+define_x128_unpacker_delta!(from_u10_delta1, from_u10, 10, decode_delta1);
+define_x128_unpacker_delta!(from_u11_delta1, from_u11, 11, decode_delta1);
+define_x128_unpacker_delta!(from_u12_delta1, from_u12, 12, decode_delta1);
+define_x128_unpacker_delta!(from_u13_delta1, from_u13, 13, decode_delta1);
+define_x128_unpacker_delta!(from_u14_delta1, from_u14, 14, decode_delta1);
+define_x128_unpacker_delta!(from_u15_delta1, from_u15, 15, decode_delta1);
+define_x128_unpacker_delta!(from_u16_delta1, from_u16, 16, decode_delta1);
+define_x128_unpacker_delta!(from_u17_delta1, from_u17, 17, decode_delta1);
+define_x128_unpacker_delta!(from_u18_delta1, from_u18, 18, decode_delta1);
+define_x128_unpacker_delta!(from_u19_delta1, from_u19, 19, decode_delta1);
+define_x128_unpacker_delta!(from_u20_delta1, from_u20, 20, decode_delta1);
+define_x128_unpacker_delta!(from_u21_delta1, from_u21, 21, decode_delta1);
+define_x128_unpacker_delta!(from_u22_delta1, from_u22, 22, decode_delta1);
+define_x128_unpacker_delta!(from_u23_delta1, from_u23, 23, decode_delta1);
+define_x128_unpacker_delta!(from_u24_delta1, from_u24, 24, decode_delta1);
+define_x128_unpacker_delta!(from_u25_delta1, from_u25, 25, decode_delta1);
+define_x128_unpacker_delta!(from_u26_delta1, from_u26, 26, decode_delta1);
+define_x128_unpacker_delta!(from_u27_delta1, from_u27, 27, decode_delta1);
+define_x128_unpacker_delta!(from_u28_delta1, from_u28, 28, decode_delta1);
+define_x128_unpacker_delta!(from_u29_delta1, from_u29, 29, decode_delta1);
+define_x128_unpacker_delta!(from_u30_delta1, from_u30, 30, decode_delta1);
+define_x128_unpacker_delta!(from_u31_delta1, from_u31, 31, decode_delta1);
+define_x128_unpacker_delta!(from_u32_delta1, from_u32, 32, decode_delta1);
+define_x128_unpacker_delta!(from_u33_delta1, from_u33, 33, decode_delta1);
+define_x128_unpacker_delta!(from_u34_delta1, from_u34, 34, decode_delta1);
+define_x128_unpacker_delta!(from_u35_delta1, from_u35, 35, decode_delta1);
+define_x128_unpacker_delta!(from_u36_delta1, from_u36, 36, decode_delta1);
+define_x128_unpacker_delta!(from_u37_delta1, from_u37, 37, decode_delta1);
+define_x128_unpacker_delta!(from_u38_delta1, from_u38, 38, decode_delta1);
+define_x128_unpacker_delta!(from_u39_delta1, from_u39, 39, decode_delta1);
+define_x128_unpacker_delta!(from_u40_delta1, from_u40, 40, decode_delta1);
+define_x128_unpacker_delta!(from_u41_delta1, from_u41, 41, decode_delta1);
+define_x128_unpacker_delta!(from_u42_delta1, from_u42, 42, decode_delta1);
+define_x128_unpacker_delta!(from_u43_delta1, from_u43, 43, decode_delta1);
+define_x128_unpacker_delta!(from_u44_delta1, from_u44, 44, decode_delta1);
+define_x128_unpacker_delta!(from_u45_delta1, from_u45, 45, decode_delta1);
+define_x128_unpacker_delta!(from_u46_delta1, from_u46, 46, decode_delta1);
+define_x128_unpacker_delta!(from_u47_delta1, from_u47, 47, decode_delta1);
+define_x128_unpacker_delta!(from_u48_delta1, from_u48, 48, decode_delta1);
+define_x128_unpacker_delta!(from_u49_delta1, from_u49, 49, decode_delta1);
+define_x128_unpacker_delta!(from_u50_delta1, from_u50, 50, decode_delta1);
+define_x128_unpacker_delta!(from_u51_delta1, from_u51, 51, decode_delta1);
+define_x128_unpacker_delta!(from_u52_delta1, from_u52, 52, decode_delta1);
+define_x128_unpacker_delta!(from_u53_delta1, from_u53, 53, decode_delta1);
+define_x128_unpacker_delta!(from_u54_delta1, from_u54, 54, decode_delta1);
+define_x128_unpacker_delta!(from_u55_delta1, from_u55, 55, decode_delta1);
+define_x128_unpacker_delta!(from_u56_delta1, from_u56, 56, decode_delta1);
+define_x128_unpacker_delta!(from_u57_delta1, from_u57, 57, decode_delta1);
+define_x128_unpacker_delta!(from_u58_delta1, from_u58, 58, decode_delta1);
+define_x128_unpacker_delta!(from_u59_delta1, from_u59, 59, decode_delta1);
+define_x128_unpacker_delta!(from_u60_delta1, from_u60, 60, decode_delta1);
+define_x128_unpacker_delta!(from_u61_delta1, from_u61, 61, decode_delta1);
+define_x128_unpacker_delta!(from_u62_delta1, from_u62, 62, decode_delta1);
+define_x128_unpacker_delta!(from_u63_delta1, from_u63, 63, decode_delta1);
+define_x128_unpacker_delta!(from_u64_delta1, from_u64, 64, decode_delta1);
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+fn decode_delta(last_value: __m512i, block: &mut [__m512i; 8]) -> __m512i {
+    let zero = _mm512_setzero_si512();
+    let idx_last = _mm512_set1_epi64(7);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        block[i] = _mm512_add_epi64(block[i], _mm512_alignr_epi64::<7>(block[i], zero));
+        block[i] = _mm512_add_epi64(block[i], _mm512_alignr_epi64::<6>(block[i], zero));
+        block[i] = _mm512_add_epi64(block[i], _mm512_alignr_epi64::<4>(block[i], zero));
+    }
+
+    block[0] = _mm512_add_epi64(block[0], last_value);
+    for i in 1..8 {
+        block[i] = _mm512_add_epi64(block[i], _mm512_permutexvar_epi64(idx_last, block[i - 1]));
+    }
+
+    _mm512_permutexvar_epi64(idx_last, block[7])
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+fn decode_delta1(last_value: __m512i, block: &mut [__m512i; 8]) -> __m512i {
+    let ones = _mm512_set1_epi64(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        block[i] = _mm512_add_epi64(block[i], ones);
+    }
+
+    decode_delta(last_value, block)
+}
+
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u64, input: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+        from_u9_for,
+        from_u10_for,
+        from_u11_for,
+        from_u12_for,
+        from_u13_for,
+        from_u14_for,
+        from_u15_for,
+        from_u16_for,
+        from_u17_for,
+        from_u18_for,
+        from_u19_for,
+        from_u20_for,
+        from_u21_for,
+        from_u22_for,
+        from_u23_for,
+        from_u24_for,
+        from_u25_for,
+        from_u26_for,
+        from_u27_for,
+        from_u28_for,
+        from_u29_for,
+        from_u30_for,
+        from_u31_for,
+        from_u32_for,
+        from_u33_for,
+        from_u34_for,
+        from_u35_for,
+        from_u36_for,
+        from_u37_for,
+        from_u38_for,
+        from_u39_for,
+        from_u40_for,
+        from_u41_for,
+        from_u42_for,
+        from_u43_for,
+        from_u44_for,
+        from_u45_for,
+        from_u46_for,
+        from_u47_for,
+        from_u48_for,
+        from_u49_for,
+        from_u50_for,
+        from_u51_for,
+        from_u52_for,
+        from_u53_for,
+        from_u54_for,
+        from_u55_for,
+        from_u56_for,
+        from_u57_for,
+        from_u58_for,
+        from_u59_for,
+        from_u60_for,
+        from_u61_for,
+        from_u62_for,
+        from_u63_for,
+        from_u64_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+unsafe fn from_u0_for(reference: u64, _input: *const u8, out: &mut [u64; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        unsafe fn $func_name(
+            reference: u64,
+            input: *const u8,
+            out: &mut [u64; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u64x64(left, add_reference(merge_u64x64(unpacked), reference));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u64x64(left, add_reference(merge_u64x64(unpacked), reference));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u64x64(right, add_reference(merge_u64x64(unpacked), reference));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u64x64(left, add_reference(merge_u64x64(unpacked), reference));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u64x64(right, add_reference(merge_u64x64(unpacked), reference));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+define_x128_unpacker_for!(from_u9_for, from_u9, 9);
+define_x128_unpacker_for!(from_u10_for, from_u10, 10);
+define_x128_unpacker_for!(from_u11_for, from_u11, 11);
+define_x128_unpacker_for!(from_u12_for, from_u12, 12);
+define_x128_unpacker_for!(from_u13_for, from_u13, 13);
+define_x128_unpacker_for!(from_u14_for, from_u14, 14);
+define_x128_unpacker_for!(from_u15_for, from_u15, 15);
+define_x128_unpacker_for!(from_u16_for, from_u16, 16);
+define_x128_unpacker_for!(from_u17_for, from_u17, 17);
+define_x128_unpacker_for!(from_u18_for, from_u18, 18);
+define_x128_unpacker_for!(from_u19_for, from_u19, 19);
+define_x128_unpacker_for!(from_u20_for, from_u20, 20);
+define_x128_unpacker_for!(from_u21_for, from_u21, 21);
+define_x128_unpacker_for!(from_u22_for, from_u22, 22);
+define_x128_unpacker_for!(from_u23_for, from_u23, 23);
+define_x128_unpacker_for!(from_u24_for, from_u24, 24);
+define_x128_unpacker_for!(from_u25_for, from_u25, 25);
+define_x128_unpacker_for!(from_u26_for, from_u26, 26);
+define_x128_unpacker_for!(from_u27_for, from_u27, 27);
+define_x128_unpacker_for!(from_u28_for, from_u28, 28);
+define_x128_unpacker_for!(from_u29_for, from_u29, 29);
+define_x128_unpacker_for!(from_u30_for, from_u30, 30);
+define_x128_unpacker_for!(from_u31_for, from_u31, 31);
+define_x128_unpacker_for!(from_u32_for, from_u32, 32);
+define_x128_unpacker_for!(from_u33_for, from_u33, 33);
+define_x128_unpacker_for!(from_u34_for, from_u34, 34);
+define_x128_unpacker_for!(from_u35_for, from_u35, 35);
+define_x128_unpacker_for!(from_u36_for, from_u36, 36);
+define_x128_unpacker_for!(from_u37_for, from_u37, 37);
+define_x128_unpacker_for!(from_u38_for, from_u38, 38);
+define_x128_unpacker_for!(from_u39_for, from_u39, 39);
+define_x128_unpacker_for!(from_u40_for, from_u40, 40);
+define_x128_unpacker_for!(from_u41_for, from_u41, 41);
+define_x128_unpacker_for!(from_u42_for, from_u42, 42);
+define_x128_unpacker_for!(from_u43_for, from_u43, 43);
+define_x128_unpacker_for!(from_u44_for, from_u44, 44);
+define_x128_unpacker_for!(from_u45_for, from_u45, 45);
+define_x128_unpacker_for!(from_u46_for, from_u46, 46);
+define_x128_unpacker_for!(from_u47_for, from_u47, 47);
+define_x128_unpacker_for!(from_u48_for, from_u48, 48);
+define_x128_unpacker_for!(from_u49_for, from_u49, 49);
+define_x128_unpacker_for!(from_u50_for, from_u50, 50);
+define_x128_unpacker_for!(from_u51_for, from_u51, 51);
+define_x128_unpacker_for!(from_u52_for, from_u52, 52);
+define_x128_unpacker_for!(from_u53_for, from_u53, 53);
+define_x128_unpacker_for!(from_u54_for, from_u54, 54);
+define_x128_unpacker_for!(from_u55_for, from_u55, 55);
+define_x128_unpacker_for!(from_u56_for, from_u56, 56);
+define_x128_unpacker_for!(from_u57_for, from_u57, 57);
+define_x128_unpacker_for!(from_u58_for, from_u58, 58);
+define_x128_unpacker_for!(from_u59_for, from_u59, 59);
+define_x128_unpacker_for!(from_u60_for, from_u60, 60);
+define_x128_unpacker_for!(from_u61_for, from_u61, 61);
+define_x128_unpacker_for!(from_u62_for, from_u62, 62);
+define_x128_unpacker_for!(from_u63_for, from_u63, 63);
+define_x128_unpacker_for!(from_u64_for, from_u64, 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint64::X128_MAX_OUTPUT_LEN;
+    use crate::uint64::avx512::pack_x128::to_nbits_for;
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_decode_delta_zero_starting_value() {
+        let expected_values: [u64; X64] = core::array::from_fn(|i| i as u64);
+        let mut values = [1; X64];
+        values[0] = 0;
+
+        let mut block = unsafe { merge_u64x64(load_u64x64(&values)) };
+        unsafe { decode_delta(_mm512_set1_epi64(0), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u64x64(&mut result, block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_decode_delta_starting_value() {
+        let expected_values: [u64; X64] = core::array::from_fn(|i| u32::MAX as u64 + i as u64);
+        let mut values = [1; X64];
+        values[0] = 0;
+
+        let mut block = unsafe { merge_u64x64(load_u64x64(&values)) };
+        unsafe { decode_delta(_mm512_set1_epi64(u32::MAX as u64 as i64), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u64x64(&mut result, block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_decode_delta1_zero_starting_value() {
+        let expected_values: [u64; X64] = core::array::from_fn(|i| i as u64 + 1);
+        let values = [0; X64];
+
+        let mut block = unsafe { merge_u64x64(load_u64x64(&values)) };
+        unsafe { decode_delta1(_mm512_set1_epi64(0), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u64x64(&mut result, block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_decode_delta1_starting_value() {
+        let expected_values: [u64; X64] = core::array::from_fn(|i| u32::MAX as u64 + i as u64 + 1);
+        let values = [0; X64];
+
+        let mut block = unsafe { merge_u64x64(load_u64x64(&values)) };
+        unsafe { decode_delta1(_mm512_set1_epi64(u32::MAX as u64 as i64), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u64x64(&mut result, block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint64::test_util::load_uint64_regression_layout();
+
+        let mut output_buffer = [0u64; X128];
+        for (len, bit_len, expected_output, input) in tester.iter_tests() {
+            unsafe { from_nbits(bit_len as usize, input.as_ptr(), &mut output_buffer, len) };
+
+            let produced_buffer = &output_buffer[..len];
+            assert_eq!(
+                produced_buffer,
+                &expected_output[..len],
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+
+    #[rstest::rstest]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_for_roundtrip(
+        #[values(0, 1, 17, 33, 64)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u64::MAX >> (64 - nbits)
+        };
+        let reference = u64::MAX - mask;
+        let expected: [u64; X128] =
+            core::array::from_fn(|i| reference + ((i as u64).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+}
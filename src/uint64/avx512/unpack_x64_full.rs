@@ -0,0 +1,107 @@
+use core::arch::x86_64::*;
+
+use crate::X64;
+use crate::uint32::avx512::unpack_x64_full as uint32;
+
+/// The offset of the high bits within the packed block, these always follow
+/// the low 32 bits of every element.
+const HI_OFFSET: usize = crate::uint32::max_compressed_size::<X64>(32);
+
+macro_rules! define_lo_unpacker {
+    ($func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        #[doc = concat!("Unpack the ", stringify!($bit_length), "-bit integers from the input pointer.")]
+        ///
+        /// The high 32 bits of every element are always zero.
+        ///
+        /// # Safety
+        /// - `input` must be safe to read `max_compressed_size::<X64>(nbits)` bytes from.
+        /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+        pub(crate) unsafe fn $func_name(input: *const u8) -> [[__m512i; 4]; 2] {
+            let lo = unsafe { uint32::$func_name(input) };
+            [lo, [_mm512_setzero_si512(); 4]]
+        }
+    };
+}
+
+macro_rules! define_split_unpacker {
+    ($func_name:ident, $hi_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx512f", enable = "avx512bw")]
+        #[doc = concat!("Unpack the ", stringify!($bit_length), "-bit integers from the input pointer.")]
+        ///
+        /// # Safety
+        /// - `input` must be safe to read `max_compressed_size::<X64>(nbits)` bytes from.
+        /// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+        pub(crate) unsafe fn $func_name(input: *const u8) -> [[__m512i; 4]; 2] {
+            let lo = unsafe { uint32::from_u32(input) };
+            let hi = unsafe { uint32::$hi_func_name(input.add(HI_OFFSET)) };
+            [lo, hi]
+        }
+    };
+}
+
+define_lo_unpacker!(from_u1, 1);
+define_lo_unpacker!(from_u2, 2);
+define_lo_unpacker!(from_u3, 3);
+define_lo_unpacker!(from_u4, 4);
+define_lo_unpacker!(from_u5, 5);
+define_lo_unpacker!(from_u6, 6);
+define_lo_unpacker!(from_u7, 7);
+define_lo_unpacker!(from_u8, 8);
+define_lo_unpacker!(from_u9, 9);
+define_lo_unpacker!(from_u10, 10);
+define_lo_unpacker!(from_u11, 11);
+define_lo_unpacker!(from_u12, 12);
+define_lo_unpacker!(from_u13, 13);
+define_lo_unpacker!(from_u14, 14);
+define_lo_unpacker!(from_u15, 15);
+define_lo_unpacker!(from_u16, 16);
+define_lo_unpacker!(from_u17, 17);
+define_lo_unpacker!(from_u18, 18);
+define_lo_unpacker!(from_u19, 19);
+define_lo_unpacker!(from_u20, 20);
+define_lo_unpacker!(from_u21, 21);
+define_lo_unpacker!(from_u22, 22);
+define_lo_unpacker!(from_u23, 23);
+define_lo_unpacker!(from_u24, 24);
+define_lo_unpacker!(from_u25, 25);
+define_lo_unpacker!(from_u26, 26);
+define_lo_unpacker!(from_u27, 27);
+define_lo_unpacker!(from_u28, 28);
+define_lo_unpacker!(from_u29, 29);
+define_lo_unpacker!(from_u30, 30);
+define_lo_unpacker!(from_u31, 31);
+define_lo_unpacker!(from_u32, 32);
+
+define_split_unpacker!(from_u33, from_u1, 33);
+define_split_unpacker!(from_u34, from_u2, 34);
+define_split_unpacker!(from_u35, from_u3, 35);
+define_split_unpacker!(from_u36, from_u4, 36);
+define_split_unpacker!(from_u37, from_u5, 37);
+define_split_unpacker!(from_u38, from_u6, 38);
+define_split_unpacker!(from_u39, from_u7, 39);
+define_split_unpacker!(from_u40, from_u8, 40);
+define_split_unpacker!(from_u41, from_u9, 41);
+define_split_unpacker!(from_u42, from_u10, 42);
+define_split_unpacker!(from_u43, from_u11, 43);
+define_split_unpacker!(from_u44, from_u12, 44);
+define_split_unpacker!(from_u45, from_u13, 45);
+define_split_unpacker!(from_u46, from_u14, 46);
+define_split_unpacker!(from_u47, from_u15, 47);
+define_split_unpacker!(from_u48, from_u16, 48);
+define_split_unpacker!(from_u49, from_u17, 49);
+define_split_unpacker!(from_u50, from_u18, 50);
+define_split_unpacker!(from_u51, from_u19, 51);
+define_split_unpacker!(from_u52, from_u20, 52);
+define_split_unpacker!(from_u53, from_u21, 53);
+define_split_unpacker!(from_u54, from_u22, 54);
+define_split_unpacker!(from_u55, from_u23, 55);
+define_split_unpacker!(from_u56, from_u24, 56);
+define_split_unpacker!(from_u57, from_u25, 57);
+define_split_unpacker!(from_u58, from_u26, 58);
+define_split_unpacker!(from_u59, from_u27, 59);
+define_split_unpacker!(from_u60, from_u28, 60);
+define_split_unpacker!(from_u61, from_u29, 61);
+define_split_unpacker!(from_u62, from_u30, 62);
+define_split_unpacker!(from_u63, from_u31, 63);
+define_split_unpacker!(from_u64, from_u32, 64);
@@ -0,0 +1,98 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Subtract the frame of reference from each of the 64-bit elements held in the provided
+/// registers, which are split into their low and high 32 bits.
+pub(super) fn sub_reference(data: [[__m512i; 4]; 2], reference: u64) -> [[__m512i; 4]; 2] {
+    let [mut lo, mut hi] = data;
+    let reference_lo = _mm512_set1_epi32(reference as u32 as i32);
+    let reference_hi = _mm512_set1_epi32((reference >> 32) as u32 as i32);
+    let one = _mm512_set1_epi32(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        // The low half borrows from the high half when it is smaller than the reference.
+        let borrow = _mm512_cmplt_epu32_mask(lo[i], reference_lo);
+        lo[i] = _mm512_sub_epi32(lo[i], reference_lo);
+        let diff_hi = _mm512_sub_epi32(hi[i], reference_hi);
+        hi[i] = _mm512_mask_sub_epi32(diff_hi, borrow, diff_hi, one);
+    }
+    [lo, hi]
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Add the frame of reference back to each of the 64-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m512i; 8], reference: u64) -> [__m512i; 8] {
+    let reference = _mm512_set1_epi64(reference as i64);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..8 {
+        data[i] = _mm512_add_epi64(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the number of bits required to represent the largest of the first `n` 64-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m512i; 8], n: usize) -> u32 {
+    let mut acc = _mm512_setzero_si512();
+    for (i, register) in data.iter().enumerate() {
+        let selected = n.saturating_sub(i * 8).min(8);
+        let mask = ((1u16 << selected) - 1) as __mmask8;
+        acc = _mm512_or_si512(acc, _mm512_maskz_mov_epi64(mask, *register));
+    }
+
+    64 - (_mm512_reduce_or_epi64(acc) as u64).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint64::avx512::data::{load_u64x64, merge_u64x64, store_u64x64};
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_sub_add_reference() {
+        // The reference low bits are larger than some of the elements low bits, forcing
+        // a borrow from the high bits.
+        let reference = (3 << 32) | 0xFFFF_FF00;
+        let input: [u64; X64] = core::array::from_fn(|i| reference + (i as u64 * 0x1_0000_0011));
+        let expected: [u64; X64] = input.map(|v| v - reference);
+
+        let data = unsafe { load_u64x64(&input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+
+        let mut output = [0; X64];
+        let merged = unsafe { merge_u64x64(subtracted) };
+        unsafe { store_u64x64(&mut output, merged) };
+        assert_eq!(output, expected);
+
+        let added = unsafe { add_reference(merged, reference) };
+        unsafe { store_u64x64(&mut output, added) };
+        assert_eq!(output, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(8, 8)]
+    #[case::partial(37, 37)]
+    #[case::full(X64, 64)]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u64; X64] = core::array::from_fn(|i| 1 << i);
+        input[X64 - 1] = u64::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u64; X64], [__m512i; 8]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
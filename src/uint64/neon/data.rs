@@ -0,0 +1,91 @@
+use core::arch::aarch64::*;
+
+use crate::X64;
+
+#[target_feature(enable = "neon")]
+/// Load 64 64-bit elements split into 2 sets of 16, 128 bit registers holding the
+/// low and high 32 bits of each element respectively.
+pub(super) fn load_u64x64(block: &[u64; X64]) -> [[uint32x4_t; 16]; 2] {
+    split_u64x64(load_merged_u64x64(block))
+}
+
+#[target_feature(enable = "neon")]
+/// Load 32, 128 bit registers holding 64 64-bit elements.
+pub(super) fn load_merged_u64x64(block: &[u64; X64]) -> [uint64x2_t; 32] {
+    let ptr: *const u64 = block.as_ptr();
+    let mut data = [vdupq_n_u64(0); 32];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..32 {
+        data[i] = unsafe { vld1q_u64(ptr.add(i * 2)) };
+    }
+
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Split 32, 128 bit registers holding 64 64-bit elements into 2 sets of 16, 128 bit
+/// registers holding the low and high 32 bits of each element respectively.
+pub(super) fn split_u64x64(data: [uint64x2_t; 32]) -> [[uint32x4_t; 16]; 2] {
+    let mut lo = [vdupq_n_u32(0); 16];
+    let mut hi = [vdupq_n_u32(0); 16];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        let d1 = vreinterpretq_u32_u64(data[i * 2]);
+        let d2 = vreinterpretq_u32_u64(data[i * 2 + 1]);
+
+        lo[i] = vuzp1q_u32(d1, d2);
+        hi[i] = vuzp2q_u32(d1, d2);
+    }
+
+    [lo, hi]
+}
+
+#[target_feature(enable = "neon")]
+/// Merge the low and high 32 bits of each element held in 2 sets of 16, 128 bit registers
+/// producing 32, 128 bit registers holding 64 64-bit elements.
+pub(super) fn merge_u64x64(data: [[uint32x4_t; 16]; 2]) -> [uint64x2_t; 32] {
+    let [lo, hi] = data;
+    let mut merged = [vdupq_n_u64(0); 32];
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        merged[i * 2] = vreinterpretq_u64_u32(vzip1q_u32(lo[i], hi[i]));
+        merged[i * 2 + 1] = vreinterpretq_u64_u32(vzip2q_u32(lo[i], hi[i]));
+    }
+
+    merged
+}
+
+#[target_feature(enable = "neon")]
+/// Store 32, 128 bit registers holding 64 64-bit elements.
+pub(super) fn store_u64x64(block: &mut [u64; X64], data: [uint64x2_t; 32]) {
+    let ptr: *mut u64 = block.as_mut_ptr();
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..32 {
+        unsafe { vst1q_u64(ptr.add(i * 2), data[i]) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_load_merge_roundtrip() {
+        let expected: [u64; X64] = core::array::from_fn(|i| ((i as u64) << 32) | (i as u64 + 64));
+
+        let split = unsafe { load_u64x64(&expected) };
+        let lo = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(split[0]) };
+        let hi = unsafe { core::mem::transmute::<[uint32x4_t; 16], [u32; X64]>(split[1]) };
+        assert_eq!(lo, core::array::from_fn(|i| i as u32 + 64));
+        assert_eq!(hi, core::array::from_fn(|i| i as u32));
+
+        let mut output = [0; X64];
+        unsafe { store_u64x64(&mut output, merge_u64x64(split)) };
+        assert_eq!(output, expected);
+    }
+}
@@ -0,0 +1,97 @@
+use core::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+/// Subtract the frame of reference from each of the 64-bit elements held in the provided
+/// registers, which are split into their low and high 32 bits.
+pub(super) fn sub_reference(data: [[uint32x4_t; 16]; 2], reference: u64) -> [[uint32x4_t; 16]; 2] {
+    let [mut lo, mut hi] = data;
+    let reference_lo = vdupq_n_u32(reference as u32);
+    let reference_hi = vdupq_n_u32((reference >> 32) as u32);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..16 {
+        // The low half borrows from the high half when it is smaller than the reference,
+        // the comparison mask is all ones (`-1`) for lanes that borrow.
+        let borrow = vcltq_u32(lo[i], reference_lo);
+        lo[i] = vsubq_u32(lo[i], reference_lo);
+        hi[i] = vaddq_u32(vsubq_u32(hi[i], reference_hi), borrow);
+    }
+    [lo, hi]
+}
+
+#[target_feature(enable = "neon")]
+/// Add the frame of reference back to each of the 64-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [uint64x2_t; 32], reference: u64) -> [uint64x2_t; 32] {
+    let reference = vdupq_n_u64(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..32 {
+        data[i] = vaddq_u64(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the number of bits required to represent the largest of the first `n` 64-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[uint64x2_t; 32], n: usize) -> u32 {
+    const POSITIONS: [u64; 2] = [0, 1];
+
+    let limit = vdupq_n_u64(n as u64);
+    let step = vdupq_n_u64(2);
+    let mut positions = unsafe { vld1q_u64(POSITIONS.as_ptr()) };
+
+    let mut acc = vdupq_n_u64(0);
+    for register in data {
+        let selected = vcltq_u64(positions, limit);
+        acc = vorrq_u64(acc, vandq_u64(selected, *register));
+        positions = vaddq_u64(positions, step);
+    }
+
+    64 - (vgetq_lane_u64::<0>(acc) | vgetq_lane_u64::<1>(acc)).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint64::neon::data::{load_u64x64, merge_u64x64, store_u64x64};
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_sub_add_reference() {
+        // The reference low bits are larger than some of the elements low bits, forcing
+        // a borrow from the high bits.
+        let reference = (3 << 32) | 0xFFFF_FF00;
+        let input: [u64; X64] = core::array::from_fn(|i| reference + (i as u64 * 0x1_0000_0011));
+        let expected: [u64; X64] = input.map(|v| v - reference);
+
+        let data = unsafe { load_u64x64(&input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+
+        let mut output = [0; X64];
+        let merged = unsafe { merge_u64x64(subtracted) };
+        unsafe { store_u64x64(&mut output, merged) };
+        assert_eq!(output, expected);
+
+        let added = unsafe { add_reference(merged, reference) };
+        unsafe { store_u64x64(&mut output, added) };
+        assert_eq!(output, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(2, 2)]
+    #[case::partial(37, 37)]
+    #[case::full(X64, 64)]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u64; X64] = core::array::from_fn(|i| 1 << i);
+        input[X64 - 1] = u64::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u64; X64], [uint64x2_t; 32]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
@@ -0,0 +1,32 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::X64;
+use crate::uint32::scalar::polyfill::*;
+
+#[inline]
+/// Load 64 64-bit elements split into 2 sets of 8, 256 bit registers holding the
+/// low and high 32 bits of each element respectively.
+pub(super) fn load_u64x64(block: &[u64; X64]) -> [[u32x8; 8]; 2] {
+    let mut lo = [u32x8::ZERO; 8];
+    let mut hi = [u32x8::ZERO; 8];
+    for i in 0..8 {
+        for j in 0..8 {
+            let value = block[i * 8 + j];
+            lo[i][j] = value as u32;
+            hi[i][j] = (value >> 32) as u32;
+        }
+    }
+    [lo, hi]
+}
+
+#[inline]
+/// Merge the low and high 32 bits of each element held in 2 sets of 8, 256 bit registers
+/// and store the resulting 64 64-bit elements.
+pub(super) fn store_u64x64(block: &mut [u64; X64], data: [[u32x8; 8]; 2]) {
+    let [lo, hi] = data;
+    for i in 0..8 {
+        for j in 0..8 {
+            block[i * 8 + j] = ((hi[i][j] as u64) << 32) | lo[i][j] as u64;
+        }
+    }
+}
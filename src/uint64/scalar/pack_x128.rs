@@ -0,0 +1,283 @@
+use super::data::load_u64x64;
+use super::util::sub_reference;
+use super::{pack_x64_full, pack_x64_partial};
+use crate::uint64::{max_compressed_size, split_block};
+use crate::{X64, X128};
+
+#[inline]
+/// Bitpack the provided block of integers to `nbits` bit length  elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - `nbits` must be between 0 and 64.
+/// - `pack_n` must be no greater than 128.
+pub unsafe fn to_nbits(nbits: usize, out: *mut u8, block: &[u64; X128], pack_n: usize) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *mut u8, &[u64; X128], usize); 65] = [
+        to_u0, to_u1, to_u2, to_u3, to_u4, to_u5, to_u6, to_u7, to_u8, to_u9, to_u10, to_u11,
+        to_u12, to_u13, to_u14, to_u15, to_u16, to_u17, to_u18, to_u19, to_u20, to_u21, to_u22,
+        to_u23, to_u24, to_u25, to_u26, to_u27, to_u28, to_u29, to_u30, to_u31, to_u32, to_u33,
+        to_u34, to_u35, to_u36, to_u37, to_u38, to_u39, to_u40, to_u41, to_u42, to_u43, to_u44,
+        to_u45, to_u46, to_u47, to_u48, to_u49, to_u50, to_u51, to_u52, to_u53, to_u54, to_u55,
+        to_u56, to_u57, to_u58, to_u59, to_u60, to_u61, to_u62, to_u63, to_u64,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(out, block, pack_n) };
+}
+
+unsafe fn to_u0(_out: *mut u8, _block: &[u64; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer {
+    ($func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(out: *mut u8, block: &[u64; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let left = load_u64x64(left);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$func_name(out.add(0), left) };
+                let right = load_u64x64(right);
+                unsafe {
+                    pack_x64_partial::$func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$func_name(out.add(0), left) };
+                let right = load_u64x64(right);
+                unsafe {
+                    pack_x64_full::$func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer!(to_u1, 1);
+define_x128_packer!(to_u2, 2);
+define_x128_packer!(to_u3, 3);
+define_x128_packer!(to_u4, 4);
+define_x128_packer!(to_u5, 5);
+define_x128_packer!(to_u6, 6);
+define_x128_packer!(to_u7, 7);
+define_x128_packer!(to_u8, 8);
+define_x128_packer!(to_u9, 9);
+define_x128_packer!(to_u10, 10);
+define_x128_packer!(to_u11, 11);
+define_x128_packer!(to_u12, 12);
+define_x128_packer!(to_u13, 13);
+define_x128_packer!(to_u14, 14);
+define_x128_packer!(to_u15, 15);
+define_x128_packer!(to_u16, 16);
+define_x128_packer!(to_u17, 17);
+define_x128_packer!(to_u18, 18);
+define_x128_packer!(to_u19, 19);
+define_x128_packer!(to_u20, 20);
+define_x128_packer!(to_u21, 21);
+define_x128_packer!(to_u22, 22);
+define_x128_packer!(to_u23, 23);
+define_x128_packer!(to_u24, 24);
+define_x128_packer!(to_u25, 25);
+define_x128_packer!(to_u26, 26);
+define_x128_packer!(to_u27, 27);
+define_x128_packer!(to_u28, 28);
+define_x128_packer!(to_u29, 29);
+define_x128_packer!(to_u30, 30);
+define_x128_packer!(to_u31, 31);
+define_x128_packer!(to_u32, 32);
+define_x128_packer!(to_u33, 33);
+define_x128_packer!(to_u34, 34);
+define_x128_packer!(to_u35, 35);
+define_x128_packer!(to_u36, 36);
+define_x128_packer!(to_u37, 37);
+define_x128_packer!(to_u38, 38);
+define_x128_packer!(to_u39, 39);
+define_x128_packer!(to_u40, 40);
+define_x128_packer!(to_u41, 41);
+define_x128_packer!(to_u42, 42);
+define_x128_packer!(to_u43, 43);
+define_x128_packer!(to_u44, 44);
+define_x128_packer!(to_u45, 45);
+define_x128_packer!(to_u46, 46);
+define_x128_packer!(to_u47, 47);
+define_x128_packer!(to_u48, 48);
+define_x128_packer!(to_u49, 49);
+define_x128_packer!(to_u50, 50);
+define_x128_packer!(to_u51, 51);
+define_x128_packer!(to_u52, 52);
+define_x128_packer!(to_u53, 53);
+define_x128_packer!(to_u54, 54);
+define_x128_packer!(to_u55, 55);
+define_x128_packer!(to_u56, 56);
+define_x128_packer!(to_u57, 57);
+define_x128_packer!(to_u58, 58);
+define_x128_packer!(to_u59, 59);
+define_x128_packer!(to_u60, 60);
+define_x128_packer!(to_u61, 61);
+define_x128_packer!(to_u62, 62);
+define_x128_packer!(to_u63, 63);
+define_x128_packer!(to_u64, 64);
+
+#[inline]
+/// Bitpack the provided block of integers to `nbits` bit length elements after
+/// subtracting the `reference` value from each element.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X128>(nbits)` bytes to.
+/// - `nbits` must be between 0 and 64.
+/// - `pack_n` must be no greater than 128.
+/// - `reference` must be no greater than any of the first `pack_n` elements.
+pub unsafe fn to_nbits_for(
+    nbits: usize,
+    reference: u64,
+    out: *mut u8,
+    block: &[u64; X128],
+    pack_n: usize,
+) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(pack_n <= X128, "BUG: invalid pack_n provided: {pack_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u64, out: *mut u8, &[u64; X128], usize); 65] = [
+        to_u0_for, to_u1_for, to_u2_for, to_u3_for, to_u4_for, to_u5_for, to_u6_for, to_u7_for,
+        to_u8_for, to_u9_for, to_u10_for, to_u11_for, to_u12_for, to_u13_for, to_u14_for,
+        to_u15_for, to_u16_for, to_u17_for, to_u18_for, to_u19_for, to_u20_for, to_u21_for,
+        to_u22_for, to_u23_for, to_u24_for, to_u25_for, to_u26_for, to_u27_for, to_u28_for,
+        to_u29_for, to_u30_for, to_u31_for, to_u32_for, to_u33_for, to_u34_for, to_u35_for,
+        to_u36_for, to_u37_for, to_u38_for, to_u39_for, to_u40_for, to_u41_for, to_u42_for,
+        to_u43_for, to_u44_for, to_u45_for, to_u46_for, to_u47_for, to_u48_for, to_u49_for,
+        to_u50_for, to_u51_for, to_u52_for, to_u53_for, to_u54_for, to_u55_for, to_u56_for,
+        to_u57_for, to_u58_for, to_u59_for, to_u60_for, to_u61_for, to_u62_for, to_u63_for,
+        to_u64_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, out, block, pack_n) };
+}
+
+unsafe fn to_u0_for(_reference: u64, _out: *mut u8, _block: &[u64; X128], _pack_n: usize) {}
+
+macro_rules! define_x128_packer_for {
+    ($func_name:ident, $pack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(reference: u64, out: *mut u8, block: &[u64; X128], pack_n: usize) {
+            let [left, right] = split_block(block);
+
+            let left = sub_reference(load_u64x64(left), reference);
+            if pack_n <= 64 {
+                unsafe { pack_x64_partial::$pack_func_name(out.add(0), left, pack_n) };
+            } else if pack_n < 128 {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u64x64(right), reference);
+                unsafe {
+                    pack_x64_partial::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                        pack_n - X64,
+                    )
+                }
+            } else {
+                unsafe { pack_x64_full::$pack_func_name(out.add(0), left) };
+                let right = sub_reference(load_u64x64(right), reference);
+                unsafe {
+                    pack_x64_full::$pack_func_name(
+                        out.add(max_compressed_size::<X64>($bit_length)),
+                        right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+define_x128_packer_for!(to_u1_for, to_u1, 1);
+define_x128_packer_for!(to_u2_for, to_u2, 2);
+define_x128_packer_for!(to_u3_for, to_u3, 3);
+define_x128_packer_for!(to_u4_for, to_u4, 4);
+define_x128_packer_for!(to_u5_for, to_u5, 5);
+define_x128_packer_for!(to_u6_for, to_u6, 6);
+define_x128_packer_for!(to_u7_for, to_u7, 7);
+define_x128_packer_for!(to_u8_for, to_u8, 8);
+define_x128_packer_for!(to_u9_for, to_u9, 9);
+define_x128_packer_for!(to_u10_for, to_u10, 10);
+define_x128_packer_for!(to_u11_for, to_u11, 11);
+define_x128_packer_for!(to_u12_for, to_u12, 12);
+define_x128_packer_for!(to_u13_for, to_u13, 13);
+define_x128_packer_for!(to_u14_for, to_u14, 14);
+define_x128_packer_for!(to_u15_for, to_u15, 15);
+define_x128_packer_for!(to_u16_for, to_u16, 16);
+define_x128_packer_for!(to_u17_for, to_u17, 17);
+define_x128_packer_for!(to_u18_for, to_u18, 18);
+define_x128_packer_for!(to_u19_for, to_u19, 19);
+define_x128_packer_for!(to_u20_for, to_u20, 20);
+define_x128_packer_for!(to_u21_for, to_u21, 21);
+define_x128_packer_for!(to_u22_for, to_u22, 22);
+define_x128_packer_for!(to_u23_for, to_u23, 23);
+define_x128_packer_for!(to_u24_for, to_u24, 24);
+define_x128_packer_for!(to_u25_for, to_u25, 25);
+define_x128_packer_for!(to_u26_for, to_u26, 26);
+define_x128_packer_for!(to_u27_for, to_u27, 27);
+define_x128_packer_for!(to_u28_for, to_u28, 28);
+define_x128_packer_for!(to_u29_for, to_u29, 29);
+define_x128_packer_for!(to_u30_for, to_u30, 30);
+define_x128_packer_for!(to_u31_for, to_u31, 31);
+define_x128_packer_for!(to_u32_for, to_u32, 32);
+define_x128_packer_for!(to_u33_for, to_u33, 33);
+define_x128_packer_for!(to_u34_for, to_u34, 34);
+define_x128_packer_for!(to_u35_for, to_u35, 35);
+define_x128_packer_for!(to_u36_for, to_u36, 36);
+define_x128_packer_for!(to_u37_for, to_u37, 37);
+define_x128_packer_for!(to_u38_for, to_u38, 38);
+define_x128_packer_for!(to_u39_for, to_u39, 39);
+define_x128_packer_for!(to_u40_for, to_u40, 40);
+define_x128_packer_for!(to_u41_for, to_u41, 41);
+define_x128_packer_for!(to_u42_for, to_u42, 42);
+define_x128_packer_for!(to_u43_for, to_u43, 43);
+define_x128_packer_for!(to_u44_for, to_u44, 44);
+define_x128_packer_for!(to_u45_for, to_u45, 45);
+define_x128_packer_for!(to_u46_for, to_u46, 46);
+define_x128_packer_for!(to_u47_for, to_u47, 47);
+define_x128_packer_for!(to_u48_for, to_u48, 48);
+define_x128_packer_for!(to_u49_for, to_u49, 49);
+define_x128_packer_for!(to_u50_for, to_u50, 50);
+define_x128_packer_for!(to_u51_for, to_u51, 51);
+define_x128_packer_for!(to_u52_for, to_u52, 52);
+define_x128_packer_for!(to_u53_for, to_u53, 53);
+define_x128_packer_for!(to_u54_for, to_u54, 54);
+define_x128_packer_for!(to_u55_for, to_u55, 55);
+define_x128_packer_for!(to_u56_for, to_u56, 56);
+define_x128_packer_for!(to_u57_for, to_u57, 57);
+define_x128_packer_for!(to_u58_for, to_u58, 58);
+define_x128_packer_for!(to_u59_for, to_u59, 59);
+define_x128_packer_for!(to_u60_for, to_u60, 60);
+define_x128_packer_for!(to_u61_for, to_u61, 61);
+define_x128_packer_for!(to_u62_for, to_u62, 62);
+define_x128_packer_for!(to_u63_for, to_u63, 63);
+define_x128_packer_for!(to_u64_for, to_u64, 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint64::X128_MAX_OUTPUT_LEN;
+
+    #[test]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint64::test_util::load_uint64_regression_layout();
+
+        let mut output_buffer = [0; X128_MAX_OUTPUT_LEN];
+        for (len, bit_len, input, expected_output) in tester.iter_tests() {
+            unsafe { to_nbits(bit_len as usize, output_buffer.as_mut_ptr(), input, len) };
+
+            let produced_buffer = &output_buffer[..expected_output.len()];
+            assert_eq!(
+                produced_buffer, expected_output,
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+}
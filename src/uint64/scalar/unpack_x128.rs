@@ -0,0 +1,462 @@
+use super::data::*;
+use super::util::add_reference;
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint64::{max_compressed_size, split_block_mut};
+use crate::{X64, X128};
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u64; X128], read_n: usize) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(out: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8, from_u9,
+        from_u10, from_u11, from_u12, from_u13, from_u14, from_u15, from_u16, from_u17, from_u18,
+        from_u19, from_u20, from_u21, from_u22, from_u23, from_u24, from_u25, from_u26, from_u27,
+        from_u28, from_u29, from_u30, from_u31, from_u32, from_u33, from_u34, from_u35, from_u36,
+        from_u37, from_u38, from_u39, from_u40, from_u41, from_u42, from_u43, from_u44, from_u45,
+        from_u46, from_u47, from_u48, from_u49, from_u50, from_u51, from_u52, from_u53, from_u54,
+        from_u55, from_u56, from_u57, from_u58, from_u59, from_u60, from_u61, from_u62, from_u63,
+        from_u64,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta(
+    nbits: usize,
+    last_value: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits(nbits, input, out, read_n) };
+    decode_delta(last_value, out);
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-1-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta1(
+    nbits: usize,
+    last_value: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits(nbits, input, out, read_n) };
+    decode_delta1(last_value, out);
+}
+
+unsafe fn from_u0(_input: *const u8, out: &mut [u64; X128], _read_n: usize) {
+    out.fill(0);
+}
+
+macro_rules! define_x128_unpacker {
+    ($func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(input: *const u8, out: &mut [u64; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
+                store_u64x64(left, unpacked);
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                store_u64x64(left, unpacked);
+                let unpacked = unsafe {
+                    unpack_x64_partial::$func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u64x64(right, unpacked);
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                store_u64x64(left, unpacked);
+                let unpacked = unsafe {
+                    unpack_x64_full::$func_name(input.add(max_compressed_size::<X64>($bit_length)))
+                };
+                store_u64x64(right, unpacked);
+            }
+        }
+    };
+}
+
+define_x128_unpacker!(from_u1, 1);
+define_x128_unpacker!(from_u2, 2);
+define_x128_unpacker!(from_u3, 3);
+define_x128_unpacker!(from_u4, 4);
+define_x128_unpacker!(from_u5, 5);
+define_x128_unpacker!(from_u6, 6);
+define_x128_unpacker!(from_u7, 7);
+define_x128_unpacker!(from_u8, 8);
+define_x128_unpacker!(from_u9, 9);
+define_x128_unpacker!(from_u10, 10);
+define_x128_unpacker!(from_u11, 11);
+define_x128_unpacker!(from_u12, 12);
+define_x128_unpacker!(from_u13, 13);
+define_x128_unpacker!(from_u14, 14);
+define_x128_unpacker!(from_u15, 15);
+define_x128_unpacker!(from_u16, 16);
+define_x128_unpacker!(from_u17, 17);
+define_x128_unpacker!(from_u18, 18);
+define_x128_unpacker!(from_u19, 19);
+define_x128_unpacker!(from_u20, 20);
+define_x128_unpacker!(from_u21, 21);
+define_x128_unpacker!(from_u22, 22);
+define_x128_unpacker!(from_u23, 23);
+define_x128_unpacker!(from_u24, 24);
+define_x128_unpacker!(from_u25, 25);
+define_x128_unpacker!(from_u26, 26);
+define_x128_unpacker!(from_u27, 27);
+define_x128_unpacker!(from_u28, 28);
+define_x128_unpacker!(from_u29, 29);
+define_x128_unpacker!(from_u30, 30);
+define_x128_unpacker!(from_u31, 31);
+define_x128_unpacker!(from_u32, 32);
+define_x128_unpacker!(from_u33, 33);
+define_x128_unpacker!(from_u34, 34);
+define_x128_unpacker!(from_u35, 35);
+define_x128_unpacker!(from_u36, 36);
+define_x128_unpacker!(from_u37, 37);
+define_x128_unpacker!(from_u38, 38);
+define_x128_unpacker!(from_u39, 39);
+define_x128_unpacker!(from_u40, 40);
+define_x128_unpacker!(from_u41, 41);
+define_x128_unpacker!(from_u42, 42);
+define_x128_unpacker!(from_u43, 43);
+define_x128_unpacker!(from_u44, 44);
+define_x128_unpacker!(from_u45, 45);
+define_x128_unpacker!(from_u46, 46);
+define_x128_unpacker!(from_u47, 47);
+define_x128_unpacker!(from_u48, 48);
+define_x128_unpacker!(from_u49, 49);
+define_x128_unpacker!(from_u50, 50);
+define_x128_unpacker!(from_u51, 51);
+define_x128_unpacker!(from_u52, 52);
+define_x128_unpacker!(from_u53, 53);
+define_x128_unpacker!(from_u54, 54);
+define_x128_unpacker!(from_u55, 55);
+define_x128_unpacker!(from_u56, 56);
+define_x128_unpacker!(from_u57, 57);
+define_x128_unpacker!(from_u58, 58);
+define_x128_unpacker!(from_u59, 59);
+define_x128_unpacker!(from_u60, 60);
+define_x128_unpacker!(from_u61, 61);
+define_x128_unpacker!(from_u62, 62);
+define_x128_unpacker!(from_u63, 63);
+define_x128_unpacker!(from_u64, 64);
+
+fn decode_delta(mut last_value: u64, block: &mut [u64; X128]) -> u64 {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..128 {
+        last_value = last_value.wrapping_add(block[i]);
+        block[i] = last_value;
+    }
+    last_value
+}
+
+fn decode_delta1(mut last_value: u64, block: &mut [u64; X128]) -> u64 {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..128 {
+        last_value = last_value.wrapping_add(block[i]).wrapping_add(1);
+        block[i] = last_value;
+    }
+    last_value
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 64.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u64,
+    input: *const u8,
+    out: &mut [u64; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 64, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u64, input: *const u8, &mut [u64; X128], usize); 65] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+        from_u9_for,
+        from_u10_for,
+        from_u11_for,
+        from_u12_for,
+        from_u13_for,
+        from_u14_for,
+        from_u15_for,
+        from_u16_for,
+        from_u17_for,
+        from_u18_for,
+        from_u19_for,
+        from_u20_for,
+        from_u21_for,
+        from_u22_for,
+        from_u23_for,
+        from_u24_for,
+        from_u25_for,
+        from_u26_for,
+        from_u27_for,
+        from_u28_for,
+        from_u29_for,
+        from_u30_for,
+        from_u31_for,
+        from_u32_for,
+        from_u33_for,
+        from_u34_for,
+        from_u35_for,
+        from_u36_for,
+        from_u37_for,
+        from_u38_for,
+        from_u39_for,
+        from_u40_for,
+        from_u41_for,
+        from_u42_for,
+        from_u43_for,
+        from_u44_for,
+        from_u45_for,
+        from_u46_for,
+        from_u47_for,
+        from_u48_for,
+        from_u49_for,
+        from_u50_for,
+        from_u51_for,
+        from_u52_for,
+        from_u53_for,
+        from_u54_for,
+        from_u55_for,
+        from_u56_for,
+        from_u57_for,
+        from_u58_for,
+        from_u59_for,
+        from_u60_for,
+        from_u61_for,
+        from_u62_for,
+        from_u63_for,
+        from_u64_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+unsafe fn from_u0_for(reference: u64, _input: *const u8, out: &mut [u64; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(
+            reference: u64,
+            input: *const u8,
+            out: &mut [u64; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                store_u64x64(left, add_reference(unpacked, reference));
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u64x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                store_u64x64(right, add_reference(unpacked, reference));
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                store_u64x64(left, add_reference(unpacked, reference));
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                store_u64x64(right, add_reference(unpacked, reference));
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+define_x128_unpacker_for!(from_u9_for, from_u9, 9);
+define_x128_unpacker_for!(from_u10_for, from_u10, 10);
+define_x128_unpacker_for!(from_u11_for, from_u11, 11);
+define_x128_unpacker_for!(from_u12_for, from_u12, 12);
+define_x128_unpacker_for!(from_u13_for, from_u13, 13);
+define_x128_unpacker_for!(from_u14_for, from_u14, 14);
+define_x128_unpacker_for!(from_u15_for, from_u15, 15);
+define_x128_unpacker_for!(from_u16_for, from_u16, 16);
+define_x128_unpacker_for!(from_u17_for, from_u17, 17);
+define_x128_unpacker_for!(from_u18_for, from_u18, 18);
+define_x128_unpacker_for!(from_u19_for, from_u19, 19);
+define_x128_unpacker_for!(from_u20_for, from_u20, 20);
+define_x128_unpacker_for!(from_u21_for, from_u21, 21);
+define_x128_unpacker_for!(from_u22_for, from_u22, 22);
+define_x128_unpacker_for!(from_u23_for, from_u23, 23);
+define_x128_unpacker_for!(from_u24_for, from_u24, 24);
+define_x128_unpacker_for!(from_u25_for, from_u25, 25);
+define_x128_unpacker_for!(from_u26_for, from_u26, 26);
+define_x128_unpacker_for!(from_u27_for, from_u27, 27);
+define_x128_unpacker_for!(from_u28_for, from_u28, 28);
+define_x128_unpacker_for!(from_u29_for, from_u29, 29);
+define_x128_unpacker_for!(from_u30_for, from_u30, 30);
+define_x128_unpacker_for!(from_u31_for, from_u31, 31);
+define_x128_unpacker_for!(from_u32_for, from_u32, 32);
+define_x128_unpacker_for!(from_u33_for, from_u33, 33);
+define_x128_unpacker_for!(from_u34_for, from_u34, 34);
+define_x128_unpacker_for!(from_u35_for, from_u35, 35);
+define_x128_unpacker_for!(from_u36_for, from_u36, 36);
+define_x128_unpacker_for!(from_u37_for, from_u37, 37);
+define_x128_unpacker_for!(from_u38_for, from_u38, 38);
+define_x128_unpacker_for!(from_u39_for, from_u39, 39);
+define_x128_unpacker_for!(from_u40_for, from_u40, 40);
+define_x128_unpacker_for!(from_u41_for, from_u41, 41);
+define_x128_unpacker_for!(from_u42_for, from_u42, 42);
+define_x128_unpacker_for!(from_u43_for, from_u43, 43);
+define_x128_unpacker_for!(from_u44_for, from_u44, 44);
+define_x128_unpacker_for!(from_u45_for, from_u45, 45);
+define_x128_unpacker_for!(from_u46_for, from_u46, 46);
+define_x128_unpacker_for!(from_u47_for, from_u47, 47);
+define_x128_unpacker_for!(from_u48_for, from_u48, 48);
+define_x128_unpacker_for!(from_u49_for, from_u49, 49);
+define_x128_unpacker_for!(from_u50_for, from_u50, 50);
+define_x128_unpacker_for!(from_u51_for, from_u51, 51);
+define_x128_unpacker_for!(from_u52_for, from_u52, 52);
+define_x128_unpacker_for!(from_u53_for, from_u53, 53);
+define_x128_unpacker_for!(from_u54_for, from_u54, 54);
+define_x128_unpacker_for!(from_u55_for, from_u55, 55);
+define_x128_unpacker_for!(from_u56_for, from_u56, 56);
+define_x128_unpacker_for!(from_u57_for, from_u57, 57);
+define_x128_unpacker_for!(from_u58_for, from_u58, 58);
+define_x128_unpacker_for!(from_u59_for, from_u59, 59);
+define_x128_unpacker_for!(from_u60_for, from_u60, 60);
+define_x128_unpacker_for!(from_u61_for, from_u61, 61);
+define_x128_unpacker_for!(from_u62_for, from_u62, 62);
+define_x128_unpacker_for!(from_u63_for, from_u63, 63);
+define_x128_unpacker_for!(from_u64_for, from_u64, 64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint64::X128_MAX_OUTPUT_LEN;
+    use crate::uint64::scalar::pack_x128::to_nbits_for;
+
+    #[test]
+    fn test_decode_delta_zero_starting_value() {
+        let expected_values: [u64; X128] = core::array::from_fn(|i| i as u64);
+        let mut block = [1; X128];
+        block[0] = 0;
+        decode_delta(0, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta_starting_value() {
+        let expected_values: [u64; X128] = core::array::from_fn(|i| 4 + i as u64);
+        let mut block = [1; X128];
+        block[0] = 0;
+        decode_delta(4, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta1_zero_starting_value() {
+        let expected_values: [u64; X128] = core::array::from_fn(|i| i as u64 + 1);
+        let mut block = [0; X128];
+        decode_delta1(0, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta1_starting_value() {
+        let expected_values: [u64; X128] = core::array::from_fn(|i| i as u64 + 5);
+        let mut block = [0; X128];
+        decode_delta1(4, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint64::test_util::load_uint64_regression_layout();
+
+        let mut output_buffer = [0u64; X128];
+        for (len, bit_len, expected_output, input) in tester.iter_tests() {
+            unsafe { from_nbits(bit_len as usize, input.as_ptr(), &mut output_buffer, len) };
+
+            let produced_buffer = &output_buffer[..len];
+            assert_eq!(
+                produced_buffer,
+                &expected_output[..len],
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+
+    #[rstest::rstest]
+    fn test_for_roundtrip(
+        #[values(0, 1, 17, 33, 64)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u64::MAX >> (64 - nbits)
+        };
+        let reference = u64::MAX - mask;
+        let expected: [u64; X128] =
+            core::array::from_fn(|i| reference + ((i as u64).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+}
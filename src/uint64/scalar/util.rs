@@ -0,0 +1,61 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::uint32::scalar::polyfill::u32x8;
+
+/// Subtract the frame of reference from each of the 64-bit elements held in the provided
+/// registers, which are split into their low and high 32 bits.
+pub(super) fn sub_reference(data: [[u32x8; 8]; 2], reference: u64) -> [[u32x8; 8]; 2] {
+    let [mut lo, mut hi] = data;
+    for i in 0..8 {
+        for j in 0..8 {
+            let value = ((hi[i][j] as u64) << 32) | lo[i][j] as u64;
+            let value = value.wrapping_sub(reference);
+            lo[i][j] = value as u32;
+            hi[i][j] = (value >> 32) as u32;
+        }
+    }
+    [lo, hi]
+}
+
+/// Add the frame of reference back to each of the 64-bit elements held in the provided
+/// registers, which are split into their low and high 32 bits.
+pub(super) fn add_reference(data: [[u32x8; 8]; 2], reference: u64) -> [[u32x8; 8]; 2] {
+    let [mut lo, mut hi] = data;
+    for i in 0..8 {
+        for j in 0..8 {
+            let value = ((hi[i][j] as u64) << 32) | lo[i][j] as u64;
+            let value = value.wrapping_add(reference);
+            lo[i][j] = value as u32;
+            hi[i][j] = (value >> 32) as u32;
+        }
+    }
+    [lo, hi]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint64::scalar::data::{load_u64x64, store_u64x64};
+
+    #[test]
+    fn test_sub_add_reference() {
+        // The reference low bits are larger than some of the elements low bits, forcing
+        // a borrow from the high bits.
+        let reference = (3 << 32) | 0xFFFF_FF00;
+        let input: [u64; X64] = core::array::from_fn(|i| reference + (i as u64 * 0x1_0000_0011));
+        let expected: [u64; X64] = input.map(|v| v - reference);
+
+        let data = load_u64x64(&input);
+        let subtracted = sub_reference(data, reference);
+
+        let mut output = [0; X64];
+        store_u64x64(&mut output, subtracted);
+        assert_eq!(output, expected);
+
+        let added = add_reference(subtracted, reference);
+        let mut output = [0; X64];
+        store_u64x64(&mut output, added);
+        assert_eq!(output, input);
+    }
+}
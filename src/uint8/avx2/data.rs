@@ -0,0 +1,29 @@
+use core::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Load 64 8-bit elements from `ptr` into 2, 256 bit registers.
+///
+/// # Safety
+/// - `ptr` must be safe to read `64` bytes from.
+pub(super) unsafe fn load_u8x64(ptr: *const u8) -> [__m256i; 2] {
+    let ptr: *const __m256i = ptr.cast();
+    unsafe {
+        [
+            _mm256_loadu_si256(ptr.add(0)),
+            _mm256_loadu_si256(ptr.add(1)),
+        ]
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Store 2, 256 bit registers holding 64 8-bit elements to `ptr`.
+///
+/// # Safety
+/// - `ptr` must be safe to write `64` bytes to.
+pub(super) unsafe fn store_u8x64(ptr: *mut u8, data: [__m256i; 2]) {
+    let ptr: *mut __m256i = ptr.cast();
+    unsafe { _mm256_storeu_si256(ptr.add(0), data[0]) };
+    unsafe { _mm256_storeu_si256(ptr.add(1), data[1]) };
+}
@@ -0,0 +1,465 @@
+use core::arch::x86_64::*;
+
+use super::data::*;
+use super::util::add_reference;
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint8::{max_compressed_size, split_block_mut};
+use crate::{X64, X128};
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u8; X128], read_n: usize) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta(
+    nbits: usize,
+    last_value: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u8, input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0_delta,
+        from_u1_delta,
+        from_u2_delta,
+        from_u3_delta,
+        from_u4_delta,
+        from_u5_delta,
+        from_u6_delta,
+        from_u7_delta,
+        from_u8_delta,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, input, out, read_n) };
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-1-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta1(
+    nbits: usize,
+    last_value: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u8, input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0_delta1,
+        from_u1_delta1,
+        from_u2_delta1,
+        from_u3_delta1,
+        from_u4_delta1,
+        from_u5_delta1,
+        from_u6_delta1,
+        from_u7_delta1,
+        from_u8_delta1,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(last_value, input, out, read_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0(_input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    out.fill(0);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_delta(last_value: u8, _input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    out.fill(last_value);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_delta1(last_value: u8, _input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..X128 {
+        out[i] = (i as u8).wrapping_add(last_value).wrapping_add(1);
+    }
+}
+
+macro_rules! define_x128_unpacker {
+    ($func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(input: *const u8, out: &mut [u8; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+                let unpacked = unsafe {
+                    unpack_x64_partial::$func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+                let unpacked = unsafe {
+                    unpack_x64_full::$func_name(input.add(max_compressed_size::<X64>($bit_length)))
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            }
+        }
+    };
+}
+
+macro_rules! define_x128_unpacker_delta {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr, $delta_func_name:ident) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(
+            last_value: u8,
+            input: *const u8,
+            out: &mut [u8; X128],
+            read_n: usize,
+        ) {
+            let [left, right] = split_block_mut(out);
+
+            let mut last_value = _mm256_set1_epi8(last_value as i8);
+
+            if read_n <= 64 {
+                let mut unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                $delta_func_name(last_value, &mut unpacked);
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+            } else if read_n < 128 {
+                let mut unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                last_value = $delta_func_name(last_value, &mut unpacked);
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+
+                let mut unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                $delta_func_name(last_value, &mut unpacked);
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            } else {
+                let mut unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                last_value = $delta_func_name(last_value, &mut unpacked);
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+
+                let mut unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                $delta_func_name(last_value, &mut unpacked);
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            }
+        }
+    };
+}
+
+define_x128_unpacker!(from_u1, 1);
+define_x128_unpacker!(from_u2, 2);
+define_x128_unpacker!(from_u3, 3);
+define_x128_unpacker!(from_u4, 4);
+define_x128_unpacker!(from_u5, 5);
+define_x128_unpacker!(from_u6, 6);
+define_x128_unpacker!(from_u7, 7);
+define_x128_unpacker!(from_u8, 8);
+
+// Delta encoding
+define_x128_unpacker_delta!(from_u1_delta, from_u1, 1, decode_delta);
+define_x128_unpacker_delta!(from_u2_delta, from_u2, 2, decode_delta);
+define_x128_unpacker_delta!(from_u3_delta, from_u3, 3, decode_delta);
+define_x128_unpacker_delta!(from_u4_delta, from_u4, 4, decode_delta);
+define_x128_unpacker_delta!(from_u5_delta, from_u5, 5, decode_delta);
+define_x128_unpacker_delta!(from_u6_delta, from_u6, 6, decode_delta);
+define_x128_unpacker_delta!(from_u7_delta, from_u7, 7, decode_delta);
+define_x128_unpacker_delta!(from_u8_delta, from_u8, 8, decode_delta);
+
+// Delta-1 encoding
+define_x128_unpacker_delta!(from_u1_delta1, from_u1, 1, decode_delta1);
+define_x128_unpacker_delta!(from_u2_delta1, from_u2, 2, decode_delta1);
+define_x128_unpacker_delta!(from_u3_delta1, from_u3, 3, decode_delta1);
+define_x128_unpacker_delta!(from_u4_delta1, from_u4, 4, decode_delta1);
+define_x128_unpacker_delta!(from_u5_delta1, from_u5, 5, decode_delta1);
+define_x128_unpacker_delta!(from_u6_delta1, from_u6, 6, decode_delta1);
+define_x128_unpacker_delta!(from_u7_delta1, from_u7, 7, decode_delta1);
+define_x128_unpacker_delta!(from_u8_delta1, from_u8, 8, decode_delta1);
+
+#[target_feature(enable = "avx2")]
+fn decode_delta(mut last_value: __m256i, block: &mut [__m256i; 2]) -> __m256i {
+    let last_byte = _mm256_set1_epi8(15);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        let mut sum = block[i];
+        sum = _mm256_add_epi8(sum, _mm256_slli_si256::<1>(sum));
+        sum = _mm256_add_epi8(sum, _mm256_slli_si256::<2>(sum));
+        sum = _mm256_add_epi8(sum, _mm256_slli_si256::<4>(sum));
+        sum = _mm256_add_epi8(sum, _mm256_slli_si256::<8>(sum));
+
+        // Each 128 bit lane holds its own prefix sum, carry the low lane total into the high lane.
+        let lane_totals = _mm256_shuffle_epi8(sum, last_byte);
+        let cross_lane_add = _mm256_permute2x128_si256::<0x08>(lane_totals, lane_totals);
+        sum = _mm256_add_epi8(sum, cross_lane_add);
+
+        let result = _mm256_add_epi8(sum, last_value);
+        block[i] = result;
+
+        let lane_totals = _mm256_shuffle_epi8(result, last_byte);
+        last_value = _mm256_permute2x128_si256::<0x11>(lane_totals, lane_totals);
+    }
+
+    last_value
+}
+
+#[target_feature(enable = "avx2")]
+fn decode_delta1(last_value: __m256i, block: &mut [__m256i; 2]) -> __m256i {
+    let ones = _mm256_set1_epi8(1);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        block[i] = _mm256_add_epi8(block[i], ones);
+    }
+
+    decode_delta(last_value, block)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - The runtime CPU must support the `avx2` instructions.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u8, input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn from_u0_for(reference: u8, _input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        #[target_feature(enable = "avx2")]
+        unsafe fn $func_name(reference: u8, input: *const u8, out: &mut [u8; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), add_reference(unpacked, reference)) };
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), add_reference(unpacked, reference)) };
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint8::X128_MAX_OUTPUT_LEN;
+    use crate::uint8::avx2::pack_x128::to_nbits_for;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_decode_delta_zero_starting_value() {
+        let expected_values: [u8; X64] = core::array::from_fn(|i| i as u8);
+        let mut values = [1; X64];
+        values[0] = 0;
+
+        let mut block = unsafe { load_u8x64(values.as_ptr()) };
+        unsafe { decode_delta(_mm256_set1_epi8(0), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u8x64(result.as_mut_ptr(), block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_decode_delta_starting_value() {
+        let expected_values: [u8; X64] = core::array::from_fn(|i| 100 + i as u8);
+        let mut values = [1; X64];
+        values[0] = 0;
+
+        let mut block = unsafe { load_u8x64(values.as_ptr()) };
+        unsafe { decode_delta(_mm256_set1_epi8(100), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u8x64(result.as_mut_ptr(), block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_decode_delta1_zero_starting_value() {
+        let expected_values: [u8; X64] = core::array::from_fn(|i| i as u8 + 1);
+        let values = [0; X64];
+
+        let mut block = unsafe { load_u8x64(values.as_ptr()) };
+        unsafe { decode_delta1(_mm256_set1_epi8(0), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u8x64(result.as_mut_ptr(), block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_decode_delta1_starting_value() {
+        let expected_values: [u8; X64] = core::array::from_fn(|i| (i as u8).wrapping_add(101));
+        let values = [0; X64];
+
+        let mut block = unsafe { load_u8x64(values.as_ptr()) };
+        unsafe { decode_delta1(_mm256_set1_epi8(100), &mut block) };
+
+        let mut result = [0; X64];
+        unsafe { store_u8x64(result.as_mut_ptr(), block) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_decode_delta_wrapping() {
+        let expected_values: [u8; X128] =
+            core::array::from_fn(|i| (i as u8).wrapping_mul(3).wrapping_add(250));
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        packed.fill(3);
+
+        let mut result = [0; X128];
+        unsafe { from_nbits_delta(8, 247, packed.as_ptr(), &mut result, X128) };
+        assert_eq!(result, expected_values);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint8::test_util::load_uint8_regression_layout();
+
+        let mut output_buffer = [0u8; X128];
+        for (len, bit_len, expected_output, input) in tester.iter_tests() {
+            unsafe { from_nbits(bit_len as usize, input.as_ptr(), &mut output_buffer, len) };
+
+            let produced_buffer = &output_buffer[..len];
+            assert_eq!(
+                produced_buffer,
+                &expected_output[..len],
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+
+    #[rstest::rstest]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_for_roundtrip(
+        #[values(0, 1, 3, 7, 8)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u8::MAX >> (8 - nbits)
+        };
+        let reference = u8::MAX - mask;
+        let expected: [u8; X128] =
+            core::array::from_fn(|i| reference + ((i as u8).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+}
@@ -0,0 +1,94 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+/// Subtract the frame of reference from each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [__m256i; 2], reference: u8) -> [__m256i; 2] {
+    let reference = _mm256_set1_epi8(reference as i8);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        data[i] = _mm256_sub_epi8(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Add the frame of reference back to each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [__m256i; 2], reference: u8) -> [__m256i; 2] {
+    let reference = _mm256_set1_epi8(reference as i8);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        data[i] = _mm256_add_epi8(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "avx2")]
+/// Returns the number of bits required to represent the largest of the first `n` 8-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[__m256i; 2], n: usize) -> u32 {
+    #[rustfmt::skip]
+    let mut positions = _mm256_setr_epi8(
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+    );
+    let limit = _mm256_set1_epi8(n as i8 - 1);
+    let step = _mm256_set1_epi8(32);
+
+    let mut acc = _mm256_setzero_si256();
+    for register in data {
+        let excluded = _mm256_cmpgt_epi8(positions, limit);
+        acc = _mm256_or_si256(acc, _mm256_andnot_si256(excluded, *register));
+        positions = _mm256_add_epi8(positions, step);
+    }
+
+    acc = _mm256_or_si256(acc, _mm256_permute2x128_si256::<0x01>(acc, acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0x4E>(acc));
+    acc = _mm256_or_si256(acc, _mm256_shuffle_epi32::<0xB1>(acc));
+
+    let lanes = _mm256_cvtsi256_si32(acc) as u32;
+    let lanes = lanes | (lanes >> 16);
+    let lanes = lanes | (lanes >> 8);
+    8 - (lanes as u8).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u8::MAX - 80;
+        let input: [u8; X64] = core::array::from_fn(|i| reference + i as u8);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [__m256i; 2]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[__m256i; 2], [u8; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(32, 8)]
+    #[case::partial(5, 5)]
+    #[case::full(X64, 8)]
+    #[cfg_attr(not(target_feature = "avx2"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u8; X64] = core::array::from_fn(|i| 1 << (i % 8));
+        input[X64 - 1] = u8::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [__m256i; 2]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
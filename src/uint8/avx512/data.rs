@@ -0,0 +1,21 @@
+use core::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+/// Load 64 8-bit elements from `ptr` into a single 512 bit register.
+///
+/// # Safety
+/// - `ptr` must be safe to read `64` bytes from.
+pub(super) unsafe fn load_u8x64(ptr: *const u8) -> __m512i {
+    unsafe { _mm512_loadu_si512(ptr.cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+/// Store a 512 bit register holding 64 8-bit elements to `ptr`.
+///
+/// # Safety
+/// - `ptr` must be safe to write `64` bytes to.
+pub(super) unsafe fn store_u8x64(ptr: *mut u8, data: __m512i) {
+    unsafe { _mm512_storeu_si512(ptr.cast(), data) };
+}
@@ -0,0 +1,92 @@
+use core::arch::x86_64::*;
+
+use super::data::*;
+use crate::uint32::avx512::pack_x64_full::{
+    pack_u1_registers,
+    pack_u2_registers,
+    pack_u3_registers,
+    pack_u4_registers,
+    pack_u5_registers,
+    pack_u6_registers,
+    pack_u7_registers,
+};
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 1-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(1)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u1(out: *mut u8, block: __m512i) {
+    unsafe { pack_u1_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 2-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(2)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u2(out: *mut u8, block: __m512i) {
+    unsafe { pack_u2_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 3-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(3)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u3(out: *mut u8, block: __m512i) {
+    unsafe { pack_u3_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 4-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(4)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u4(out: *mut u8, block: __m512i) {
+    unsafe { pack_u4_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 5-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(5)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u5(out: *mut u8, block: __m512i) {
+    unsafe { pack_u5_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 6-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(6)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u6(out: *mut u8, block: __m512i) {
+    unsafe { pack_u6_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 7-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(7)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u7(out: *mut u8, block: __m512i) {
+    unsafe { pack_u7_registers(out, block) }
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Bitpack the provided block of integers to 8-bit elements.
+///
+/// # Safety
+/// - `out` must be safe to write `max_compressed_size::<X64>(8)` bytes to.
+/// - The runtime CPU must support the `avx512f` and `avx512bw` instructions.
+pub(crate) unsafe fn to_u8(out: *mut u8, block: __m512i) {
+    unsafe { store_u8x64(out, block) }
+}
@@ -0,0 +1,72 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Subtract the frame of reference from each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(data: __m512i, reference: u8) -> __m512i {
+    _mm512_sub_epi8(data, _mm512_set1_epi8(reference as i8))
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Add the frame of reference back to each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(data: __m512i, reference: u8) -> __m512i {
+    _mm512_add_epi8(data, _mm512_set1_epi8(reference as i8))
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+/// Returns the number of bits required to represent the largest of the first `n` 8-bit
+/// elements held in the provided register.
+pub(super) fn bit_length(data: &__m512i, n: usize) -> u32 {
+    let mask = if n >= 64 { u64::MAX } else { (1 << n) - 1 };
+    let lanes = _mm512_reduce_or_epi64(_mm512_maskz_mov_epi8(mask, *data)) as u64;
+
+    let lanes = lanes | (lanes >> 32);
+    let lanes = lanes | (lanes >> 16);
+    let lanes = lanes | (lanes >> 8);
+    8 - (lanes as u8).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+
+    #[test]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_sub_add_reference() {
+        let reference = u8::MAX - 80;
+        let input: [u8; X64] = core::array::from_fn(|i| reference + i as u8);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], __m512i>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<__m512i, [u8; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(64, 8)]
+    #[case::partial(5, 5)]
+    #[case::full(X64, 8)]
+    #[cfg_attr(
+        not(all(target_feature = "avx512f", target_feature = "avx512bw")),
+        ignore
+    )]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u8; X64] = core::array::from_fn(|i| 1 << (i % 8));
+        input[X64 - 1] = u8::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], __m512i>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
@@ -0,0 +1,635 @@
+//! 8-bit integer bitpacking routines
+#[cfg(any(
+    all(target_arch = "x86_64", any(feature = "avx2", feature = "avx512")),
+    all(target_arch = "aarch64", feature = "neon"),
+))]
+use crate::Backend;
+use crate::core::{CompressibleArray, CompressionDetails};
+use crate::{Codec, X64, X128};
+
+#[cfg(target_endian = "big")]
+compile_error!("big endian machines are not supported");
+
+#[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+pub mod avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+pub mod avx512;
+#[cfg(all(target_arch = "aarch64", feature = "neon"))]
+pub mod neon;
+pub mod scalar;
+#[cfg(test)]
+mod test_util;
+
+/// The maximum output size of a compressed buffer for a [X128] block, assuming worst case compression.
+pub const X128_MAX_OUTPUT_LEN: usize = <[u8; X128] as CompressibleArray>::MAX_OUTPUT_SIZE;
+
+#[track_caller]
+/// Returns the number of bytes that will be been written for a given bit length and number
+/// of elements that were packed when using the bitpacking functions.
+pub const fn max_compressed_size<const BLOCK_SIZE: usize>(bit_length: usize) -> usize {
+    const {
+        assert!(
+            BLOCK_SIZE == X128 || BLOCK_SIZE == X64,
+            "BLOCK_SIZE must be either X128 or X256"
+        )
+    };
+    compressed_size(bit_length, BLOCK_SIZE)
+}
+
+#[inline]
+#[track_caller]
+/// Returns the number of bytes that will be been written for a given bit length and number
+/// of elements that were packed when using the bitpacking functions.
+pub const fn compressed_size(bit_length: usize, num_elements: usize) -> usize {
+    assert!(bit_length <= 8, "bit length must be between 0 and 8");
+    if num_elements > 64 {
+        block_bytes(bit_length, 64) + block_bytes(bit_length, num_elements - 64)
+    } else {
+        block_bytes(bit_length, num_elements)
+    }
+}
+
+const fn block_bytes(bit_length: usize, num_elements: usize) -> usize {
+    let quotient = bit_length / 4;
+    let remainder = bit_length % 4;
+    let remainder_bytes = num_elements.div_ceil(8) * remainder;
+    (quotient * num_elements).div_ceil(2) + remainder_bytes
+}
+
+impl CompressibleArray for [u8; X128] {
+    type CompressedBuffer = [u8; Self::MAX_OUTPUT_SIZE];
+    type InitialValue = u8;
+    const MAX_OUTPUT_SIZE: usize = X128 * size_of::<u8>();
+
+    fn compress_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_x128(output, input, n) },
+            _ => unsafe { scalar::pack_x128(output, input, n) },
+        }
+    }
+
+    fn compress_delta_ref_with(
+        codec: Codec,
+        initial_value: u8,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_delta_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_delta_x128(initial_value, output, input, n) },
+            _ => unsafe { scalar::pack_delta_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
+        }
+    }
+
+    fn compress_delta_checked_with(
+        _codec: Codec,
+        initial_value: u8,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = unsafe { scalar::try_pack_delta_x128(initial_value, output, input, n) }?;
+
+        Ok(CompressionDetails {
+            last_value,
+            ..details
+        })
+    }
+
+    fn compress_delta1_ref_with(
+        codec: Codec,
+        initial_value: u8,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_delta1_x128(initial_value, output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_delta1_x128(initial_value, output, input, n) },
+            _ => unsafe { scalar::pack_delta1_x128(initial_value, output, input, n) },
+        };
+
+        CompressionDetails {
+            last_value,
+            ..details
+        }
+    }
+
+    fn compress_delta1_checked_with(
+        _codec: Codec,
+        initial_value: u8,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> Result<CompressionDetails, usize> {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        let last_value = Self::last_value(initial_value, n, input);
+        let details = unsafe { scalar::try_pack_delta1_x128(initial_value, output, input, n) }?;
+
+        Ok(CompressionDetails {
+            last_value,
+            ..details
+        })
+    }
+
+    fn compress_for_with(
+        codec: Codec,
+        n: usize,
+        input: &Self,
+        output: &mut Self::CompressedBuffer,
+    ) -> CompressionDetails {
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe { avx512::pack_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::pack_for_x128(output, input, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::pack_for_x128(output, input, n) },
+            _ => unsafe { scalar::pack_for_x128(output, input, n) },
+        }
+    }
+    fn decompress_unpadded_with(
+        codec: Codec,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 8,
+            "compressed bitlength must be no more than 8"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_x128(compressed_bit_length, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe { avx2::unpack_x128(compressed_bit_length, input, output, n) },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe { neon::unpack_x128(compressed_bit_length, input, output, n) },
+            _ => unsafe { scalar::unpack_x128(compressed_bit_length, input, output, n) },
+        }
+    }
+
+    fn decompress_delta_unpadded_with(
+        codec: Codec,
+        initial_value: u8,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 8,
+            "compressed bitlength must be no more than 8"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            _ => unsafe {
+                scalar::unpack_delta_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+        }
+    }
+
+    fn decompress_delta1_unpadded_with(
+        codec: Codec,
+        initial_value: u8,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 8,
+            "compressed bitlength must be no more than 8"
+        );
+        assert!(
+            input.len() >= compressed_size(compressed_bit_length as usize, n),
+            "input buffer is too small to hold the compressed block",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+            _ => unsafe {
+                scalar::unpack_delta1_x128(compressed_bit_length, initial_value, input, output, n)
+            },
+        }
+    }
+
+    fn decompress_for_with(
+        codec: Codec,
+        reference: u8,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        assert!(
+            compressed_bit_length <= 8,
+            "compressed bitlength must be no more than 8"
+        );
+        assert!(
+            input.len() >= max_compressed_size::<X128>(compressed_bit_length as usize),
+            "input buffer is too small/incorrectly padded to safely decompress",
+        );
+        assert!(n <= X128, "provided n is is greater than 128",);
+
+        match codec.backend() {
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => unsafe {
+                avx512::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "x86_64", feature = "avx2"))]
+            Backend::Avx2 => unsafe {
+                avx2::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => unsafe {
+                neon::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+            _ => unsafe {
+                scalar::unpack_for_x128(compressed_bit_length, reference, input, output, n)
+            },
+        }
+    }
+
+    fn decompress_with_offset_with(
+        codec: Codec,
+        base: u8,
+        n: usize,
+        compressed_bit_length: u8,
+        input: &[u8],
+        output: &mut Self,
+    ) -> usize {
+        Self::decompress_for_with(codec, base, n, compressed_bit_length, input, output)
+    }
+
+    fn encoding_bit_lengths(initial_value: u8, n: usize, input: &Self) -> [u8; 3] {
+        assert!(n <= X128, "provided n is is greater than 128");
+
+        unsafe {
+            [
+                scalar::bit_length_x128(input, n),
+                scalar::bit_length_delta_x128(initial_value, input, n),
+                scalar::bit_length_delta1_x128(initial_value, input, n),
+            ]
+        }
+    }
+
+    fn last_value(initial_value: u8, n: usize, input: &Self) -> u64 {
+        n.checked_sub(1).map_or(initial_value, |i| input[i]) as u64
+    }
+
+    fn encode_delta_in_place(mut last_value: u8, input: &mut Self, delta1: bool) {
+        for value in input.iter_mut() {
+            let current = *value;
+            *value = current.wrapping_sub(last_value).wrapping_sub(delta1 as u8);
+            last_value = current;
+        }
+    }
+
+    fn wrapping_offset(value: u8, base: u8) -> u8 {
+        value.wrapping_add(base)
+    }
+
+    fn compressed_buffer_mut(output: &mut [u8]) -> &mut Self::CompressedBuffer {
+        (&mut output[..Self::MAX_OUTPUT_SIZE]).try_into().unwrap()
+    }
+}
+
+#[inline]
+pub(super) fn split_block(block: &[u8; X128]) -> [&[u8; X64]; 2] {
+    crate::util::split_slice::<_, X128, X64>(block)
+}
+
+#[inline]
+pub(super) fn split_block_mut(block: &mut [u8; X128]) -> [&mut [u8; X64]; 2] {
+    crate::util::split_slice_mut::<_, X128, X64>(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case(8, 128)]
+    #[case(0, 0)]
+    #[should_panic(expected = "bit length must be between 0 and 8")]
+    #[case(9, 128)]
+    fn test_bitpacking_output_length_validation(
+        #[case] bit_length: usize,
+        #[case] num_elements: usize,
+    ) {
+        compressed_size(bit_length, num_elements);
+    }
+
+    #[test]
+    fn test_compress_and_decompress_one_exception() {
+        let mut values = [1u8; 128];
+        values[77] = 200;
+        let original_values = values;
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 8);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress(
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed, original_values);
+    }
+
+    #[rstest::rstest]
+    #[case::all_zeroes(0)]
+    #[case::all_nibble_max(0x0F)]
+    #[case::all_max(u8::MAX)]
+    fn test_compress_and_decompress_edge_cases(#[case] value: u8) {
+        let values = [value; 128];
+        let original_values = values;
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(X128, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress(
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed, original_values);
+    }
+
+    #[test]
+    fn test_compress_decompress_real_data_sample() {
+        let sample_data = crate::test_utils::load_sample_u32_doc_id_data_x128();
+
+        for select_n in 1..X128 {
+            let mut out = [0; X128_MAX_OUTPUT_LEN];
+            let mut decompressed = [0; X128];
+            for sample in sample_data.iter() {
+                let original: [u8; X128] = core::array::from_fn(|i| sample[i] as u8);
+                let details = crate::compress(select_n, &original, &mut out);
+                let read = crate::decompress(
+                    select_n,
+                    details.compressed_bit_length,
+                    &out,
+                    &mut decompressed,
+                );
+                assert_eq!(details.bytes_written, read);
+                assert_eq!(
+                    original[..select_n],
+                    decompressed[..select_n],
+                    "select_n:{select_n}, details:{details:?} read:{read}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequentially_increasing_delta() {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+
+        // Values wrap around `u8::MAX` a few times, the deltas must still be `1`.
+        let mut values: Vec<u8> = (0..896).map(|v: u32| v as u8).collect();
+        let mut last_value = u8::MAX;
+        for chunk in values.chunks_exact_mut(X128) {
+            let original = chunk.to_vec();
+            let input: &mut [u8; X128] = chunk.try_into().unwrap();
+
+            let details = crate::compress_delta(last_value, X128, input, &mut compressed);
+            assert_eq!(details.compressed_bit_length, 1);
+
+            let read = crate::decompress_delta(
+                last_value,
+                X128,
+                details.compressed_bit_length,
+                &compressed,
+                &mut decompressed,
+            );
+            assert_eq!(read, details.bytes_written);
+            assert_eq!(decompressed.as_slice(), original.as_slice());
+
+            last_value = *original.last().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sequentially_increasing_delta1() {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+
+        let mut values: Vec<u8> = (1..897).map(|v: u32| v as u8).collect();
+        let mut last_value = 0;
+        for chunk in values.chunks_exact_mut(X128) {
+            let original = chunk.to_vec();
+            let input: &mut [u8; X128] = chunk.try_into().unwrap();
+
+            let details = crate::compress_delta1(last_value, X128, input, &mut compressed);
+            assert_eq!(details.compressed_bit_length, 0);
+
+            let read = crate::decompress_delta1(
+                last_value,
+                X128,
+                details.compressed_bit_length,
+                &compressed,
+                &mut decompressed,
+            );
+            assert_eq!(read, details.bytes_written);
+            assert_eq!(decompressed.as_slice(), original.as_slice());
+
+            last_value = *original.last().unwrap();
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(1)]
+    #[case(37)]
+    #[case(64)]
+    #[case(100)]
+    #[case(128)]
+    fn test_delta_partial_block(#[case] n: usize) {
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let mut decompressed = [0; X128];
+
+        let mut values: [u8; X128] = core::array::from_fn(|i| (i * 2) as u8);
+        let original = values;
+
+        let details = crate::compress_delta(0, n, &mut values, &mut compressed);
+        let read = crate::decompress_delta(
+            0,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(read, details.bytes_written);
+        assert_eq!(decompressed[..n], original[..n]);
+    }
+
+    #[rstest::rstest]
+    fn test_compress_and_decompress_for(#[values(1, 37, 64, 100, X128)] n: usize) {
+        let values: [u8; X128] = core::array::from_fn(|i| 200 + (i * 7 % 37) as u8);
+        let min = values[..n].iter().copied().min().unwrap();
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(n, &values, &mut compressed);
+        assert_eq!(details.reference, min as u64);
+        assert!(details.compressed_bit_length <= 6);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_for(
+            details.reference as u8,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], values[..n]);
+    }
+
+    #[rstest::rstest]
+    #[case::all_zeroes(0)]
+    #[case::all_max(u8::MAX)]
+    fn test_compress_and_decompress_for_constant(#[case] value: u8) {
+        let values = [value; X128];
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 0);
+        assert_eq!(details.bytes_written, 0);
+
+        let mut decompressed = [0; X128];
+        crate::decompress_for(value, X128, 0, &compressed, &mut decompressed);
+        assert_eq!(decompressed, values);
+    }
+
+    #[test]
+    fn test_compress_and_decompress_for_full_range() {
+        let mut values = [u8::MAX; X128];
+        values[3] = 0;
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress_for(X128, &values, &mut compressed);
+        assert_eq!(details.compressed_bit_length, 8);
+        assert_eq!(details.reference, 0);
+
+        let mut decompressed = [0; X128];
+        crate::decompress_for(
+            0,
+            X128,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(decompressed, values);
+    }
+
+    #[rstest::rstest]
+    fn test_decompress_with_offset(
+        #[values(1, 37, 64, 100, X128)] n: usize,
+        #[values(0, 100, u8::MAX - 3)] base: u8,
+    ) {
+        let values: [u8; X128] = core::array::from_fn(|i| (i * 7 % 37) as u8);
+        let expected = values.map(|v| v.wrapping_add(base));
+
+        let mut compressed = [0; X128_MAX_OUTPUT_LEN];
+        let details = crate::compress(n, &values, &mut compressed);
+
+        let mut decompressed = [0; X128];
+        let bytes_read = crate::decompress_with_offset(
+            base,
+            n,
+            details.compressed_bit_length,
+            &compressed,
+            &mut decompressed,
+        );
+        assert_eq!(details.bytes_written, bytes_read);
+        assert_eq!(decompressed[..n], expected[..n]);
+    }
+}
@@ -0,0 +1,31 @@
+use core::arch::aarch64::*;
+
+#[inline]
+#[target_feature(enable = "neon")]
+/// Load 64 8-bit elements from `ptr` into 4, 128 bit registers.
+///
+/// # Safety
+/// - `ptr` must be safe to read `64` bytes from.
+pub(super) unsafe fn load_u8x64(ptr: *const u8) -> [uint8x16_t; 4] {
+    unsafe {
+        [
+            vld1q_u8(ptr.add(0)),
+            vld1q_u8(ptr.add(16)),
+            vld1q_u8(ptr.add(32)),
+            vld1q_u8(ptr.add(48)),
+        ]
+    }
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+/// Store 4, 128 bit registers holding 64 8-bit elements to `ptr`.
+///
+/// # Safety
+/// - `ptr` must be safe to write `64` bytes to.
+pub(super) unsafe fn store_u8x64(ptr: *mut u8, data: [uint8x16_t; 4]) {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        unsafe { vst1q_u8(ptr.add(i * 16), data[i]) };
+    }
+}
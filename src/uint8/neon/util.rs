@@ -0,0 +1,85 @@
+use core::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+/// Subtract the frame of reference from each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [uint8x16_t; 4], reference: u8) -> [uint8x16_t; 4] {
+    let reference = vdupq_n_u8(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = vsubq_u8(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Add the frame of reference back to each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [uint8x16_t; 4], reference: u8) -> [uint8x16_t; 4] {
+    let reference = vdupq_n_u8(reference);
+
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..4 {
+        data[i] = vaddq_u8(data[i], reference);
+    }
+    data
+}
+
+#[target_feature(enable = "neon")]
+/// Returns the number of bits required to represent the largest of the first `n` 8-bit
+/// elements held in the provided registers.
+pub(super) fn bit_length(data: &[uint8x16_t; 4], n: usize) -> u32 {
+    const POSITIONS: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+    let limit = vdupq_n_u8(n as u8);
+    let step = vdupq_n_u8(16);
+    let mut positions = unsafe { vld1q_u8(POSITIONS.as_ptr()) };
+
+    let mut acc = vdupq_n_u8(0);
+    for register in data {
+        let selected = vcltq_u8(positions, limit);
+        acc = vmaxq_u8(acc, vandq_u8(selected, *register));
+        positions = vaddq_u8(positions, step);
+    }
+
+    8 - vmaxvq_u8(acc).leading_zeros()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_sub_add_reference() {
+        let reference = u8::MAX - 80;
+        let input: [u8; X64] = core::array::from_fn(|i| reference + i as u8);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
+        let subtracted = unsafe { sub_reference(data, reference) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = unsafe { add_reference(subtracted, reference) };
+        let view = unsafe { core::mem::transmute::<[uint8x16_t; 4], [u8; X64]>(added) };
+        assert_eq!(view, input);
+    }
+
+    #[rstest::rstest]
+    #[case::empty(0, 0)]
+    #[case::first(1, 1)]
+    #[case::register_boundary(16, 8)]
+    #[case::partial(5, 5)]
+    #[case::full(X64, 8)]
+    #[cfg_attr(not(target_feature = "neon"), ignore)]
+    fn test_bit_length(#[case] n: usize, #[case] expected: u32) {
+        let mut input: [u8; X64] = core::array::from_fn(|i| 1 << (i % 8));
+        input[X64 - 1] = u8::MAX;
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [uint8x16_t; 4]>(input) };
+        assert_eq!(unsafe { bit_length(&data, n) }, expected);
+    }
+}
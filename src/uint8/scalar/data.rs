@@ -0,0 +1,25 @@
+use crate::uint32::scalar::polyfill::*;
+
+#[inline]
+/// Load 64 8-bit elements from `ptr` into 2, 256 bit registers.
+///
+/// # Safety
+/// - `ptr` must be safe to read `64` bytes from.
+pub(super) unsafe fn load_u8x64(ptr: *const u8) -> [u8x32; 2] {
+    unsafe {
+        [
+            _scalar_load_u8x32(ptr.add(0)),
+            _scalar_load_u8x32(ptr.add(32)),
+        ]
+    }
+}
+
+#[inline]
+/// Store 2, 256 bit registers holding 64 8-bit elements to `ptr`.
+///
+/// # Safety
+/// - `ptr` must be safe to write `64` bytes to.
+pub(super) unsafe fn store_u8x64(ptr: *mut u8, data: [u8x32; 2]) {
+    unsafe { _scalar_store_u8x32(ptr.add(0), data[0]) };
+    unsafe { _scalar_store_u8x32(ptr.add(32), data[1]) };
+}
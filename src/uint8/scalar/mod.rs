@@ -0,0 +1,260 @@
+use crate::uint8::{X128_MAX_OUTPUT_LEN, compressed_size};
+use crate::{CompressionDetails, X128};
+
+pub(super) mod data;
+mod pack_x128;
+pub(crate) mod pack_x64_full;
+pub(crate) mod pack_x64_partial;
+mod unpack_x128;
+pub(crate) mod unpack_x64_full;
+pub(crate) mod unpack_x64_partial;
+mod util;
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out`.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 8 - max.leading_zeros();
+
+    unsafe { pack_x128::to_nbits(nbits as usize, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: 0,
+        last_value: 0,
+    }
+}
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out` after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_delta_x128(
+    mut last_value: u8,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value);
+        last_value = value;
+    }
+
+    unsafe { pack_x128(out, &deltas, pack_n) }
+}
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_delta1_x128(
+    mut last_value: u8,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let mut deltas = [0; X128];
+    for (delta, &value) in deltas.iter_mut().zip(block).take(pack_n) {
+        *delta = value.wrapping_sub(last_value).wrapping_sub(1);
+        last_value = value;
+    }
+
+    unsafe { pack_x128(out, &deltas, pack_n) }
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_x128(block: &[u8; X128], pack_n: usize) -> u8 {
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    (8 - max.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta_x128(mut last_value: u8, block: &[u8; X128], pack_n: usize) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= value.wrapping_sub(last_value);
+        last_value = value;
+    }
+    (8 - acc.leading_zeros()) as u8
+}
+
+/// Returns the bit length required to pack the first `pack_n` integers of the block after
+/// applying Delta-1 encoding.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn bit_length_delta1_x128(mut last_value: u8, block: &[u8; X128], pack_n: usize) -> u8 {
+    let mut acc = 0;
+    for &value in block.iter().take(pack_n) {
+        acc |= value.wrapping_sub(last_value).wrapping_sub(1);
+        last_value = value;
+    }
+    (8 - acc.leading_zeros()) as u8
+}
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out` after
+/// applying Delta encoding, unless one of the integers is smaller than the integer before it,
+/// in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta_x128(
+    mut last_value: u8,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let mut deltas = [0; X128];
+    for (i, (delta, &value)) in deltas.iter_mut().zip(block).take(pack_n).enumerate() {
+        if value < last_value {
+            return Err(i);
+        }
+        *delta = value - last_value;
+        last_value = value;
+    }
+
+    Ok(unsafe { pack_x128(out, &deltas, pack_n) })
+}
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out` after
+/// applying Delta-1 encoding, unless one of the integers is not greater than the integer
+/// before it, in which case its position is returned and nothing is written.
+///
+/// The first integer is compared against `last_value`.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn try_pack_delta1_x128(
+    mut last_value: u8,
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> Result<CompressionDetails, usize> {
+    let mut deltas = [0; X128];
+    for (i, (delta, &value)) in deltas.iter_mut().zip(block).take(pack_n).enumerate() {
+        if value <= last_value {
+            return Err(i);
+        }
+        *delta = value - last_value - 1;
+        last_value = value;
+    }
+
+    Ok(unsafe { pack_x128(out, &deltas, pack_n) })
+}
+
+/// Unpack a block of 128 8-bit integers from `input`.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_x128(nbits: u8, input: &[u8], block: &mut [u8; X128], read_n: usize) -> usize {
+    unsafe { unpack_x128::from_nbits(nbits as usize, input.as_ptr(), block, read_n) };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 8-bit integers from `input` and reverse the
+/// applied Delta encoding.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_delta_x128(
+    nbits: u8,
+    last_value: u8,
+    input: &[u8],
+    block: &mut [u8; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_delta(nbits as usize, last_value, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Unpack a block of 128 8-bit integers from `input` and reverse the
+/// applied Delta-1 encoding.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_delta1_x128(
+    nbits: u8,
+    last_value: u8,
+    input: &[u8],
+    block: &mut [u8; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_delta1(nbits as usize, last_value, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
+
+/// Pack a block of 128 8-bit integers and write the compressed block to `out` after
+/// subtracting the minimum value of the block from each element.
+///
+/// # Safety
+/// - `pack_n` must be less than or equal to 128.
+pub unsafe fn pack_for_x128(
+    out: &mut [u8; X128_MAX_OUTPUT_LEN],
+    block: &[u8; X128],
+    pack_n: usize,
+) -> CompressionDetails {
+    let reference = block.iter().take(pack_n).copied().min().unwrap_or(0);
+    let max = block.iter().take(pack_n).fold(0, |a, b| a.max(*b));
+    let nbits = 8 - (max - reference).leading_zeros();
+
+    unsafe { pack_x128::to_nbits_for(nbits as usize, reference, out.as_mut_ptr(), block, pack_n) };
+
+    CompressionDetails {
+        compressed_bit_length: nbits as u8,
+        bytes_written: compressed_size(nbits as usize, pack_n),
+        reference: reference as u64,
+        last_value: 0,
+    }
+}
+
+/// Unpack a block of 128 8-bit integers and add the `reference` value back to each element.
+///
+/// # Safety
+/// - `read_n` must be less than or equal to 128.
+/// - `input` buffer must be able to hold the packed length of `read_n` values for a given bit
+///   length, the padding of a partial block is never read.
+/// - `nbits` must be no greater than `8`.
+pub unsafe fn unpack_for_x128(
+    nbits: u8,
+    reference: u8,
+    input: &[u8],
+    block: &mut [u8; X128],
+    read_n: usize,
+) -> usize {
+    unsafe {
+        unpack_x128::from_nbits_for(nbits as usize, reference, input.as_ptr(), block, read_n)
+    };
+    compressed_size(nbits as usize, read_n)
+}
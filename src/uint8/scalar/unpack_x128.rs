@@ -0,0 +1,282 @@
+use super::data::*;
+use super::util::add_reference;
+use super::{unpack_x64_full, unpack_x64_partial};
+use crate::uint8::{max_compressed_size, split_block_mut};
+use crate::{X64, X128};
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits(nbits: usize, input: *const u8, out: &mut [u8; X128], read_n: usize) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0, from_u1, from_u2, from_u3, from_u4, from_u5, from_u6, from_u7, from_u8,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(input, out, read_n) };
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta(
+    nbits: usize,
+    last_value: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits(nbits, input, out, read_n) };
+    decode_delta(last_value, out);
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements which have
+/// been delta-1-encoded.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_delta1(
+    nbits: usize,
+    last_value: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    unsafe { from_nbits(nbits, input, out, read_n) };
+    decode_delta1(last_value, out);
+}
+
+unsafe fn from_u0(_input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    out.fill(0);
+}
+
+macro_rules! define_x128_unpacker {
+    ($func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(input: *const u8, out: &mut [u8; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked = unsafe { unpack_x64_partial::$func_name(input.add(0), read_n) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+                let unpacked = unsafe {
+                    unpack_x64_partial::$func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), unpacked) };
+                let unpacked = unsafe {
+                    unpack_x64_full::$func_name(input.add(max_compressed_size::<X64>($bit_length)))
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), unpacked) };
+            }
+        }
+    };
+}
+
+define_x128_unpacker!(from_u1, 1);
+define_x128_unpacker!(from_u2, 2);
+define_x128_unpacker!(from_u3, 3);
+define_x128_unpacker!(from_u4, 4);
+define_x128_unpacker!(from_u5, 5);
+define_x128_unpacker!(from_u6, 6);
+define_x128_unpacker!(from_u7, 7);
+define_x128_unpacker!(from_u8, 8);
+
+fn decode_delta(mut last_value: u8, block: &mut [u8; X128]) -> u8 {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..128 {
+        last_value = last_value.wrapping_add(block[i]);
+        block[i] = last_value;
+    }
+    last_value
+}
+
+fn decode_delta1(mut last_value: u8, block: &mut [u8; X128]) -> u8 {
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..128 {
+        last_value = last_value.wrapping_add(block[i]).wrapping_add(1);
+        block[i] = last_value;
+    }
+    last_value
+}
+
+#[inline]
+/// Unpack the provided block of `nbits` bit length elements and add the `reference`
+/// value back to each element.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X128>(nbits)` bytes from.
+/// - `nbits` must be between 0 and 8.
+/// - `read_n` must be no greater than 128.
+pub unsafe fn from_nbits_for(
+    nbits: usize,
+    reference: u8,
+    input: *const u8,
+    out: &mut [u8; X128],
+    read_n: usize,
+) {
+    debug_assert!(nbits <= 8, "BUG: invalid nbits provided: {nbits}");
+    debug_assert!(read_n <= X128, "BUG: invalid read_n provided: {read_n}");
+    #[allow(clippy::type_complexity)]
+    const LUT: [unsafe fn(u8, input: *const u8, &mut [u8; X128], usize); 9] = [
+        from_u0_for,
+        from_u1_for,
+        from_u2_for,
+        from_u3_for,
+        from_u4_for,
+        from_u5_for,
+        from_u6_for,
+        from_u7_for,
+        from_u8_for,
+    ];
+    let func = unsafe { LUT.get_unchecked(nbits) };
+    unsafe { func(reference, input, out, read_n) };
+}
+
+unsafe fn from_u0_for(reference: u8, _input: *const u8, out: &mut [u8; X128], _read_n: usize) {
+    out.fill(reference);
+}
+
+macro_rules! define_x128_unpacker_for {
+    ($func_name:ident, $unpack_func_name:ident, $bit_length:expr) => {
+        unsafe fn $func_name(reference: u8, input: *const u8, out: &mut [u8; X128], read_n: usize) {
+            let [left, right] = split_block_mut(out);
+
+            if read_n <= 64 {
+                let unpacked =
+                    unsafe { unpack_x64_partial::$unpack_func_name(input.add(0), read_n) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+            } else if read_n < 128 {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+                let unpacked = unsafe {
+                    unpack_x64_partial::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                        read_n - X64,
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), add_reference(unpacked, reference)) };
+            } else {
+                let unpacked = unsafe { unpack_x64_full::$unpack_func_name(input.add(0)) };
+                unsafe { store_u8x64(left.as_mut_ptr(), add_reference(unpacked, reference)) };
+                let unpacked = unsafe {
+                    unpack_x64_full::$unpack_func_name(
+                        input.add(max_compressed_size::<X64>($bit_length)),
+                    )
+                };
+                unsafe { store_u8x64(right.as_mut_ptr(), add_reference(unpacked, reference)) };
+            }
+        }
+    };
+}
+
+define_x128_unpacker_for!(from_u1_for, from_u1, 1);
+define_x128_unpacker_for!(from_u2_for, from_u2, 2);
+define_x128_unpacker_for!(from_u3_for, from_u3, 3);
+define_x128_unpacker_for!(from_u4_for, from_u4, 4);
+define_x128_unpacker_for!(from_u5_for, from_u5, 5);
+define_x128_unpacker_for!(from_u6_for, from_u6, 6);
+define_x128_unpacker_for!(from_u7_for, from_u7, 7);
+define_x128_unpacker_for!(from_u8_for, from_u8, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uint8::X128_MAX_OUTPUT_LEN;
+    use crate::uint8::scalar::pack_x128::to_nbits_for;
+
+    #[test]
+    fn test_decode_delta_zero_starting_value() {
+        let expected_values: [u8; X128] = core::array::from_fn(|i| i as u8);
+        let mut block = [1; X128];
+        block[0] = 0;
+        decode_delta(0, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta_starting_value() {
+        let expected_values: [u8; X128] = core::array::from_fn(|i| 4 + i as u8);
+        let mut block = [1; X128];
+        block[0] = 0;
+        decode_delta(4, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta1_zero_starting_value() {
+        let expected_values: [u8; X128] = core::array::from_fn(|i| i as u8 + 1);
+        let mut block = [0; X128];
+        decode_delta1(0, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_decode_delta1_starting_value() {
+        let expected_values: [u8; X128] = core::array::from_fn(|i| i as u8 + 5);
+        let mut block = [0; X128];
+        decode_delta1(4, &mut block);
+        assert_eq!(block, expected_values);
+    }
+
+    #[test]
+    fn test_v1_layout_regression() {
+        let tester = crate::uint8::test_util::load_uint8_regression_layout();
+
+        let mut output_buffer = [0u8; X128];
+        for (len, bit_len, expected_output, input) in tester.iter_tests() {
+            unsafe { from_nbits(bit_len as usize, input.as_ptr(), &mut output_buffer, len) };
+
+            let produced_buffer = &output_buffer[..len];
+            assert_eq!(
+                produced_buffer,
+                &expected_output[..len],
+                "regression test failed, outputs do not match, length:{len} bit_len:{bit_len}"
+            )
+        }
+    }
+
+    #[rstest::rstest]
+    fn test_for_roundtrip(
+        #[values(0, 1, 3, 7, 8)] nbits: usize,
+        #[values(1, 37, 64, 100, X128)] n: usize,
+    ) {
+        let mask = if nbits == 0 {
+            0
+        } else {
+            u8::MAX >> (8 - nbits)
+        };
+        let reference = u8::MAX - mask;
+        let expected: [u8; X128] =
+            core::array::from_fn(|i| reference + ((i as u8).wrapping_mul(151) & mask));
+
+        let mut packed = [0; X128_MAX_OUTPUT_LEN];
+        let mut unpacked = [0; X128];
+        unsafe {
+            to_nbits_for(nbits, reference, packed.as_mut_ptr(), &expected, n);
+            from_nbits_for(nbits, reference, packed.as_ptr(), &mut unpacked, n);
+        }
+        assert_eq!(unpacked[..n], expected[..n]);
+    }
+}
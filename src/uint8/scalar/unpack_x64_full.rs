@@ -0,0 +1,113 @@
+use super::data::*;
+use crate::uint32::scalar::polyfill::u8x32;
+use crate::uint32::scalar::unpack_x64_full::{
+    unpack_u1_registers,
+    unpack_u2_registers,
+    unpack_u3_registers,
+    unpack_u4_registers,
+    unpack_u5_registers,
+    unpack_u6_registers,
+    unpack_u7_registers,
+};
+
+/// Unpack the 1-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(1)` bytes from.
+pub(crate) unsafe fn from_u1(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u1_registers(input) }
+}
+
+/// Unpack the 2-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(2)` bytes from.
+pub(crate) unsafe fn from_u2(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u2_registers(input) }
+}
+
+/// Unpack the 3-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(3)` bytes from.
+pub(crate) unsafe fn from_u3(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u3_registers(input) }
+}
+
+/// Unpack the 4-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(4)` bytes from.
+pub(crate) unsafe fn from_u4(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u4_registers(input) }
+}
+
+/// Unpack the 5-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(5)` bytes from.
+pub(crate) unsafe fn from_u5(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u5_registers(input) }
+}
+
+/// Unpack the 6-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(6)` bytes from.
+pub(crate) unsafe fn from_u6(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u6_registers(input) }
+}
+
+/// Unpack the 7-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(7)` bytes from.
+pub(crate) unsafe fn from_u7(input: *const u8) -> [u8x32; 2] {
+    unsafe { unpack_u7_registers(input) }
+}
+
+/// Unpack the 8-bit integers from the input pointer and return the result registers.
+///
+/// # Safety
+/// - `input` must be safe to read `max_compressed_size::<X64>(8)` bytes from.
+pub(crate) unsafe fn from_u8(input: *const u8) -> [u8x32; 2] {
+    unsafe { load_u8x64(input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+    use crate::uint8::max_compressed_size;
+    use crate::uint8::scalar::pack_x64_full;
+
+    #[rstest::rstest]
+    #[case(1, from_u1, pack_x64_full::to_u1)]
+    #[case(2, from_u2, pack_x64_full::to_u2)]
+    #[case(3, from_u3, pack_x64_full::to_u3)]
+    #[case(4, from_u4, pack_x64_full::to_u4)]
+    #[case(5, from_u5, pack_x64_full::to_u5)]
+    #[case(6, from_u6, pack_x64_full::to_u6)]
+    #[case(7, from_u7, pack_x64_full::to_u7)]
+    #[case(8, from_u8, pack_x64_full::to_u8)]
+    fn test_pack_unpack(
+        #[case] bit_len: usize,
+        #[case] unpacker: unsafe fn(*const u8) -> [u8x32; 2],
+        #[case] packer: unsafe fn(*mut u8, [u8x32; 2]),
+    ) {
+        let max_value = (u8::MAX >> (8 - bit_len)) as usize;
+        let expected: [u8; X64] = core::array::from_fn(|i| ((i * 7) % (max_value + 1)) as u8);
+
+        let mut packed = [0; X64];
+        unsafe { packer(packed.as_mut_ptr(), load_u8x64(expected.as_ptr())) };
+        assert!(
+            packed[max_compressed_size::<X64>(bit_len)..]
+                .iter()
+                .all(|b| *b == 0)
+        );
+
+        let mut output = [0; X64];
+        unsafe { store_u8x64(output.as_mut_ptr(), unpacker(packed.as_ptr())) };
+        assert_eq!(output, expected);
+    }
+}
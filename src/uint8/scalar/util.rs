@@ -0,0 +1,47 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::uint32::scalar::polyfill::u8x32;
+
+/// Subtract the frame of reference from each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn sub_reference(mut data: [u8x32; 2], reference: u8) -> [u8x32; 2] {
+    for i in 0..2 {
+        for j in 0..32 {
+            data[i][j] = data[i][j].wrapping_sub(reference);
+        }
+    }
+    data
+}
+
+/// Add the frame of reference back to each of the 8-bit elements held in the provided
+/// registers.
+pub(super) fn add_reference(mut data: [u8x32; 2], reference: u8) -> [u8x32; 2] {
+    for i in 0..2 {
+        for j in 0..32 {
+            data[i][j] = data[i][j].wrapping_add(reference);
+        }
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::X64;
+
+    #[test]
+    fn test_sub_add_reference() {
+        let reference = u8::MAX - 80;
+        let input: [u8; X64] = core::array::from_fn(|i| reference + i as u8);
+        let expected: [u8; X64] = core::array::from_fn(|i| i as u8);
+
+        let data = unsafe { core::mem::transmute::<[u8; X64], [u8x32; 2]>(input) };
+        let subtracted = sub_reference(data, reference);
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(subtracted) };
+        assert_eq!(view, expected);
+
+        let added = add_reference(subtracted, reference);
+        let view = unsafe { core::mem::transmute::<[u8x32; 2], [u8; X64]>(added) };
+        assert_eq!(view, input);
+    }
+}
@@ -14,3 +14,186 @@ pub(super) fn split_slice_mut<T, const N1: usize, const N2: usize>(
     let right: &mut [T; N2] = right.try_into().unwrap();
     [left, right]
 }
+
+#[inline]
+/// Split a slice of up to `2 * N` values into the two halves of a block.
+///
+/// Full halves are borrowed from `data` in place, only the final partial half is copied to
+/// `staged`, which also stands in for the right half when `data` fits within the left.
+pub(super) fn stage_halves<'a, T: Copy, const N: usize>(
+    data: &'a [T],
+    staged: &'a mut [T; N],
+) -> [&'a [T; N]; 2] {
+    let (full, tail) = data.as_chunks::<N>();
+    staged[..tail.len()].copy_from_slice(tail);
+
+    let staged = &*staged;
+    match full {
+        [] => [staged, staged],
+        [left] => [left, staged],
+        [left, right] => [left, right],
+        _ => unreachable!("BUG: slice holds more than two halves"),
+    }
+}
+
+#[inline]
+/// Select the two mutable halves of a block from the `full` halves of a slice of up to
+/// `2 * N` values, using `staged` for the final partial half and `spare` for a right half
+/// which is not part of the slice.
+///
+/// The values written to `staged` must be copied back to the tail of the slice.
+pub(super) fn stage_halves_mut<'a, T, const N: usize>(
+    full: &'a mut [[T; N]],
+    staged: &'a mut [T; N],
+    spare: &'a mut [T; N],
+) -> [&'a mut [T; N]; 2] {
+    match full {
+        [] => [staged, spare],
+        [left] => [left, staged],
+        [left, right] => [left, right],
+        _ => unreachable!("BUG: slice holds more than two halves"),
+    }
+}
+
+#[inline(always)]
+/// Read up to `N` bytes of a packed region holding `len` bytes, zeroing the rest.
+///
+/// The unpack kernels load whole registers, this bounds the loads from the final partial
+/// half of a block to the bytes the region actually occupies.
+///
+/// # Safety
+/// - `input` must be safe to read `min(len, N)` bytes from.
+pub(super) unsafe fn read_partial<const N: usize>(input: *const u8, len: usize) -> [u8; N] {
+    let mut buffer = [0; N];
+    unsafe { core::ptr::copy_nonoverlapping(input, buffer.as_mut_ptr(), len.min(N)) };
+    buffer
+}
+
+#[inline]
+/// Reinterpret a block of signed 32-bit integers as their unsigned counterparts.
+pub(super) fn as_unsigned_i32<const N: usize>(data: &[i32; N]) -> &[u32; N] {
+    // SAFETY: `i32` and `u32` share the same size and alignment and every bit pattern is valid.
+    unsafe { &*(data as *const [i32; N]).cast::<[u32; N]>() }
+}
+
+#[inline]
+/// Reinterpret a mutable block of signed 32-bit integers as their unsigned counterparts.
+pub(super) fn as_unsigned_i32_mut<const N: usize>(data: &mut [i32; N]) -> &mut [u32; N] {
+    // SAFETY: `i32` and `u32` share the same size and alignment and every bit pattern is valid.
+    unsafe { &mut *(data as *mut [i32; N]).cast::<[u32; N]>() }
+}
+
+#[inline]
+/// Reinterpret a block of signed 16-bit integers as their unsigned counterparts.
+pub(super) fn as_unsigned_i16<const N: usize>(data: &[i16; N]) -> &[u16; N] {
+    // SAFETY: `i16` and `u16` share the same size and alignment and every bit pattern is valid.
+    unsafe { &*(data as *const [i16; N]).cast::<[u16; N]>() }
+}
+
+#[inline]
+/// Reinterpret a mutable block of signed 16-bit integers as their unsigned counterparts.
+pub(super) fn as_unsigned_i16_mut<const N: usize>(data: &mut [i16; N]) -> &mut [u16; N] {
+    // SAFETY: `i16` and `u16` share the same size and alignment and every bit pattern is valid.
+    unsafe { &mut *(data as *mut [i16; N]).cast::<[u16; N]>() }
+}
+
+#[inline]
+/// ZigZag encode a signed 32-bit integer, mapping values of a small magnitude,
+/// positive or negative, to small unsigned values.
+pub(super) const fn zigzag_encode_i32(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+#[inline]
+/// Reverse the ZigZag encoding of a 32-bit integer.
+pub(super) const fn zigzag_decode_i32(value: u32) -> i32 {
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+#[inline]
+/// ZigZag encode a signed 16-bit integer, mapping values of a small magnitude,
+/// positive or negative, to small unsigned values.
+pub(super) const fn zigzag_encode_i16(value: i16) -> u16 {
+    ((value << 1) ^ (value >> 15)) as u16
+}
+
+#[inline]
+/// Reverse the ZigZag encoding of a 16-bit integer.
+pub(super) const fn zigzag_decode_i16(value: u16) -> i16 {
+    ((value >> 1) as i16) ^ -((value & 1) as i16)
+}
+
+#[derive(Debug, Copy, Clone)]
+/// The layout of a packed [crate::X64] half of a block.
+pub(super) enum HalfLayout {
+    /// The half was packed in full and the element order was not maintained.
+    Unordered,
+    /// The first `pack_n` elements of the half were packed with their order maintained.
+    Ordered { pack_n: usize },
+}
+
+impl HalfLayout {
+    /// Returns the number of bytes a region of 8-bit lanes occupies in the half.
+    pub(super) const fn lane_stride(self) -> usize {
+        match self {
+            HalfLayout::Unordered => crate::X64,
+            HalfLayout::Ordered { pack_n } => pack_n,
+        }
+    }
+}
+
+/// Read the `bit_length` bit value of a single 8-bit `lane` packed into `region`.
+///
+/// Only the nibble and bit-plane bytes the lane occupies are read.
+pub(super) fn unpack_lane(region: &[u8], bit_length: usize, lane: usize, layout: HalfLayout) -> u8 {
+    debug_assert!(
+        bit_length <= 8,
+        "BUG: invalid bit_length provided: {bit_length}"
+    );
+    match layout {
+        HalfLayout::Unordered => unpack_lane_unordered(region, bit_length, lane),
+        HalfLayout::Ordered { pack_n } => unpack_lane_ordered(region, bit_length, lane, pack_n),
+    }
+}
+
+fn unpack_lane_unordered(region: &[u8], bit_length: usize, lane: usize) -> u8 {
+    let u1 = |region: &[u8]| (region[lane / 8] >> (lane % 8)) & 0b1;
+    let u2 = |region: &[u8]| {
+        // Nibble pairs are merged as 16-bit words, the second 8 words being shifted by 2.
+        let nibble = lane % 32;
+        let word = nibble / 2;
+        let shift = (lane / 32) * 4 + (word / 8) * 2;
+        (region[(word % 8) * 2 + nibble % 2] >> shift) & 0b11
+    };
+    let u4 = |region: &[u8]| (region[lane % 32] >> ((lane / 32) * 4)) & 0b1111;
+
+    match bit_length {
+        0 => 0,
+        1 => u1(region),
+        2 => u2(region),
+        3 => u2(region) | (u1(&region[16..]) << 2),
+        4 => u4(region),
+        5 => u4(region) | (u1(&region[32..]) << 4),
+        6 => u4(region) | (u2(&region[32..]) << 4),
+        7 => u4(region) | (u2(&region[32..]) << 4) | (u1(&region[48..]) << 6),
+        _ => region[lane],
+    }
+}
+
+fn unpack_lane_ordered(region: &[u8], bit_length: usize, lane: usize, pack_n: usize) -> u8 {
+    let bit_planes = |region: &[u8], num_planes: usize| {
+        let step = pack_n.div_ceil(8);
+        (0..num_planes).fold(0, |value, plane| {
+            let bit = (region[plane * step + lane / 8] >> (lane % 8)) & 0b1;
+            value | (bit << plane)
+        })
+    };
+    let u4 = |region: &[u8]| (region[lane / 2] >> ((lane % 2) * 4)) & 0b1111;
+
+    match bit_length {
+        0..=3 => bit_planes(region, bit_length),
+        4 => u4(region),
+        5..=7 => u4(region) | (bit_planes(&region[pack_n.div_ceil(2)..], bit_length - 4) << 4),
+        _ => region[lane],
+    }
+}
@@ -112,3 +112,231 @@ fn test_uint16_compress_decompress_delta1() {
     );
     assert_eq!(read_n, details.bytes_written);
 }
+
+#[test]
+fn test_try_decompress_invalid_args() {
+    let compressed = [0; upack::uint16::X128_MAX_OUTPUT_LEN];
+    let mut decompressed: [u16; X128] = [0; X128];
+
+    let err = upack::try_decompress(X128, 17, &compressed, &mut decompressed).unwrap_err();
+    assert_eq!(
+        err,
+        upack::Error::InvalidBitLength {
+            bit_length: 17,
+            max_bit_length: 16
+        }
+    );
+
+    let err =
+        upack::try_decompress_delta(0, X128, 4, &compressed[..63], &mut decompressed).unwrap_err();
+    assert_eq!(
+        err,
+        upack::Error::InputTooShort {
+            required: 64,
+            actual: 63
+        }
+    );
+
+    let err =
+        upack::try_decompress_delta1(0, X128 + 1, 4, &compressed, &mut decompressed).unwrap_err();
+    assert_eq!(err, upack::Error::TooManyElements { n: X128 + 1 });
+
+    let err = upack::try_compress(X128 + 1, &decompressed, &mut [0; 256]).unwrap_err();
+    assert_eq!(err, upack::Error::TooManyElements { n: X128 + 1 });
+}
+
+#[test]
+fn test_try_decompress_corrupt_input_never_panics() {
+    let mut state = 0x9E3779B97F4A7C15u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut garbage = [0u8; upack::uint32::X128_MAX_OUTPUT_LEN];
+    let mut decompressed: [u32; X128] = [0; X128];
+    for _ in 0..2_000 {
+        garbage.iter_mut().for_each(|b| *b = next() as u8);
+        let n = (next() % 160) as usize;
+        let bit_length = (next() % 40) as u8;
+        let len = (next() as usize) % (garbage.len() + 1);
+        let input = &garbage[..len];
+
+        let expect_ok = n <= X128 && bit_length <= 32 && len >= bit_length as usize * 16;
+        let initial_value = next() as u32;
+        let results = [
+            upack::try_decompress(n, bit_length, input, &mut decompressed),
+            upack::try_decompress_delta(initial_value, n, bit_length, input, &mut decompressed),
+            upack::try_decompress_delta1(initial_value, n, bit_length, input, &mut decompressed),
+        ];
+        for result in results {
+            assert_eq!(
+                result.is_ok(),
+                expect_ok,
+                "n:{n} bit_length:{bit_length} len:{len}"
+            );
+        }
+    }
+}
+
+macro_rules! unpadded_round_trip {
+    ($name:ident, $t:ty, $max_output_len:expr, $compressed_size:path) => {
+        #[test]
+        fn $name() {
+            let mut compressed = [0; $max_output_len];
+            let mut expected: [$t; X128] = [0; X128];
+            let mut decompressed: [$t; X128] = [0; X128];
+            for bits in 0..=<$t>::BITS {
+                let mask = <$t>::MAX.checked_shr(<$t>::BITS - bits).unwrap_or(0);
+                let values: [$t; X128] =
+                    std::array::from_fn(|i| (i as $t).wrapping_mul(151).wrapping_add(7) & mask);
+
+                for n in [1, 7, 63, 64, 65, 100, X128] {
+                    let details = upack::compress(n, &values, &mut compressed);
+                    let len = $compressed_size(details.compressed_bit_length as usize, n);
+                    upack::decompress(n, details.compressed_bit_length, &compressed, &mut expected);
+
+                    // An exact length allocation so reads past the block are not hidden by padding.
+                    let exact = compressed[..len].to_vec();
+                    let read = upack::decompress_unpadded(
+                        n,
+                        details.compressed_bit_length,
+                        &exact,
+                        &mut decompressed,
+                    );
+                    assert_eq!(read, len, "bits:{bits} n:{n}");
+                    assert_eq!(decompressed[..n], expected[..n], "bits:{bits} n:{n}");
+                }
+            }
+        }
+    };
+}
+
+unpadded_round_trip!(
+    test_uint8_decompress_unpadded,
+    u8,
+    upack::uint8::X128_MAX_OUTPUT_LEN,
+    upack::uint8::compressed_size
+);
+unpadded_round_trip!(
+    test_uint16_decompress_unpadded,
+    u16,
+    upack::uint16::X128_MAX_OUTPUT_LEN,
+    upack::uint16::compressed_size
+);
+unpadded_round_trip!(
+    test_uint32_decompress_unpadded,
+    u32,
+    upack::uint32::X128_MAX_OUTPUT_LEN,
+    upack::uint32::compressed_size
+);
+unpadded_round_trip!(
+    test_uint64_decompress_unpadded,
+    u64,
+    upack::uint64::X128_MAX_OUTPUT_LEN,
+    upack::uint64::compressed_size
+);
+
+#[test]
+fn test_uint32_decompress_delta_unpadded() {
+    let mut samples = load_sample_u32_doc_id_data_x128();
+
+    let mut compressed = [0; upack::uint32::X128_MAX_OUTPUT_LEN];
+    let mut decompressed: [u32; X128] = [0; X128];
+    for sample in samples.iter_mut() {
+        let expected = *sample;
+        let details = upack::compress_delta(0, 100, sample, &mut compressed);
+        let exact = &compressed[..details.bytes_written];
+
+        let read = upack::decompress_delta_unpadded(
+            0,
+            100,
+            details.compressed_bit_length,
+            exact,
+            &mut decompressed,
+        );
+        assert_eq!(read, details.bytes_written);
+        assert_eq!(decompressed[..100], expected[..100]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "input buffer is too small to hold the compressed block")]
+fn test_decompress_unpadded_truncated() {
+    let mut decompressed: [u32; X128] = [0; X128];
+    let len = upack::uint32::compressed_size(5, 10);
+    upack::decompress_unpadded(10, 5, &vec![0; len - 1], &mut decompressed);
+}
+
+#[test]
+fn test_uint32_compress_delta_checked() {
+    let samples = load_sample_u32_doc_id_data_x128();
+
+    let mut compressed = [0; upack::uint32::X128_MAX_OUTPUT_LEN];
+    for sample in samples.iter() {
+        let Some(initial_value) = sample[0].checked_sub(1) else {
+            continue;
+        };
+        let details =
+            upack::compress_delta_checked(initial_value, X128, sample, &mut compressed).unwrap();
+        let expected = upack::compress_delta_ref(initial_value, X128, sample, &mut compressed);
+        assert_eq!(
+            details.compressed_bit_length,
+            expected.compressed_bit_length
+        );
+        upack::compress_delta1_checked(initial_value, X128, sample, &mut compressed).unwrap();
+
+        for index in [0, 1, 31, 63, 64, 65, 100, X128 - 1] {
+            let mut unsorted = *sample;
+            unsorted[index] = if index == 0 {
+                0
+            } else {
+                unsorted[index - 1] - 1
+            };
+
+            let err =
+                upack::compress_delta_checked(initial_value, X128, &unsorted, &mut compressed)
+                    .unwrap_err();
+            assert_eq!(err, upack::Error::Unsorted { index });
+
+            let err =
+                upack::compress_delta_checked(initial_value, index, &unsorted, &mut compressed);
+            assert!(err.is_ok(), "values after n should not be checked");
+        }
+    }
+
+    let err = upack::compress_delta_checked(5, X128, &[5u32; X128], &mut compressed);
+    assert!(err.is_ok());
+    let err = upack::compress_delta1_checked(4, X128, &[5u32; X128], &mut compressed);
+    assert_eq!(err.unwrap_err(), upack::Error::Unsorted { index: 1 });
+    let err = upack::compress_delta1_checked(5, X128, &[5u32; X128], &mut compressed);
+    assert_eq!(err.unwrap_err(), upack::Error::Unsorted { index: 0 });
+    let err = upack::compress_delta_checked(0, X128 + 1, &[0u32; X128], &mut compressed);
+    assert_eq!(
+        err.unwrap_err(),
+        upack::Error::TooManyElements { n: X128 + 1 }
+    );
+}
+
+#[test]
+fn test_compress_delta_checked_element_types() {
+    let mut values: [u16; X128] = std::array::from_fn(|i| i as u16 * 3);
+    values[70] = 1;
+    let err = upack::compress_delta_checked(0, X128, &values, &mut [0; 256]);
+    assert_eq!(err.unwrap_err(), upack::Error::Unsorted { index: 70 });
+
+    let mut values: [u8; X128] = std::array::from_fn(|i| i as u8 + 1);
+    values[5] = 5;
+    let err = upack::compress_delta1_checked(0, X128, &values, &mut [0; 128]);
+    assert_eq!(err.unwrap_err(), upack::Error::Unsorted { index: 5 });
+
+    let mut values: [u64; X128] = std::array::from_fn(|i| u64::MAX / 2 + i as u64);
+    values[127] = 0;
+    let err = upack::compress_delta_checked(u64::MAX / 2, X128, &values, &mut [0; 1024]);
+    assert_eq!(err.unwrap_err(), upack::Error::Unsorted { index: 127 });
+
+    let values: [i32; X128] = std::array::from_fn(|i| 64 - i as i32);
+    assert!(upack::compress_delta1_checked(0, X128, &values, &mut [0; 512]).is_ok());
+}
@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 use anyhow::Context;
 use clap::Parser;
 use serde::Serialize;
-use upack::{X128, uint16, uint32};
+use upack::{X128, uint8, uint16, uint32, uint64};
 
 const SEEDS: [u64; 3] = [287365827356235, 93847569834756, 52673285690852452];
 
@@ -22,6 +22,8 @@ fn main() -> anyhow::Result<()> {
 
     process_uint32(&args.output).context("generate uint32")?;
     process_uint16(&args.output).context("generate uint16")?;
+    process_uint64(&args.output).context("generate uint64")?;
+    process_uint8(&args.output).context("generate uint8")?;
 
     tracing::info!("done!");
 
@@ -68,6 +70,46 @@ fn process_uint16(output: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn process_uint64(output: &Path) -> anyhow::Result<()> {
+    let uint64_path = output.join("uint64");
+    let _ = std::fs::create_dir_all(&uint64_path);
+
+    let mut metadata = Metadata {
+        seeds: SEEDS.to_vec(),
+        ..Default::default()
+    };
+
+    for seed in SEEDS {
+        generate_permutations_uint64(&uint64_path, &mut metadata, seed)
+            .context("generate permutations")?;
+    }
+
+    let dumped = serde_json::to_vec(&metadata).context("serialize metadata")?;
+    std::fs::write(uint64_path.join("metadata.json"), dumped).context("write metadata")?;
+
+    Ok(())
+}
+
+fn process_uint8(output: &Path) -> anyhow::Result<()> {
+    let uint8_path = output.join("uint8");
+    let _ = std::fs::create_dir_all(&uint8_path);
+
+    let mut metadata = Metadata {
+        seeds: SEEDS.to_vec(),
+        ..Default::default()
+    };
+
+    for seed in SEEDS {
+        generate_permutations_uint8(&uint8_path, &mut metadata, seed)
+            .context("generate permutations")?;
+    }
+
+    let dumped = serde_json::to_vec(&metadata).context("serialize metadata")?;
+    std::fs::write(uint8_path.join("metadata.json"), dumped).context("write metadata")?;
+
+    Ok(())
+}
+
 #[derive(Default, Serialize)]
 struct Metadata {
     seeds: Vec<u64>,
@@ -199,3 +241,120 @@ fn generate_permutations_uint16(
 
     Ok(())
 }
+
+fn generate_permutations_uint64(
+    output: &Path,
+    metadata: &mut Metadata,
+    seed: u64,
+) -> anyhow::Result<()> {
+    fastrand::seed(seed);
+
+    let mut temp_output = [0; uint64::X128_MAX_OUTPUT_LEN];
+
+    let mut output_buffer = Vec::new();
+    let mut input_buffer = Vec::new();
+
+    for len in 1..=X128 {
+        for bit_len in 1..=64 {
+            let max_value = u64::MAX >> (64 - bit_len);
+            let min_value = 1 << (bit_len - 1);
+
+            let mut sample = [0; X128];
+            for v in sample.iter_mut() {
+                *v = fastrand::u64(min_value..=max_value);
+            }
+
+            let output = upack::compress(len, &sample, &mut temp_output);
+            assert_eq!(
+                output.compressed_bit_length, bit_len as u8,
+                "bit len doesn't match expected"
+            );
+
+            let output_start = output_buffer.len();
+            output_buffer.extend_from_slice(&temp_output[..output.bytes_written]);
+
+            let input_start = input_buffer.len();
+            input_buffer.extend_from_slice(&sample);
+
+            let block = BlockMetadata {
+                seed,
+                compressed_start: output_start,
+                compressed_length: output.bytes_written,
+                input_start,
+            };
+
+            metadata
+                .offsets
+                .entry(format!("len:{len},bit:{bit_len}"))
+                .or_default()
+                .push(block);
+        }
+    }
+
+    let raw_buffer: &[u8] = bytemuck::cast_slice(&input_buffer);
+    std::fs::write(output.join(format!("seeded-{seed}.raw")), raw_buffer)
+        .context("write raw input buffer")?;
+
+    std::fs::write(output.join(format!("seeded-{seed}.upack")), output_buffer)
+        .context("write compressed buffer")?;
+
+    Ok(())
+}
+
+fn generate_permutations_uint8(
+    output: &Path,
+    metadata: &mut Metadata,
+    seed: u64,
+) -> anyhow::Result<()> {
+    fastrand::seed(seed);
+
+    let mut temp_output = [0; uint8::X128_MAX_OUTPUT_LEN];
+
+    let mut output_buffer = Vec::new();
+    let mut input_buffer = Vec::new();
+
+    for len in 1..=X128 {
+        for bit_len in 1..=8 {
+            let max_value = u8::MAX >> (8 - bit_len);
+            let min_value = 1 << (bit_len - 1);
+
+            let mut sample = [0; X128];
+            for v in sample.iter_mut() {
+                *v = fastrand::u8(min_value..=max_value);
+            }
+
+            let output = upack::compress(len, &sample, &mut temp_output);
+            assert_eq!(
+                output.compressed_bit_length, bit_len as u8,
+                "bit len doesn't match expected"
+            );
+
+            let output_start = output_buffer.len();
+            output_buffer.extend_from_slice(&temp_output[..output.bytes_written]);
+
+            let input_start = input_buffer.len();
+            input_buffer.extend_from_slice(&sample);
+
+            let block = BlockMetadata {
+                seed,
+                compressed_start: output_start,
+                compressed_length: output.bytes_written,
+                input_start,
+            };
+
+            metadata
+                .offsets
+                .entry(format!("len:{len},bit:{bit_len}"))
+                .or_default()
+                .push(block);
+        }
+    }
+
+    std::fs::write(output.join(format!("seeded-{seed}.raw")), input_buffer)
+        .context("write raw input buffer")?;
+
+    std::fs::write(output.join(format!("seeded-{seed}.upack")), output_buffer)
+        .context("write compressed buffer")?;
+
+    Ok(())
+}